@@ -122,6 +122,7 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type HeldCurrencyIds = ();
 }
 
 pub struct MockCashModule;
@@ -24,6 +24,12 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!   - `surplus auction`: sell excessive surplus for getting native coin to burn
+//!   - `debit auction`: inflate some native token to sell for getting stable coin to eliminate excessive
+//!     bad debit
+//!   - `dutch collateral auction`: sell collateral assets at a descending price, any keeper can buy
+//!     part of the collateral at the current price and settle instantly, the auction can be restarted
+//!     at the latest oracle price once its price has decayed to the floor price
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -38,19 +44,22 @@ use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult
 use orml_utilities::OffchainErr;
 use primitives::{AuctionId, Balance, CurrencyId};
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	offchain::{
 		storage::StorageValueRef,
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{AtLeast32BitUnsigned, CheckedDiv, One, Saturating, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, PriceProvider, Rate};
+use support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, Price, PriceProvider, Rate, Ratio,
+};
 
 mod mock;
 mod tests;
@@ -123,6 +132,121 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of an debit auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug)]
+pub struct DebitAuctionItem<BlockNumber> {
+	/// Initial amount of native currency for sale
+	#[codec(compact)]
+	initial_amount: Balance,
+	/// Current amount of native currency for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Fix amount of debit value(stable currency) which want to get by this
+	/// auction
+	#[codec(compact)]
+	fix: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
+impl<BlockNumber> DebitAuctionItem<BlockNumber> {
+	/// Return amount for sale at specific last bid price and new bid price
+	fn amount_for_sale(&self, last_bid_price: Balance, new_bid_price: Balance) -> Balance {
+		if new_bid_price > last_bid_price && new_bid_price > self.fix {
+			Rate::checked_from_rational(sp_std::cmp::max(last_bid_price, self.fix), new_bid_price)
+				.and_then(|n| n.checked_mul_int(self.amount))
+				.unwrap_or(self.amount)
+		} else {
+			self.amount
+		}
+	}
+}
+
+/// Information of an surplus auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug)]
+pub struct SurplusAuctionItem<BlockNumber> {
+	/// Fixed amount of surplus(stable currency) for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
+/// Price decay curve of dutch collateral auction
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum PriceDecayCurve<BlockNumber> {
+	/// Price decreases linearly from the start price and reaches the floor
+	/// price after `duration` blocks
+	Linear { duration: BlockNumber },
+	/// Price is multiplied by `cut` every `step` blocks until it reaches the
+	/// floor price
+	StairstepExponential { step: BlockNumber, cut: Ratio },
+}
+
+/// Parameters of dutch collateral auction for specific collateral type
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct DutchAuctionParameters<BlockNumber> {
+	/// The premium on oracle price as the start price of dutch auction,
+	/// start_price = oracle_price * (1 + start_premium)
+	pub start_premium: Rate,
+	/// The ratio of oracle price as the floor price of dutch auction,
+	/// floor_price = oracle_price * floor_ratio
+	pub floor_ratio: Ratio,
+	/// The curve of price decay
+	pub curve: PriceDecayCurve<BlockNumber>,
+}
+
+/// Information of an dutch collateral auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug)]
+pub struct DutchCollateralAuctionItem<AccountId, BlockNumber> {
+	/// Collateral auction info, `amount` and `target` are the remainders
+	/// after collateral has been taken
+	auction: CollateralAuctionItem<AccountId, BlockNumber>,
+	/// Start price of collateral in stable currency
+	start_price: Price,
+	/// Floor price of collateral in stable currency
+	floor_price: Price,
+	/// Price decay curve of this auction
+	curve: PriceDecayCurve<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> DutchCollateralAuctionItem<AccountId, BlockNumber> {
+	/// Return the price of collateral in stable currency at specific block
+	fn current_price(&self, now: BlockNumber) -> Price {
+		let elapsed = now.saturating_sub(self.auction.start_time);
+		let price = match self.curve {
+			PriceDecayCurve::Linear { duration } => {
+				if elapsed >= duration {
+					self.floor_price
+				} else {
+					let decay_ratio = Ratio::checked_from_rational::<u128, u128>(
+						elapsed.unique_saturated_into(),
+						duration.unique_saturated_into(),
+					)
+					.unwrap_or_else(Ratio::one);
+					self.start_price.saturating_sub(
+						self.start_price
+							.saturating_sub(self.floor_price)
+							.saturating_mul(decay_ratio),
+					)
+				}
+			}
+			PriceDecayCurve::StairstepExponential { step, cut } => {
+				let steps: u32 = elapsed
+					.checked_div(&step)
+					.unwrap_or_else(Zero::zero)
+					.unique_saturated_into();
+				self.start_price.saturating_mul(cut.saturating_pow(steps as usize))
+			}
+		};
+
+		sp_std::cmp::max(price, self.floor_price)
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -148,6 +272,10 @@ pub mod module {
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
 
+		/// The native currency id
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
 		/// Currency to transfer assets
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
@@ -179,6 +307,10 @@ pub mod module {
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The origin which may update dutch auction parameters. Root can
+		/// always do this.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -197,11 +329,24 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// Current price of dutch collateral auction is higher than the max
+		/// price
+		ExceedMaxPrice,
+		/// Dutch auction parameters are invalid
+		InvalidDutchAuctionParams,
+		/// Price of dutch collateral auction has not decayed to the floor
+		/// price yet
+		AuctionNotReachFloorPrice,
+		/// System has already been shutdown
+		AlreadyShutdown,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		Option<DutchAuctionParameters<T::BlockNumber>> = "Option<DutchAuctionParameters<BlockNumber>>"
+	)]
 	pub enum Event<T: Config> {
 		/// Collateral auction created. \[auction_id, collateral_type,
 		/// collateral_amount, target_bid_price\]
@@ -214,6 +359,29 @@ pub mod module {
 		/// Dex take collateral auction. \[auction_id, collateral_type,
 		/// collateral_amount, turnover\]
 		DEXTakeCollateralAuction(AuctionId, CurrencyId, Balance, Balance),
+		/// Dutch collateral auction created. \[auction_id, collateral_type,
+		/// collateral_amount, target_bid_price, start_price\]
+		NewDutchCollateralAuction(AuctionId, CurrencyId, Balance, Balance, Price),
+		/// Collateral of dutch collateral auction taken. \[auction_id,
+		/// collateral_type, collateral_amount, buyer, payment_amount\]
+		DutchCollateralAuctionTaken(AuctionId, CurrencyId, Balance, T::AccountId, Balance),
+		/// Dutch collateral auction restarted. \[auction_id, start_price,
+		/// floor_price\]
+		DutchCollateralAuctionReset(AuctionId, Price, Price),
+		/// Dutch auction parameters updated. \[collateral_type,
+		/// new_parameters\]
+		DutchAuctionParamsUpdated(CurrencyId, Option<DutchAuctionParameters<T::BlockNumber>>),
+		/// Debit auction created. \[auction_id, initial_supply_amount,
+		/// fix_payment_amount\]
+		NewDebitAuction(AuctionId, Balance, Balance),
+		/// Surplus auction created. \[auction_id, fix_surplus_amount\]
+		NewSurplusAuction(AuctionId, Balance),
+		/// Debit auction dealt. \[auction_id, debit_auction_amount, winner,
+		/// payment_amount\]
+		DebitAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
+		/// Surplus auction dealt. \[auction_id, surplus_auction_amount, winner,
+		/// payment_amount\]
+		SurplusAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -239,6 +407,52 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to debit auction info
+	///
+	/// DebitAuctions: map AuctionId => Option<DebitAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auctions)]
+	pub type DebitAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DebitAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from auction id to surplus auction info
+	///
+	/// SurplusAuctions: map AuctionId => Option<SurplusAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auctions)]
+	pub type SurplusAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, SurplusAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Record of total fix amount of all active debit auctions
+	///
+	/// TotalDebitInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_debit_in_auction)]
+	pub type TotalDebitInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Record of total surplus amount of all active surplus auctions
+	///
+	/// TotalSurplusInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_surplus_in_auction)]
+	pub type TotalSurplusInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Mapping from auction id to dutch collateral auction info
+	///
+	/// DutchCollateralAuctions: map AuctionId => Option<DutchCollateralAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_collateral_auctions)]
+	pub type DutchCollateralAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DutchCollateralAuctionItem<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from collateral type to its dutch auction parameters
+	///
+	/// DutchAuctionParams: map CurrencyId => Option<DutchAuctionParameters>
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_auction_params)]
+	pub type DutchAuctionParams<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, DutchAuctionParameters<T::BlockNumber>, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -285,6 +499,82 @@ pub mod module {
 			Self::deposit_event(Event::CancelAuction(id));
 			Ok(())
 		}
+
+		/// Buy collateral from dutch collateral auction at the current price.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `id`: dutch collateral auction id.
+		/// - `max_amount`: the maximum collateral amount to buy.
+		/// - `max_price`: the acceptable maximum price of collateral in stable
+		///   currency.
+		#[pallet::weight(T::WeightInfo::buy_collateral())]
+		#[transactional]
+		pub fn buy_collateral(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			#[pallet::compact] max_amount: Balance,
+			max_price: Price,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::dutch_collateral_auction_take_handler(
+				<frame_system::Pallet<T>>::block_number(),
+				&who,
+				id,
+				max_amount,
+				max_price,
+			)?;
+			Ok(())
+		}
+
+		/// Update the dutch auction parameters of specific collateral type,
+		/// `None` means collateral of this type will not be sold by dutch
+		/// auction.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `params`: new dutch auction parameters.
+		#[pallet::weight((T::WeightInfo::set_dutch_auction_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_dutch_auction_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<DutchAuctionParameters<T::BlockNumber>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(params) = params {
+				let valid_curve = match params.curve {
+					PriceDecayCurve::Linear { duration } => !duration.is_zero(),
+					PriceDecayCurve::StairstepExponential { step, cut } => !step.is_zero() && cut < Ratio::one(),
+				};
+				ensure!(
+					valid_curve
+						&& !params.floor_ratio.is_zero()
+						&& params.floor_ratio <= Rate::one().saturating_add(params.start_premium),
+					Error::<T>::InvalidDutchAuctionParams
+				);
+			}
+			DutchAuctionParams::<T>::mutate_exists(currency_id, |maybe_params| *maybe_params = params);
+			Self::deposit_event(Event::DutchAuctionParamsUpdated(currency_id, params));
+			Ok(())
+		}
+
+		/// Restart the dutch collateral auction whose price has decayed to
+		/// the floor price, the start price and floor price are recalculated
+		/// by the current oracle price and dutch auction parameters.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `id`: dutch collateral auction id.
+		#[pallet::weight(T::WeightInfo::reset_dutch_collateral_auction())]
+		#[transactional]
+		pub fn reset_dutch_collateral_auction(origin: OriginFor<T>, id: AuctionId) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::dutch_collateral_auction_reset_handler(<frame_system::Pallet<T>>::block_number(), id)
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -303,7 +593,10 @@ pub mod module {
 							return InvalidTransaction::Stale.into();
 						}
 					}
-				} else {
+				} else if !<DebitAuctions<T>>::contains_key(auction_id)
+					&& !<SurplusAuctions<T>>::contains_key(auction_id)
+					&& !<DutchCollateralAuctions<T>>::contains_key(auction_id)
+				{
 					return InvalidTransaction::Stale.into();
 				}
 
@@ -387,6 +680,25 @@ impl<T: Config> Pallet<T> {
 			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
 		}
 
+		// start iterations to cancel debit, surplus and dutch collateral auctions after all
+		// collateral auctions have been handled. these auctions will be removed after cancelled,
+		// so always iterate from the beginning.
+		if finished {
+			let auction_ids = <DebitAuctions<T>>::iter()
+				.map(|(id, _)| id)
+				.chain(<SurplusAuctions<T>>::iter().map(|(id, _)| id))
+				.chain(<DutchCollateralAuctions<T>>::iter().map(|(id, _)| id));
+			for auction_id in auction_ids {
+				if iteration_count >= max_iterations {
+					break;
+				}
+				iteration_count += 1;
+
+				Self::submit_cancel_auction_tx(auction_id);
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
+		}
+
 		if finished {
 			to_be_continue.clear();
 		} else {
@@ -413,6 +725,64 @@ impl<T: Config> Pallet<T> {
 			);
 		}
 
+		Self::settle_cancelled_collateral_auction(&collateral_auction)?;
+
+		// if there's bid
+		if let Some((bidder, bid_price)) = last_bid {
+			// refund stable token to the bidder
+			T::CDPTreasury::issue_debit(&bidder, bid_price, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		Ok(())
+	}
+
+	fn cancel_debit_auction(id: AuctionId, debit_auction: DebitAuctionItem<T::BlockNumber>) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, _)) = Self::get_last_bid(id) {
+			// refund stable token to the bidder
+			T::CDPTreasury::issue_debit(&bidder, debit_auction.fix, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// decrease total debit in auction
+		TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+
+		Ok(())
+	}
+
+	fn cancel_surplus_auction(id: AuctionId, surplus_auction: SurplusAuctionItem<T::BlockNumber>) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, bid_price)) = Self::get_last_bid(id) {
+			// refund the burned native token to the bidder
+			T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, bid_price)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// decrease total surplus in auction
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+
+		Ok(())
+	}
+
+	fn cancel_dutch_collateral_auction(
+		dutch_collateral_auction: DutchCollateralAuctionItem<T::AccountId, T::BlockNumber>,
+	) -> DispatchResult {
+		// there's no bidder of dutch collateral auction to refund
+		Self::settle_cancelled_collateral_auction(&dutch_collateral_auction.auction)
+	}
+
+	/// Confiscate collateral to offset the target at settle price, refund the
+	/// remain collateral to refund recipient and update the auction records.
+	fn settle_cancelled_collateral_auction(
+		collateral_auction: &CollateralAuctionItem<T::AccountId, T::BlockNumber>,
+	) -> DispatchResult {
 		// calculate how much collateral to offset target in settle price
 		let settle_price =
 			T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), collateral_auction.currency_id)
@@ -434,15 +804,6 @@ impl<T: Config> Pallet<T> {
 			refund_collateral_amount,
 		)?;
 
-		// if there's bid
-		if let Some((bidder, bid_price)) = last_bid {
-			// refund stable token to the bidder
-			T::CDPTreasury::issue_debit(&bidder, bid_price, false)?;
-
-			// decrease account ref of bidder
-			frame_system::Pallet::<T>::dec_consumers(&bidder);
-		}
-
 		// decrease account ref of refund recipient
 		frame_system::Pallet::<T>::dec_consumers(&collateral_auction.refund_recipient);
 
@@ -574,6 +935,170 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Handles debit auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn debit_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		<DebitAuctions<T>>::try_mutate_exists(
+			id,
+			|debit_auction| -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+				let mut debit_auction = debit_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+				// ensure new bid price is valid
+				ensure!(
+					Self::check_minimum_increment(
+						new_bid_price,
+						last_bid_price,
+						debit_auction.fix,
+						Self::get_minimum_increment_size(now, debit_auction.start_time),
+					) && new_bid_price >= debit_auction.fix,
+					Error::<T>::InvalidBidPrice,
+				);
+
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					// there's bid before, transfer the stablecoin from new bidder to last bidder
+					T::Currency::transfer(
+						T::GetStableCurrencyId::get(),
+						&new_bidder,
+						last_bidder,
+						debit_auction.fix,
+					)?;
+				} else {
+					// there's no bid before, transfer stablecoin to CDP treasury
+					T::CDPTreasury::deposit_surplus(&new_bidder, debit_auction.fix)?;
+				}
+
+				Self::swap_bidders(&new_bidder, last_bidder);
+
+				debit_auction.amount = debit_auction.amount_for_sale(last_bid_price, new_bid_price);
+
+				Ok(now + Self::get_auction_time_to_close(now, debit_auction.start_time))
+			},
+		)
+	}
+
+	/// Handles surplus auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn surplus_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		let surplus_auction = Self::surplus_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+		// ensure new bid price is valid
+		ensure!(
+			Self::check_minimum_increment(
+				new_bid_price,
+				last_bid_price,
+				Zero::zero(),
+				Self::get_minimum_increment_size(now, surplus_auction.start_time),
+			),
+			Error::<T>::InvalidBidPrice,
+		);
+
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+		let burn_native_amount = if let Some(last_bidder) = last_bidder {
+			// refund last bidder
+			T::Currency::transfer(native_currency_id, &new_bidder, last_bidder, last_bid_price)?;
+			new_bid_price.saturating_sub(last_bid_price)
+		} else {
+			new_bid_price
+		};
+
+		// burn remain native token from new bidder
+		T::Currency::withdraw(native_currency_id, &new_bidder, burn_native_amount)?;
+
+		Self::swap_bidders(&new_bidder, last_bidder);
+
+		Ok(now + Self::get_auction_time_to_close(now, surplus_auction.start_time))
+	}
+
+	fn debit_auction_end_handler(
+		auction_id: AuctionId,
+		debit_auction: DebitAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, _)) = winner {
+			// issue native token to winner, it shouldn't fail and affect the process.
+			// but even it failed, just the winner did not get the amount. it can be fixed
+			// by treasury council.
+			let res = T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, debit_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"deposit: failed to issue native {:?} to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					debit_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::DebitAuctionDealt(
+				auction_id,
+				debit_auction.amount,
+				bidder,
+				debit_auction.fix,
+			));
+		}
+
+		TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+	}
+
+	fn surplus_auction_end_handler(
+		auction_id: AuctionId,
+		surplus_auction: SurplusAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, bid_price)) = winner {
+			// transfer stable currency from CDP treasury to winner, it shouldn't fail and
+			// affect the process. but even it failed, just the winner did not get the
+			// amount. it can be fixed by treasury council.
+			let res = T::CDPTreasury::withdraw_surplus(&bidder, surplus_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"withdraw_surplus: failed to withdraw {:?} stable from CDP treasury to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					surplus_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::SurplusAuctionDealt(
+				auction_id,
+				surplus_auction.amount,
+				bidder,
+				bid_price,
+			));
+		}
+
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+	}
+
 	fn collateral_auction_end_handler(
 		auction_id: AuctionId,
 		collateral_auction: CollateralAuctionItem<T::AccountId, T::BlockNumber>,
@@ -708,6 +1233,122 @@ impl<T: Config> Pallet<T> {
 		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
 	}
 
+	/// Calculate the start price and floor price of dutch collateral auction
+	/// by the oracle price of `currency_id`.
+	fn get_dutch_auction_prices(
+		currency_id: CurrencyId,
+		params: &DutchAuctionParameters<T::BlockNumber>,
+	) -> Result<(Price, Price), DispatchError> {
+		let oracle_price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.filter(|price| !price.is_zero())
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let start_price = oracle_price.saturating_mul(Rate::one().saturating_add(params.start_premium));
+		let floor_price = oracle_price.saturating_mul(params.floor_ratio);
+		Ok((start_price, floor_price))
+	}
+
+	/// Handles restarting dutch collateral auction whose price has decayed to
+	/// the floor price at `now`, the price decays again from the start price
+	/// recalculated by the current oracle price.
+	pub fn dutch_collateral_auction_reset_handler(now: T::BlockNumber, id: AuctionId) -> DispatchResult {
+		DutchCollateralAuctions::<T>::try_mutate(id, |maybe_dutch_collateral_auction| -> DispatchResult {
+			let dutch_collateral_auction = maybe_dutch_collateral_auction
+				.as_mut()
+				.ok_or(Error::<T>::AuctionNotExists)?;
+			ensure!(
+				dutch_collateral_auction.current_price(now) <= dutch_collateral_auction.floor_price,
+				Error::<T>::AuctionNotReachFloorPrice
+			);
+
+			let currency_id = dutch_collateral_auction.auction.currency_id;
+			let params = Self::dutch_auction_params(currency_id).ok_or(Error::<T>::InvalidDutchAuctionParams)?;
+			let (start_price, floor_price) = Self::get_dutch_auction_prices(currency_id, &params)?;
+			dutch_collateral_auction.start_price = start_price;
+			dutch_collateral_auction.floor_price = floor_price;
+			dutch_collateral_auction.curve = params.curve;
+			dutch_collateral_auction.auction.start_time = now;
+
+			Self::deposit_event(Event::DutchCollateralAuctionReset(id, start_price, floor_price));
+			Ok(())
+		})
+	}
+
+	/// Handles taking collateral of dutch collateral auction at the price of
+	/// `now`. The dutch collateral auction will be finished if all collateral
+	/// has been taken or target has been reached, and the remain collateral
+	/// will be refunded to refund recipient.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn dutch_collateral_auction_take_handler(
+		now: T::BlockNumber,
+		who: &T::AccountId,
+		id: AuctionId,
+		max_amount: Balance,
+		max_price: Price,
+	) -> DispatchResult {
+		ensure!(!max_amount.is_zero(), Error::<T>::InvalidAmount);
+		let mut dutch_collateral_auction = Self::dutch_collateral_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let price = dutch_collateral_auction.current_price(now);
+		ensure!(price <= max_price, Error::<T>::ExceedMaxPrice);
+
+		let collateral_auction = &mut dutch_collateral_auction.auction;
+		let always_forward = collateral_auction.always_forward();
+		let mut take_amount = sp_std::cmp::min(max_amount, collateral_auction.amount);
+		let mut payment = price.checked_mul_int(take_amount).ok_or(Error::<T>::InvalidAmount)?;
+
+		// buyer only need to pay the remain target, take less collateral in proportion.
+		if !always_forward && payment > collateral_auction.target {
+			take_amount = multiply_by_rational(take_amount, collateral_auction.target, payment)
+				.map_err(|_| Error::<T>::InvalidAmount)?;
+			payment = collateral_auction.target;
+		}
+		ensure!(!take_amount.is_zero() && !payment.is_zero(), Error::<T>::InvalidAmount);
+
+		// transfer payment from buyer to CDP treasury and collateral to buyer from CDP treasury
+		T::CDPTreasury::deposit_surplus(who, payment)?;
+		T::CDPTreasury::withdraw_collateral(who, collateral_auction.currency_id, take_amount)?;
+
+		collateral_auction.amount = collateral_auction.amount.saturating_sub(take_amount);
+		TotalCollateralInAuction::<T>::mutate(collateral_auction.currency_id, |balance| {
+			*balance = balance.saturating_sub(take_amount)
+		});
+		if !always_forward {
+			collateral_auction.target = collateral_auction.target.saturating_sub(payment);
+			TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(payment));
+		}
+
+		Self::deposit_event(Event::DutchCollateralAuctionTaken(
+			id,
+			collateral_auction.currency_id,
+			take_amount,
+			who.clone(),
+			payment,
+		));
+
+		if collateral_auction.amount.is_zero() || (!always_forward && collateral_auction.target.is_zero()) {
+			// refund remain collateral to refund recipient from CDP treasury
+			T::CDPTreasury::withdraw_collateral(
+				&collateral_auction.refund_recipient,
+				collateral_auction.currency_id,
+				collateral_auction.amount,
+			)?;
+			TotalCollateralInAuction::<T>::mutate(collateral_auction.currency_id, |balance| {
+				*balance = balance.saturating_sub(collateral_auction.amount)
+			});
+
+			// decrement recipient account reference
+			frame_system::Pallet::<T>::dec_consumers(&collateral_auction.refund_recipient);
+
+			<DutchCollateralAuctions<T>>::remove(id);
+			T::Auction::remove_auction(id);
+		} else {
+			<DutchCollateralAuctions<T>>::insert(id, dutch_collateral_auction);
+		}
+
+		Ok(())
+	}
+
 	/// increment `new_bidder` reference and decrement `last_bidder`
 	/// reference if any
 	fn swap_bidders(new_bidder: &T::AccountId, last_bidder: Option<&T::AccountId>) {
@@ -736,7 +1377,15 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 		new_bid: (T::AccountId, Balance),
 		last_bid: Option<(T::AccountId, Balance)>,
 	) -> OnNewBidResult<T::BlockNumber> {
-		let bid_result = Self::collateral_auction_bid_handler(now, id, new_bid, last_bid);
+		let bid_result = if <CollateralAuctions<T>>::contains_key(id) {
+			Self::collateral_auction_bid_handler(now, id, new_bid, last_bid)
+		} else if <DebitAuctions<T>>::contains_key(id) {
+			Self::debit_auction_bid_handler(now, id, new_bid, last_bid)
+		} else if <SurplusAuctions<T>>::contains_key(id) {
+			Self::surplus_auction_bid_handler(now, id, new_bid, last_bid)
+		} else {
+			Err(Error::<T>::AuctionNotExists.into())
+		};
 
 		match bid_result {
 			Ok(new_auction_end_time) => OnNewBidResult {
@@ -753,6 +1402,10 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::debit_auction_end_handler(id, debit_auction, winner.clone());
+		} else if let Some(surplus_auction) = <SurplusAuctions<T>>::take(id) {
+			Self::surplus_auction_end_handler(id, surplus_auction, winner.clone());
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -821,9 +1474,140 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		Ok(())
 	}
 
+	/// Create dutch collateral auction, fallback to create collateral auction
+	/// if the dutch auction parameters of `currency_id` is not set.
+	fn new_dutch_collateral_auction(
+		refund_recipient: &T::AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+	) -> DispatchResult {
+		let params = match Self::dutch_auction_params(currency_id) {
+			Some(params) => params,
+			None => return Self::new_collateral_auction(refund_recipient, currency_id, amount, target),
+		};
+
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		let (start_price, floor_price) = Self::get_dutch_auction_prices(currency_id, &params)?;
+
+		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		if !target.is_zero() {
+			// no-op if target is zero
+			TotalTargetInAuction::<T>::try_mutate(|total| -> DispatchResult {
+				*total = total.checked_add(target).ok_or(Error::<T>::InvalidAmount)?;
+				Ok(())
+			})?;
+		}
+
+		// dutch collateral auction is not handled by `Auction`, create an auction without end-time
+		// to share the auction id with collateral auctions. bids of it will be rejected by
+		// `AuctionHandler` because it's not a collateral auction.
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let auction_id = T::Auction::new_auction(start_time, None)?;
+
+		<DutchCollateralAuctions<T>>::insert(
+			auction_id,
+			DutchCollateralAuctionItem {
+				auction: CollateralAuctionItem {
+					refund_recipient: refund_recipient.clone(),
+					currency_id,
+					initial_amount: amount,
+					amount,
+					target,
+					start_time,
+				},
+				start_price,
+				floor_price,
+				curve: params.curve,
+			},
+		);
+
+		// increment recipient account reference
+		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
+			// No providers for the locks. This is impossible under normal circumstances
+			// since the funds that are under the lock will themselves be stored in the
+			// account and therefore will need a reference.
+			log::warn!(
+				target: "auction-manager",
+				"Attempt to `inc_consumers` for {:?} failed. \
+				This is unexpected but should be safe.",
+				refund_recipient.clone()
+			);
+		}
+
+		Self::deposit_event(Event::NewDutchCollateralAuction(
+			auction_id,
+			currency_id,
+			amount,
+			target,
+			start_price,
+		));
+		Ok(())
+	}
+
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult {
+		ensure!(
+			!initial_amount.is_zero() && !fix_debit.is_zero(),
+			Error::<T>::InvalidAmount,
+		);
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		TotalDebitInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(fix_debit).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<DebitAuctions<T>>::insert(
+			auction_id,
+			DebitAuctionItem {
+				initial_amount,
+				amount: initial_amount,
+				fix: fix_debit,
+				start_time,
+			},
+		);
+
+		Self::deposit_event(Event::NewDebitAuction(auction_id, initial_amount, fix_debit));
+		Ok(())
+	}
+
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		TotalSurplusInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<SurplusAuctions<T>>::insert(auction_id, SurplusAuctionItem { amount, start_time });
+
+		Self::deposit_event(Event::NewSurplusAuction(auction_id, amount));
+		Ok(())
+	}
+
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
-		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
-		Self::cancel_collateral_auction(id, collateral_auction)?;
+		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			Self::cancel_collateral_auction(id, collateral_auction)?;
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::cancel_debit_auction(id, debit_auction)?;
+		} else if let Some(surplus_auction) = <SurplusAuctions<T>>::take(id) {
+			Self::cancel_surplus_auction(id, surplus_auction)?;
+		} else {
+			let dutch_collateral_auction =
+				<DutchCollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
+			Self::cancel_dutch_collateral_auction(dutch_collateral_auction)?;
+		}
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
@@ -835,4 +1619,12 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	fn get_total_target_in_auction() -> Self::Balance {
 		Self::total_target_in_auction()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Self::total_debit_in_auction()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Self::total_surplus_in_auction()
+	}
 }
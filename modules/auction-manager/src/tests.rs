@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
-use sp_runtime::traits::One;
+use sp_runtime::traits::{BadOrigin, One};
 
 #[test]
 fn get_auction_time_to_close_work() {
@@ -471,3 +471,603 @@ fn cancel_collateral_auction_work() {
 		assert_eq!(bob_ref_count_1, bob_ref_count_0 - 1);
 	});
 }
+
+#[test]
+fn debit_auction_methods() {
+	ExtBuilder::default().build().execute_with(|| {
+		let debit_auction = DebitAuctionItem {
+			initial_amount: 100,
+			amount: 100,
+			fix: 100,
+			start_time: 0,
+		};
+		assert_eq!(debit_auction.amount_for_sale(0, 100), 100);
+		assert_eq!(debit_auction.amount_for_sale(100, 200), 50);
+		assert_eq!(debit_auction.amount_for_sale(150, 300), 50);
+		assert_eq!(debit_auction.amount_for_sale(200, 200), 100);
+	});
+}
+
+#[test]
+fn new_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(0, 100),
+			Error::<Runtime>::InvalidAmount,
+		);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(200, 0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewDebitAuction(0, 200, 100)));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(2001)
+			})
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(200, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn new_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewSurplusAuction(0, 100)));
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 100);
+		assert_eq!(
+			AuctionManagerModule::surplus_auctions(0),
+			Some(SurplusAuctionItem {
+				amount: 100,
+				start_time: 1
+			})
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn debit_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 99), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None).is_ok());
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 200);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 + 1);
+
+		let carol_ref_count_0 = System::consumers(&CAROL);
+		assert!(AuctionManagerModule::debit_auction_bid_handler(2, 0, (CAROL, 200), Some((BOB, 100))).is_ok());
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 100);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0);
+		assert_eq!(System::consumers(&CAROL), carol_ref_count_0 + 1);
+	});
+}
+
+#[test]
+fn surplus_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 0), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None).is_ok());
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		assert_eq!(Tokens::total_issuance(ACA), 2950);
+
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(2, 0, (CAROL, 51), Some((BOB, 50))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(2, 0, (CAROL, 100), Some((BOB, 50))).is_ok());
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(ACA, &CAROL), 900);
+		assert_eq!(Tokens::total_issuance(ACA), 2900);
+	});
+}
+
+#[test]
+fn debit_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 200);
+		assert!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None).is_ok());
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 100)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DebitAuctionDealt(
+			0, 300, BOB, 100,
+		)));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1300);
+		assert_eq!(AuctionManagerModule::debit_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+
+		// debit auction without bid
+		AuctionManagerModule::on_auction_ended(1, None);
+		assert_eq!(AuctionManagerModule::debit_auctions(1), None);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+	});
+}
+
+#[test]
+fn surplus_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None).is_ok());
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 50)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::SurplusAuctionDealt(
+			0, 100, BOB, 50,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 400);
+		assert_eq!(AuctionManagerModule::surplus_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		assert!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None).is_ok());
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::debit_auctions(0), None);
+		assert!(!AuctionModule::auction_info(0).is_some());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None).is_ok());
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::surplus_auctions(0), None);
+		assert!(!AuctionModule::auction_info(0).is_some());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+fn dutch_auction_params() -> DutchAuctionParameters<BlockNumber> {
+	DutchAuctionParameters {
+		start_premium: Rate::saturating_from_rational(1, 2),
+		floor_ratio: Ratio::saturating_from_rational(1, 2),
+		curve: PriceDecayCurve::Linear { duration: 100 },
+	}
+}
+
+#[test]
+fn dutch_collateral_auction_current_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut dutch_collateral_auction: DutchCollateralAuctionItem<AccountId, BlockNumber> =
+			DutchCollateralAuctionItem {
+				auction: CollateralAuctionItem {
+					refund_recipient: ALICE,
+					currency_id: BTC,
+					initial_amount: 100,
+					amount: 100,
+					target: 100,
+					start_time: 1,
+				},
+				start_price: Price::saturating_from_rational(3, 2),
+				floor_price: Price::saturating_from_rational(1, 2),
+				curve: PriceDecayCurve::Linear { duration: 100 },
+			};
+		assert_eq!(
+			dutch_collateral_auction.current_price(0),
+			Price::saturating_from_rational(3, 2)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(1),
+			Price::saturating_from_rational(3, 2)
+		);
+		assert_eq!(dutch_collateral_auction.current_price(51), Price::one());
+		assert_eq!(
+			dutch_collateral_auction.current_price(101),
+			Price::saturating_from_rational(1, 2)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(200),
+			Price::saturating_from_rational(1, 2)
+		);
+
+		dutch_collateral_auction.curve = PriceDecayCurve::StairstepExponential {
+			step: 10,
+			cut: Ratio::saturating_from_rational(1, 2),
+		};
+		assert_eq!(
+			dutch_collateral_auction.current_price(1),
+			Price::saturating_from_rational(3, 2)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(10),
+			Price::saturating_from_rational(3, 2)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(11),
+			Price::saturating_from_rational(3, 4)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(20),
+			Price::saturating_from_rational(3, 4)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(21),
+			Price::saturating_from_rational(1, 2)
+		);
+	});
+}
+
+#[test]
+fn set_dutch_auction_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::set_dutch_auction_params(Origin::signed(BOB), BTC, Some(dutch_auction_params())),
+			BadOrigin
+		);
+		assert_noop!(
+			AuctionManagerModule::set_dutch_auction_params(
+				Origin::signed(ALICE),
+				BTC,
+				Some(DutchAuctionParameters {
+					floor_ratio: Ratio::saturating_from_rational(2, 1),
+					..dutch_auction_params()
+				})
+			),
+			Error::<Runtime>::InvalidDutchAuctionParams
+		);
+		assert_noop!(
+			AuctionManagerModule::set_dutch_auction_params(
+				Origin::signed(ALICE),
+				BTC,
+				Some(DutchAuctionParameters {
+					curve: PriceDecayCurve::StairstepExponential {
+						step: 10,
+						cut: Ratio::one()
+					},
+					..dutch_auction_params()
+				})
+			),
+			Error::<Runtime>::InvalidDutchAuctionParams
+		);
+		assert_noop!(
+			AuctionManagerModule::set_dutch_auction_params(
+				Origin::signed(ALICE),
+				BTC,
+				Some(DutchAuctionParameters {
+					curve: PriceDecayCurve::Linear { duration: 0 },
+					..dutch_auction_params()
+				})
+			),
+			Error::<Runtime>::InvalidDutchAuctionParams
+		);
+
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			Some(dutch_auction_params())
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchAuctionParamsUpdated(
+			BTC,
+			Some(dutch_auction_params()),
+		)));
+		assert_eq!(
+			AuctionManagerModule::dutch_auction_params(BTC),
+			Some(dutch_auction_params())
+		);
+
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			None
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchAuctionParamsUpdated(
+			BTC, None,
+		)));
+		assert_eq!(AuctionManagerModule::dutch_auction_params(BTC), None);
+	});
+}
+
+#[test]
+fn new_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// fallback to collateral auction if dutch auction params is not set
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 10, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewCollateralAuction(
+			0, BTC, 10, 100,
+		)));
+		assert!(AuctionManagerModule::collateral_auctions(0).is_some());
+
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			Some(dutch_auction_params())
+		));
+		let ref_count_0 = System::consumers(&ALICE);
+		assert_noop!(
+			AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 0, 100),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		MockPriceSource::set_relative_price(None);
+		assert_noop!(
+			AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 20, 100),
+			Error::<Runtime>::InvalidFeedPrice,
+		);
+
+		MockPriceSource::set_relative_price(Some(Price::one()));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 20, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewDutchCollateralAuction(
+			1,
+			BTC,
+			20,
+			100,
+			Price::saturating_from_rational(3, 2),
+		)));
+		assert_eq!(
+			AuctionModule::auctions(1),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: None
+			})
+		);
+		assert!(AuctionManagerModule::collateral_auctions(1).is_none());
+		assert_eq!(
+			AuctionManagerModule::dutch_collateral_auctions(1),
+			Some(DutchCollateralAuctionItem {
+				auction: CollateralAuctionItem {
+					refund_recipient: ALICE,
+					currency_id: BTC,
+					initial_amount: 20,
+					amount: 20,
+					target: 100,
+					start_time: 1,
+				},
+				start_price: Price::saturating_from_rational(3, 2),
+				floor_price: Price::saturating_from_rational(1, 2),
+				curve: PriceDecayCurve::Linear { duration: 100 },
+			})
+		);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 30);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 200);
+		assert_eq!(System::consumers(&ALICE), ref_count_0 + 1);
+	});
+}
+
+#[test]
+fn buy_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			Some(dutch_auction_params())
+		));
+		assert_noop!(
+			AuctionManagerModule::buy_collateral(Origin::signed(BOB), 0, 40, Price::saturating_from_rational(3, 2)),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(
+			&ALICE, BTC, 100, 100
+		));
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		assert_noop!(
+			AuctionManagerModule::buy_collateral(Origin::signed(BOB), 0, 40, Price::one()),
+			Error::<Runtime>::ExceedMaxPrice,
+		);
+		assert_noop!(
+			AuctionManagerModule::buy_collateral(Origin::signed(BOB), 0, 0, Price::saturating_from_rational(3, 2)),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		// buy part of collateral at start price
+		assert_ok!(AuctionManagerModule::buy_collateral(
+			Origin::signed(BOB),
+			0,
+			40,
+			Price::saturating_from_rational(3, 2)
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionTaken(
+			0, BTC, 40, BOB, 60,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 940);
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1040);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 60);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 60);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 60);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 40);
+		let dutch_collateral_auction = AuctionManagerModule::dutch_collateral_auctions(0).unwrap();
+		assert_eq!(dutch_collateral_auction.auction.amount, 60);
+		assert_eq!(dutch_collateral_auction.auction.target, 40);
+
+		// price decayed, buyer only need to pay the remain target
+		System::set_block_number(51);
+		assert_ok!(AuctionManagerModule::buy_collateral(
+			Origin::signed(CAROL),
+			0,
+			60,
+			Price::one()
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionTaken(
+			0, BTC, 40, CAROL, 40,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 960);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 1040);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+
+		// remain collateral is refunded after target reached
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 920);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn reset_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::reset_dutch_collateral_auction(Origin::signed(BOB), 0),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			Some(dutch_auction_params())
+		));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(
+			&ALICE, BTC, 100, 100
+		));
+
+		System::set_block_number(100);
+		assert_noop!(
+			AuctionManagerModule::reset_dutch_collateral_auction(Origin::signed(BOB), 0),
+			Error::<Runtime>::AuctionNotReachFloorPrice,
+		);
+
+		// no one buys at the floor price
+		System::set_block_number(101);
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
+		assert_ok!(AuctionManagerModule::reset_dutch_collateral_auction(
+			Origin::signed(BOB),
+			0
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionReset(
+			0,
+			Price::saturating_from_rational(3, 4),
+			Price::saturating_from_rational(1, 4),
+		)));
+		let dutch_collateral_auction = AuctionManagerModule::dutch_collateral_auctions(0).unwrap();
+		assert_eq!(dutch_collateral_auction.auction.start_time, 101);
+		assert_eq!(
+			dutch_collateral_auction.current_price(101),
+			Price::saturating_from_rational(3, 4)
+		);
+		assert_eq!(
+			dutch_collateral_auction.current_price(201),
+			Price::saturating_from_rational(1, 4)
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::reset_dutch_collateral_auction(Origin::signed(BOB), 0),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn cancel_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::set_dutch_auction_params(
+			Origin::signed(ALICE),
+			BTC,
+			Some(dutch_auction_params())
+		));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 50));
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::buy_collateral(Origin::signed(BOB), 0, 40, Price::saturating_from_rational(3, 2)),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1050);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 50);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
@@ -0,0 +1,124 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the cdp engine module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_cdp_engine_rpc_runtime_api::CDPHealth;
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as CdpEngineClient;
+pub use module_cdp_engine_rpc_runtime_api::CdpEngineApi as CdpEngineRuntimeApi;
+
+#[rpc]
+pub trait CdpEngineApi<BlockHash, AccountId, ResponseType> {
+	#[rpc(name = "cdpEngine_getCdpHealth")]
+	fn get_cdp_health(
+		&self,
+		who: AccountId,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Option<ResponseType>>;
+
+	#[rpc(name = "cdpEngine_getCdpHealths")]
+	fn get_cdp_healths(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<(CurrencyId, ResponseType)>>;
+}
+
+/// A struct that implements the [`CdpEngineApi`].
+pub struct CdpEngine<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CdpEngine<C, B> {
+	/// Create new `CdpEngine` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		CdpEngine {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> CdpEngineApi<<Block as BlockT>::Hash, AccountId, CDPHealth<Balance>>
+	for CdpEngine<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CdpEngineRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_cdp_health(
+		&self,
+		who: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<CDPHealth<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_cdp_health(&at, who, currency_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get cdp health.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_cdp_healths(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(CurrencyId, CDPHealth<Balance>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_cdp_healths(&at, who).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get cdp healths.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
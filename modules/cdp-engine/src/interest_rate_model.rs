@@ -0,0 +1,94 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Interest rate models to adjust the interest rate per sec of CDPs
+//! dynamically.
+
+use super::*;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::FixedPointNumber;
+use support::{DEXManager, InterestRateModel};
+
+/// Kinked utilization model, the interest rate per sec rises slowly with the
+/// utilization of the maximum total debit value until it reaches the kink,
+/// and rises sharply after that.
+///
+/// rate = base + multiplier * min(utilization, kink) + jump_multiplier *
+/// max(utilization - kink, 0)
+pub struct KinkedUtilizationModel<BaseRate, Multiplier, JumpMultiplier, Kink>(
+	sp_std::marker::PhantomData<(BaseRate, Multiplier, JumpMultiplier, Kink)>,
+);
+
+impl<BaseRate, Multiplier, JumpMultiplier, Kink> InterestRateModel<CurrencyId>
+	for KinkedUtilizationModel<BaseRate, Multiplier, JumpMultiplier, Kink>
+where
+	BaseRate: Get<Rate>,
+	Multiplier: Get<Rate>,
+	JumpMultiplier: Get<Rate>,
+	Kink: Get<Ratio>,
+{
+	fn get_interest_rate_per_sec(_currency_id: CurrencyId, utilization: Ratio) -> Option<Rate> {
+		let kink = Kink::get();
+		let normal_rate = Multiplier::get().saturating_mul(utilization.min(kink));
+		let jump_rate = JumpMultiplier::get().saturating_mul(utilization.saturating_sub(kink));
+
+		Some(BaseRate::get().saturating_add(normal_rate).saturating_add(jump_rate))
+	}
+}
+
+/// Peg deviation model, the interest rate per sec is driven by the deviation
+/// of the stablecoin price in DEX from its peg. The stablecoin price in DEX is
+/// the time-weighted average price of the pool of the stablecoin and the
+/// reference currency over the last `TwapWindow` blocks, so that it can't be
+/// moved by the swaps within a block. The rate rises when the stablecoin is
+/// below its peg and falls when it is above.
+///
+/// rate = base + sensitivity * (1 - dex_price / peg_price)
+pub struct PegDeviationModel<T, DEX, GetReferenceCurrencyId, BaseRate, Sensitivity, TwapWindow>(
+	sp_std::marker::PhantomData<(T, DEX, GetReferenceCurrencyId, BaseRate, Sensitivity, TwapWindow)>,
+);
+
+impl<T, DEX, GetReferenceCurrencyId, BaseRate, Sensitivity, TwapWindow> InterestRateModel<CurrencyId>
+	for PegDeviationModel<T, DEX, GetReferenceCurrencyId, BaseRate, Sensitivity, TwapWindow>
+where
+	T: Config,
+	DEX: DEXManager<T::AccountId, CurrencyId, Balance>,
+	GetReferenceCurrencyId: Get<CurrencyId>,
+	BaseRate: Get<Rate>,
+	Sensitivity: Get<Rate>,
+	TwapWindow: Get<u32>,
+{
+	fn get_interest_rate_per_sec(_currency_id: CurrencyId, _utilization: Ratio) -> Option<Rate> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let reference_currency_id = GetReferenceCurrencyId::get();
+
+		let dex_price =
+			DEX::get_time_weighted_average_price(stable_currency_id, reference_currency_id, TwapWindow::get())?
+				.checked_mul(&T::PriceSource::get_price(reference_currency_id)?)?;
+		let peg_price = T::PriceSource::get_price(stable_currency_id)?;
+		let price_ratio = dex_price.checked_div(&peg_price)?;
+
+		let base_rate = BaseRate::get();
+		let sensitivity = Sensitivity::get();
+		if price_ratio < Ratio::one() {
+			Some(base_rate.saturating_add(sensitivity.saturating_mul(Ratio::one().saturating_sub(price_ratio))))
+		} else {
+			Some(base_rate.saturating_sub(sensitivity.saturating_mul(price_ratio.saturating_sub(Ratio::one()))))
+		}
+	}
+}
@@ -33,10 +33,13 @@ use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
-use loans::Position;
+use loans::{MultiCollateralPosition, Position};
 use orml_traits::Change;
 use orml_utilities::OffchainErr;
-use primitives::{Amount, Balance, CurrencyId};
+use primitives::{
+	cdp::{CDPHealth, CDPStatus as CDPHealthStatus},
+	Amount, Balance, CurrencyId,
+};
 use rand_chacha::{
 	rand_core::{RngCore, SeedableRng},
 	ChaChaRng,
@@ -47,7 +50,7 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{Bounded, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	traits::{Bounded, CheckedDiv, CheckedMul, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
@@ -55,15 +58,18 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
+	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, InterestRateModel, Price, PriceCircuitBreaker,
+	PriceProvider, Rate, Ratio, RiskManager,
 };
 
 mod debit_exchange_rate_convertor;
+mod interest_rate_model;
 mod mock;
 mod tests;
 pub mod weights;
 
 pub use debit_exchange_rate_convertor::DebitExchangeRateConvertor;
+pub use interest_rate_model::{KinkedUtilizationModel, PegDeviationModel};
 pub use module::*;
 pub use weights::WeightInfo;
 
@@ -102,6 +108,21 @@ pub struct RiskManagementParams {
 	pub required_collateral_ratio: Option<Ratio>,
 }
 
+/// Risk management params of multi-collateral CDPs, the liquidation ratio
+/// and required collateral ratio of each collateral type are used as the
+/// haircut of its collateral value.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct MultiCollateralRiskManagementParams {
+	/// Maximum total debit value generated from all multi-collateral CDPs.
+	pub maximum_total_debit_value: Balance,
+
+	/// Extra interest rate per sec, `None` value means not set
+	pub interest_rate_per_sec: Option<Rate>,
+
+	/// Liquidation penalty rate, `None` value means not set
+	pub liquidation_penalty: Option<Rate>,
+}
+
 // typedef to help polkadot.js disambiguate Change with different generic
 // parameters
 type ChangeOptionRate = Change<Option<Rate>>;
@@ -115,6 +136,8 @@ pub enum LiquidationStrategy {
 	Auction,
 	/// Liquidation CDP's collateral by swap with DEX
 	Exchange,
+	/// Liquidation CDP's collateral by create dutch collateral auction
+	DutchAuction,
 }
 
 /// Status of CDP
@@ -171,6 +194,19 @@ pub mod module {
 		/// The price source of all types of currencies related to CDP
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The price circuit breaker, issuing new debit is refused when the
+		/// breaker of the collateral is tripped
+		type PriceCircuitBreaker: PriceCircuitBreaker<CurrencyId>;
+
+		/// The interest rate model to adjust the interest rate per sec of
+		/// collateral types dynamically when accumulating interest
+		type InterestRateModel: InterestRateModel<CurrencyId>;
+
+		/// The max change of the interest rate per sec from the interest rate
+		/// model in a single block
+		#[pallet::constant]
+		type MaxInterestRatePerSecChange: Get<Rate>;
+
 		/// A configuration for base priority of unsigned transactions.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
@@ -224,6 +260,10 @@ pub mod module {
 		MustAfterShutdown,
 		/// Failed to swap debit by default path list
 		SwapDebitFailed,
+		/// Liquidation strategy is invalid
+		InvalidLiquidationStrategy,
+		/// The price circuit breaker of the collateral is tripped
+		PriceCircuitBreakerTripped,
 	}
 
 	#[pallet::event]
@@ -254,9 +294,34 @@ pub mod module {
 		/// The hard cap of total debit value for specific collateral type
 		/// updated. \[collateral_type, new_total_debit_value\]
 		MaximumTotalDebitValueUpdated(CurrencyId, Balance),
+		/// The close factor of partial liquidation for specific collateral
+		/// type updated. \[collateral_type, new_close_factor\]
+		CloseFactorUpdated(CurrencyId, Option<Ratio>),
 		/// The global interest rate per sec for all types of collateral
 		/// updated. \[new_global_interest_rate_per_sec\]
 		GlobalInterestRatePerSecUpdated(Rate),
+		/// The interest rate per sec for specific collateral type updated by
+		/// the interest rate model, `None` means fallback to the interest rate
+		/// per sec of risk management params. \[collateral_type,
+		/// new_interest_rate_per_sec\]
+		DynamicInterestRatePerSecUpdated(CurrencyId, Option<Rate>),
+		/// Liquidate the unsafe multi-collateral CDP. \[owner, collaterals,
+		/// bad_debt_value\]
+		LiquidateUnsafeMultiCollateralCDP(T::AccountId, Vec<(CurrencyId, Balance)>, Balance),
+		/// Settle the multi-collateral CDP has debit. \[owner\]
+		SettleMultiCollateralCDPInDebit(T::AccountId),
+		/// The interest rate per sec for multi-collateral CDPs updated.
+		/// \[new_interest_rate_per_sec\]
+		MultiCollateralInterestRatePerSecUpdated(Option<Rate>),
+		/// The liquidation penalty rate for multi-collateral CDPs updated.
+		/// \[new_liquidation_panelty\]
+		MultiCollateralLiquidationPenaltyUpdated(Option<Rate>),
+		/// The hard cap of total debit value for multi-collateral CDPs
+		/// updated. \[new_total_debit_value\]
+		MultiCollateralMaximumTotalDebitValueUpdated(Balance),
+		/// The auction strategy of liquidation for specific collateral type
+		/// updated. \[collateral_type, new_liquidation_strategy\]
+		LiquidationStrategyUpdated(CurrencyId, LiquidationStrategy),
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -281,6 +346,25 @@ pub mod module {
 	#[pallet::getter(fn collateral_params)]
 	pub type CollateralParams<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, RiskManagementParams, ValueQuery>;
 
+	/// Mapping from collateral type to its close factor, if it's set,
+	/// liquidation of unsafe CDP under this collateral type only closes enough
+	/// debit to bring it back to the required collateral ratio, and at most
+	/// this proportion of its debit at once. Liquidate the whole CDP if not set.
+	///
+	/// CloseFactors: CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn close_factors)]
+	pub type CloseFactors<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
+	/// Mapping from collateral type to its interest rate per sec adjusted by
+	/// the interest rate model, it takes precedence over the interest rate
+	/// per sec of risk management params
+	///
+	/// DynamicInterestRatePerSec: CurrencyId => Option<Rate>
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_interest_rate_per_sec)]
+	pub type DynamicInterestRatePerSec<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Rate, OptionQuery>;
+
 	/// Timestamp in seconds of the last interest accumulation
 	///
 	/// LastAccumulationSecs: u64
@@ -288,6 +372,30 @@ pub mod module {
 	#[pallet::getter(fn last_accumulation_secs)]
 	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The exchange rate of debit units and debit value of multi-collateral
+	/// CDPs
+	///
+	/// MultiCollateralDebitExchangeRate: Option<ExchangeRate>
+	#[pallet::storage]
+	#[pallet::getter(fn multi_collateral_debit_exchange_rate)]
+	pub type MultiCollateralDebitExchangeRate<T: Config> = StorageValue<_, ExchangeRate, OptionQuery>;
+
+	/// The risk management params of multi-collateral CDPs
+	///
+	/// MultiCollateralParams: MultiCollateralRiskManagementParams
+	#[pallet::storage]
+	#[pallet::getter(fn multi_collateral_params)]
+	pub type MultiCollateralParams<T: Config> = StorageValue<_, MultiCollateralRiskManagementParams, ValueQuery>;
+
+	/// Mapping from collateral type to the auction strategy of liquidation
+	/// when DEX can not take the collateral, `None` means collateral auction
+	///
+	/// LiquidationStrategies: map CurrencyId => Option<LiquidationStrategy>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_strategies)]
+	pub type LiquidationStrategies<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, LiquidationStrategy, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		#[allow(clippy::type_complexity)]
@@ -356,10 +464,11 @@ pub mod module {
 			} else {
 				Default::default()
 			};
-			<T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
-				now_as_secs,
-				Self::last_accumulation_secs(),
-			))
+			let count = Self::accumulate_interest(now_as_secs, Self::last_accumulation_secs());
+			// the interest rate model reads the collateral params, total positions and
+			// dynamic interest rate per sec of each collateral type, and updates the latter.
+			<T as Config>::WeightInfo::on_initialize(count)
+				.saturating_add(T::DbWeight::get().reads_writes(3 * count as Weight, count as Weight))
 		}
 
 		/// Runs after every block. Start offchain worker to check CDP and
@@ -451,7 +560,8 @@ pub mod module {
 		///
 		/// - `currency_id`: collateral type.
 		/// - `interest_rate_per_sec`: extra interest rate per sec, `None` means do not update,
-		///   `Some(None)` means update it to `None`.
+		///   `Some(None)` means update it to `None`. Updating it also resets the interest rate
+		///   per sec adjusted by the interest rate model.
 		/// - `liquidation_ratio`: liquidation ratio, `None` means do not update, `Some(None)` means
 		///   update it to `None`.
 		/// - `liquidation_penalty`: liquidation penalty, `None` means do not update, `Some(None)`
@@ -459,6 +569,8 @@ pub mod module {
 		/// - `required_collateral_ratio`: required collateral ratio, `None` means do not update,
 		///   `Some(None)` means update it to `None`.
 		/// - `maximum_total_debit_value`: maximum total debit value.
+		/// - `close_factor`: close factor of partial liquidation, `None` means do not update,
+		///   `Some(None)` means update it to `None`.
 		#[pallet::weight((<T as Config>::WeightInfo::set_collateral_params(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn set_collateral_params(
@@ -469,6 +581,7 @@ pub mod module {
 			liquidation_penalty: ChangeOptionRate,
 			required_collateral_ratio: ChangeOptionRatio,
 			maximum_total_debit_value: ChangeBalance,
+			close_factor: ChangeOptionRatio,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(
@@ -480,6 +593,10 @@ pub mod module {
 			if let Change::NewValue(update) = interest_rate_per_sec {
 				collateral_params.interest_rate_per_sec = update;
 				Self::deposit_event(Event::InterestRatePerSecUpdated(currency_id, update));
+				// the interest rate model restarts adjusting from the new rate.
+				if DynamicInterestRatePerSec::<T>::take(currency_id).is_some() {
+					Self::deposit_event(Event::DynamicInterestRatePerSecUpdated(currency_id, None));
+				}
 			}
 			if let Change::NewValue(update) = liquidation_ratio {
 				collateral_params.liquidation_ratio = update;
@@ -497,9 +614,121 @@ pub mod module {
 				collateral_params.maximum_total_debit_value = val;
 				Self::deposit_event(Event::MaximumTotalDebitValueUpdated(currency_id, val));
 			}
+			if let Change::NewValue(update) = close_factor {
+				CloseFactors::<T>::set(currency_id, update);
+				Self::deposit_event(Event::CloseFactorUpdated(currency_id, update));
+			}
 			CollateralParams::<T>::insert(currency_id, collateral_params);
 			Ok(())
 		}
+
+		/// Liquidate unsafe multi-collateral CDP
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `who`: CDP's owner.
+		#[pallet::weight(<T as Config>::WeightInfo::liquidate_multi_collateral(
+			T::CollateralCurrencyIds::get().len() as u32
+		))]
+		#[transactional]
+		pub fn liquidate_multi_collateral(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::liquidate_unsafe_multi_collateral_cdp(who)?;
+			Ok(())
+		}
+
+		/// Settle multi-collateral CDP has debit after system shutdown
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `who`: CDP's owner.
+		#[pallet::weight(<T as Config>::WeightInfo::settle_multi_collateral(
+			T::CollateralCurrencyIds::get().len() as u32
+		))]
+		#[transactional]
+		pub fn settle_multi_collateral(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+			Self::settle_multi_collateral_cdp_has_debit(who)?;
+			Ok(())
+		}
+
+		/// Update parameters related to risk management of multi-collateral
+		/// CDP
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `interest_rate_per_sec`: extra interest rate per sec, `None` means do not update,
+		///   `Some(None)` means update it to `None`.
+		/// - `liquidation_penalty`: liquidation penalty, `None` means do not update, `Some(None)`
+		///   means update it to `None`.
+		/// - `maximum_total_debit_value`: maximum total debit value.
+		#[pallet::weight((<T as Config>::WeightInfo::set_multi_collateral_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_multi_collateral_params(
+			origin: OriginFor<T>,
+			interest_rate_per_sec: ChangeOptionRate,
+			liquidation_penalty: ChangeOptionRate,
+			maximum_total_debit_value: ChangeBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let mut params = Self::multi_collateral_params();
+			if let Change::NewValue(update) = interest_rate_per_sec {
+				params.interest_rate_per_sec = update;
+				Self::deposit_event(Event::MultiCollateralInterestRatePerSecUpdated(update));
+			}
+			if let Change::NewValue(update) = liquidation_penalty {
+				params.liquidation_penalty = update;
+				Self::deposit_event(Event::MultiCollateralLiquidationPenaltyUpdated(update));
+			}
+			if let Change::NewValue(val) = maximum_total_debit_value {
+				params.maximum_total_debit_value = val;
+				Self::deposit_event(Event::MultiCollateralMaximumTotalDebitValueUpdated(val));
+			}
+			MultiCollateralParams::<T>::put(params);
+			Ok(())
+		}
+
+		/// Update the auction strategy of liquidation under specific
+		/// collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `strategy`: liquidation strategy, only `Auction` and `DutchAuction` are valid.
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidation_strategy(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_liquidation_strategy(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			strategy: LiquidationStrategy,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+
+			match strategy {
+				LiquidationStrategy::Auction => LiquidationStrategies::<T>::remove(currency_id),
+				LiquidationStrategy::DutchAuction => {
+					LiquidationStrategies::<T>::insert(currency_id, LiquidationStrategy::DutchAuction)
+				}
+				LiquidationStrategy::Exchange => return Err(Error::<T>::InvalidLiquidationStrategy.into()),
+			}
+			Self::deposit_event(Event::LiquidationStrategyUpdated(currency_id, strategy));
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -540,6 +769,38 @@ pub mod module {
 						.propagate(true)
 						.build()
 				}
+				Call::liquidate_multi_collateral(who) => {
+					let account = T::Lookup::lookup(who.clone())?;
+					let position = <LoansOf<T>>::multi_collateral_positions(&account);
+					if !matches!(
+						Self::check_multi_collateral_cdp_status(&position.collateral_list(), position.debit),
+						CDPStatus::Unsafe
+					) || T::EmergencyShutdown::is_shutdown()
+					{
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides((<frame_system::Pallet<T>>::block_number(), who))
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				Call::settle_multi_collateral(who) => {
+					let account = T::Lookup::lookup(who.clone())?;
+					let MultiCollateralPosition { debit, .. } = <LoansOf<T>>::multi_collateral_positions(account);
+					if debit.is_zero() || !T::EmergencyShutdown::is_shutdown() {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(who)
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
 				_ => InvalidTransaction::Call.into(),
 			}
 		}
@@ -582,8 +843,40 @@ impl<T: Config> Pallet<T> {
 						}
 					}
 				}
+
+				// adjust the rate after the interest of the elapsed interval is accumulated
+				// at the previous rate.
+				Self::update_dynamic_interest_rate_per_sec(currency_id);
 				count += 1;
 			}
+
+			let rate_to_accumulate =
+				Self::compound_interest_rate(Self::get_multi_collateral_interest_rate_per_sec(), interval_secs);
+			let total_debits = <LoansOf<T>>::total_multi_collateral_position().debit;
+			if !rate_to_accumulate.is_zero() && !total_debits.is_zero() {
+				let debit_exchange_rate = Self::get_multi_collateral_debit_exchange_rate();
+				let debit_exchange_rate_increment = debit_exchange_rate.saturating_mul(rate_to_accumulate);
+				let issued_stable_coin_balance = debit_exchange_rate_increment.saturating_mul_int(total_debits);
+
+				// issue stablecoin to surplus pool
+				match <T as Config>::CDPTreasury::on_system_surplus(issued_stable_coin_balance) {
+					Ok(_) => {
+						// update exchange rate when issue success
+						MultiCollateralDebitExchangeRate::<T>::put(
+							debit_exchange_rate.saturating_add(debit_exchange_rate_increment),
+						);
+					}
+					Err(e) => {
+						log::warn!(
+							target: "cdp-engine",
+							"on_system_surplus: failed to on system surplus {:?}: {:?}. \
+							This is unexpected but should be safe",
+							issued_stable_coin_balance, e
+						);
+					}
+				}
+			}
+			count += 1;
 		}
 
 		// update last accumulation timestamp
@@ -615,6 +908,30 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	fn submit_unsigned_multi_collateral_liquidation_tx(who: T::AccountId) {
+		let who = T::Lookup::unlookup(who);
+		let call = Call::<T>::liquidate_multi_collateral(who.clone());
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "cdp-engine offchain worker",
+				"submit unsigned liquidation tx for \nmulti-collateral CDP - AccountId {:?} \nfailed!",
+				who,
+			);
+		}
+	}
+
+	fn submit_unsigned_multi_collateral_settlement_tx(who: T::AccountId) {
+		let who = T::Lookup::unlookup(who);
+		let call = Call::<T>::settle_multi_collateral(who.clone());
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "cdp-engine offchain worker",
+				"submit unsigned settlement tx for \nmulti-collateral CDP - AccountId {:?} \nfailed!",
+				who,
+			);
+		}
+	}
+
 	fn _offchain_worker() -> Result<(), OffchainErr> {
 		let collateral_currency_ids = T::CollateralCurrencyIds::get();
 		if collateral_currency_ids.len().is_zero() {
@@ -642,7 +959,7 @@ impl<T: Config> Pallet<T> {
 				(last_collateral_position, maybe_last_iterator_previous_key)
 			} else {
 				let mut rng = ChaChaRng::from_seed(sp_io::offchain::random_seed());
-				(pick_u32(&mut rng, collateral_currency_ids.len() as u32), None)
+				(pick_u32(&mut rng, collateral_currency_ids.len() as u32 + 1), None)
 			};
 
 		// get the max iterationns config
@@ -651,8 +968,63 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or(Some(DEFAULT_MAX_ITERATIONS))
 			.ok_or(OffchainErr::OffchainStore)?;
 
-		let currency_id = collateral_currency_ids[collateral_position as usize];
 		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+
+		// the position after all collateral types is for multi-collateral CDPs
+		if collateral_position as usize >= collateral_currency_ids.len() {
+			let mut map_iterator = match start_key.clone() {
+				Some(key) => <loans::MultiCollateralPositions<T>>::iter_from(key),
+				None => <loans::MultiCollateralPositions<T>>::iter(),
+			};
+
+			let mut finished = true;
+			let mut iteration_count = 0;
+
+			#[allow(clippy::while_let_on_iterator)]
+			while let Some((who, position)) = map_iterator.next() {
+				if iteration_count >= max_iterations {
+					finished = false;
+					break;
+				}
+
+				if !is_shutdown
+					&& matches!(
+						Self::check_multi_collateral_cdp_status(&position.collateral_list(), position.debit),
+						CDPStatus::Unsafe
+					) {
+					// liquidate unsafe CDPs before emergency shutdown occurs
+					Self::submit_unsigned_multi_collateral_liquidation_tx(who);
+				} else if is_shutdown && !position.debit.is_zero() {
+					// settle CDPs with debit after emergency shutdown occurs.
+					Self::submit_unsigned_multi_collateral_settlement_tx(who);
+				}
+
+				iteration_count += 1;
+
+				// extend offchain worker lock
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
+			log::debug!(
+				target: "cdp-engine offchain worker",
+				"iteration info:\n max iterations is {:?}\n multi-collateral CDPs, start key: {:?}, iterate count: {:?}\n",
+				max_iterations,
+				start_key,
+				iteration_count,
+			);
+
+			if finished {
+				to_be_continue.set(&(0u32, Option::<Vec<u8>>::None));
+			} else {
+				to_be_continue.set(&(collateral_position, Some(map_iterator.last_raw_key())));
+			}
+
+			// Consume the guard but **do not** unlock the underlying lock.
+			guard.forget();
+
+			return Ok(());
+		}
+
+		let currency_id = collateral_currency_ids[collateral_position as usize];
 		let mut map_iterator =
 			<loans::Positions<T>>::iter_prefix_from(currency_id, start_key.clone().ok_or(OffchainErr::OffchainStore)?);
 
@@ -700,13 +1072,8 @@ impl<T: Config> Pallet<T> {
 		// if iteration for map storage finished, clear to be continue record
 		// otherwise, update to be continue record
 		if finished {
-			let next_collateral_position =
-				if collateral_position < collateral_currency_ids.len().saturating_sub(1) as u32 {
-					collateral_position + 1
-				} else {
-					0
-				};
-			to_be_continue.set(&(next_collateral_position, Option::<Vec<u8>>::None));
+			// move to the next collateral type, or multi-collateral CDPs after the last one
+			to_be_continue.set(&(collateral_position + 1, Option::<Vec<u8>>::None));
 		} else {
 			to_be_continue.set(&(collateral_position, Some(map_iterator.last_raw_key())));
 		}
@@ -741,12 +1108,43 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn get_interest_rate_per_sec(currency_id: CurrencyId) -> Rate {
-		Self::collateral_params(currency_id)
-			.interest_rate_per_sec
+		Self::dynamic_interest_rate_per_sec(currency_id)
+			.or_else(|| Self::collateral_params(currency_id).interest_rate_per_sec)
 			.unwrap_or_default()
 			.saturating_add(Self::global_interest_rate_per_sec())
 	}
 
+	/// Move the interest rate per sec of `currency_id` towards the rate of the
+	/// interest rate model, the change is bounded by
+	/// `MaxInterestRatePerSecChange`.
+	fn update_dynamic_interest_rate_per_sec(currency_id: CurrencyId) {
+		let params = Self::collateral_params(currency_id);
+		let total_debit_value = Self::get_debit_value(currency_id, <LoansOf<T>>::total_positions(currency_id).debit);
+		let utilization = Ratio::checked_from_rational(total_debit_value, params.maximum_total_debit_value)
+			.unwrap_or_else(Ratio::one);
+
+		if let Some(target_rate) = T::InterestRateModel::get_interest_rate_per_sec(currency_id, utilization) {
+			let current_rate = Self::dynamic_interest_rate_per_sec(currency_id)
+				.or(params.interest_rate_per_sec)
+				.unwrap_or_default();
+			let max_change = T::MaxInterestRatePerSecChange::get();
+			let new_rate = if target_rate > current_rate {
+				current_rate.saturating_add(target_rate.saturating_sub(current_rate).min(max_change))
+			} else {
+				current_rate.saturating_sub(current_rate.saturating_sub(target_rate).min(max_change))
+			};
+
+			if Self::dynamic_interest_rate_per_sec(currency_id) != Some(new_rate) {
+				DynamicInterestRatePerSec::<T>::insert(currency_id, new_rate);
+				Self::deposit_event(Event::DynamicInterestRatePerSecUpdated(currency_id, Some(new_rate)));
+			}
+		} else if DynamicInterestRatePerSec::<T>::take(currency_id).is_some() {
+			// fallback to the interest rate per sec of risk management params if the
+			// interest rate model is unavailable.
+			Self::deposit_event(Event::DynamicInterestRatePerSecUpdated(currency_id, None));
+		}
+	}
+
 	pub fn compound_interest_rate(rate_per_sec: Rate, secs: u64) -> Rate {
 		rate_per_sec
 			.saturating_add(Rate::one())
@@ -774,6 +1172,71 @@ impl<T: Config> Pallet<T> {
 		crate::DebitExchangeRateConvertor::<T>::convert((currency_id, debit_balance))
 	}
 
+	pub fn get_multi_collateral_interest_rate_per_sec() -> Rate {
+		Self::multi_collateral_params()
+			.interest_rate_per_sec
+			.unwrap_or_default()
+			.saturating_add(Self::global_interest_rate_per_sec())
+	}
+
+	pub fn get_multi_collateral_liquidation_penalty() -> Rate {
+		Self::multi_collateral_params()
+			.liquidation_penalty
+			.unwrap_or_else(T::DefaultLiquidationPenalty::get)
+	}
+
+	pub fn get_multi_collateral_debit_exchange_rate() -> ExchangeRate {
+		Self::multi_collateral_debit_exchange_rate().unwrap_or_else(T::DefaultDebitExchangeRate::get)
+	}
+
+	pub fn get_multi_collateral_debit_value(debit_balance: Balance) -> Balance {
+		Self::get_multi_collateral_debit_exchange_rate().saturating_mul_int(debit_balance)
+	}
+
+	/// Get the total collateral value of the basket which can back the debit
+	/// value, the value of each collateral is divided by its liquidation
+	/// ratio, or required collateral ratio if `use_required_ratio` and it's
+	/// set.
+	pub fn get_multi_collateral_backing_value(
+		collaterals: &[(CurrencyId, Balance)],
+		use_required_ratio: bool,
+	) -> Result<Balance, DispatchError> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		collaterals
+			.iter()
+			.try_fold(Zero::zero(), |total: Balance, (currency_id, amount)| {
+				let feed_price = T::PriceSource::get_relative_price(*currency_id, stable_currency_id)
+					.ok_or(Error::<T>::InvalidFeedPrice)?;
+				let ratio = if use_required_ratio {
+					Self::required_collateral_ratio(*currency_id)
+						.unwrap_or_else(|| Self::get_liquidation_ratio(*currency_id))
+				} else {
+					Self::get_liquidation_ratio(*currency_id)
+				};
+				let backing_value = ratio
+					.reciprocal()
+					.map(|r| r.saturating_mul_int(feed_price.saturating_mul_int(*amount)))
+					.unwrap_or_else(Balance::max_value);
+				Ok(total.saturating_add(backing_value))
+			})
+	}
+
+	pub fn check_multi_collateral_cdp_status(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_amount: Balance,
+	) -> CDPStatus {
+		match Self::get_multi_collateral_backing_value(collaterals, false) {
+			Ok(backing_value) => {
+				if backing_value < Self::get_multi_collateral_debit_value(debit_amount) {
+					CDPStatus::Unsafe
+				} else {
+					CDPStatus::Safe
+				}
+			}
+			Err(e) => CDPStatus::ChecksFailed(e),
+		}
+	}
+
 	pub fn calculate_collateral_ratio(
 		currency_id: CurrencyId,
 		collateral_balance: Balance,
@@ -786,6 +1249,91 @@ impl<T: Config> Pallet<T> {
 		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
 	}
 
+	/// Get the current collateral ratio of the CDP, `None` if the price of
+	/// collateral is unavailable
+	pub fn get_collateral_ratio(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Option<Ratio> {
+		T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.map(|price| Self::calculate_collateral_ratio(currency_id, collateral_balance, debit_balance, price))
+	}
+
+	/// Get the collateral price at which the CDP becomes unsafe
+	pub fn get_liquidation_price(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Option<Price> {
+		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+		Price::checked_from_rational(debit_value, collateral_balance)?
+			.checked_mul(&Self::get_liquidation_ratio(currency_id))
+	}
+
+	/// Get the stable value the CDP can still issue without breaking the
+	/// required collateral ratio and the hard cap of total debit value
+	pub fn get_available_to_borrow(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Balance {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let params = Self::collateral_params(currency_id);
+		let collateral_price = match T::PriceSource::get_relative_price(currency_id, stable_currency_id) {
+			Some(price) => price,
+			None => return Zero::zero(),
+		};
+
+		let collateral_value = collateral_price.saturating_mul_int(collateral_balance);
+		let max_debit_value = params
+			.required_collateral_ratio
+			.unwrap_or_else(|| Self::get_liquidation_ratio(currency_id))
+			.reciprocal()
+			.map_or(collateral_value, |ratio| ratio.saturating_mul_int(collateral_value));
+		let total_debit_value = Self::get_debit_value(currency_id, <LoansOf<T>>::total_positions(currency_id).debit);
+
+		max_debit_value
+			.saturating_sub(Self::get_debit_value(currency_id, debit_balance))
+			.min(params.maximum_total_debit_value.saturating_sub(total_debit_value))
+	}
+
+	/// Get the health of the CDP of `who` under `currency_id`, `None` if the
+	/// CDP is empty
+	pub fn get_cdp_health(who: &T::AccountId, currency_id: CurrencyId) -> Option<CDPHealth<Balance>> {
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+		if collateral.is_zero() && debit.is_zero() {
+			return None;
+		}
+
+		let status = match Self::check_cdp_status(currency_id, collateral, debit) {
+			CDPStatus::Safe => CDPHealthStatus::Safe,
+			CDPStatus::Unsafe => CDPHealthStatus::Unsafe,
+			CDPStatus::ChecksFailed(_) => CDPHealthStatus::ChecksFailed,
+		};
+
+		Some(CDPHealth {
+			collateral,
+			debit,
+			debit_value: Self::get_debit_value(currency_id, debit),
+			status,
+			collateral_ratio: Self::get_collateral_ratio(currency_id, collateral, debit),
+			liquidation_ratio: Self::get_liquidation_ratio(currency_id),
+			liquidation_price: Self::get_liquidation_price(currency_id, collateral, debit),
+			available_to_borrow: Self::get_available_to_borrow(currency_id, collateral, debit),
+			accrued_interest: Self::get_accrued_interest(currency_id, who),
+		})
+	}
+
+	/// Get the interest accrued on the debit of the CDP since its principal
+	/// was recorded
+	pub fn get_accrued_interest(currency_id: CurrencyId, who: &T::AccountId) -> Balance {
+		let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, who);
+		<LoansOf<T>>::debit_principals(currency_id, who).map_or(Zero::zero(), |principal| {
+			Self::get_debit_value(currency_id, debit).saturating_sub(principal)
+		})
+	}
+
 	pub fn adjust_position(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
@@ -796,10 +1344,43 @@ impl<T: Config> Pallet<T> {
 			T::CollateralCurrencyIds::get().contains(&currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		if debit_adjustment.is_positive() {
+			ensure!(
+				!T::PriceCircuitBreaker::is_tripped(currency_id),
+				Error::<T>::PriceCircuitBreakerTripped,
+			);
+		}
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
 		Ok(())
 	}
 
+	pub fn adjust_multi_collateral_position(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		let collateral_currency_ids = T::CollateralCurrencyIds::get();
+		ensure!(
+			collateral_adjustments
+				.iter()
+				.all(|(currency_id, _)| collateral_currency_ids.contains(currency_id)),
+			Error::<T>::InvalidCollateralType,
+		);
+		if debit_adjustment.is_positive() {
+			let position = <LoansOf<T>>::multi_collateral_positions(who);
+			ensure!(
+				!position
+					.collaterals
+					.keys()
+					.chain(collateral_adjustments.iter().map(|(currency_id, _)| currency_id))
+					.any(|currency_id| T::PriceCircuitBreaker::is_tripped(*currency_id)),
+				Error::<T>::PriceCircuitBreakerTripped,
+			);
+		}
+		<LoansOf<T>>::adjust_multi_collateral_position(who, collateral_adjustments, debit_adjustment)?;
+		Ok(())
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
@@ -820,6 +1401,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	// settle multi-collateral cdp has debit when emergency shutdown
+	pub fn settle_multi_collateral_cdp_has_debit(who: T::AccountId) -> DispatchResult {
+		let position = <LoansOf<T>>::multi_collateral_positions(&who);
+		ensure!(!position.debit.is_zero(), Error::<T>::NoDebitValue);
+
+		// confiscate collaterals in cdp to cdp treasury by order until they cover the bad debt,
+		// and decrease CDP's debit to zero
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let mut remain_debt_value = Self::get_multi_collateral_debit_value(position.debit);
+		let mut confiscate_collaterals: Vec<(CurrencyId, Balance)> = vec![];
+		for (currency_id, collateral) in position.collaterals {
+			if remain_debt_value.is_zero() {
+				break;
+			}
+
+			let settle_price: Price = T::PriceSource::get_relative_price(stable_currency_id, currency_id)
+				.ok_or(Error::<T>::InvalidFeedPrice)?;
+			let required_collateral_amount = settle_price.saturating_mul_int(remain_debt_value);
+			if required_collateral_amount <= collateral {
+				confiscate_collaterals.push((currency_id, required_collateral_amount));
+				remain_debt_value = Zero::zero();
+			} else {
+				let feed_price: Price = T::PriceSource::get_relative_price(currency_id, stable_currency_id)
+					.ok_or(Error::<T>::InvalidFeedPrice)?;
+				confiscate_collaterals.push((currency_id, collateral));
+				remain_debt_value = remain_debt_value.saturating_sub(feed_price.saturating_mul_int(collateral));
+			}
+		}
+
+		// confiscate collaterals and all debit
+		<LoansOf<T>>::confiscate_multi_collateral_and_debit(&who, &confiscate_collaterals, position.debit)?;
+
+		Self::deposit_event(Event::SettleMultiCollateralCDPInDebit(who));
+		Ok(())
+	}
+
 	// close cdp has debit by swap collateral to exact debit
 	#[transactional]
 	pub fn close_cdp_has_debit_by_dex(
@@ -910,7 +1527,10 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::MustBeUnsafe
 		);
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
+		// confiscate part of collateral and debit of unsafe cdp to cdp treasury if partial
+		// liquidation is available, otherwise confiscate all of them.
+		let (collateral, debit) =
+			Self::calculate_partial_liquidation(currency_id, collateral, debit).unwrap_or((collateral, debit));
 		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
 
 		let bad_debt_value = Self::get_debit_value(currency_id, debit);
@@ -959,15 +1579,7 @@ impl<T: Config> Pallet<T> {
 			}
 
 			// if cannot liquidate by swap, create collateral auctions by cdp treasury
-			<T as Config>::CDPTreasury::create_collateral_auctions(
-				currency_id,
-				collateral,
-				target_stable_amount,
-				who.clone(),
-				true,
-			)?;
-
-			Ok(LiquidationStrategy::Auction)
+			Self::create_collateral_auctions_by_strategy(currency_id, collateral, target_stable_amount, who.clone())
 		})()?;
 
 		Self::deposit_event(Event::LiquidateUnsafeCDP(
@@ -981,6 +1593,153 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Calculate the collateral and debit amounts to confiscate to bring the
+	/// unsafe CDP back to the required collateral ratio, capped by the close
+	/// factor. Return `None` if partial liquidation is unavailable and the
+	/// whole CDP should be liquidated.
+	pub fn calculate_partial_liquidation(
+		currency_id: CurrencyId,
+		collateral: Balance,
+		debit: Balance,
+	) -> Option<(Balance, Balance)> {
+		let close_factor = Self::close_factors(currency_id)?;
+		let params = Self::collateral_params(currency_id);
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let collateral_price = T::PriceSource::get_relative_price(currency_id, stable_currency_id)?;
+		let stable_price = T::PriceSource::get_relative_price(stable_currency_id, currency_id)?;
+		let collateral_value = collateral_price.saturating_mul_int(collateral);
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		let target_ratio = params
+			.required_collateral_ratio
+			.unwrap_or_else(|| Self::get_liquidation_ratio(currency_id));
+
+		// the collateral ratio of the confiscated part, including the liquidation penalty
+		// and the slippage limit of swap.
+		let supply_ratio = Ratio::one()
+			.saturating_add(Self::get_liquidation_penalty(currency_id))
+			.checked_div(&Ratio::one().saturating_sub(T::MaxSwapSlippageCompareToOracle::get()))?;
+
+		// closing debit cannot raise the collateral ratio if the ratio of the confiscated
+		// part is not below the target ratio.
+		if target_ratio <= supply_ratio {
+			return None;
+		}
+
+		// solve (collateral_value - supply_ratio * x) / (debit_value - x) = target_ratio
+		let close_ratio = Ratio::checked_from_rational(
+			target_ratio
+				.saturating_mul_int(debit_value)
+				.saturating_sub(collateral_value),
+			debit_value,
+		)?
+		.checked_div(&target_ratio.saturating_sub(supply_ratio))?
+		.min(close_factor);
+
+		let debit_decrease = close_ratio.saturating_mul_int(debit);
+		let remain_debit_value = Self::get_debit_value(currency_id, debit.saturating_sub(debit_decrease));
+		if debit_decrease.is_zero() || debit_decrease >= debit || remain_debit_value < T::MinimumDebitValue::get() {
+			return None;
+		}
+
+		let collateral_confiscate = supply_ratio
+			.saturating_mul_int(stable_price.saturating_mul_int(Self::get_debit_value(currency_id, debit_decrease)));
+		if collateral_confiscate >= collateral {
+			return None;
+		}
+
+		Some((collateral_confiscate, debit_decrease))
+	}
+
+	// liquidate unsafe multi-collateral cdp
+	pub fn liquidate_unsafe_multi_collateral_cdp(who: T::AccountId) -> DispatchResult {
+		let position = <LoansOf<T>>::multi_collateral_positions(&who);
+		let collaterals = position.collateral_list();
+
+		// ensure the cdp is unsafe
+		ensure!(
+			matches!(
+				Self::check_multi_collateral_cdp_status(&collaterals, position.debit),
+				CDPStatus::Unsafe
+			),
+			Error::<T>::MustBeUnsafe
+		);
+
+		// confiscate all collaterals and debit of unsafe cdp to cdp treasury
+		<LoansOf<T>>::confiscate_multi_collateral_and_debit(&who, &collaterals, position.debit)?;
+
+		let bad_debt_value = Self::get_multi_collateral_debit_value(position.debit);
+		let target_stable_amount =
+			Self::get_multi_collateral_liquidation_penalty().saturating_mul_acc_int(bad_debt_value);
+
+		// split the target to the collateral auctions by the proportion of collateral values
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let collateral_values: Vec<Balance> = collaterals
+			.iter()
+			.map(|(currency_id, amount)| {
+				T::PriceSource::get_relative_price(*currency_id, stable_currency_id)
+					.expect("the oracle price should be avalible because liquidation are triggered by it.")
+					.saturating_mul_int(*amount)
+			})
+			.collect();
+		let total_collateral_value = collateral_values
+			.iter()
+			.fold(Balance::zero(), |total, value| total.saturating_add(*value));
+		let mut remain_target = target_stable_amount;
+		for (index, (currency_id, amount)) in collaterals.iter().enumerate() {
+			let target = if index == collaterals.len() - 1 {
+				remain_target
+			} else {
+				Ratio::checked_from_rational(collateral_values[index], total_collateral_value)
+					.unwrap_or_default()
+					.saturating_mul_int(target_stable_amount)
+					.min(remain_target)
+			};
+			remain_target = remain_target.saturating_sub(target);
+
+			Self::create_collateral_auctions_by_strategy(*currency_id, *amount, target, who.clone())?;
+		}
+
+		Self::deposit_event(Event::LiquidateUnsafeMultiCollateralCDP(
+			who,
+			collaterals,
+			bad_debt_value,
+		));
+		Ok(())
+	}
+
+	/// Create collateral auctions or dutch collateral auction by the
+	/// liquidation strategy of `currency_id`.
+	fn create_collateral_auctions_by_strategy(
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		refund_receiver: T::AccountId,
+	) -> Result<LiquidationStrategy, DispatchError> {
+		match Self::liquidation_strategies(currency_id) {
+			Some(LiquidationStrategy::DutchAuction) => {
+				<T as Config>::CDPTreasury::create_dutch_collateral_auction(
+					currency_id,
+					amount,
+					target,
+					refund_receiver,
+				)?;
+				Ok(LiquidationStrategy::DutchAuction)
+			}
+			_ => {
+				<T as Config>::CDPTreasury::create_collateral_auctions(
+					currency_id,
+					amount,
+					target,
+					refund_receiver,
+					true,
+				)?;
+				Ok(LiquidationStrategy::Auction)
+			}
+		}
+	}
+}
+
 impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pallet<T> {
 	fn get_bad_debt_value(currency_id: CurrencyId, debit_balance: Balance) -> Balance {
 		Self::get_debit_value(currency_id, debit_balance)
@@ -1033,6 +1792,51 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 
 		Ok(())
 	}
+
+	fn get_multi_collateral_debit_value(debit_balance: Balance) -> Balance {
+		Self::get_multi_collateral_debit_value(debit_balance)
+	}
+
+	fn check_multi_collateral_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: Balance,
+		check_required_ratio: bool,
+	) -> DispatchResult {
+		if !debit_balance.is_zero() {
+			let debit_value = Self::get_multi_collateral_debit_value(debit_balance);
+
+			// check the required collateral ratios
+			if check_required_ratio {
+				ensure!(
+					Self::get_multi_collateral_backing_value(collaterals, true)? >= debit_value,
+					Error::<T>::BelowRequiredCollateralRatio
+				);
+			}
+
+			// check the liquidation ratios
+			ensure!(
+				Self::get_multi_collateral_backing_value(collaterals, false)? >= debit_value,
+				Error::<T>::BelowLiquidationRatio
+			);
+
+			// check the minimum_debit_value
+			ensure!(
+				debit_value >= T::MinimumDebitValue::get(),
+				Error::<T>::RemainDebitValueTooSmall,
+			);
+		}
+
+		Ok(())
+	}
+
+	fn check_multi_collateral_debit_cap(total_debit_balance: Balance) -> DispatchResult {
+		let hard_cap = Self::multi_collateral_params().maximum_total_debit_value;
+		let total_debit_value = Self::get_multi_collateral_debit_value(total_debit_balance);
+
+		ensure!(total_debit_value <= hard_cap, Error::<T>::ExceedDebitValueHardCap,);
+
+		Ok(())
+	}
 }
 
 /// Pick a new PRN, in the range [0, `max`) (exclusive).
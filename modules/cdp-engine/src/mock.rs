@@ -156,12 +156,34 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 		match (base, quote) {
 			(AUSD, BTC) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			(BTC, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(AUSD, DOT) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(DOT, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			_ => None,
 		}
 	}
 
-	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
-		unimplemented!()
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			BTC | DOT => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			_ => None,
+		}
+	}
+}
+
+thread_local! {
+	static INTEREST_RATE_PER_SEC: RefCell<Option<Rate>> = RefCell::new(None);
+}
+
+pub struct MockInterestRateModel;
+impl MockInterestRateModel {
+	pub fn set_interest_rate_per_sec(rate: Option<Rate>) {
+		INTEREST_RATE_PER_SEC.with(|v| *v.borrow_mut() = rate);
+	}
+}
+impl InterestRateModel<CurrencyId> for MockInterestRateModel {
+	fn get_interest_rate_per_sec(_currency_id: CurrencyId, _utilization: Ratio) -> Option<Rate> {
+		INTEREST_RATE_PER_SEC.with(|v| *v.borrow_mut())
 	}
 }
 
@@ -180,6 +202,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -188,6 +227,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Default::default()
 	}
 
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
@@ -217,6 +264,8 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 10;
+	pub const PriceObservationLimit: u32 = 10;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -231,11 +280,16 @@ impl dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = ();
 }
 
 parameter_types! {
@@ -263,6 +317,21 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+thread_local! {
+	static TRIPPED_CURRENCY: RefCell<Option<CurrencyId>> = RefCell::new(None);
+}
+
+pub fn mock_trip_price_circuit_breaker(currency_id: CurrencyId) {
+	TRIPPED_CURRENCY.with(|v| *v.borrow_mut() = Some(currency_id))
+}
+
+pub struct MockPriceCircuitBreaker;
+impl PriceCircuitBreaker<CurrencyId> for MockPriceCircuitBreaker {
+	fn is_tripped(currency_id: CurrencyId) -> bool {
+		TRIPPED_CURRENCY.with(|v| *v.borrow() == Some(currency_id))
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -273,6 +342,7 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(10, 100);
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub MaxInterestRatePerSecChange: Rate = Rate::saturating_from_rational(1, 1000);
 	pub const UnsignedPriority: u64 = 1 << 20;
 	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
@@ -284,6 +354,9 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type PriceCircuitBreaker = MockPriceCircuitBreaker;
+	type InterestRateModel = MockInterestRateModel;
+	type MaxInterestRatePerSecChange = MaxInterestRatePerSecChange;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, parameter_types};
 use mock::{Event, *};
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BadOrigin;
@@ -38,6 +38,7 @@ fn check_cdp_status_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Safe);
 
@@ -49,6 +50,7 @@ fn check_cdp_status_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Unsafe);
 
@@ -90,6 +92,7 @@ fn get_liquidation_penalty_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_penalty(BTC),
@@ -113,6 +116,7 @@ fn get_liquidation_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_ratio(BTC),
@@ -143,6 +147,50 @@ fn set_global_params_work() {
 	});
 }
 
+#[test]
+fn set_liquidation_strategy_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategy(Origin::signed(5), BTC, LiquidationStrategy::DutchAuction),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategy(Origin::signed(1), AUSD, LiquidationStrategy::DutchAuction),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategy(Origin::signed(1), BTC, LiquidationStrategy::Exchange),
+			Error::<Runtime>::InvalidLiquidationStrategy
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_strategy(
+			Origin::signed(1),
+			BTC,
+			LiquidationStrategy::DutchAuction
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationStrategyUpdated(
+			BTC,
+			LiquidationStrategy::DutchAuction,
+		)));
+		assert_eq!(
+			CDPEngineModule::liquidation_strategies(BTC),
+			Some(LiquidationStrategy::DutchAuction)
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_strategy(
+			Origin::signed(1),
+			BTC,
+			LiquidationStrategy::Auction
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationStrategyUpdated(
+			BTC,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPEngineModule::liquidation_strategies(BTC), None);
+	});
+}
+
 #[test]
 fn set_collateral_params_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -155,6 +203,7 @@ fn set_collateral_params_work() {
 				Change::NoChange,
 				Change::NoChange,
 				Change::NoChange,
+				Change::NoChange,
 			),
 			Error::<Runtime>::InvalidCollateralType
 		);
@@ -169,6 +218,7 @@ fn set_collateral_params_work() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10000),
+				Change::NoChange,
 			),
 			BadOrigin
 		);
@@ -180,6 +230,7 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 2))),
 		));
 		System::assert_has_event(Event::CDPEngineModule(crate::Event::InterestRatePerSecUpdated(
 			BTC,
@@ -200,6 +251,10 @@ fn set_collateral_params_work() {
 		System::assert_has_event(Event::CDPEngineModule(crate::Event::MaximumTotalDebitValueUpdated(
 			BTC, 10000,
 		)));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::CloseFactorUpdated(
+			BTC,
+			Some(Ratio::saturating_from_rational(1, 2)),
+		)));
 
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
@@ -209,6 +264,7 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		let new_collateral_params = CDPEngineModule::collateral_params(BTC);
@@ -230,6 +286,74 @@ fn set_collateral_params_work() {
 			Some(Ratio::saturating_from_rational(9, 5))
 		);
 		assert_eq!(new_collateral_params.maximum_total_debit_value, 10000);
+		assert_eq!(
+			CDPEngineModule::close_factors(BTC),
+			Some(Ratio::saturating_from_rational(1, 2))
+		);
+	});
+}
+
+#[test]
+fn get_cdp_health_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
+
+		assert_eq!(
+			CDPEngineModule::get_liquidation_price(BTC, 100, 300),
+			Some(Price::saturating_from_rational(45, 100))
+		);
+		assert_eq!(CDPEngineModule::get_liquidation_price(BTC, 0, 300), None);
+		assert_eq!(
+			CDPEngineModule::get_collateral_ratio(BTC, 100, 300),
+			Some(Ratio::saturating_from_rational(100, 30))
+		);
+
+		assert_eq!(CDPEngineModule::get_available_to_borrow(BTC, 100, 300), 25);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(40),
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::get_available_to_borrow(BTC, 100, 300), 10);
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(CDPEngineModule::get_available_to_borrow(BTC, 100, 300), 0);
+		assert_eq!(CDPEngineModule::get_collateral_ratio(BTC, 100, 300), None);
+
+		assert_eq!(CDPEngineModule::get_accrued_interest(BTC, &ALICE), 0);
+		DebitExchangeRate::<Runtime>::insert(BTC, ExchangeRate::saturating_from_rational(2, 10));
+		assert_eq!(CDPEngineModule::get_accrued_interest(BTC, &ALICE), 30);
+		assert_eq!(CDPEngineModule::get_accrued_interest(BTC, &BOB), 0);
+
+		assert_eq!(CDPEngineModule::get_cdp_health(&BOB, BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_cdp_health(&ALICE, BTC),
+			Some(CDPHealth {
+				collateral: 100,
+				debit: 300,
+				debit_value: 60,
+				status: CDPHealthStatus::ChecksFailed,
+				collateral_ratio: None,
+				liquidation_ratio: Ratio::saturating_from_rational(3, 2),
+				liquidation_price: Some(Price::saturating_from_rational(9, 10)),
+				available_to_borrow: 0,
+				accrued_interest: 30,
+			})
+		);
 	});
 }
 
@@ -244,6 +368,7 @@ fn calculate_collateral_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::calculate_collateral_ratio(BTC, 100, 500, Price::saturating_from_rational(1, 1)),
@@ -263,6 +388,7 @@ fn check_debit_cap_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::check_debit_cap(BTC, 100000));
 		assert_noop!(
@@ -283,6 +409,7 @@ fn check_position_valid_failed_when_invalid_feed_price() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		MockPriceSource::set_relative_price(None);
@@ -307,6 +434,7 @@ fn check_position_valid_failed_when_remain_debit_value_too_small() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 2, 10, true),
@@ -326,6 +454,7 @@ fn check_position_valid_ratio_below_liquidate_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 91, 500, true),
@@ -345,6 +474,7 @@ fn check_position_valid_ratio_below_required_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::check_position_valid(BTC, 89, 500, false));
 		assert_noop!(
@@ -365,6 +495,7 @@ fn adjust_position_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::adjust_position(&ALICE, ACA, 100, 500),
@@ -399,6 +530,7 @@ fn remain_debit_value_too_small_check() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert!(!CDPEngineModule::adjust_position(&ALICE, BTC, 0, -490).is_ok());
@@ -418,6 +550,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -436,6 +569,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
@@ -459,6 +593,147 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 	});
 }
 
+#[test]
+fn partial_liquidate_unsafe_cdp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(4, 5))),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 96, 300));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 300));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 904);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 30);
+
+		// make the cdps unsafe
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(7, 2))),
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(4, 1))),
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 96, 300), CDPStatus::Unsafe);
+
+		// close enough debit to bring the cdp back to the required collateral ratio
+		assert_eq!(
+			CDPEngineModule::calculate_partial_liquidation(BTC, 96, 300),
+			Some((36, 150))
+		);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			36,
+			15,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 15);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 904);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 30);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 150);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 60);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 60, 150), CDPStatus::Safe);
+
+		// limited by the close factor
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 5))),
+		));
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 300), CDPStatus::Unsafe);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			BOB,
+			14,
+			6,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 21);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 240);
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 86);
+
+		// fallback to liquidate the whole cdp if the close factor is not set
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(None),
+		));
+		assert_eq!(CDPEngineModule::calculate_partial_liquidation(BTC, 86, 240), None);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, BTC));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 45);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 0);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_dutch_collateral_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_liquidation_strategy(
+			Origin::signed(1),
+			BTC,
+			LiquidationStrategy::DutchAuction
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::DutchAuction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
 #[test]
 fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -471,6 +746,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
@@ -497,6 +773,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		// pool is enough, but slippage limit the swap
@@ -533,6 +810,7 @@ fn liquidate_unsafe_cdp_by_swap() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
@@ -559,6 +837,7 @@ fn liquidate_unsafe_cdp_by_swap() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
@@ -606,6 +885,7 @@ fn get_interest_rate_per_sec_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_interest_rate_per_sec(BTC),
@@ -664,6 +944,7 @@ fn accumulate_interest_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
@@ -673,6 +954,7 @@ fn accumulate_interest_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		CDPEngineModule::accumulate_interest(1, 0);
@@ -726,6 +1008,182 @@ fn accumulate_interest_work() {
 	});
 }
 
+#[test]
+fn accumulate_interest_with_interest_rate_model_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(1, 100)
+		);
+
+		// the change of interest rate per sec is bounded in a single accumulation, and
+		// the interest of the elapsed interval is accumulated at the previous rate
+		MockInterestRateModel::set_interest_rate_per_sec(Some(Rate::saturating_from_rational(5, 100)));
+		CDPEngineModule::accumulate_interest(1, 0);
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::DynamicInterestRatePerSecUpdated(
+			BTC,
+			Some(Rate::saturating_from_rational(11, 1000)),
+		)));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::DynamicInterestRatePerSecUpdated(
+			DOT,
+			Some(Rate::saturating_from_rational(1, 1000)),
+		)));
+		assert_eq!(
+			CDPEngineModule::dynamic_interest_rate_per_sec(BTC),
+			Some(Rate::saturating_from_rational(11, 1000))
+		);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(11, 1000)
+		);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(101, 1000)
+		);
+
+		MockInterestRateModel::set_interest_rate_per_sec(Some(Rate::saturating_from_rational(105, 10000)));
+		CDPEngineModule::accumulate_interest(2, 1);
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::DynamicInterestRatePerSecUpdated(
+			BTC,
+			Some(Rate::saturating_from_rational(105, 10000)),
+		)));
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(102111, 1000000)
+		);
+		assert_eq!(
+			CDPEngineModule::dynamic_interest_rate_per_sec(BTC),
+			Some(Rate::saturating_from_rational(105, 10000))
+		);
+
+		// fallback to the configured rate if the interest rate model is unavailable
+		MockInterestRateModel::set_interest_rate_per_sec(None);
+		CDPEngineModule::accumulate_interest(3, 2);
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::DynamicInterestRatePerSecUpdated(
+			BTC, None,
+		)));
+		assert_eq!(CDPEngineModule::dynamic_interest_rate_per_sec(BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(1, 100)
+		);
+
+		// updating the interest rate per sec by governance resets the adjusted rate
+		MockInterestRateModel::set_interest_rate_per_sec(Some(Rate::saturating_from_rational(5, 100)));
+		CDPEngineModule::accumulate_interest(4, 3);
+		assert_eq!(
+			CDPEngineModule::dynamic_interest_rate_per_sec(BTC),
+			Some(Rate::saturating_from_rational(11, 1000))
+		);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::DynamicInterestRatePerSecUpdated(
+			BTC, None,
+		)));
+		assert_eq!(CDPEngineModule::dynamic_interest_rate_per_sec(BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(2, 100)
+		);
+	});
+}
+
+parameter_types! {
+	pub BaseRate: Rate = Rate::saturating_from_rational(1, 100);
+	pub Multiplier: Rate = Rate::saturating_from_rational(1, 10);
+	pub JumpMultiplier: Rate = Rate::one();
+	pub Kink: Ratio = Ratio::saturating_from_rational(8, 10);
+	pub Sensitivity: Rate = Rate::saturating_from_rational(1, 2);
+	pub const GetReferenceCurrencyId: CurrencyId = BTC;
+	pub const TwapWindow: u32 = 10;
+}
+
+#[test]
+fn kinked_utilization_model_work() {
+	type Model = KinkedUtilizationModel<BaseRate, Multiplier, JumpMultiplier, Kink>;
+	assert_eq!(
+		Model::get_interest_rate_per_sec(BTC, Ratio::zero()),
+		Some(Rate::saturating_from_rational(1, 100))
+	);
+	assert_eq!(
+		Model::get_interest_rate_per_sec(BTC, Ratio::saturating_from_rational(5, 10)),
+		Some(Rate::saturating_from_rational(6, 100))
+	);
+	assert_eq!(
+		Model::get_interest_rate_per_sec(BTC, Ratio::saturating_from_rational(9, 10)),
+		Some(Rate::saturating_from_rational(19, 100))
+	);
+}
+
+#[test]
+fn peg_deviation_model_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		type Model = PegDeviationModel<Runtime, DEXModule, GetReferenceCurrencyId, BaseRate, Sensitivity, TwapWindow>;
+		System::set_block_number(1);
+		assert_eq!(Model::get_interest_rate_per_sec(BTC, Ratio::zero()), None);
+
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			125,
+			0,
+			false
+		));
+		// the time-weighted average price is unavailable until the window elapses
+		assert_eq!(Model::get_interest_rate_per_sec(BTC, Ratio::zero()), None);
+
+		System::set_block_number(11);
+		assert_eq!(
+			Model::get_interest_rate_per_sec(BTC, Ratio::zero()),
+			Some(Rate::saturating_from_rational(11, 100))
+		);
+
+		// the swaps in the current block don't move the rate
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(CAROL),
+			vec![BTC, AUSD],
+			100,
+			0
+		));
+		assert_eq!(
+			Model::get_interest_rate_per_sec(BTC, Ratio::zero()),
+			Some(Rate::saturating_from_rational(11, 100))
+		);
+
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(11, 10)));
+		assert_eq!(
+			Model::get_interest_rate_per_sec(BTC, Ratio::zero()),
+			Some(Rate::saturating_from_rational(7, 100))
+		);
+
+		// stablecoin above its peg
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(3, 2)));
+		assert_eq!(Model::get_interest_rate_per_sec(BTC, Ratio::zero()), Some(Rate::zero()));
+	});
+}
+
 #[test]
 fn settle_cdp_has_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -738,6 +1196,7 @@ fn settle_cdp_has_debit_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -785,6 +1244,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
@@ -814,6 +1274,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None),
@@ -828,6 +1289,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		assert_noop!(
@@ -887,6 +1349,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_eq!(DEXModule::get_liquidity_pool(BTC, ACA), (100, 1000));
@@ -907,6 +1370,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::CloseCDPInDebitByDEX(
@@ -922,3 +1386,321 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 		assert_eq!(CDPTreasuryModule::get_debit_pool(), 50);
 	});
 }
+
+fn set_multi_collateral_risk_params() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		Origin::signed(1),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		Origin::signed(1),
+		DOT,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(None),
+		Change::NewValue(10000),
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::set_multi_collateral_params(
+		Origin::signed(1),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NewValue(10000),
+	));
+}
+
+#[test]
+fn set_multi_collateral_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_multi_collateral_params(
+				Origin::signed(5),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+			),
+			BadOrigin
+		);
+
+		assert_ok!(CDPEngineModule::set_multi_collateral_params(
+			Origin::signed(1),
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(10000),
+		));
+		System::assert_has_event(Event::CDPEngineModule(
+			crate::Event::MultiCollateralInterestRatePerSecUpdated(Some(Rate::saturating_from_rational(1, 100000))),
+		));
+		System::assert_has_event(Event::CDPEngineModule(
+			crate::Event::MultiCollateralLiquidationPenaltyUpdated(Some(Rate::saturating_from_rational(2, 10))),
+		));
+		System::assert_has_event(Event::CDPEngineModule(
+			crate::Event::MultiCollateralMaximumTotalDebitValueUpdated(10000),
+		));
+		assert_eq!(
+			CDPEngineModule::multi_collateral_params(),
+			MultiCollateralRiskManagementParams {
+				maximum_total_debit_value: 10000,
+				interest_rate_per_sec: Some(Rate::saturating_from_rational(1, 100000)),
+				liquidation_penalty: Some(Rate::saturating_from_rational(2, 10)),
+			}
+		);
+		assert_eq!(
+			CDPEngineModule::get_multi_collateral_liquidation_penalty(),
+			Rate::saturating_from_rational(2, 10)
+		);
+
+		assert_ok!(CDPEngineModule::set_multi_collateral_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NewValue(None),
+			Change::NoChange,
+		));
+		assert_eq!(
+			CDPEngineModule::get_multi_collateral_liquidation_penalty(),
+			DefaultLiquidationPenalty::get()
+		);
+	});
+}
+
+#[test]
+fn check_multi_collateral_cdp_status_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_multi_collateral_risk_params();
+
+		// backing value = 100 / 1.5 + 100 / 2 = 116
+		assert_eq!(
+			CDPEngineModule::get_multi_collateral_backing_value(&[(DOT, 100), (BTC, 100)], false),
+			Ok(116)
+		);
+		// backing value = 100 / 2 + 100 / 1.8 = 105
+		assert_eq!(
+			CDPEngineModule::get_multi_collateral_backing_value(&[(DOT, 100), (BTC, 100)], true),
+			Ok(105)
+		);
+		assert_eq!(
+			CDPEngineModule::check_multi_collateral_cdp_status(&[(DOT, 100), (BTC, 100)], 1160),
+			CDPStatus::Safe
+		);
+		assert_eq!(
+			CDPEngineModule::check_multi_collateral_cdp_status(&[(DOT, 100), (BTC, 100)], 1170),
+			CDPStatus::Unsafe
+		);
+
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(
+			CDPEngineModule::check_multi_collateral_cdp_status(&[(DOT, 100), (BTC, 100)], 1160),
+			CDPStatus::ChecksFailed(Error::<Runtime>::InvalidFeedPrice.into())
+		);
+	});
+}
+
+#[test]
+fn adjust_multi_collateral_position_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		set_multi_collateral_risk_params();
+
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 100), (ACA, 100)], 500),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 100), (DOT, 100)], 1060),
+			Error::<Runtime>::BelowRequiredCollateralRatio,
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 100), (DOT, 100)], 10),
+			Error::<Runtime>::RemainDebitValueTooSmall,
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 1000), (DOT, 1000)], 100010),
+			Error::<Runtime>::ExceedDebitValueHardCap,
+		);
+
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1000
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_eq!(
+			LoansModule::multi_collateral_positions(ALICE).collateral_list(),
+			vec![(DOT, 100), (BTC, 100)]
+		);
+		assert_eq!(LoansModule::multi_collateral_positions(ALICE).debit, 1000);
+
+		// the liquidation ratio check is still applied when required ratio check is skipped
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			DOT,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 10)], 0),
+			Error::<Runtime>::BelowLiquidationRatio,
+		);
+	});
+}
+
+#[test]
+fn adjust_position_refused_when_price_circuit_breaker_tripped() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		set_multi_collateral_risk_params();
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1000
+		));
+
+		mock_trip_price_circuit_breaker(BTC);
+
+		// cannot issue new debit, but can still repay
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 100),
+			Error::<Runtime>::PriceCircuitBreakerTripped,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+
+		// the tripped collateral in the position also refuses new debit
+		assert_noop!(
+			CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[(DOT, 10)], 10),
+			Error::<Runtime>::PriceCircuitBreakerTripped,
+		);
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[], -100));
+		assert_eq!(LoansModule::multi_collateral_positions(ALICE).debit, 900);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_multi_collateral_cdp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		set_multi_collateral_risk_params();
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1000
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_multi_collateral_cdp(ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		// backing value = 100 / 5 + 100 / 1.5 = 86 < 100
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			DOT,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_multi_collateral(Origin::none(), ALICE));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeMultiCollateralCDP(
+			ALICE,
+			vec![(DOT, 100), (BTC, 100)],
+			100,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_eq!(
+			LoansModule::multi_collateral_positions(ALICE),
+			MultiCollateralPosition::default()
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::liquidate_multi_collateral(Origin::none(), ALICE),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
+#[test]
+fn settle_multi_collateral_cdp_has_debit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		set_multi_collateral_risk_params();
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 300), (DOT, 100)],
+			0
+		));
+		assert_noop!(
+			CDPEngineModule::settle_multi_collateral_cdp_has_debit(ALICE),
+			Error::<Runtime>::NoDebitValue,
+		);
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(&ALICE, &[], 1500));
+		assert_noop!(
+			CDPEngineModule::settle_multi_collateral(Origin::none(), ALICE),
+			Error::<Runtime>::MustAfterShutdown
+		);
+
+		mock_shutdown();
+		assert_ok!(CDPEngineModule::settle_multi_collateral(Origin::none(), ALICE));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::SettleMultiCollateralCDPInDebit(
+			ALICE,
+		)));
+		assert_eq!(
+			LoansModule::multi_collateral_positions(ALICE).collateral_list(),
+			vec![(BTC, 250)]
+		);
+		assert_eq!(LoansModule::multi_collateral_positions(ALICE).debit, 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 150);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 50);
+	});
+}
+
+#[test]
+fn accumulate_interest_of_multi_collateral_cdps_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_multi_collateral_risk_params();
+		assert_ok!(CDPEngineModule::set_multi_collateral_params(
+			Origin::signed(1),
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		CDPEngineModule::accumulate_interest(1, 0);
+		assert_eq!(CDPEngineModule::multi_collateral_debit_exchange_rate(), None);
+		assert_ok!(CDPEngineModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			300
+		));
+
+		CDPEngineModule::accumulate_interest(2, 1);
+		assert_eq!(
+			CDPEngineModule::multi_collateral_debit_exchange_rate(),
+			Some(ExchangeRate::saturating_from_rational(101, 1000))
+		);
+		assert_eq!(CDPEngineModule::get_multi_collateral_debit_value(300), 30);
+		assert_eq!(CDPEngineModule::get_multi_collateral_debit_value(1000), 101);
+	});
+}
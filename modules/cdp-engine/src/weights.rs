@@ -53,6 +53,10 @@ pub trait WeightInfo {
 	fn liquidate_by_auction() -> Weight;
 	fn liquidate_by_dex() -> Weight;
 	fn settle() -> Weight;
+	fn liquidate_multi_collateral(c: u32, ) -> Weight;
+	fn settle_multi_collateral(c: u32, ) -> Weight;
+	fn set_multi_collateral_params() -> Weight;
+	fn set_liquidation_strategy() -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -67,7 +71,7 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn set_collateral_params() -> Weight {
 		(37_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	fn set_global_params() -> Weight {
 		(11_000_000 as Weight)
@@ -75,18 +79,45 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(28 as Weight))
-			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+			.saturating_add(T::DbWeight::get().reads(29 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
 	}
 	fn liquidate_by_dex() -> Weight {
 		(252_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(29 as Weight))
-			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+			.saturating_add(T::DbWeight::get().reads(30 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
 	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn liquidate_multi_collateral(c: u32, ) -> Weight {
+		(121_604_000 as Weight)
+			// Standard Error: 265_000
+			.saturating_add((86_175_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
+	}
+	fn settle_multi_collateral(c: u32, ) -> Weight {
+		(74_268_000 as Weight)
+			// Standard Error: 121_000
+			.saturating_add((23_511_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_multi_collateral_params() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_strategy() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
 
@@ -101,7 +132,7 @@ impl WeightInfo for () {
 	fn set_collateral_params() -> Weight {
 		(37_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn set_global_params() -> Weight {
 		(11_000_000 as Weight)
@@ -109,17 +140,44 @@ impl WeightInfo for () {
 	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(28 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(29 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
 	}
 	fn liquidate_by_dex() -> Weight {
 		(252_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(29 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(30 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
 	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn liquidate_multi_collateral(c: u32, ) -> Weight {
+		(121_604_000 as Weight)
+			// Standard Error: 265_000
+			.saturating_add((86_175_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
+	}
+	fn settle_multi_collateral(c: u32, ) -> Weight {
+		(74_268_000 as Weight)
+			// Standard Error: 121_000
+			.saturating_add((23_511_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_multi_collateral_params() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_strategy() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 }
@@ -30,7 +30,7 @@
 
 use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use orml_traits::{Change, MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, Zero},
@@ -45,6 +45,10 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+// typedef to help polkadot.js disambiguate Change with different generic
+// parameters
+type ChangeBalance = Change<Balance>;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -73,7 +77,8 @@ pub mod module {
 
 		/// The cap of lots number when create collateral auction on a
 		/// liquidation or to create debit/surplus auction on block end.
-		/// If set to 0, does not work.
+		/// If set to 0, collateral auctions are not split and no debit/surplus
+		/// auction is created.
 		#[pallet::constant]
 		type MaxAuctionsCount: Get<u32>;
 
@@ -107,6 +112,17 @@ pub mod module {
 		/// The expected amount size for per lot collateral auction of specific
 		/// collateral type updated. \[collateral_type, new_size\]
 		ExpectedCollateralAuctionSizeUpdated(CurrencyId, Balance),
+		/// The buffer size of surplus pool updated. \[new_size\]
+		SurplusBufferSizeUpdated(Balance),
+		/// The fixed amount of stable currency for sale per surplus auction
+		/// updated. \[new_size\]
+		SurplusAuctionFixedSizeUpdated(Balance),
+		/// The fixed amount of debit to cover per debit auction updated.
+		/// \[new_size\]
+		DebitAuctionFixedSizeUpdated(Balance),
+		/// The initial amount of native currency for sale per debit auction
+		/// updated. \[new_amount\]
+		InitialAmountPerDebitAuctionUpdated(Balance),
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -125,6 +141,38 @@ pub mod module {
 	#[pallet::getter(fn debit_pool)]
 	pub type DebitPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The amount of surplus kept in surplus pool, surplus auctions will only
+	/// be created for the excess part.
+	///
+	/// SurplusBufferSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_buffer_size)]
+	pub type SurplusBufferSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of stable currency for sale per surplus auction. If
+	/// zero, surplus auction will not be created.
+	///
+	/// SurplusAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auction_fixed_size)]
+	pub type SurplusAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of debit to cover per debit auction. If zero, debit
+	/// auction will not be created.
+	///
+	/// DebitAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auction_fixed_size)]
+	pub type DebitAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The initial amount of native currency for sale per debit auction. If
+	/// zero, debit auction will not be created.
+	///
+	/// InitialAmountPerDebitAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn initial_amount_per_debit_auction)]
+	pub type InitialAmountPerDebitAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub expected_collateral_auction_size: Vec<(CurrencyId, Balance)>,
@@ -155,6 +203,12 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Create surplus auctions and debit auctions for the remain surplus or
+		/// debit when block begin
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			T::WeightInfo::on_initialize(Self::create_surplus_and_debit_auctions())
+		}
+
 		/// Handle excessive surplus or debits of system when block end
 		fn on_finalize(_now: T::BlockNumber) {
 			// offset the same amount between debit pool and surplus pool
@@ -216,6 +270,45 @@ pub mod module {
 			Self::deposit_event(Event::ExpectedCollateralAuctionSizeUpdated(currency_id, size));
 			Ok(())
 		}
+
+		/// Update parameters related to surplus auction and debit auction
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `surplus_buffer_size`: the amount of surplus kept in surplus pool
+		/// - `surplus_auction_fixed_size`: the amount of stable currency for sale per surplus
+		///   auction
+		/// - `debit_auction_fixed_size`: the amount of debit to cover per debit auction
+		/// - `initial_amount_per_debit_auction`: the initial amount of native currency for sale
+		///   per debit auction
+		#[pallet::weight((T::WeightInfo::set_debit_and_surplus_handle_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_debit_and_surplus_handle_params(
+			origin: OriginFor<T>,
+			surplus_buffer_size: ChangeBalance,
+			surplus_auction_fixed_size: ChangeBalance,
+			debit_auction_fixed_size: ChangeBalance,
+			initial_amount_per_debit_auction: ChangeBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Change::NewValue(val) = surplus_buffer_size {
+				SurplusBufferSize::<T>::put(val);
+				Self::deposit_event(Event::SurplusBufferSizeUpdated(val));
+			}
+			if let Change::NewValue(val) = surplus_auction_fixed_size {
+				SurplusAuctionFixedSize::<T>::put(val);
+				Self::deposit_event(Event::SurplusAuctionFixedSizeUpdated(val));
+			}
+			if let Change::NewValue(val) = debit_auction_fixed_size {
+				DebitAuctionFixedSize::<T>::put(val);
+				Self::deposit_event(Event::DebitAuctionFixedSizeUpdated(val));
+			}
+			if let Change::NewValue(val) = initial_amount_per_debit_auction {
+				InitialAmountPerDebitAuction::<T>::put(val);
+				Self::deposit_event(Event::InitialAmountPerDebitAuctionUpdated(val));
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -242,7 +335,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn offset_surplus_and_debit() {
-		let offset_amount = sp_std::cmp::min(Self::debit_pool(), Self::surplus_pool());
+		// the surplus in auction has been sold, cannot be used to offset debit.
+		let surplus_not_in_auction =
+			Self::surplus_pool().saturating_sub(T::AuctionManagerHandler::get_total_surplus_in_auction());
+		let offset_amount = sp_std::cmp::min(Self::debit_pool(), surplus_not_in_auction);
 
 		// Burn the amount that is equal to offset amount of stable currency.
 		if !offset_amount.is_zero() {
@@ -265,6 +361,58 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	/// Create surplus auctions for the surplus exceeding buffer size, and
+	/// debit auctions for the debit not in auction. The count of created
+	/// auctions is capped by `MaxAuctionsCount`. Return the count of created
+	/// auctions.
+	fn create_surplus_and_debit_auctions() -> u32 {
+		let max_auctions_count = T::MaxAuctionsCount::get();
+		let mut created_lots: u32 = 0;
+
+		let surplus_auction_fixed_size = Self::surplus_auction_fixed_size();
+		if !surplus_auction_fixed_size.is_zero() {
+			let surplus_buffer_size = Self::surplus_buffer_size();
+			let mut remain_surplus_pool =
+				Self::surplus_pool().saturating_sub(T::AuctionManagerHandler::get_total_surplus_in_auction());
+
+			while remain_surplus_pool >= surplus_buffer_size.saturating_add(surplus_auction_fixed_size) {
+				if created_lots >= max_auctions_count {
+					break;
+				}
+				if T::AuctionManagerHandler::new_surplus_auction(surplus_auction_fixed_size).is_err() {
+					break;
+				}
+				created_lots = created_lots.saturating_add(1);
+				remain_surplus_pool = remain_surplus_pool.saturating_sub(surplus_auction_fixed_size);
+			}
+		}
+
+		let debit_auction_fixed_size = Self::debit_auction_fixed_size();
+		let initial_amount_per_debit_auction = Self::initial_amount_per_debit_auction();
+		if !debit_auction_fixed_size.is_zero() && !initial_amount_per_debit_auction.is_zero() {
+			let mut remain_debit_pool =
+				Self::debit_pool().saturating_sub(T::AuctionManagerHandler::get_total_debit_in_auction());
+
+			while remain_debit_pool >= debit_auction_fixed_size {
+				if created_lots >= max_auctions_count {
+					break;
+				}
+				if T::AuctionManagerHandler::new_debit_auction(
+					initial_amount_per_debit_auction,
+					debit_auction_fixed_size,
+				)
+				.is_err()
+				{
+					break;
+				}
+				created_lots = created_lots.saturating_add(1);
+				remain_debit_pool = remain_debit_pool.saturating_sub(debit_auction_fixed_size);
+			}
+		}
+
+		created_lots
+	}
 }
 
 impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
@@ -317,6 +465,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		T::Currency::transfer(T::GetStableCurrencyId::get(), from, &Self::account_id(), surplus)
 	}
 
+	fn withdraw_surplus(to: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), to, surplus)
+	}
+
 	fn deposit_collateral(from: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)
 	}
@@ -453,6 +605,21 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		}
 		Ok(())
 	}
+
+	fn create_dutch_collateral_auction(
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		refund_receiver: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::total_collaterals_not_in_auction(currency_id) >= amount,
+			Error::<T>::CollateralNotEnough,
+		);
+
+		// dutch auction can be bought partially, so there's no need to split it into lots.
+		T::AuctionManagerHandler::new_dutch_collateral_auction(&refund_receiver, currency_id, amount, target)
+	}
 }
 
 #[cfg(feature = "std")]
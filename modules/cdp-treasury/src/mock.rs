@@ -126,6 +126,8 @@ parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 10;
+	pub const PriceObservationLimit: u32 = 10;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -139,16 +141,25 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = ();
 }
 
 thread_local! {
 	pub static TOTAL_COLLATERAL_AUCTION: RefCell<u32> = RefCell::new(0);
 	pub static TOTAL_COLLATERAL_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static TOTAL_DEBIT_AUCTION: RefCell<u32> = RefCell::new(0);
+	pub static TOTAL_DEBIT_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static TOTAL_SURPLUS_AUCTION: RefCell<u32> = RefCell::new(0);
+	pub static TOTAL_SURPLUS_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
 }
 
 pub struct MockAuctionManager;
@@ -168,6 +179,27 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+	) -> DispatchResult {
+		Self::new_collateral_auction(refund_recipient, currency_id, amount, target)
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult {
+		TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut() += 1);
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut() += fix_debit);
+		Ok(())
+	}
+
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult {
+		TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut() += 1);
+		TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut() += amount);
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -179,6 +211,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_target_in_auction() -> Self::Balance {
 		unimplemented!()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut())
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut())
+	}
 }
 
 ord_parameter_types! {
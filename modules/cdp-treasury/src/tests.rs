@@ -99,6 +99,87 @@ fn offset_surplus_and_debit_on_finalize_work() {
 	});
 }
 
+#[test]
+fn create_surplus_auctions_on_initialize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NewValue(100),
+			Change::NewValue(200),
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_eq!(
+			CDPTreasuryModule::on_initialize(1),
+			<() as WeightInfo>::on_initialize(4)
+		);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut()), 800);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
+
+		// remain surplus not in auction doesn't exceed buffer size and fixed size
+		assert_eq!(
+			CDPTreasuryModule::on_initialize(2),
+			<() as WeightInfo>::on_initialize(0)
+		);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut()), 800);
+
+		// surplus in auction cannot be used to offset debit
+		assert_ok!(CDPTreasuryModule::on_system_debit(300));
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 800);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 4);
+	});
+}
+
+#[test]
+fn create_debit_auctions_on_initialize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_debit(350));
+
+		// initial amount per debit auction is zero
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100),
+			Change::NoChange,
+		));
+		CDPTreasuryModule::on_initialize(1);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 0);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(1000),
+		));
+		CDPTreasuryModule::on_initialize(2);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 3);
+		assert_eq!(TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut()), 300);
+
+		CDPTreasuryModule::on_initialize(3);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 3);
+
+		// exceed lots count cap
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10),
+			Change::NoChange,
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(100));
+		CDPTreasuryModule::on_initialize(4);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 8);
+		assert_eq!(TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut()), 350);
+	});
+}
+
 #[test]
 fn issue_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -139,6 +220,18 @@ fn deposit_surplus_work() {
 	});
 }
 
+#[test]
+fn withdraw_surplus_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 500);
+		assert!(!CDPTreasuryModule::withdraw_surplus(&ALICE, 501).is_ok());
+		assert_ok!(CDPTreasuryModule::withdraw_surplus(&ALICE, 300));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1300);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+	});
+}
+
 #[test]
 fn deposit_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -391,6 +484,29 @@ fn create_collateral_auctions_work() {
 	});
 }
 
+#[test]
+fn create_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(BTC, &CDPTreasuryModule::account_id(), 10000));
+		assert_ok!(CDPTreasuryModule::set_expected_collateral_auction_size(
+			Origin::signed(1),
+			BTC,
+			300
+		));
+		assert_noop!(
+			CDPTreasuryModule::create_dutch_collateral_auction(BTC, 10001, 1000, ALICE),
+			Error::<Runtime>::CollateralNotEnough,
+		);
+
+		// dutch auction will not be splited
+		assert_ok!(CDPTreasuryModule::create_dutch_collateral_auction(
+			BTC, 1000, 1000, ALICE
+		));
+		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 1);
+		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 1000);
+	});
+}
+
 #[test]
 fn set_expected_collateral_auction_size_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -411,6 +527,54 @@ fn set_expected_collateral_auction_size_work() {
 	});
 }
 
+#[test]
+fn set_debit_and_surplus_handle_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPTreasuryModule::set_debit_and_surplus_handle_params(
+				Origin::signed(5),
+				Change::NewValue(100),
+				Change::NewValue(200),
+				Change::NewValue(300),
+				Change::NewValue(400),
+			),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NewValue(100),
+			Change::NewValue(200),
+			Change::NewValue(300),
+			Change::NewValue(400),
+		));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusBufferSizeUpdated(100)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusAuctionFixedSizeUpdated(
+			200,
+		)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::DebitAuctionFixedSizeUpdated(
+			300,
+		)));
+		System::assert_last_event(Event::CDPTreasuryModule(
+			crate::Event::InitialAmountPerDebitAuctionUpdated(400),
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_auction_fixed_size(), 200);
+		assert_eq!(CDPTreasuryModule::debit_auction_fixed_size(), 300);
+		assert_eq!(CDPTreasuryModule::initial_amount_per_debit_auction(), 400);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(500),
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::initial_amount_per_debit_auction(), 500);
+	});
+}
+
 #[test]
 fn extract_surplus_to_treasury_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn extract_surplus_to_treasury() -> Weight;
 	fn auction_collateral() -> Weight;
 	fn set_expected_collateral_auction_size() -> Weight;
+	fn set_debit_and_surplus_handle_params() -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
 }
 
 /// Weights for module_cdp_treasury using the Acala node and recommended hardware.
@@ -70,6 +72,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(14_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(18_362_000 as Weight)
+			// Standard Error: 112_000
+			.saturating_add((47_218_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,4 +102,16 @@ impl WeightInfo for () {
 		(14_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(18_362_000 as Weight)
+			// Standard Error: 112_000
+			.saturating_add((47_218_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -39,17 +39,19 @@ use orml_traits::{
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
 };
 use primitives::{evm::EvmAddress, CurrencyId};
+use sp_core::{H256, U256};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	traits::{CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
-	DispatchError, DispatchResult,
+	DispatchError, DispatchResult, SaturatedConversion,
 };
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	fmt::Debug,
-	marker, result,
+	marker, result, vec,
+	vec::Vec,
 };
-use support::{AddressMapping, EVMBridge, InvokeContext};
+use support::{AddressMapping, EVMBridge, HeldCurrencyIds, InvokeContext};
 
 mod mock;
 mod tests;
@@ -65,6 +67,9 @@ type CurrencyIdOf<T> =
 type AmountOf<T> =
 	<<T as Config>::MultiCurrency as MultiCurrencyExtended<<T as frame_system::Config>::AccountId>>::Amount;
 
+/// The topic of ERC20 event `Transfer(address indexed from, address indexed to, uint256 value)`.
+pub const TRANSFER_EVENT: &[u8; 32] = primitives_proc_macro::keccak256!("Transfer(address,address,uint256)");
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -90,6 +95,10 @@ pub mod module {
 		/// Mapping from address to account id.
 		type AddressMapping: AddressMapping<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The non-native currencies held by an account, used to emit the
+		/// mirrored token logs of `transfer_all`.
+		type HeldCurrencyIds: HeldCurrencyIds<Self::AccountId, CurrencyId>;
 	}
 
 	#[pallet::error]
@@ -159,6 +168,7 @@ pub mod module {
 			let to = T::Lookup::lookup(dest)?;
 			T::NativeCurrency::transfer(&from, &to, amount)?;
 
+			Self::log_mirrored_transfer(T::GetNativeCurrencyId::get(), Some(&from), Some(&to), amount);
 			Self::deposit_event(Event::Transferred(T::GetNativeCurrencyId::get(), from, to, amount));
 			Ok(())
 		}
@@ -181,6 +191,80 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Transfer `amount` of `currency_id`, and log the mirrored transfer if
+	/// `log_mirrored_transfer` is true.
+	fn do_transfer(
+		currency_id: CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		log_mirrored_transfer: bool,
+	) -> DispatchResult {
+		if amount.is_zero() || from == to {
+			return Ok(());
+		}
+
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				let sender = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
+				let origin = T::EVMBridge::get_origin().ok_or(Error::<T>::RealOriginNotFound)?;
+				let origin_address = T::AddressMapping::get_or_create_evm_address(&origin);
+				let address = T::AddressMapping::get_or_create_evm_address(to);
+				T::EVMBridge::transfer(
+					InvokeContext {
+						contract,
+						sender,
+						origin: origin_address,
+					},
+					address,
+					amount,
+				)?;
+			}
+			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::transfer(from, to, amount)?,
+			_ => T::MultiCurrency::transfer(currency_id, from, to, amount)?,
+		}
+
+		if log_mirrored_transfer {
+			Self::log_mirrored_transfer(currency_id, Some(from), Some(to), amount);
+		}
+		Self::deposit_event(Event::Transferred(currency_id, from.clone(), to.clone(), amount));
+		Ok(())
+	}
+
+	/// Emit the ERC20 `Transfer` log of the mirrored token of `currency_id`, `None` party means
+	/// minting or burning. Nothing is emitted for ERC20 tokens, which have their own logs, or if
+	/// any party has no EVM address.
+	fn log_mirrored_transfer(
+		currency_id: CurrencyId,
+		from: Option<&T::AccountId>,
+		to: Option<&T::AccountId>,
+		amount: BalanceOf<T>,
+	) {
+		if matches!(currency_id, CurrencyId::Erc20(_)) || amount.is_zero() {
+			return;
+		}
+		let evm_address_of = |who: Option<&T::AccountId>| match who {
+			Some(who) => T::AddressMapping::get_evm_address(who),
+			None => Some(EvmAddress::default()),
+		};
+
+		if let (Ok(contract), Some(from), Some(to)) = (
+			EvmAddress::try_from(currency_id),
+			evm_address_of(from),
+			evm_address_of(to),
+		) {
+			let mut data = [0u8; 32];
+			U256::from(amount.saturated_into::<u128>()).to_big_endian(&mut data);
+			T::EVMBridge::log(
+				contract,
+				vec![H256::from(TRANSFER_EVENT), H256::from(from), H256::from(to)],
+				data.to_vec(),
+			);
+		}
+	}
+}
+
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	type CurrencyId = CurrencyIdOf<T>;
 	type Balance = BalanceOf<T>;
@@ -269,32 +353,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		to: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
-		if amount.is_zero() || from == to {
-			return Ok(());
-		}
-
-		match currency_id {
-			CurrencyId::Erc20(contract) => {
-				let sender = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let origin = T::EVMBridge::get_origin().ok_or(Error::<T>::RealOriginNotFound)?;
-				let origin_address = T::AddressMapping::get_or_create_evm_address(&origin);
-				let address = T::AddressMapping::get_or_create_evm_address(to);
-				T::EVMBridge::transfer(
-					InvokeContext {
-						contract,
-						sender,
-						origin: origin_address,
-					},
-					address,
-					amount,
-				)?;
-			}
-			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::transfer(from, to, amount)?,
-			_ => T::MultiCurrency::transfer(currency_id, from, to, amount)?,
-		}
-
-		Self::deposit_event(Event::Transferred(currency_id, from.clone(), to.clone(), amount));
-		Ok(())
+		Self::do_transfer(currency_id, from, to, amount, true)
 	}
 
 	fn deposit(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
@@ -306,6 +365,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::deposit(who, amount)?,
 			_ => T::MultiCurrency::deposit(currency_id, who, amount)?,
 		}
+		Self::log_mirrored_transfer(currency_id, None, Some(who), amount);
 		Self::deposit_event(Event::Deposited(currency_id, who.clone(), amount));
 		Ok(())
 	}
@@ -319,6 +379,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::withdraw(who, amount)?,
 			_ => T::MultiCurrency::withdraw(currency_id, who, amount)?,
 		}
+		Self::log_mirrored_transfer(currency_id, Some(who), None, amount);
 		Self::deposit_event(Event::Withdrawn(currency_id, who.clone(), amount));
 		Ok(())
 	}
@@ -332,11 +393,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> Self::Balance {
-		match currency_id {
+		let gap = match currency_id {
 			CurrencyId::Erc20(_) => Default::default(),
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::slash(who, amount),
 			_ => T::MultiCurrency::slash(currency_id, who, amount),
-		}
+		};
+		Self::log_mirrored_transfer(currency_id, Some(who), None, amount.saturating_sub(gap));
+		gap
 	}
 }
 
@@ -349,6 +412,13 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::update_balance(who, by_amount)?,
 			_ => T::MultiCurrency::update_balance(currency_id, who, by_amount)?,
 		}
+		if let Ok(amount) = by_amount.abs().try_into() {
+			if by_amount.is_positive() {
+				Self::log_mirrored_transfer(currency_id, None, Some(who), amount);
+			} else {
+				Self::log_mirrored_transfer(currency_id, Some(who), None, amount);
+			}
+		}
 		Self::deposit_event(Event::BalanceUpdated(currency_id, who.clone(), by_amount));
 		Ok(())
 	}
@@ -402,11 +472,13 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
-		match currency_id {
+		let gap = match currency_id {
 			CurrencyId::Erc20(_) => value,
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::slash_reserved(who, value),
 			_ => T::MultiCurrency::slash_reserved(currency_id, who, value),
-		}
+		};
+		Self::log_mirrored_transfer(currency_id, Some(who), None, value.saturating_sub(gap));
+		gap
 	}
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
@@ -497,7 +569,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 		value: Self::Balance,
 		status: BalanceStatus,
 	) -> result::Result<Self::Balance, DispatchError> {
-		match currency_id {
+		let gap = match currency_id {
 			CurrencyId::Erc20(contract) => {
 				if value.is_zero() {
 					return Ok(value);
@@ -554,7 +626,12 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				T::NativeCurrency::repatriate_reserved(slashed, beneficiary, value, status)
 			}
 			_ => T::MultiCurrency::repatriate_reserved(currency_id, slashed, beneficiary, value, status),
+		}?;
+		// the reserved balance is part of the balance of the mirrored token
+		if slashed != beneficiary {
+			Self::log_mirrored_transfer(currency_id, Some(slashed), Some(beneficiary), value.saturating_sub(gap));
 		}
+		Ok(gap)
 	}
 }
 
@@ -839,11 +916,82 @@ where
 impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	#[transactional]
 	fn transfer_all(source: &T::AccountId, dest: &T::AccountId) -> DispatchResult {
+		let non_native_free_balances: Vec<(CurrencyId, BalanceOf<T>)> = T::HeldCurrencyIds::held_currency_ids(source)
+			.into_iter()
+			.map(|currency_id| (currency_id, T::MultiCurrency::free_balance(currency_id, source)))
+			.collect();
+
 		// transfer non-native free to dest
 		T::MultiCurrency::transfer_all(source, dest)?;
+		for (currency_id, amount) in non_native_free_balances {
+			Self::log_mirrored_transfer(currency_id, Some(source), Some(dest), amount);
+		}
 
 		// transfer all free to dest
-		T::NativeCurrency::transfer(source, dest, T::NativeCurrency::free_balance(source))
+		let native_free_balance = T::NativeCurrency::free_balance(source);
+		T::NativeCurrency::transfer(source, dest, native_free_balance)?;
+		Self::log_mirrored_transfer(
+			T::GetNativeCurrencyId::get(),
+			Some(source),
+			Some(dest),
+			native_free_balance,
+		);
+		Ok(())
+	}
+}
+
+/// The `MultiCurrency` impl for the multicurrency precompile. The mirrored token contracts emit
+/// the ERC20 `Transfer` log themselves after calling the precompile, so the transfers made through
+/// it are not logged again.
+pub struct EvmMultiCurrency<T>(marker::PhantomData<T>);
+
+impl<T: Config> MultiCurrency<T::AccountId> for EvmMultiCurrency<T> {
+	type CurrencyId = CurrencyIdOf<T>;
+	type Balance = BalanceOf<T>;
+
+	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::minimum_balance(currency_id)
+	}
+
+	fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::total_issuance(currency_id)
+	}
+
+	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::total_balance(currency_id, who)
+	}
+
+	fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, who)
+	}
+
+	fn ensure_can_withdraw(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::ensure_can_withdraw(currency_id, who, amount)
+	}
+
+	fn transfer(
+		currency_id: Self::CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		<Pallet<T>>::do_transfer(currency_id, from, to, amount, false)
+	}
+
+	fn deposit(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::deposit(currency_id, who, amount)
+	}
+
+	fn withdraw(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::withdraw(currency_id, who, amount)
+	}
+
+	fn can_slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> bool {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::can_slash(currency_id, who, amount)
+	}
+
+	fn slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> Self::Balance {
+		<Pallet<T> as MultiCurrency<T::AccountId>>::slash(currency_id, who, amount)
 	}
 }
 
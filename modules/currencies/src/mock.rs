@@ -184,6 +184,16 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type HeldCurrencyIds = MockHeldCurrencyIds;
+}
+
+pub struct MockHeldCurrencyIds;
+impl HeldCurrencyIds<AccountId, CurrencyId> for MockHeldCurrencyIds {
+	fn held_currency_ids(who: &AccountId) -> Vec<CurrencyId> {
+		tokens::Accounts::<Runtime>::iter_prefix(who)
+			.map(|(currency_id, _)| currency_id)
+			.collect()
+	}
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
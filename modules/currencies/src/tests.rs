@@ -23,9 +23,11 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	alice, bob, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency, Currencies, Event, ExtBuilder,
-	NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	alice, alice_evm_addr, bob, bob_evm_addr, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency,
+	Currencies, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens, EVM, ID_1,
+	NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
+use module_evm::Log;
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
 use support::EVM as EVMTrait;
@@ -318,6 +320,178 @@ fn call_event_should_work() {
 		});
 }
 
+fn transfer_log(currency_id: CurrencyId, from: EvmAddress, to: EvmAddress, amount: u128) -> Event {
+	let mut data = [0u8; 32];
+	U256::from(amount).to_big_endian(&mut data);
+	Event::EVM(module_evm::Event::Log(Log {
+		address: EvmAddress::try_from(currency_id).unwrap(),
+		topics: vec![H256::from(TRANSFER_EVENT), H256::from(from), H256::from(to)],
+		data: data.to_vec(),
+	}))
+}
+
+fn has_event(event: Event) -> bool {
+	System::events().iter().any(|record| record.event == event)
+}
+
+#[test]
+fn mirrored_token_transfer_should_emit_evm_log() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::transfer(Some(alice()).into(), bob(), X_TOKEN_ID, 50));
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				50
+			)));
+
+			assert_ok!(Currencies::transfer_native_currency(Some(alice()).into(), bob(), 10));
+			assert!(has_event(transfer_log(
+				NATIVE_CURRENCY_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				10
+			)));
+
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::deposit(
+				X_TOKEN_ID,
+				&alice(),
+				100
+			));
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				H160::default(),
+				alice_evm_addr(),
+				100
+			)));
+
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::withdraw(
+				X_TOKEN_ID,
+				&alice(),
+				20
+			));
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				H160::default(),
+				20
+			)));
+
+			assert_ok!(Currencies::update_balance(Origin::root(), bob(), X_TOKEN_ID, -30));
+			assert!(has_event(transfer_log(X_TOKEN_ID, bob_evm_addr(), H160::default(), 30)));
+
+			// no log if any party has no EVM address
+			System::reset_events();
+			assert_ok!(Currencies::transfer(
+				Some(alice()).into(),
+				AccountId::new([9u8; 32]),
+				X_TOKEN_ID,
+				10
+			));
+			assert!(!System::events()
+				.iter()
+				.any(|record| matches!(record.event, Event::EVM(module_evm::Event::Log(_)))));
+		});
+}
+
+#[test]
+fn mirrored_token_slash_should_emit_evm_log() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_eq!(Currencies::slash(X_TOKEN_ID, &alice(), 30), 0);
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				H160::default(),
+				30
+			)));
+
+			// only the slashed amount is logged
+			assert_eq!(Currencies::slash(NATIVE_CURRENCY_ID, &bob(), 150), 50);
+			assert!(has_event(transfer_log(
+				NATIVE_CURRENCY_ID,
+				bob_evm_addr(),
+				H160::default(),
+				100
+			)));
+
+			assert_ok!(Currencies::reserve(X_TOKEN_ID, &bob(), 40));
+			assert_eq!(Currencies::slash_reserved(X_TOKEN_ID, &bob(), 50), 10);
+			assert!(has_event(transfer_log(X_TOKEN_ID, bob_evm_addr(), H160::default(), 40)));
+		});
+}
+
+#[test]
+fn mirrored_token_repatriate_reserved_should_emit_evm_log() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::reserve(X_TOKEN_ID, &alice(), 50));
+			assert_eq!(
+				Currencies::repatriate_reserved(X_TOKEN_ID, &alice(), &bob(), 30, BalanceStatus::Free),
+				Ok(0)
+			);
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				30
+			)));
+
+			// only the repatriated amount is logged
+			assert_eq!(
+				Currencies::repatriate_reserved(X_TOKEN_ID, &alice(), &bob(), 30, BalanceStatus::Reserved),
+				Ok(10)
+			);
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				20
+			)));
+
+			// no log if the balance doesn't move between accounts
+			System::reset_events();
+			assert_ok!(Currencies::reserve(X_TOKEN_ID, &bob(), 10));
+			assert_eq!(
+				Currencies::repatriate_reserved(X_TOKEN_ID, &bob(), &bob(), 10, BalanceStatus::Free),
+				Ok(0)
+			);
+			assert!(!System::events()
+				.iter()
+				.any(|record| matches!(record.event, Event::EVM(module_evm::Event::Log(_)))));
+		});
+}
+
+#[test]
+fn mirrored_token_transfer_all_should_emit_evm_log() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Currencies as TransferAll<AccountId>>::transfer_all(&alice(), &bob()));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 200);
+			assert!(has_event(transfer_log(
+				X_TOKEN_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				100
+			)));
+			assert!(has_event(transfer_log(
+				NATIVE_CURRENCY_ID,
+				alice_evm_addr(),
+				bob_evm_addr(),
+				100
+			)));
+		});
+}
+
 #[test]
 fn erc20_total_issuance_should_work() {
 	ExtBuilder::default()
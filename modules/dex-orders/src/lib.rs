@@ -0,0 +1,533 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # DEX Orders Module
+//!
+//! ## Overview
+//!
+//! Resting orders on top of DEX. The supply currency of an order is reserved
+//! when it is placed, and the offchain worker submits unsigned transactions to
+//! swap it through DEX once the price of the trading path reaches the trigger
+//! price. A deposit in the native currency is reserved along with every order
+//! to bound the number of resting orders. Limit orders are executed when the
+//! price rises to the trigger price, stop-loss orders are executed when the
+//! price falls to the trigger price.
+//! Orders can be cancelled by their owners, and are released after expiry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{log, pallet_prelude::*, transactional};
+use frame_system::{
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::*,
+};
+use orml_traits::{GetByKey, MultiReservableCurrency};
+use orml_utilities::OffchainErr;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	offchain::{
+		storage::StorageValueRef,
+		storage_lock::{StorageLock, Time},
+		Duration,
+	},
+	traits::Zero,
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+	},
+	ArithmeticError, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::prelude::*;
+use support::{DEXManager, Price};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const OFFCHAIN_WORKER_DATA: &[u8] = b"acala/dex-orders/data/";
+pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/dex-orders/lock/";
+pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/dex-orders/max-iterations/";
+pub const LOCK_DURATION: u64 = 100;
+pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
+
+pub type OrderId = u64;
+
+/// Kind of order
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum OrderKind {
+	/// Execute when the price of the trading path rises to the trigger price
+	/// or above.
+	Limit,
+	/// Execute when the price of the trading path falls to the trigger price
+	/// or below.
+	StopLoss,
+}
+
+/// Resting order to swap with DEX
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Order<AccountId, BlockNumber> {
+	/// The owner of the order.
+	pub owner: AccountId,
+	/// The kind of the order.
+	pub kind: OrderKind,
+	/// Trading path to swap, the supply amount of the first currency is
+	/// reserved until the order is executed, cancelled or expired.
+	pub path: Vec<CurrencyId>,
+	/// Exact supply amount of the swap.
+	pub supply_amount: Balance,
+	/// The price of the supply currency in the target currency which
+	/// triggers the order.
+	pub trigger_price: Price,
+	/// Minimum acceptable target amount of the swap.
+	pub min_target_amount: Balance,
+	/// The order can't be executed after this block.
+	pub expiry: BlockNumber,
+	/// The deposit of native currency reserved until the order is
+	/// executed, cancelled or expired.
+	pub deposit: Balance,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to reserve the supply amount of orders
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// DEX to execute orders
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The limit for length of trading path
+		#[pallet::constant]
+		type TradingPathLimit: Get<u32>;
+
+		/// The native currency id
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The deposit of native currency reserved for every order
+		#[pallet::constant]
+		type OrderDeposit: Get<Balance>;
+
+		/// The minimum supply amount of orders by the supply currency
+		type MinimumSupplyAmount: GetByKey<CurrencyId, Balance>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple modules send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Invalid trading path
+		InvalidTradingPath,
+		/// The supply amount is zero
+		ZeroSupplyAmount,
+		/// The supply amount is below the minimum supply amount
+		BelowMinimumSupplyAmount,
+		/// The expiry must be later than the current block
+		InvalidExpiry,
+		/// The order does not exist
+		OrderNotFound,
+		/// Only the owner can cancel the order
+		NoPermission,
+		/// The price of DEX hasn't reached the trigger price of the order
+		OrderNotTriggered,
+		/// The order is expired
+		OrderExpired,
+		/// The order isn't expired yet
+		OrderNotExpired,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Place an order. \[order_id, owner, kind\]
+		OrderPlaced(OrderId, T::AccountId, OrderKind),
+		/// Cancel an order. \[order_id, owner\]
+		OrderCancelled(OrderId, T::AccountId),
+		/// Execute an order. \[order_id, owner, supply_amount, target_amount\]
+		OrderExecuted(OrderId, T::AccountId, Balance, Balance),
+		/// An order is expired. \[order_id, owner\]
+		OrderExpired(OrderId, T::AccountId),
+	}
+
+	/// Resting orders.
+	///
+	/// Orders: map OrderId => Option<Order>
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, Order<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// The id for next order.
+	///
+	/// NextOrderId: OrderId
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Runs after every block. Start offchain worker to check orders and
+		/// submit unsigned tx to execute triggered orders or release expired
+		/// orders.
+		fn offchain_worker(now: T::BlockNumber) {
+			if let Err(e) = Self::_offchain_worker() {
+				log::info!(
+					target: "dex-orders offchain worker",
+					"cannot run offchain worker at {:?}: {:?}",
+					now,
+					e,
+				);
+			} else {
+				log::debug!(
+					target: "dex-orders offchain worker",
+					"offchain worker start at block: {:?} already done!",
+					now,
+				);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place an order, reserve the supply amount of the first currency of
+		/// the trading path and the order deposit of native currency.
+		///
+		/// - `kind`: limit order or stop-loss order.
+		/// - `path`: trading path.
+		/// - `supply_amount`: exact supply amount.
+		/// - `trigger_price`: the price of the supply currency in the target currency which
+		///   triggers the order.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		/// - `expiry`: the order can't be executed after this block.
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			kind: OrderKind,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] supply_amount: Balance,
+			trigger_price: Price,
+			#[pallet::compact] min_target_amount: Balance,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let path_length = path.len();
+			ensure!(
+				path_length >= 2
+					&& path_length <= T::TradingPathLimit::get() as usize
+					&& path[0] != path[path_length - 1],
+				Error::<T>::InvalidTradingPath
+			);
+			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+			ensure!(
+				supply_amount >= T::MinimumSupplyAmount::get(&path[0]),
+				Error::<T>::BelowMinimumSupplyAmount
+			);
+			ensure!(
+				expiry > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			let deposit = T::OrderDeposit::get();
+			T::Currency::reserve(path[0], &who, supply_amount)?;
+			T::Currency::reserve(T::GetNativeCurrencyId::get(), &who, deposit)?;
+
+			let order_id = Self::next_order_id();
+			NextOrderId::<T>::put(order_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			Orders::<T>::insert(
+				order_id,
+				Order {
+					owner: who.clone(),
+					kind,
+					path,
+					supply_amount,
+					trigger_price,
+					min_target_amount,
+					expiry,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::OrderPlaced(order_id, who, kind));
+			Ok(())
+		}
+
+		/// Cancel an order and release the reserved supply amount and deposit.
+		///
+		/// The dispatch origin of this call must be the owner of the order.
+		///
+		/// - `order_id`: the order to cancel.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		#[transactional]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.owner == who, Error::<T>::NoPermission);
+
+			Self::release_order(order_id, &order);
+			Self::deposit_event(Event::OrderCancelled(order_id, who));
+			Ok(())
+		}
+
+		/// Execute a triggered order by swapping with DEX.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `order_id`: the order to execute.
+		#[pallet::weight(<T as Config>::WeightInfo::execute_order(T::TradingPathLimit::get()))]
+		#[transactional]
+		pub fn execute_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(!Self::is_expired(&order), Error::<T>::OrderExpired);
+			ensure!(
+				Self::get_triggered_target_amount(&order).is_some(),
+				Error::<T>::OrderNotTriggered
+			);
+
+			Self::release_order(order_id, &order);
+			let target_amount = T::DEX::swap_with_exact_supply(
+				&order.owner,
+				&order.path,
+				order.supply_amount,
+				Self::get_min_target_amount(&order),
+			)?;
+			Self::deposit_event(Event::OrderExecuted(
+				order_id,
+				order.owner,
+				order.supply_amount,
+				target_amount,
+			));
+			Ok(Some(<T as Config>::WeightInfo::execute_order(order.path.len() as u32)).into())
+		}
+
+		/// Release an expired order.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `order_id`: the expired order.
+		#[pallet::weight(<T as Config>::WeightInfo::expire_order())]
+		#[transactional]
+		pub fn expire_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_none(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(Self::is_expired(&order), Error::<T>::OrderNotExpired);
+
+			Self::release_order(order_id, &order);
+			Self::deposit_event(Event::OrderExpired(order_id, order.owner));
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			// only accept the transactions submitted by the offchain worker of this node
+			if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
+				return InvalidTransaction::Call.into();
+			}
+
+			match call {
+				Call::execute_order(order_id) => {
+					let order = Self::orders(order_id).ok_or(InvalidTransaction::Stale)?;
+					if Self::is_expired(&order) || Self::get_triggered_target_amount(&order).is_none() {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("DexOrdersOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(order_id)
+						.longevity(64_u64)
+						.propagate(false)
+						.build()
+				}
+				Call::expire_order(order_id) => {
+					let order = Self::orders(order_id).ok_or(InvalidTransaction::Stale)?;
+					if !Self::is_expired(&order) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("DexOrdersOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(order_id)
+						.longevity(64_u64)
+						.propagate(false)
+						.build()
+				}
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn is_expired(order: &Order<T::AccountId, T::BlockNumber>) -> bool {
+		<frame_system::Pallet<T>>::block_number() > order.expiry
+	}
+
+	/// The target amount the order asks for at least.
+	fn get_min_target_amount(order: &Order<T::AccountId, T::BlockNumber>) -> Balance {
+		match order.kind {
+			OrderKind::Limit => order
+				.min_target_amount
+				.max(order.trigger_price.saturating_mul_int(order.supply_amount)),
+			OrderKind::StopLoss => order.min_target_amount,
+		}
+	}
+
+	/// Get the target amount of swapping the order with DEX now, `None`
+	/// means the order isn't triggered by the current price or the swap
+	/// can't satisfy the order.
+	pub fn get_triggered_target_amount(order: &Order<T::AccountId, T::BlockNumber>) -> Option<Balance> {
+		let target_amount = T::DEX::get_swap_target_amount(&order.path, order.supply_amount)?;
+		let trigger_amount = order.trigger_price.saturating_mul_int(order.supply_amount);
+		let triggered = match order.kind {
+			OrderKind::Limit => target_amount >= trigger_amount,
+			OrderKind::StopLoss => target_amount <= trigger_amount,
+		};
+
+		if triggered && target_amount >= Self::get_min_target_amount(order) {
+			Some(target_amount)
+		} else {
+			None
+		}
+	}
+
+	/// Remove the order and unreserve its supply amount and deposit.
+	fn release_order(order_id: OrderId, order: &Order<T::AccountId, T::BlockNumber>) {
+		Orders::<T>::remove(order_id);
+		T::Currency::unreserve(order.path[0], &order.owner, order.supply_amount);
+		T::Currency::unreserve(T::GetNativeCurrencyId::get(), &order.owner, order.deposit);
+	}
+
+	fn submit_unsigned_execution_tx(order_id: OrderId) {
+		let call = Call::<T>::execute_order(order_id);
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "dex-orders offchain worker",
+				"submit unsigned execution tx for order {:?} failed!",
+				order_id,
+			);
+		}
+	}
+
+	fn submit_unsigned_expiration_tx(order_id: OrderId) {
+		let call = Call::<T>::expire_order(order_id);
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "dex-orders offchain worker",
+				"submit unsigned expiration tx for order {:?} failed!",
+				order_id,
+			);
+		}
+	}
+
+	fn _offchain_worker() -> Result<(), OffchainErr> {
+		// check if we are a potential validator
+		if !sp_io::offchain::is_validator() {
+			return Err(OffchainErr::NotValidator);
+		}
+
+		// acquire offchain worker lock
+		let lock_expiration = Duration::from_millis(LOCK_DURATION);
+		let mut lock = StorageLock::<'_, Time>::with_deadline(OFFCHAIN_WORKER_LOCK, lock_expiration);
+		let mut guard = lock.try_lock().map_err(|_| OffchainErr::OffchainLock)?;
+
+		// get to_be_continue record
+		let to_be_continue = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA);
+		let start_key = to_be_continue.get::<Option<Vec<u8>>>().ok().flatten().flatten();
+
+		// get the max iterationns config
+		let max_iterations = StorageValueRef::persistent(OFFCHAIN_WORKER_MAX_ITERATIONS)
+			.get::<u32>()
+			.unwrap_or(Some(DEFAULT_MAX_ITERATIONS))
+			.ok_or(OffchainErr::OffchainStore)?;
+
+		let mut map_iterator = match start_key.clone() {
+			Some(key) => Orders::<T>::iter_from(key),
+			None => Orders::<T>::iter(),
+		};
+
+		let mut finished = true;
+		let mut iteration_count = 0;
+		let iteration_start_time = sp_io::offchain::timestamp();
+
+		#[allow(clippy::while_let_on_iterator)]
+		while let Some((order_id, order)) = map_iterator.next() {
+			if iteration_count >= max_iterations {
+				finished = false;
+				break;
+			}
+
+			if Self::is_expired(&order) {
+				Self::submit_unsigned_expiration_tx(order_id);
+			} else if Self::get_triggered_target_amount(&order).is_some() {
+				Self::submit_unsigned_execution_tx(order_id);
+			}
+
+			iteration_count += 1;
+
+			// extend offchain worker lock
+			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+		}
+		let iteration_end_time = sp_io::offchain::timestamp();
+		log::debug!(
+			target: "dex-orders offchain worker",
+			"iteration info:\n max iterations is {:?}\n start key: {:?}, iterate count: {:?}\n iteration start at: {:?}, end at: {:?}, execution time: {:?}\n",
+			max_iterations,
+			start_key,
+			iteration_count,
+			iteration_start_time,
+			iteration_end_time,
+			iteration_end_time.diff(&iteration_start_time)
+		);
+
+		// if iteration for map storage finished, clear to be continue record
+		// otherwise, update to be continue record
+		if finished {
+			to_be_continue.set(&Option::<Vec<u8>>::None);
+		} else {
+			to_be_continue.set(&Some(map_iterator.last_raw_key()));
+		}
+
+		// Consume the guard but **do not** unlock the underlying lock.
+		guard.forget();
+
+		Ok(())
+	}
+}
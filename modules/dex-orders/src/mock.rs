@@ -0,0 +1,233 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the dex orders module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol, TradingPair};
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::IdentityLookup,
+};
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
+
+mod dex_orders {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+ord_parameter_types! {
+	pub const ListingOrigin: AccountId = 3;
+}
+
+parameter_types! {
+	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 10;
+	pub const PriceObservationLimit: u32 = 10;
+	pub const TreasuryAccount: AccountId = 10;
+	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+}
+
+impl dex::Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
+	type PalletId = DEXPalletId;
+	type CurrencyIdMapping = ();
+	type WeightInfo = ();
+	type DEXIncentives = ();
+	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = ();
+}
+
+parameter_types! {
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
+	pub const OrderDeposit: Balance = 10;
+}
+
+parameter_type_with_key! {
+	pub MinimumSupplyAmount: |_currency_id: CurrencyId| -> Balance {
+		10
+	};
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type DEX = DexModule;
+	type TradingPathLimit = TradingPathLimit;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type OrderDeposit = OrderDeposit;
+	type MinimumSupplyAmount = MinimumSupplyAmount;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		DexOrdersModule: dex_orders::{Pallet, Storage, Call, Event<T>, ValidateUnsigned},
+		DexModule: dex::{Pallet, Storage, Call, Event<T>, Config<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+/// An extrinsic type used for tests.
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(ALICE, AUSD, 1_000_000u128),
+				(ALICE, DOT, 1_000_000u128),
+				(ALICE, BTC, 1_000_000u128),
+				(BOB, AUSD, 1_000u128),
+				(BOB, DOT, 1_000u128),
+				(BOB, ACA, 1_000u128),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		dex::GenesisConfig::<Runtime> {
+			initial_listing_trading_pairs: vec![],
+			initial_enabled_trading_pairs: vec![
+				TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+				TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
+			],
+			initial_added_liquidity_pools: vec![(
+				ALICE,
+				vec![
+					(
+						TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+						(100_000u128, 10_000u128),
+					),
+					(
+						TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
+						(100_000u128, 1_000u128),
+					),
+				],
+			)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		t.into()
+	}
+}
@@ -0,0 +1,344 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the dex orders module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+fn place_order(kind: OrderKind, trigger_price: Price, min_target_amount: Balance) -> OrderId {
+	let order_id = DexOrdersModule::next_order_id();
+	assert_ok!(DexOrdersModule::place_order(
+		Origin::signed(BOB),
+		kind,
+		vec![DOT, AUSD],
+		100,
+		trigger_price,
+		min_target_amount,
+		10
+	));
+	order_id
+}
+
+#[test]
+fn place_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT],
+				100,
+				Price::saturating_from_integer(9),
+				0,
+				10
+			),
+			Error::<Runtime>::InvalidTradingPath
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT, AUSD, DOT],
+				100,
+				Price::saturating_from_integer(9),
+				0,
+				10
+			),
+			Error::<Runtime>::InvalidTradingPath
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT, AUSD],
+				0,
+				Price::saturating_from_integer(9),
+				0,
+				10
+			),
+			Error::<Runtime>::ZeroSupplyAmount
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT, AUSD],
+				9,
+				Price::saturating_from_integer(9),
+				0,
+				10
+			),
+			Error::<Runtime>::BelowMinimumSupplyAmount
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT, AUSD],
+				100,
+				Price::saturating_from_integer(9),
+				0,
+				1
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				vec![DOT, AUSD],
+				1_001,
+				Price::saturating_from_integer(9),
+				0,
+				10
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_eq!(DexOrdersModule::next_order_id(), 0);
+		assert_ok!(DexOrdersModule::place_order(
+			Origin::signed(BOB),
+			OrderKind::Limit,
+			vec![DOT, AUSD],
+			100,
+			Price::saturating_from_integer(9),
+			0,
+			10
+		));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderPlaced(
+			0,
+			BOB,
+			OrderKind::Limit,
+		)));
+		assert_eq!(DexOrdersModule::next_order_id(), 1);
+		assert_eq!(
+			DexOrdersModule::orders(0),
+			Some(Order {
+				owner: BOB,
+				kind: OrderKind::Limit,
+				path: vec![DOT, AUSD],
+				supply_amount: 100,
+				trigger_price: Price::saturating_from_integer(9),
+				min_target_amount: 0,
+				expiry: 10,
+				deposit: 10,
+			})
+		);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 900);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 100);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 990);
+		assert_eq!(Tokens::reserved_balance(ACA, &BOB), 10);
+	});
+}
+
+#[test]
+fn cancel_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let order_id = place_order(OrderKind::Limit, Price::saturating_from_integer(9), 0);
+
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(BOB), order_id + 1),
+			Error::<Runtime>::OrderNotFound
+		);
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(ALICE), order_id),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(BOB), order_id));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderCancelled(order_id, BOB)));
+		assert_eq!(DexOrdersModule::orders(order_id), None);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+	});
+}
+
+#[test]
+fn get_triggered_target_amount_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut order = Order {
+			owner: BOB,
+			kind: OrderKind::Limit,
+			path: vec![DOT, AUSD],
+			supply_amount: 100,
+			trigger_price: Price::saturating_from_integer(10),
+			min_target_amount: 0,
+			expiry: 10,
+			deposit: 10,
+		};
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), None);
+
+		order.trigger_price = Price::saturating_from_integer(9);
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), Some(990));
+
+		order.min_target_amount = 991;
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), None);
+
+		order.kind = OrderKind::StopLoss;
+		order.min_target_amount = 0;
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), None);
+
+		order.trigger_price = Price::saturating_from_integer(10);
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), Some(990));
+
+		order.min_target_amount = 991;
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), None);
+
+		order.path = vec![DOT, BTC];
+		order.min_target_amount = 0;
+		assert_eq!(DexOrdersModule::get_triggered_target_amount(&order), None);
+	});
+}
+
+#[test]
+fn execute_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let limit_order_id = place_order(OrderKind::Limit, Price::saturating_from_integer(10), 0);
+		let stop_loss_order_id = place_order(OrderKind::StopLoss, Price::saturating_from_integer(10), 0);
+
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::signed(BOB), stop_loss_order_id),
+			BadOrigin
+		);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), stop_loss_order_id + 1),
+			Error::<Runtime>::OrderNotFound
+		);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), limit_order_id),
+			Error::<Runtime>::OrderNotTriggered
+		);
+
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 200);
+		assert_ok!(DexOrdersModule::execute_order(Origin::none(), stop_loss_order_id));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderExecuted(
+			stop_loss_order_id,
+			BOB,
+			100,
+			990,
+		)));
+		assert_eq!(DexOrdersModule::orders(stop_loss_order_id), None);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 800);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 100);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_990);
+		assert_eq!(Tokens::reserved_balance(ACA, &BOB), 10);
+
+		System::set_block_number(11);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), limit_order_id),
+			Error::<Runtime>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn expire_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let order_id = place_order(OrderKind::Limit, Price::saturating_from_integer(10), 0);
+
+		assert_noop!(DexOrdersModule::expire_order(Origin::signed(BOB), order_id), BadOrigin);
+		assert_noop!(
+			DexOrdersModule::expire_order(Origin::none(), order_id),
+			Error::<Runtime>::OrderNotExpired
+		);
+
+		System::set_block_number(11);
+		assert_ok!(DexOrdersModule::expire_order(Origin::none(), order_id));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderExpired(order_id, BOB)));
+		assert_eq!(DexOrdersModule::orders(order_id), None);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+	});
+}
+
+#[test]
+fn validate_unsigned_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let limit_order_id = place_order(OrderKind::Limit, Price::saturating_from_integer(10), 0);
+		let stop_loss_order_id = place_order(OrderKind::StopLoss, Price::saturating_from_integer(10), 0);
+
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::<Runtime>::execute_order(stop_loss_order_id)
+			),
+			InvalidTransaction::Call.into()
+		);
+		assert!(DexOrdersModule::validate_unsigned(
+			TransactionSource::InBlock,
+			&crate::Call::<Runtime>::execute_order(stop_loss_order_id)
+		)
+		.is_ok());
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Runtime>::execute_order(limit_order_id)
+			),
+			InvalidTransaction::Stale.into()
+		);
+		assert!(DexOrdersModule::validate_unsigned(
+			TransactionSource::Local,
+			&crate::Call::<Runtime>::execute_order(stop_loss_order_id)
+		)
+		.is_ok());
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Runtime>::expire_order(limit_order_id)
+			),
+			InvalidTransaction::Stale.into()
+		);
+
+		System::set_block_number(11);
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Runtime>::execute_order(stop_loss_order_id)
+			),
+			InvalidTransaction::Stale.into()
+		);
+		assert!(DexOrdersModule::validate_unsigned(
+			TransactionSource::Local,
+			&crate::Call::<Runtime>::expire_order(limit_order_id)
+		)
+		.is_ok());
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::<Runtime>::expire_order(limit_order_id + 2)
+			),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
@@ -0,0 +1,164 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the dex module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_dex_rpc_runtime_api::SwapPath;
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+	FixedU128,
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as DexClient;
+pub use module_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc]
+pub trait DexApi<BlockHash, Balance, ResponseType> {
+	#[rpc(name = "dex_getBestPriceSwapPathWithExactSupply")]
+	fn get_best_price_swap_path_with_exact_supply(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<ResponseType>>;
+
+	#[rpc(name = "dex_getBestPriceSwapPathWithExactTarget")]
+	fn get_best_price_swap_path_with_exact_target(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<ResponseType>>;
+
+	#[rpc(name = "dex_getTimeWeightedAveragePrice")]
+	fn get_time_weighted_average_price(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+		at: Option<BlockHash>,
+	) -> Result<Option<FixedU128>>;
+}
+
+/// A struct that implements the [`DexApi`].
+pub struct Dex<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Dex<C, B> {
+	/// Create new `Dex` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Dex {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, Balance> DexApi<<Block as BlockT>::Hash, Balance, SwapPath<Balance>> for Dex<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DexRuntimeApi<Block, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_best_price_swap_path_with_exact_supply(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapPath<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_best_price_swap_path_with_exact_supply(&at, supply_currency_id, target_currency_id, supply_amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get best price swap path.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_best_price_swap_path_with_exact_target(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapPath<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_best_price_swap_path_with_exact_target(&at, supply_currency_id, target_currency_id, target_amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get best price swap path.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+	fn get_time_weighted_average_price(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<FixedU128>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_time_weighted_average_price(&at, currency_id_a, currency_id_b, window)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get time-weighted average price.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
@@ -34,17 +34,22 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	log,
+	pallet_prelude::*,
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, CheckedMul, CheckedSub, One, Saturating, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio};
+use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio, StableAssetRouter};
 
 mod mock;
 mod tests;
@@ -86,6 +91,61 @@ impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
 	}
 }
 
+/// The price accumulators of TradingPair at a block. The accumulators grow
+/// by the spot prices of the liquidity pool for every elapsed block, so the
+/// time-weighted average price between two observations is the difference of
+/// the accumulators divided by the elapsed blocks.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct PriceObservation<BlockNumber> {
+	/// The cumulative price of token_0 in token_1, in the inner value of
+	/// `ExchangeRate`.
+	pub price_0_cumulative: U256,
+	/// The cumulative price of token_1 in token_0, in the inner value of
+	/// `ExchangeRate`.
+	pub price_1_cumulative: U256,
+	/// The block number of this observation.
+	pub block_number: BlockNumber,
+}
+
+/// The maximum relative weight of a token in the weighted pool.
+pub const MAX_POOL_WEIGHT: u32 = 20;
+
+/// Curve of the liquidity pool for TradingPair
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+pub enum PoolKind {
+	/// Constant product curve, `x * y = k`.
+	ConstantProduct,
+	/// Weighted product curve, `x ^ weight_0 * y ^ weight_1 = k`.
+	/// The relative weights of token_0 and token_1, for example `(4, 1)` is
+	/// a 80/20 pool. Each weight must be in `1..=MAX_POOL_WEIGHT`.
+	Weighted(u32, u32),
+}
+
+impl Default for PoolKind {
+	fn default() -> Self {
+		Self::ConstantProduct
+	}
+}
+
+impl PoolKind {
+	/// The relative weights of token_0 and token_1.
+	pub fn weights(&self) -> (u32, u32) {
+		match self {
+			Self::ConstantProduct => (1, 1),
+			Self::Weighted(weight_0, weight_1) => (*weight_0, *weight_1),
+		}
+	}
+
+	fn is_valid(&self) -> bool {
+		match self {
+			Self::ConstantProduct => true,
+			Self::Weighted(weight_0, weight_1) => {
+				(1..=MAX_POOL_WEIGHT).contains(weight_0) && (1..=MAX_POOL_WEIGHT).contains(weight_1)
+			}
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -97,7 +157,7 @@ pub mod module {
 		/// Currency for transfer currencies
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
-		/// Trading fee rate
+		/// Default trading fee rate of trading pairs without their own fee rate.
 		/// The first item of the tuple is the numerator of the fee rate, second
 		/// item is the denominator, fee_rate = numerator / denominator,
 		/// use (u32, u32) over `Rate` type to minimize internal division
@@ -109,6 +169,11 @@ pub mod module {
 		#[pallet::constant]
 		type TradingPathLimit: Get<u32>;
 
+		/// The max count of the trading pairs searched for the best trading
+		/// path, including the trading pairs of the stable asset pools.
+		#[pallet::constant]
+		type MaxTradingPairs: Get<u32>;
+
 		/// The DEX's module id, keep all assets in DEX.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -125,6 +190,23 @@ pub mod module {
 
 		/// The origin which may list, enable or disable trading pairs.
 		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of price observations kept for every trading
+		/// pair, it limits the window of time-weighted average price. Changing
+		/// it will corrupt the recorded observations.
+		#[pallet::constant]
+		type PriceObservationLimit: Get<u32>;
+
+		/// The account which receives the protocol fee.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The overarching call type, the callback of flash swaps.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo> + GetDispatchInfo;
+
+		/// The stable asset pools which the trading paths can be routed
+		/// through, for the hops whose trading pairs are not Enabled.
+		type StableAsset: StableAssetRouter<Self::AccountId, CurrencyId, Balance>;
 	}
 
 	#[pallet::error]
@@ -167,6 +249,16 @@ pub mod module {
 		UnqualifiedProvision,
 		/// Trading pair is still provisioning
 		StillProvisioning,
+		/// No available trading path between the currencies
+		NoAvailableTradingPath,
+		/// The pool kind is invalid
+		InvalidPoolKind,
+		/// The pool kind can't be changed while the pool has liquidity
+		PoolKindNotChangeable,
+		/// The exchange fee rate is invalid
+		InvalidExchangeFee,
+		/// The protocol fee share is invalid
+		InvalidProtocolFeeShare,
 	}
 
 	#[pallet::event]
@@ -186,6 +278,9 @@ pub mod module {
 		/// Use supply currency to swap target currency. \[trader, trading_path,
 		/// liquidity_change_list\]
 		Swap(T::AccountId, Vec<CurrencyId>, Vec<Balance>),
+		/// Receive target currency before paying supply currency. \[trader,
+		/// trading_path, liquidity_change_list\]
+		FlashSwap(T::AccountId, Vec<CurrencyId>, Vec<Balance>),
 		/// Enable trading pair. \[trading_pair\]
 		EnableTradingPair(TradingPair),
 		/// List provisioning trading pair. \[trading_pair\]
@@ -195,6 +290,14 @@ pub mod module {
 		/// Provisioning trading pair convert to Enabled. \[trading_pair,
 		/// pool_0_amount, pool_1_amount, total_share_amount\]
 		ProvisioningToEnabled(TradingPair, Balance, Balance, Balance),
+		/// The exchange fee rate of trading pair is updated. \[trading_pair,
+		/// exchange_fee\]
+		ExchangeFeeUpdated(TradingPair, (u32, u32)),
+		/// The share of protocol fee is updated. \[protocol_fee_share\]
+		ProtocolFeeShareUpdated(Option<(u32, u32)>),
+		/// Protocol fee is minted as dex share to the treasury.
+		/// \[trading_pair, share_amount\]
+		ProtocolFeeAccrued(TradingPair, Balance),
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -212,6 +315,38 @@ pub mod module {
 	pub type TradingPairStatuses<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, TradingPairStatus<Balance, T::BlockNumber>, ValueQuery>;
 
+	/// Pool kind for TradingPair, decides the curve of the liquidity pool.
+	///
+	/// PoolKinds: map TradingPair => PoolKind
+	#[pallet::storage]
+	#[pallet::getter(fn pool_kinds)]
+	pub type PoolKinds<T: Config> = StorageMap<_, Twox64Concat, TradingPair, PoolKind, ValueQuery>;
+
+	/// Ring buffer of the price observations of TradingPair, at most one
+	/// observation is recorded for every block in which the liquidity pool
+	/// changed.
+	///
+	/// PriceObservations: double_map TradingPair, u32 => Option<PriceObservation>
+	#[pallet::storage]
+	#[pallet::getter(fn price_observations)]
+	pub type PriceObservations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TradingPair,
+		Twox64Concat,
+		u32,
+		PriceObservation<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The total number of the price observations ever recorded for
+	/// TradingPair.
+	///
+	/// PriceObservationCount: map TradingPair => u32
+	#[pallet::storage]
+	#[pallet::getter(fn price_observation_count)]
+	pub type PriceObservationCount<T: Config> = StorageMap<_, Twox64Concat, TradingPair, u32, ValueQuery>;
+
 	/// Provision of TradingPair by AccountId.
 	///
 	/// ProvisioningPool: double_map TradingPair, AccountId => (Balance,
@@ -229,6 +364,38 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Exchange fee rate of TradingPair, `GetExchangeFee` is used if it's
+	/// not set.
+	///
+	/// ExchangeFees: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn exchange_fees)]
+	pub type ExchangeFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// The share of the exchange fee taken by the protocol, the protocol
+	/// fee is off if it's not set.
+	///
+	/// ProtocolFeeShare: Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_share)]
+	pub type ProtocolFeeShare<T: Config> = StorageValue<_, (u32, u32), OptionQuery>;
+
+	/// The product of the liquidity pool of TradingPair after the latest
+	/// liquidity change, only recorded while the protocol fee is on.
+	///
+	/// KLast: map TradingPair => U256
+	#[pallet::storage]
+	#[pallet::getter(fn k_last)]
+	pub type KLast<T: Config> = StorageMap<_, Twox64Concat, TradingPair, U256, ValueQuery>;
+
+	/// The total dex share of TradingPair minted to the treasury as
+	/// protocol fee.
+	///
+	/// AccruedProtocolFees: map TradingPair => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn accrued_protocol_fees)]
+	pub type AccruedProtocolFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -337,6 +504,88 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Flash swap with DEX, the exact target amount is delivered to the
+		/// caller before `callback` is dispatched, and the supply amount is
+		/// collected from the caller after it. The whole swap is reverted if
+		/// the callback fails or the caller can't pay the supply amount.
+		///
+		/// - `path`: trading path.
+		/// - `target_amount`: exact target amount.
+		/// - `max_supply_amount`: acceptable maximum supply amount.
+		/// - `callback`: the call to dispatch with the caller's origin after receiving target
+		///   amount.
+		#[pallet::weight((
+			<T as Config>::WeightInfo::flash_swap(path.len() as u32)
+				.saturating_add(callback.get_dispatch_info().weight),
+			DispatchClass::Normal,
+		))]
+		#[transactional]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+			callback: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::do_flash_swap_with_exact_target(&who, &path, target_amount, max_supply_amount, || {
+				callback.dispatch(origin).map(|_| ()).map_err(|e| e.error)
+			})?;
+			Ok(())
+		}
+
+		/// Trading with DEX, swap with exact supply amount through the trading
+		/// path that gives the most target amount. The path is searched
+		/// on-chain among at most `MaxTradingPairs` Enabled trading pairs and
+		/// stable asset pools, its length won't exceed `TradingPathLimit`.
+		///
+		/// - `supply_currency_id`: currency id to supply.
+		/// - `target_currency_id`: currency id to get.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply_by_best_path(T::TradingPathLimit::get(), T::MaxTradingPairs::get()))]
+		#[transactional]
+		pub fn swap_with_exact_supply_by_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (path, _) =
+				Self::get_best_price_swap_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+					.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
+		/// Trading with DEX, swap with exact target amount through the trading
+		/// path that costs the least supply amount. The path is searched
+		/// on-chain among at most `MaxTradingPairs` Enabled trading pairs and
+		/// stable asset pools, its length won't exceed `TradingPathLimit`.
+		///
+		/// - `supply_currency_id`: currency id to supply.
+		/// - `target_currency_id`: currency id to get.
+		/// - `target_amount`: exact target amount.
+		/// - `max_supply_amount`: acceptable maximum supply amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target_by_best_path(T::TradingPathLimit::get(), T::MaxTradingPairs::get()))]
+		#[transactional]
+		pub fn swap_with_exact_target_by_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (path, _) =
+				Self::get_best_price_swap_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+					.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+			Ok(())
+		}
+
 		/// Add liquidity to Enabled trading pair.
 		/// - Add provision success will record the provision, issue shares to caller in the initial
 		///   exchange rate when trading pair convert to Enabled.
@@ -457,6 +706,9 @@ pub mod module {
 		}
 
 		/// List a new provisioning trading pair.
+		///
+		/// - `pool_kind`: the curve of the liquidity pool, the weights of
+		///   `PoolKind::Weighted` are in the order of `currency_id_a` and `currency_id_b`.
 		#[pallet::weight((<T as Config>::WeightInfo::list_provisioning(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn list_provisioning(
@@ -468,8 +720,10 @@ pub mod module {
 			target_provision_a: Balance,
 			target_provision_b: Balance,
 			not_before: T::BlockNumber,
+			pool_kind: PoolKind,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(pool_kind.is_valid(), Error::<T>::InvalidPoolKind);
 
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -514,6 +768,10 @@ pub mod module {
 					not_before,
 				}),
 			);
+			PoolKinds::<T>::insert(
+				trading_pair,
+				Self::sort_pool_kind(trading_pair, currency_id_a, pool_kind),
+			);
 			Self::deposit_event(Event::ListProvisioning(trading_pair));
 			Ok(())
 		}
@@ -590,12 +848,11 @@ pub mod module {
 						Error::<T>::UnqualifiedProvision
 					);
 
-					// directly use token_0 as base to calculate initial dex share amount.
-					let (share_exchange_rate_0, share_exchange_rate_1) = (
-						ExchangeRate::one(),
-						ExchangeRate::checked_from_rational(total_provision_0, total_provision_1)
-							.ok_or(ArithmeticError::Overflow)?,
-					);
+					let (share_exchange_rate_0, share_exchange_rate_1) = Self::initial_share_exchange_rates_of(
+						Self::pool_kinds(trading_pair),
+						total_provision_0,
+						total_provision_1,
+					)?;
 					let shares_from_provision_0 = share_exchange_rate_0
 						.checked_mul_int(total_provision_0)
 						.ok_or(ArithmeticError::Overflow)?;
@@ -615,8 +872,10 @@ pub mod module {
 
 					// inject provision to liquidity pool
 					LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+						Self::update_price_accumulators(trading_pair, *pool_0, *pool_1);
 						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
 						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
+						Self::update_k_last(trading_pair, *pool_0, *pool_1);
 						Ok(())
 					})?;
 
@@ -645,16 +904,23 @@ pub mod module {
 		/// Enable a trading pair
 		/// if the status of trading pair is `Disabled`, or `Provisioning` without any accumulated
 		/// provision, enable it directly.
+		///
+		/// - `pool_kind`: the curve of the liquidity pool, the weights of
+		///   `PoolKind::Weighted` are in the order of `currency_id_a` and `currency_id_b`. It can
+		///   only be changed while the pool has no liquidity.
 		#[pallet::weight((<T as Config>::WeightInfo::enable_trading_pair(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn enable_trading_pair(
 			origin: OriginFor<T>,
 			currency_id_a: CurrencyId,
 			currency_id_b: CurrencyId,
+			pool_kind: PoolKind,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(pool_kind.is_valid(), Error::<T>::InvalidPoolKind);
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			let pool_kind = Self::sort_pool_kind(trading_pair, currency_id_a, pool_kind);
 			match Self::trading_pair_statuses(trading_pair) {
 				TradingPairStatus::<_, _>::Disabled => {}
 				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
@@ -666,8 +932,14 @@ pub mod module {
 				}
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
+			ensure!(
+				pool_kind == Self::pool_kinds(trading_pair)
+					|| T::Currency::total_issuance(trading_pair.dex_share_currency_id()).is_zero(),
+				Error::<T>::PoolKindNotChangeable
+			);
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			PoolKinds::<T>::insert(trading_pair, pool_kind);
 			Self::deposit_event(Event::EnableTradingPair(trading_pair));
 			Ok(())
 		}
@@ -695,6 +967,65 @@ pub mod module {
 			Self::deposit_event(Event::DisableTradingPair(trading_pair));
 			Ok(())
 		}
+
+		/// Set the exchange fee rate of the `Enabled` trading pair.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `exchange_fee`: the exchange fee rate as (numerator, denominator), `None` means to
+		///   use `GetExchangeFee`.
+		#[pallet::weight((<T as Config>::WeightInfo::set_exchange_fee(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_exchange_fee(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			exchange_fee: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Enabled
+				),
+				Error::<T>::MustBeEnabled
+			);
+			if let Some((numerator, denominator)) = exchange_fee {
+				ensure!(numerator < denominator, Error::<T>::InvalidExchangeFee);
+			}
+
+			ExchangeFees::<T>::set(trading_pair, exchange_fee);
+			Self::deposit_event(Event::ExchangeFeeUpdated(
+				trading_pair,
+				Self::get_exchange_fee(trading_pair),
+			));
+			Ok(())
+		}
+
+		/// Set the share of the exchange fee taken by the protocol, which is
+		/// minted as dex share to `TreasuryAccount` when the liquidity changes.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `protocol_fee_share`: the share as (numerator, denominator), `None` means to turn
+		///   off the protocol fee.
+		#[pallet::weight((<T as Config>::WeightInfo::set_protocol_fee_share(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_protocol_fee_share(origin: OriginFor<T>, protocol_fee_share: Option<(u32, u32)>) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			if let Some((numerator, denominator)) = protocol_fee_share {
+				ensure!(
+					!numerator.is_zero() && numerator < denominator,
+					Error::<T>::InvalidProtocolFeeShare
+				);
+			}
+
+			ProtocolFeeShare::<T>::set(protocol_fee_share);
+			Self::deposit_event(Event::ProtocolFeeShareUpdated(protocol_fee_share));
+			Ok(())
+		}
 	}
 }
 
@@ -703,6 +1034,42 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	/// Convert the pool kind whose weights are in the order of
+	/// `currency_id_a` to the order of the trading pair.
+	fn sort_pool_kind(trading_pair: TradingPair, currency_id_a: CurrencyId, pool_kind: PoolKind) -> PoolKind {
+		match pool_kind {
+			PoolKind::Weighted(weight_a, weight_b) if currency_id_a != trading_pair.first() => {
+				PoolKind::Weighted(weight_b, weight_a)
+			}
+			_ => pool_kind,
+		}
+	}
+
+	/// Get the weights of the supply token and the target token.
+	fn swap_weights(pool_kind: PoolKind, trading_pair: TradingPair, supply_currency_id: CurrencyId) -> (u32, u32) {
+		let (weight_0, weight_1) = pool_kind.weights();
+		if supply_currency_id == trading_pair.first() {
+			(weight_0, weight_1)
+		} else {
+			(weight_1, weight_0)
+		}
+	}
+
+	/// Get the exchange rates of token_0 and token_1 to dex share when issue
+	/// initial dex share. Directly use token_0 as base, token_1 is valued at
+	/// the spot price of the pool.
+	fn initial_share_exchange_rates_of(
+		pool_kind: PoolKind,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> sp_std::result::Result<(ExchangeRate, ExchangeRate), DispatchError> {
+		let (weight_0, weight_1) = pool_kind.weights();
+		let exchange_rate_1 = ExchangeRate::checked_from_rational(amount_0, amount_1)
+			.and_then(|rate| rate.checked_mul(&ExchangeRate::checked_from_rational(weight_1, weight_0)?))
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok((ExchangeRate::one(), exchange_rate_1))
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -849,6 +1216,8 @@ impl<T: Config> Pallet<T> {
 		);
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			Self::update_price_accumulators(trading_pair, *pool_0, *pool_1);
+			Self::accrue_protocol_fee(trading_pair, *pool_0, *pool_1)?;
 			let dex_share_currency_id = trading_pair.dex_share_currency_id();
 			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 			let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
@@ -859,11 +1228,8 @@ impl<T: Config> Pallet<T> {
 			let (pool_0_increment, pool_1_increment, share_increment): (Balance, Balance, Balance) = if total_shares
 				.is_zero()
 			{
-				// directly use token_0 as base to calculate initial dex share amount.
-				let (exchange_rate_0, exchange_rate_1) = (
-					ExchangeRate::one(),
-					ExchangeRate::checked_from_rational(max_amount_0, max_amount_1).ok_or(ArithmeticError::Overflow)?,
-				);
+				let (exchange_rate_0, exchange_rate_1) =
+					Self::initial_share_exchange_rates_of(Self::pool_kinds(trading_pair), max_amount_0, max_amount_1)?;
 
 				let shares_from_token_0 = exchange_rate_0
 					.checked_mul_int(max_amount_0)
@@ -921,6 +1287,7 @@ impl<T: Config> Pallet<T> {
 
 			*pool_0 = pool_0.checked_add(pool_0_increment).ok_or(ArithmeticError::Overflow)?;
 			*pool_1 = pool_1.checked_add(pool_1_increment).ok_or(ArithmeticError::Overflow)?;
+			Self::update_k_last(trading_pair, *pool_0, *pool_1);
 
 			if stake_increment_share {
 				T::DEXIncentives::do_deposit_dex_share(who, dex_share_currency_id, share_increment)?;
@@ -956,6 +1323,8 @@ impl<T: Config> Pallet<T> {
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			Self::update_price_accumulators(trading_pair, *pool_0, *pool_1);
+			Self::accrue_protocol_fee(trading_pair, *pool_0, *pool_1)?;
 			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
 				(min_withdrawn_a, min_withdrawn_b)
 			} else {
@@ -982,6 +1351,7 @@ impl<T: Config> Pallet<T> {
 
 			*pool_0 = pool_0.checked_sub(pool_0_decrement).ok_or(ArithmeticError::Underflow)?;
 			*pool_1 = pool_1.checked_sub(pool_1_decrement).ok_or(ArithmeticError::Underflow)?;
+			Self::update_k_last(trading_pair, *pool_0, *pool_1);
 
 			Self::deposit_event(Event::RemoveLiquidity(
 				who.clone(),
@@ -995,6 +1365,229 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Get the exchange fee rate of the trading pair.
+	pub fn get_exchange_fee(trading_pair: TradingPair) -> (u32, u32) {
+		Self::exchange_fees(trading_pair).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
+	/// Mint the protocol fee accrued since the latest liquidity change as
+	/// dex share to the treasury. The growth of `sqrt(k)` of the constant
+	/// product pool is the exchange fee, of which the protocol takes its
+	/// share by diluting the liquidity providers. Weighted pools don't
+	/// accrue protocol fee.
+	fn accrue_protocol_fee(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance) -> DispatchResult {
+		let k_last = Self::k_last(trading_pair);
+		if k_last.is_zero() {
+			return Ok(());
+		}
+
+		match (Self::protocol_fee_share(), Self::pool_kinds(trading_pair)) {
+			(Some((share_numerator, share_denominator)), PoolKind::ConstantProduct) => {
+				let root_k = U256::from(pool_0).saturating_mul(U256::from(pool_1)).integer_sqrt();
+				let root_k_last = k_last.integer_sqrt();
+				if root_k > root_k_last {
+					let dex_share_currency_id = trading_pair.dex_share_currency_id();
+					let total_shares = T::Currency::total_issuance(dex_share_currency_id);
+
+					// share_amount = total_shares * (root_k - root_k_last) * share /
+					// ((1 - share) * root_k + share * root_k_last)
+					let numerator = U256::from(total_shares)
+						.saturating_mul(root_k.saturating_sub(root_k_last))
+						.saturating_mul(U256::from(share_numerator));
+					let denominator = root_k
+						.saturating_mul(U256::from(share_denominator.saturating_sub(share_numerator)))
+						.saturating_add(root_k_last.saturating_mul(U256::from(share_numerator)));
+					let share_amount = numerator
+						.checked_div(denominator)
+						.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+						.unwrap_or_else(Zero::zero);
+
+					if !share_amount.is_zero() {
+						T::Currency::deposit(dex_share_currency_id, &T::TreasuryAccount::get(), share_amount)?;
+						AccruedProtocolFees::<T>::mutate(trading_pair, |accrued| {
+							*accrued = accrued.saturating_add(share_amount)
+						});
+						Self::deposit_event(Event::ProtocolFeeAccrued(trading_pair, share_amount));
+					}
+				}
+			}
+			_ => KLast::<T>::remove(trading_pair),
+		}
+		Ok(())
+	}
+
+	/// Record the product of the liquidity pool after the liquidity change
+	/// if the protocol fee is on.
+	fn update_k_last(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance) {
+		if Self::protocol_fee_share().is_some() && Self::pool_kinds(trading_pair) == PoolKind::ConstantProduct {
+			KLast::<T>::insert(trading_pair, U256::from(pool_0).saturating_mul(U256::from(pool_1)));
+		}
+	}
+
+	/// Get the spot prices of token_0 in token_1 and token_1 in token_0 of
+	/// the liquidity pool, they are zero if the liquidity pool is empty.
+	fn spot_prices(pool_kind: PoolKind, pool_0: Balance, pool_1: Balance) -> (ExchangeRate, ExchangeRate) {
+		let (weight_0, weight_1) = pool_kind.weights();
+		let price_0 = ExchangeRate::checked_from_rational(pool_1, pool_0)
+			.and_then(|price| price.checked_mul(&ExchangeRate::checked_from_rational(weight_0, weight_1)?))
+			.unwrap_or_default();
+		let price_1 = ExchangeRate::checked_from_rational(pool_0, pool_1)
+			.and_then(|price| price.checked_mul(&ExchangeRate::checked_from_rational(weight_1, weight_0)?))
+			.unwrap_or_default();
+		(price_0, price_1)
+	}
+
+	/// Accumulate the spot prices of the liquidity pool from the block of the
+	/// observation to the specific block.
+	fn accumulate_prices(
+		pool_kind: PoolKind,
+		observation: PriceObservation<T::BlockNumber>,
+		pool_0: Balance,
+		pool_1: Balance,
+		block_number: T::BlockNumber,
+	) -> PriceObservation<T::BlockNumber> {
+		let elapsed = U256::from(
+			block_number
+				.saturating_sub(observation.block_number)
+				.saturated_into::<u128>(),
+		);
+		let (price_0, price_1) = Self::spot_prices(pool_kind, pool_0, pool_1);
+
+		// the accumulators are allowed to overflow, only their differences are meaningful.
+		PriceObservation {
+			price_0_cumulative: observation
+				.price_0_cumulative
+				.overflowing_add(U256::from(price_0.into_inner()).saturating_mul(elapsed))
+				.0,
+			price_1_cumulative: observation
+				.price_1_cumulative
+				.overflowing_add(U256::from(price_1.into_inner()).saturating_mul(elapsed))
+				.0,
+			block_number,
+		}
+	}
+
+	/// Accumulate the spot prices of the liquidity pool before it changes,
+	/// and record the price observation of the current block.
+	fn update_price_accumulators(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let limit = T::PriceObservationLimit::get().max(1);
+		let count = Self::price_observation_count(trading_pair);
+
+		let observation = match count
+			.checked_sub(1)
+			.and_then(|latest_index| Self::price_observations(trading_pair, latest_index % limit))
+		{
+			// the prices have been accumulated in this block.
+			Some(latest) if latest.block_number == now => return,
+			Some(latest) => Self::accumulate_prices(Self::pool_kinds(trading_pair), latest, pool_0, pool_1, now),
+			None => PriceObservation {
+				block_number: now,
+				..Default::default()
+			},
+		};
+
+		PriceObservations::<T>::insert(trading_pair, count % limit, observation);
+		PriceObservationCount::<T>::insert(trading_pair, count.saturating_add(1));
+	}
+
+	/// Get the price accumulators of TradingPair at the specific block, it
+	/// must not be earlier than the oldest recorded observation.
+	fn price_observation_at(
+		trading_pair: TradingPair,
+		block_number: T::BlockNumber,
+	) -> Option<PriceObservation<T::BlockNumber>> {
+		let limit = T::PriceObservationLimit::get().max(1);
+		let count = Self::price_observation_count(trading_pair);
+		let observation_of = |index: u32| Self::price_observations(trading_pair, index % limit);
+
+		let latest = observation_of(count.checked_sub(1)?)?;
+		if block_number >= latest.block_number {
+			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+			return Some(Self::accumulate_prices(
+				Self::pool_kinds(trading_pair),
+				latest,
+				pool_0,
+				pool_1,
+				block_number,
+			));
+		}
+
+		let oldest_index = count.saturating_sub(limit);
+		if block_number < observation_of(oldest_index)?.block_number {
+			return None;
+		}
+
+		// binary search the adjacent observations around the block number
+		let (mut low, mut high) = (oldest_index, count - 1);
+		while high - low > 1 {
+			let middle = low + (high - low) / 2;
+			if observation_of(middle)?.block_number <= block_number {
+				low = middle;
+			} else {
+				high = middle;
+			}
+		}
+		let (before, after) = (observation_of(low)?, observation_of(high)?);
+
+		// the spot prices are constant between adjacent observations, interpolate the accumulators.
+		let elapsed = U256::from(
+			block_number
+				.saturating_sub(before.block_number)
+				.saturated_into::<u128>(),
+		);
+		let interval = U256::from(
+			after
+				.block_number
+				.saturating_sub(before.block_number)
+				.saturated_into::<u128>(),
+		);
+		let interpolate = |cumulative_before: U256, cumulative_after: U256| -> Option<U256> {
+			let increment = cumulative_after
+				.overflowing_sub(cumulative_before)
+				.0
+				.saturating_mul(elapsed)
+				.checked_div(interval)?;
+			Some(cumulative_before.overflowing_add(increment).0)
+		};
+
+		Some(PriceObservation {
+			price_0_cumulative: interpolate(before.price_0_cumulative, after.price_0_cumulative)?,
+			price_1_cumulative: interpolate(before.price_1_cumulative, after.price_1_cumulative)?,
+			block_number,
+		})
+	}
+
+	/// Get the time-weighted average price of `currency_id_a` in
+	/// `currency_id_b` over the last `window` blocks, the price in the
+	/// current block is excluded so that it can't be manipulated by the swaps
+	/// within a block.
+	pub fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: T::BlockNumber,
+	) -> Option<ExchangeRate> {
+		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
+		if window.is_zero() {
+			return None;
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let end = Self::price_observation_at(trading_pair, now)?;
+		let start = Self::price_observation_at(trading_pair, now.checked_sub(&window)?)?;
+		let (cumulative_end, cumulative_start) = if currency_id_a == trading_pair.first() {
+			(end.price_0_cumulative, start.price_0_cumulative)
+		} else {
+			(end.price_1_cumulative, start.price_1_cumulative)
+		};
+
+		let average = cumulative_end
+			.overflowing_sub(cumulative_start)
+			.0
+			.checked_div(U256::from(window.saturated_into::<u128>()))?;
+		TryInto::<u128>::try_into(average).ok().map(ExchangeRate::from_inner)
+	}
+
 	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
 			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
@@ -1009,11 +1602,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get how much target amount will be got for specific supply amount.
-	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+	fn get_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let supply_amount_with_fee: U256 =
 				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
 			let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
@@ -1029,11 +1627,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get how much supply amount will be paid for specific target amount.
-	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+	fn get_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let numerator: U256 = U256::from(supply_pool)
 				.saturating_mul(U256::from(target_amount))
 				.saturating_mul(U256::from(fee_denominator));
@@ -1049,6 +1652,123 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get how much target amount will be got for specific supply amount
+	/// from the weighted pool.
+	fn get_weighted_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_weight: u32,
+		target_weight: u32,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			Zero::zero()
+		} else {
+			let (fee_numerator, fee_denominator) = exchange_fee;
+			let supply_amount_with_fee: U256 = U256::from(supply_amount)
+				.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+				.checked_div(U256::from(fee_denominator))
+				.unwrap_or_else(Zero::zero);
+
+			// target_amount = target_pool * (1 - (supply_pool / (supply_pool + supply_amount_with_fee)) ^
+			// (supply_weight / target_weight))
+			fixed_div_up(
+				U256::from(supply_pool),
+				U256::from(supply_pool).saturating_add(supply_amount_with_fee),
+			)
+			.and_then(|base| fixed_pow_ratio_up(base, supply_weight, target_weight))
+			.and_then(|power| U256::from(WEIGHTED_MATH_ONE).checked_sub(power))
+			.and_then(|proportion| fixed_mul_down(U256::from(target_pool), proportion))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+		}
+	}
+
+	/// Get how much supply amount will be paid for specific target amount
+	/// to the weighted pool.
+	fn get_weighted_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_weight: u32,
+		target_weight: u32,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_amount >= target_pool {
+			Zero::zero()
+		} else {
+			let (fee_numerator, fee_denominator) = exchange_fee;
+
+			// supply_amount = supply_pool * ((target_pool / (target_pool - target_amount)) ^
+			// (target_weight / supply_weight) - 1) / (1 - fee_rate)
+			fixed_div_up(
+				U256::from(target_pool),
+				U256::from(target_pool.saturating_sub(target_amount)),
+			)
+			.and_then(|base| fixed_pow_ratio_up(base, target_weight, supply_weight))
+			.and_then(|power| power.checked_sub(U256::from(WEIGHTED_MATH_ONE)))
+			.and_then(|proportion| fixed_mul_up(U256::from(supply_pool), proportion))
+			.and_then(|n| n.checked_mul(U256::from(fee_denominator)))
+			.and_then(|n| n.checked_div(U256::from(fee_denominator.saturating_sub(fee_numerator))))
+			.and_then(|r| r.checked_add(U256::one())) // add 1 to result so that correct the possible losses caused by remainder discarding in
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+		}
+	}
+
+	/// Get how much target amount will be got for specific supply amount
+	/// from the liquidity pool of the trading pair, according to its pool kind.
+	fn get_pool_target_amount(
+		trading_pair: TradingPair,
+		supply_currency_id: CurrencyId,
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+	) -> Balance {
+		let exchange_fee = Self::get_exchange_fee(trading_pair);
+		match Self::pool_kinds(trading_pair) {
+			PoolKind::ConstantProduct => Self::get_target_amount(supply_pool, target_pool, supply_amount, exchange_fee),
+			pool_kind @ PoolKind::Weighted(..) => {
+				let (supply_weight, target_weight) = Self::swap_weights(pool_kind, trading_pair, supply_currency_id);
+				Self::get_weighted_target_amount(
+					supply_pool,
+					target_pool,
+					supply_weight,
+					target_weight,
+					supply_amount,
+					exchange_fee,
+				)
+			}
+		}
+	}
+
+	/// Get how much supply amount will be paid for specific target amount
+	/// to the liquidity pool of the trading pair, according to its pool kind.
+	fn get_pool_supply_amount(
+		trading_pair: TradingPair,
+		supply_currency_id: CurrencyId,
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+	) -> Balance {
+		let exchange_fee = Self::get_exchange_fee(trading_pair);
+		match Self::pool_kinds(trading_pair) {
+			PoolKind::ConstantProduct => Self::get_supply_amount(supply_pool, target_pool, target_amount, exchange_fee),
+			pool_kind @ PoolKind::Weighted(..) => {
+				let (supply_weight, target_weight) = Self::swap_weights(pool_kind, trading_pair, supply_currency_id);
+				Self::get_weighted_supply_amount(
+					supply_pool,
+					target_pool,
+					supply_weight,
+					target_weight,
+					target_amount,
+					exchange_fee,
+				)
+			}
+		}
+	}
+
 	fn get_target_amounts(
 		path: &[CurrencyId],
 		supply_amount: Balance,
@@ -1063,21 +1783,21 @@ impl<T: Config> Pallet<T> {
 
 		let mut i: usize = 0;
 		while i + 1 < path_length {
-			let trading_pair =
+			let target_amount = if let Some(trading_pair) = Self::enabled_trading_pair(path[i], path[i + 1]) {
+				let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
+				ensure!(
+					!supply_pool.is_zero() && !target_pool.is_zero(),
+					Error::<T>::InsufficientLiquidity
+				);
+				Self::get_pool_target_amount(trading_pair, path[i], supply_pool, target_pool, target_amounts[i])
+			} else if let Some(target_amount) =
+				T::StableAsset::get_target_amount(path[i], path[i + 1], target_amounts[i])
+			{
+				target_amount
+			} else {
 				TradingPair::from_currency_ids(path[i], path[i + 1]).ok_or(Error::<T>::InvalidCurrencyId)?;
-			ensure!(
-				matches!(
-					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
-				),
-				Error::<T>::MustBeEnabled
-			);
-			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
-			ensure!(
-				!supply_pool.is_zero() && !target_pool.is_zero(),
-				Error::<T>::InsufficientLiquidity
-			);
-			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+				return Err(Error::<T>::MustBeEnabled.into());
+			};
 			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
 
 			target_amounts[i + 1] = target_amount;
@@ -1101,21 +1821,21 @@ impl<T: Config> Pallet<T> {
 
 		let mut i: usize = path_length - 1;
 		while i > 0 {
-			let trading_pair =
+			let supply_amount = if let Some(trading_pair) = Self::enabled_trading_pair(path[i - 1], path[i]) {
+				let (supply_pool, target_pool) = Self::get_liquidity(path[i - 1], path[i]);
+				ensure!(
+					!supply_pool.is_zero() && !target_pool.is_zero(),
+					Error::<T>::InsufficientLiquidity
+				);
+				Self::get_pool_supply_amount(trading_pair, path[i - 1], supply_pool, target_pool, supply_amounts[i])
+			} else if let Some(supply_amount) =
+				T::StableAsset::get_supply_amount(path[i - 1], path[i], supply_amounts[i])
+			{
+				supply_amount
+			} else {
 				TradingPair::from_currency_ids(path[i - 1], path[i]).ok_or(Error::<T>::InvalidCurrencyId)?;
-			ensure!(
-				matches!(
-					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
-				),
-				Error::<T>::MustBeEnabled
-			);
-			let (supply_pool, target_pool) = Self::get_liquidity(path[i - 1], path[i]);
-			ensure!(
-				!supply_pool.is_zero() && !target_pool.is_zero(),
-				Error::<T>::InsufficientLiquidity
-			);
-			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+				return Err(Error::<T>::MustBeEnabled.into());
+			};
 			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
 
 			supply_amounts[i - 1] = supply_amount;
@@ -1125,6 +1845,160 @@ impl<T: Config> Pallet<T> {
 		Ok(supply_amounts)
 	}
 
+	/// The trading pair of the hop if it is Enabled. The hops whose trading
+	/// pairs are not Enabled are routed through the stable asset pools.
+	fn enabled_trading_pair(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Option<TradingPair> {
+		TradingPair::from_currency_ids(supply_currency_id, target_currency_id).filter(|trading_pair| {
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Enabled
+			)
+		})
+	}
+
+	/// Get all the trading paths from `supply_currency_id` to
+	/// `target_currency_id` through Enabled trading pairs and stable asset
+	/// pools, the length of each path won't exceed `TradingPathLimit`. At
+	/// most `MaxTradingPairs` trading pairs are searched, the ones beyond it
+	/// are ignored.
+	fn get_trading_paths(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Vec<Vec<CurrencyId>> {
+		let path_limit: usize = T::TradingPathLimit::get().saturated_into();
+		let max_trading_pairs: usize = T::MaxTradingPairs::get().saturated_into();
+		let mut enabled_trading_pairs: Vec<TradingPair> = TradingPairStatuses::<T>::iter()
+			.take(max_trading_pairs)
+			.filter_map(|(trading_pair, status)| match status {
+				TradingPairStatus::<_, _>::Enabled => Some(trading_pair),
+				_ => None,
+			})
+			.collect();
+		for (currency_id_a, currency_id_b) in T::StableAsset::trading_pairs() {
+			if enabled_trading_pairs.len() >= max_trading_pairs {
+				break;
+			}
+			if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
+				if !enabled_trading_pairs.contains(&trading_pair) {
+					enabled_trading_pairs.push(trading_pair);
+				}
+			}
+		}
+
+		let mut paths: Vec<Vec<CurrencyId>> = vec![];
+		let mut pending_paths: Vec<Vec<CurrencyId>> = vec![vec![supply_currency_id]];
+		while let Some(path) = pending_paths.pop() {
+			let last_currency_id = path[path.len() - 1];
+			for trading_pair in enabled_trading_pairs.iter() {
+				let next_currency_id = if trading_pair.first() == last_currency_id {
+					trading_pair.second()
+				} else if trading_pair.second() == last_currency_id {
+					trading_pair.first()
+				} else {
+					continue;
+				};
+
+				// a currency can appear only once in the path
+				if path.contains(&next_currency_id) {
+					continue;
+				}
+
+				let mut new_path = path.clone();
+				new_path.push(next_currency_id);
+				if next_currency_id == target_currency_id {
+					paths.push(new_path);
+				} else if new_path.len() < path_limit {
+					pending_paths.push(new_path);
+				}
+			}
+		}
+
+		paths
+	}
+
+	/// Get the trading path among `paths` which gives the most target amount
+	/// for the exact supply amount, and the target amount of it. The invalid
+	/// paths are skipped. If several paths give the same target amount, the
+	/// shortest one is preferred.
+	fn best_path_with_exact_supply(
+		paths: Vec<Vec<CurrencyId>>,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		let mut best: Option<(Vec<CurrencyId>, Balance)> = None;
+
+		for path in paths {
+			if let Ok(amounts) = Self::get_target_amounts(&path, supply_amount) {
+				let target_amount = amounts[amounts.len() - 1];
+				let is_better = match &best {
+					Some((best_path, best_target_amount)) => {
+						target_amount > *best_target_amount
+							|| (target_amount == *best_target_amount && path.len() < best_path.len())
+					}
+					None => true,
+				};
+				if is_better {
+					best = Some((path, target_amount));
+				}
+			}
+		}
+
+		best
+	}
+
+	/// Get the trading path among `paths` which costs the least supply
+	/// amount for the exact target amount, and the supply amount of it. The
+	/// invalid paths are skipped. If several paths cost the same supply
+	/// amount, the shortest one is preferred.
+	fn best_path_with_exact_target(
+		paths: Vec<Vec<CurrencyId>>,
+		target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		let mut best: Option<(Vec<CurrencyId>, Balance)> = None;
+
+		for path in paths {
+			if let Ok(amounts) = Self::get_supply_amounts(&path, target_amount) {
+				let supply_amount = amounts[0];
+				let is_better = match &best {
+					Some((best_path, best_supply_amount)) => {
+						supply_amount < *best_supply_amount
+							|| (supply_amount == *best_supply_amount && path.len() < best_path.len())
+					}
+					None => true,
+				};
+				if is_better {
+					best = Some((path, supply_amount));
+				}
+			}
+		}
+
+		best
+	}
+
+	/// Get the trading path which gives the most target amount for the
+	/// exact supply amount, and the target amount of it. If several paths
+	/// give the same target amount, the shortest one is preferred.
+	pub fn get_best_price_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::best_path_with_exact_supply(
+			Self::get_trading_paths(supply_currency_id, target_currency_id),
+			supply_amount,
+		)
+	}
+
+	/// Get the trading path which costs the least supply amount for the
+	/// exact target amount, and the supply amount of it. If several paths
+	/// cost the same supply amount, the shortest one is preferred.
+	pub fn get_best_price_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::best_path_with_exact_target(
+			Self::get_trading_paths(supply_currency_id, target_currency_id),
+			target_amount,
+		)
+	}
+
 	fn _swap(
 		supply_currency_id: CurrencyId,
 		target_currency_id: CurrencyId,
@@ -1132,8 +2006,15 @@ impl<T: Config> Pallet<T> {
 		target_decrement: Balance,
 	) -> DispatchResult {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(supply_currency_id, target_currency_id) {
+			let pool_kind = Self::pool_kinds(trading_pair);
 			LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+				Self::update_price_accumulators(trading_pair, *pool_0, *pool_1);
 				let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+				let (supply_pool, target_pool) = if supply_currency_id == trading_pair.first() {
+					(*pool_0, *pool_1)
+				} else {
+					(*pool_1, *pool_0)
+				};
 
 				if supply_currency_id == trading_pair.first() {
 					*pool_0 = pool_0.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
@@ -1143,12 +2024,44 @@ impl<T: Config> Pallet<T> {
 					*pool_1 = pool_1.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
 				}
 
-				// invariant check to ensure the constant product formulas (k = x * y)
-				let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
-				ensure!(
-					invariant_after_swap >= invariant_before_swap,
-					Error::<T>::InvariantCheckFailed,
-				);
+				match pool_kind {
+					PoolKind::ConstantProduct => {
+						// invariant check to ensure the constant product formulas (k = x * y)
+						let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+						ensure!(
+							invariant_after_swap >= invariant_before_swap,
+							Error::<T>::InvariantCheckFailed,
+						);
+					}
+					PoolKind::Weighted(..) => {
+						// the weighted formulas can't be evaluated exactly, but both directions round in
+						// favour of the pool, so the swap is valid if either of them accepts it.
+						let (supply_weight, target_weight) =
+							Self::swap_weights(pool_kind, trading_pair, supply_currency_id);
+						let exchange_fee = Self::get_exchange_fee(trading_pair);
+						let max_target_decrement = Self::get_weighted_target_amount(
+							supply_pool,
+							target_pool,
+							supply_weight,
+							target_weight,
+							supply_increment,
+							exchange_fee,
+						);
+						let min_supply_increment = Self::get_weighted_supply_amount(
+							supply_pool,
+							target_pool,
+							supply_weight,
+							target_weight,
+							target_decrement,
+							exchange_fee,
+						);
+						ensure!(
+							target_decrement <= max_target_decrement
+								|| (!min_supply_increment.is_zero() && supply_increment >= min_supply_increment),
+							Error::<T>::InvariantCheckFailed,
+						);
+					}
+				}
 				Ok(())
 			})?;
 		}
@@ -1160,12 +2073,23 @@ impl<T: Config> Pallet<T> {
 		while i + 1 < path.len() {
 			let (supply_currency_id, target_currency_id) = (path[i], path[i + 1]);
 			let (supply_increment, target_decrement) = (amounts[i], amounts[i + 1]);
-			Self::_swap(
-				supply_currency_id,
-				target_currency_id,
-				supply_increment,
-				target_decrement,
-			)?;
+			if Self::enabled_trading_pair(supply_currency_id, target_currency_id).is_some() {
+				Self::_swap(
+					supply_currency_id,
+					target_currency_id,
+					supply_increment,
+					target_decrement,
+				)?;
+			} else {
+				// the module account pays and receives the assets of the hop through the stable asset pool
+				T::StableAsset::swap(
+					&Self::account_id(),
+					supply_currency_id,
+					target_currency_id,
+					supply_increment,
+					target_decrement,
+				)?;
+			}
 			i += 1;
 		}
 		Ok(())
@@ -1215,6 +2139,32 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Swap(who.clone(), path.to_vec(), amounts));
 		Ok(actual_supply_amount)
 	}
+
+	/// Swap with exact target amount, but the supply amount is paid after
+	/// `callback` is executed. Ensured atomic.
+	#[transactional]
+	fn do_flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let amounts = Self::get_supply_amounts(path, target_amount)?;
+		ensure!(amounts[0] <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
+		let module_account_id = Self::account_id();
+		let actual_supply_amount = amounts[0];
+
+		// the liquidity pools are updated before the callback, so the callback
+		// always sees the price after this swap.
+		Self::_swap_by_path(path, &amounts)?;
+		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
+		callback()?;
+		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)?;
+
+		Self::deposit_event(Event::FlashSwap(who.clone(), path.to_vec(), amounts));
+		Ok(actual_supply_amount)
+	}
 }
 
 impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
@@ -1239,6 +2189,30 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 			.map(|amounts| amounts[0])
 	}
 
+	fn get_best_price_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::get_best_price_swap_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+	}
+
+	fn get_best_price_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::get_best_price_swap_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+	}
+
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+	) -> Option<ExchangeRate> {
+		Self::get_time_weighted_average_price(currency_id_a, currency_id_b, window.into())
+	}
+
 	fn swap_with_exact_supply(
 		who: &T::AccountId,
 		path: &[CurrencyId],
@@ -1257,6 +2231,16 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		Self::do_swap_with_exact_target(who, path, target_amount, max_supply_amount)
 	}
 
+	fn flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		Self::do_flash_swap_with_exact_target(who, path, target_amount, max_supply_amount, callback)
+	}
+
 	// `do_add_liquidity` is used in genesis_build,
 	// but transactions are not supported by BasicExternalities,
 	// put `transactional` here
@@ -1302,3 +2286,95 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		)
 	}
 }
+
+/// The fixed point one of the weighted pool math.
+const WEIGHTED_MATH_ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Multiply fixed point numbers, round down.
+fn fixed_mul_down(a: U256, b: U256) -> Option<U256> {
+	a.checked_mul(b)?.checked_div(U256::from(WEIGHTED_MATH_ONE))
+}
+
+/// Multiply fixed point numbers, round up.
+fn fixed_mul_up(a: U256, b: U256) -> Option<U256> {
+	let product = a.checked_mul(b)?;
+	if product.is_zero() {
+		Some(product)
+	} else {
+		(product - 1)
+			.checked_div(U256::from(WEIGHTED_MATH_ONE))?
+			.checked_add(U256::one())
+	}
+}
+
+/// Divide fixed point numbers, round up.
+fn fixed_div_up(a: U256, b: U256) -> Option<U256> {
+	let numerator = a.checked_mul(U256::from(WEIGHTED_MATH_ONE))?;
+	if numerator.is_zero() {
+		Some(numerator)
+	} else {
+		(numerator - 1).checked_div(b)?.checked_add(U256::one())
+	}
+}
+
+/// Fixed point `base ^ exp`, round down.
+fn fixed_pow_down(base: U256, exp: u32) -> Option<U256> {
+	(0..exp).try_fold(U256::from(WEIGHTED_MATH_ONE), |acc, _| fixed_mul_down(acc, base))
+}
+
+/// Fixed point `base ^ exp`, round up.
+fn fixed_pow_up(base: U256, exp: u32) -> Option<U256> {
+	(0..exp).try_fold(U256::from(WEIGHTED_MATH_ONE), |acc, _| fixed_mul_up(acc, base))
+}
+
+/// The smallest fixed point `root` which satisfies `root ^ n >= a`.
+fn fixed_root_up(a: U256, n: u32) -> Option<U256> {
+	if n <= 1 || a.is_zero() {
+		return Some(a);
+	}
+
+	// start from `2 ^ ceil(log2(a) / n)`, which is no less than the root as `a < 2 ^ (bits - 59)`.
+	let one = U256::from(WEIGHTED_MATH_ONE);
+	let log2_upper = a.bits() as i32 - 59;
+	let mut root = if log2_upper >= 0 {
+		one << ((log2_upper + n as i32 - 1) / n as i32) as usize
+	} else {
+		(one >> ((-log2_upper) / n as i32) as usize).max(U256::one())
+	};
+
+	// Newton's method converges to the root from above.
+	loop {
+		let power = fixed_pow_down(root, n - 1)?;
+		if power.is_zero() {
+			break;
+		}
+		let next = U256::from(n - 1)
+			.checked_mul(root)?
+			.checked_add(a.checked_mul(one)? / power)?
+			/ U256::from(n);
+		if next >= root {
+			break;
+		}
+		root = next;
+	}
+
+	// correct the rounding errors
+	while fixed_pow_down(root, n)? < a {
+		root = root.checked_add(U256::one())?;
+	}
+	Some(root)
+}
+
+/// Fixed point `base ^ (numerator / denominator)`, round up.
+fn fixed_pow_ratio_up(base: U256, numerator: u32, denominator: u32) -> Option<U256> {
+	let (mut x, mut y) = (numerator, denominator);
+	while !y.is_zero() {
+		let remainder = x % y;
+		x = y;
+		y = remainder;
+	}
+	if x.is_zero() {
+		return None;
+	}
+	fixed_pow_up(fixed_root_up(base, denominator / x)?, numerator / x)
+}
@@ -33,6 +33,7 @@ pub type AccountId = u128;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const STABLE_ASSET_POOL: AccountId = 11;
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -108,6 +109,56 @@ impl DEXIncentives<AccountId, CurrencyId, Balance> for MockDEXIncentives {
 	}
 }
 
+parameter_types! {
+	pub static MockStableAssetPairs: Vec<(CurrencyId, CurrencyId)> = vec![];
+}
+
+/// Swaps the pairs of `MockStableAssetPairs` at 1:1.
+pub struct MockStableAsset;
+impl StableAssetRouter<AccountId, CurrencyId, Balance> for MockStableAsset {
+	fn trading_pairs() -> Vec<(CurrencyId, CurrencyId)> {
+		MockStableAssetPairs::get()
+	}
+
+	fn get_target_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Balance> {
+		MockStableAssetPairs::get()
+			.iter()
+			.any(|pair| {
+				*pair == (supply_currency_id, target_currency_id) || *pair == (target_currency_id, supply_currency_id)
+			})
+			.then(|| supply_amount)
+	}
+
+	fn get_supply_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Balance> {
+		Self::get_target_amount(target_currency_id, supply_currency_id, target_amount)
+	}
+
+	fn swap(
+		who: &AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		target_amount: Balance,
+	) -> DispatchResult {
+		let max_target_amount = Self::get_target_amount(supply_currency_id, target_currency_id, supply_amount)
+			.ok_or(DispatchError::Other("no stable asset pool"))?;
+		ensure!(
+			target_amount <= max_target_amount,
+			DispatchError::Other("insufficient target amount")
+		);
+		Tokens::transfer(supply_currency_id, who, &STABLE_ASSET_POOL, supply_amount)?;
+		Tokens::transfer(target_currency_id, &STABLE_ASSET_POOL, who, target_amount)
+	}
+}
+
 ord_parameter_types! {
 	pub const ListingOrigin: AccountId = 3;
 }
@@ -115,6 +166,9 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub static MaxTradingPairs: u32 = 10;
+	pub const PriceObservationLimit: u32 = 10;
+	pub const TreasuryAccount: AccountId = 10;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -123,11 +177,16 @@ impl Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = MockStableAsset;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -23,8 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE,
-	AUSD, BOB, BTC, DOT,
+	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, GetExchangeFee, ListingOrigin, MaxTradingPairs,
+	MockStableAssetPairs, Origin, Runtime, System, Tokens, TreasuryAccount, ACA, ALICE, AUSD, BOB, BTC, DOT,
+	STABLE_ASSET_POOL,
 };
 use orml_traits::MultiReservableCurrency;
 use sp_runtime::traits::BadOrigin;
@@ -44,6 +45,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				PoolKind::ConstantProduct
 			),
 			BadOrigin
 		);
@@ -61,6 +63,7 @@ fn list_provisioning_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -83,6 +86,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				PoolKind::ConstantProduct
 			),
 			Error::<Runtime>::InvalidCurrencyId
 		);
@@ -97,6 +101,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				PoolKind::ConstantProduct
 			),
 			Error::<Runtime>::MustBeDisabled
 		);
@@ -145,6 +150,7 @@ fn update_provisioning_parameters_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -184,7 +190,7 @@ fn enable_diabled_trading_pair_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT),
+			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT, PoolKind::ConstantProduct),
 			BadOrigin
 		);
 
@@ -195,7 +201,8 @@ fn enable_diabled_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -204,12 +211,91 @@ fn enable_diabled_trading_pair_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), DOT, AUSD),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				PoolKind::ConstantProduct
+			),
 			Error::<Runtime>::AlreadyEnabled
 		);
 	});
 }
 
+#[test]
+fn enable_weighted_trading_pair_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				PoolKind::Weighted(0, 1)
+			),
+			Error::<Runtime>::InvalidPoolKind
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				PoolKind::Weighted(MAX_POOL_WEIGHT + 1, 1)
+			),
+			Error::<Runtime>::InvalidPoolKind
+		);
+
+		assert_eq!(DexModule::pool_kinds(AUSDDOTPair::get()), PoolKind::ConstantProduct);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			DOT,
+			AUSD,
+			PoolKind::Weighted(1, 4)
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		assert_eq!(DexModule::pool_kinds(AUSDDOTPair::get()), PoolKind::Weighted(4, 1));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			40_000,
+			10_000,
+			0,
+			false
+		));
+		assert_eq!(
+			Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+			50_000
+		);
+
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				PoolKind::ConstantProduct
+			),
+			Error::<Runtime>::PoolKindNotChangeable
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			PoolKind::Weighted(4, 1)
+		));
+	});
+}
+
 #[test]
 fn enable_provisioning_without_provision_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -224,6 +310,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_ok!(DexModule::list_provisioning(
 			Origin::signed(ListingOrigin::get()),
@@ -234,6 +321,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -255,7 +343,8 @@ fn enable_provisioning_without_provision_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -264,7 +353,12 @@ fn enable_provisioning_without_provision_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				BTC,
+				PoolKind::ConstantProduct
+			),
 			Error::<Runtime>::StillProvisioning
 		);
 	});
@@ -284,6 +378,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -304,6 +399,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -381,7 +477,8 @@ fn disable_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			PoolKind::ConstantProduct
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -418,6 +515,7 @@ fn disable_trading_pair_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 		assert_noop!(
 			DexModule::disable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
@@ -426,6 +524,100 @@ fn disable_trading_pair_work() {
 	});
 }
 
+#[test]
+fn set_exchange_fee_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((3, 1000))),
+			Error::<Runtime>::MustBeEnabled
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			PoolKind::ConstantProduct
+		));
+
+		assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), GetExchangeFee::get());
+		assert_noop!(
+			DexModule::set_exchange_fee(Origin::signed(ALICE), AUSD, DOT, Some((3, 1000))),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, AUSD, Some((3, 1000))),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((100, 100))),
+			Error::<Runtime>::InvalidExchangeFee
+		);
+
+		assert_ok!(DexModule::set_exchange_fee(
+			Origin::signed(ListingOrigin::get()),
+			DOT,
+			AUSD,
+			Some((3, 1000))
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ExchangeFeeUpdated(
+			AUSDDOTPair::get(),
+			(3, 1000),
+		)));
+		assert_eq!(DexModule::exchange_fees(AUSDDOTPair::get()), Some((3, 1000)));
+		assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (3, 1000));
+		assert_eq!(DexModule::get_exchange_fee(AUSDBTCPair::get()), GetExchangeFee::get());
+
+		assert_ok!(DexModule::set_exchange_fee(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			None
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ExchangeFeeUpdated(
+			AUSDDOTPair::get(),
+			GetExchangeFee::get(),
+		)));
+		assert_eq!(DexModule::exchange_fees(AUSDDOTPair::get()), None);
+		assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), GetExchangeFee::get());
+	});
+}
+
+#[test]
+fn set_protocol_fee_share_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::set_protocol_fee_share(Origin::signed(ALICE), Some((1, 6))),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::set_protocol_fee_share(Origin::signed(ListingOrigin::get()), Some((0, 6))),
+			Error::<Runtime>::InvalidProtocolFeeShare
+		);
+		assert_noop!(
+			DexModule::set_protocol_fee_share(Origin::signed(ListingOrigin::get()), Some((6, 6))),
+			Error::<Runtime>::InvalidProtocolFeeShare
+		);
+
+		assert_eq!(DexModule::protocol_fee_share(), None);
+		assert_ok!(DexModule::set_protocol_fee_share(
+			Origin::signed(ListingOrigin::get()),
+			Some((1, 6))
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ProtocolFeeShareUpdated(Some((1, 6)))));
+		assert_eq!(DexModule::protocol_fee_share(), Some((1, 6)));
+
+		assert_ok!(DexModule::set_protocol_fee_share(
+			Origin::signed(ListingOrigin::get()),
+			None
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ProtocolFeeShareUpdated(None)));
+		assert_eq!(DexModule::protocol_fee_share(), None);
+	});
+}
+
 #[test]
 fn add_provision_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -451,6 +643,7 @@ fn add_provision_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			10,
+			PoolKind::ConstantProduct
 		));
 
 		assert_noop!(
@@ -533,6 +726,7 @@ fn claim_dex_share_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			0,
+			PoolKind::ConstantProduct
 		));
 
 		assert_ok!(DexModule::add_provision(
@@ -623,26 +817,115 @@ fn get_liquidity_work() {
 #[test]
 fn get_target_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_target_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000), 9949);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_target_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 1, 1000000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 10000, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
 #[test]
 fn get_supply_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 1, 1), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949), 9999);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999), 9949);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801), 1000);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 1, 1, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 9949, GetExchangeFee::get()),
+			9999
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 9999, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 1801, GetExchangeFee::get()),
+			1000
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
+	});
+}
+
+#[test]
+fn get_weighted_target_amount_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			DexModule::get_weighted_target_amount(40000, 0, 4, 1, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(0, 10000, 4, 1, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(40000, 10000, 4, 1, 0, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(40000, 10000, 4, 1, 1000, GetExchangeFee::get()),
+			931
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(40000, 10000, 4, 1, 10000, GetExchangeFee::get()),
+			5871
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(10000, 40000, 1, 4, 1000, GetExchangeFee::get()),
+			932
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(10000, 40000, 1, 4, 100000, GetExchangeFee::get()),
+			17985
+		);
+	});
+}
+
+#[test]
+fn get_weighted_supply_amount_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(0, 10000, 4, 1, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(40000, 10000, 4, 1, 0, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(40000, 10000, 4, 1, 10000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(40000, 10000, 4, 1, 931, GetExchangeFee::get()),
+			1001
+		);
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(40000, 10000, 4, 1, 1000, GetExchangeFee::get()),
+			1079
+		);
+		assert_eq!(
+			DexModule::get_weighted_supply_amount(10000, 40000, 1, 4, 4000, GetExchangeFee::get()),
+			5295
+		);
+		assert_eq!(
+			DexModule::get_weighted_target_amount(10000, 40000, 1, 4, 5295, GetExchangeFee::get()),
+			4000
+		);
 	});
 }
 
@@ -685,6 +968,39 @@ fn get_target_amounts_work() {
 		});
 }
 
+#[test]
+fn get_target_amounts_with_exchange_fee_of_trading_pair_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 24874])
+			);
+			assert_eq!(
+				DexModule::get_supply_amounts(&[DOT, AUSD], 24874),
+				Ok(vec![10000, 24874])
+			);
+
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((0, 100))
+			));
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 25000])
+			);
+			assert_eq!(
+				DexModule::get_supply_amounts(&[DOT, AUSD], 24874),
+				Ok(vec![9900, 24874])
+			);
+		});
+}
+
 #[test]
 fn calculate_amount_for_big_number_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -696,7 +1012,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_supply_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				1_000_000_000_000_000_000_000
+				1_000_000_000_000_000_000_000,
+				GetExchangeFee::get()
 			),
 			3_140_495_867_768_595_041_323
 		);
@@ -704,7 +1021,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_target_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				3_140_495_867_768_595_041_323
+				3_140_495_867_768_595_041_323,
+				GetExchangeFee::get()
 			),
 			1_000_000_000_000_000_000_000
 		);
@@ -770,6 +1088,29 @@ fn _swap_work() {
 		});
 }
 
+#[test]
+fn _swap_weighted_pool_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			PoolKinds::<Runtime>::insert(AUSDDOTPair::get(), PoolKind::Weighted(4, 1));
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (40000, 10000));
+
+			assert_eq!(DexModule::get_target_amounts(&[AUSD, DOT], 1000), Ok(vec![1000, 931]));
+			assert_eq!(DexModule::get_supply_amounts(&[AUSD, DOT], 931), Ok(vec![1001, 931]));
+			assert_noop!(
+				DexModule::_swap(AUSD, DOT, 1000, 932),
+				Error::<Runtime>::InvariantCheckFailed
+			);
+			assert_ok!(DexModule::_swap(AUSD, DOT, 1000, 931));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (41000, 9069));
+			assert_eq!(DexModule::get_target_amounts(&[DOT, AUSD], 100), Ok(vec![100, 111]));
+			assert_ok!(DexModule::_swap(DOT, AUSD, 100, 111));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (40889, 9169));
+		});
+}
+
 #[test]
 fn _swap_by_path_work() {
 	ExtBuilder::default()
@@ -790,47 +1131,139 @@ fn _swap_by_path_work() {
 }
 
 #[test]
-fn add_liquidity_work() {
+fn update_price_accumulators_work() {
 	ExtBuilder::default()
 		.initialize_enabled_trading_pairs()
 		.build()
 		.execute_with(|| {
 			System::set_block_number(1);
-
-			assert_noop!(
-				DexModule::add_liquidity(Origin::signed(ALICE), ACA, AUSD, 100_000_000, 100_000_000, 0, false),
-				Error::<Runtime>::MustBeEnabled
-			);
-			assert_noop!(
-				DexModule::add_liquidity(Origin::signed(ALICE), AUSD, DOT, 0, 100_000_000, 0, false),
-				Error::<Runtime>::InvalidLiquidityIncrement
-			);
-
-			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
-			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
-			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
-			assert_eq!(
-				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
-				0
-			);
-			assert_eq!(
-				Tokens::reserved_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
-				0
-			);
-			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000);
-			assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000);
-
+			assert_eq!(DexModule::price_observation_count(AUSDDOTPair::get()), 0);
 			assert_ok!(DexModule::add_liquidity(
 				Origin::signed(ALICE),
 				AUSD,
 				DOT,
-				5_000_000_000_000,
-				1_000_000_000_000,
+				100_000,
+				10_000,
 				0,
-				false,
+				false
 			));
-			System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
-				ALICE,
+			assert_eq!(DexModule::price_observation_count(AUSDDOTPair::get()), 1);
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get(), 0),
+				Some(PriceObservation {
+					price_0_cumulative: U256::zero(),
+					price_1_cumulative: U256::zero(),
+					block_number: 1,
+				})
+			);
+
+			System::set_block_number(3);
+			assert_ok!(DexModule::_swap(AUSD, DOT, 1_000, 90));
+			assert_ok!(DexModule::_swap(AUSD, DOT, 1_000, 90));
+			assert_eq!(DexModule::price_observation_count(AUSDDOTPair::get()), 2);
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get(), 1),
+				Some(PriceObservation {
+					price_0_cumulative: U256::from(200_000_000_000_000_000u128),
+					price_1_cumulative: U256::from(20_000_000_000_000_000_000u128),
+					block_number: 3,
+				})
+			);
+		});
+}
+
+#[test]
+fn get_time_weighted_average_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		DexModule::update_price_accumulators(AUSDDOTPair::get(), 0, 0);
+		LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (100_000, 10_000));
+
+		System::set_block_number(5);
+		DexModule::update_price_accumulators(AUSDDOTPair::get(), 100_000, 10_000);
+		LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (100_000, 20_000));
+
+		System::set_block_number(9);
+		assert_eq!(DexModule::get_time_weighted_average_price(AUSD, DOT, 0), None);
+		assert_eq!(DexModule::get_time_weighted_average_price(AUSD, AUSD, 4), None);
+		assert_eq!(DexModule::get_time_weighted_average_price(AUSD, DOT, 9), None);
+		assert_eq!(
+			DexModule::get_time_weighted_average_price(DOT, AUSD, 4),
+			Some(ExchangeRate::saturating_from_integer(5))
+		);
+		assert_eq!(
+			DexModule::get_time_weighted_average_price(DOT, AUSD, 8),
+			Some(ExchangeRate::saturating_from_rational(15, 2))
+		);
+		assert_eq!(
+			DexModule::get_time_weighted_average_price(DOT, AUSD, 6),
+			Some(ExchangeRate::from_inner(6_666_666_666_666_666_666))
+		);
+		assert_eq!(
+			DexModule::get_time_weighted_average_price(AUSD, DOT, 8),
+			Some(ExchangeRate::saturating_from_rational(15, 100))
+		);
+
+		// the oldest observations are overwritten
+		for block_number in 10..=20 {
+			System::set_block_number(block_number);
+			DexModule::update_price_accumulators(AUSDDOTPair::get(), 100_000, 20_000);
+		}
+		assert_eq!(DexModule::price_observation_count(AUSDDOTPair::get()), 13);
+		assert_eq!(
+			DexModule::price_observations(AUSDDOTPair::get(), 2).map(|observation| observation.block_number),
+			Some(20)
+		);
+		assert_eq!(
+			DexModule::get_time_weighted_average_price(DOT, AUSD, 9),
+			Some(ExchangeRate::saturating_from_integer(5))
+		);
+		assert_eq!(DexModule::get_time_weighted_average_price(DOT, AUSD, 10), None);
+	});
+}
+
+#[test]
+fn add_liquidity_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::add_liquidity(Origin::signed(ALICE), ACA, AUSD, 100_000_000, 100_000_000, 0, false),
+				Error::<Runtime>::MustBeEnabled
+			);
+			assert_noop!(
+				DexModule::add_liquidity(Origin::signed(ALICE), AUSD, DOT, 0, 100_000_000, 0, false),
+				Error::<Runtime>::InvalidLiquidityIncrement
+			);
+
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+			assert_eq!(
+				Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				0
+			);
+			assert_eq!(
+				Tokens::reserved_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				0
+			);
+			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				5_000_000_000_000,
+				1_000_000_000_000,
+				0,
+				false,
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
+				ALICE,
 				AUSD,
 				5_000_000_000_000,
 				DOT,
@@ -1079,6 +1512,122 @@ fn remove_liquidity_work() {
 		});
 }
 
+#[test]
+fn protocol_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let dex_share_currency_id = AUSDDOTPair::get().dex_share_currency_id();
+
+			assert_ok!(DexModule::set_protocol_fee_share(
+				Origin::signed(ListingOrigin::get()),
+				Some((1, 6))
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				5_000_000_000_000,
+				1_000_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(Tokens::total_issuance(dex_share_currency_id), 10_000_000_000_000);
+			assert_eq!(
+				DexModule::k_last(AUSDDOTPair::get()),
+				U256::from(5_000_000_000_000u128) * U256::from(1_000_000_000_000u128)
+			);
+
+			// no fee has been collected by the pool yet
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				5_000_000_000_000,
+				1_000_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()), 0);
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				10_000_000_000_000,
+				0,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(5_000_000_000_000, 1_000_000_000_000)
+			);
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()), 0);
+
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[DOT, AUSD],
+				1_000_000_000_000,
+				0,
+			));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(2_512_562_814_071, 2_000_000_000_000)
+			);
+
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000_000,
+				0,
+				0,
+				false,
+			));
+			assert!(System::events().iter().any(|record| record.event
+				== Event::DexModule(crate::Event::ProtocolFeeAccrued(AUSDDOTPair::get(), 4_173_629_253))));
+			assert_eq!(
+				Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()),
+				4_173_629_253
+			);
+			assert_eq!(DexModule::accrued_protocol_fees(AUSDDOTPair::get()), 4_173_629_253);
+			assert_eq!(Tokens::total_issuance(dex_share_currency_id), 9_004_173_629_253);
+			let (pool_0, pool_1) = DexModule::get_liquidity(AUSD, DOT);
+			assert_eq!(
+				DexModule::k_last(AUSDDOTPair::get()),
+				U256::from(pool_0) * U256::from(pool_1)
+			);
+
+			// turn off the protocol fee
+			assert_ok!(DexModule::set_protocol_fee_share(
+				Origin::signed(ListingOrigin::get()),
+				None
+			));
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[DOT, AUSD],
+				1_000_000_000_000,
+				0,
+			));
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000_000,
+				0,
+				0,
+				false,
+			));
+			assert_eq!(
+				Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()),
+				4_173_629_253
+			);
+			assert_eq!(DexModule::k_last(AUSDDOTPair::get()), U256::zero());
+		});
+}
+
 #[test]
 fn do_swap_with_exact_supply_work() {
 	ExtBuilder::default()
@@ -1320,6 +1869,405 @@ fn do_swap_with_exact_target_work() {
 		});
 }
 
+#[test]
+fn do_flash_swap_with_exact_target_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::do_flash_swap_with_exact_target(
+					&BOB,
+					&[DOT, AUSD],
+					250_000_000_000_000,
+					100_000_000_000_000,
+					|| Ok(())
+				),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+			assert_noop!(
+				DexModule::do_flash_swap_with_exact_target(
+					&BOB,
+					&[DOT, AUSD],
+					250_000_000_000_000,
+					200_000_000_000_000,
+					|| Err(DispatchError::Other("callback failed"))
+				),
+				DispatchError::Other("callback failed")
+			);
+			assert_noop!(
+				DexModule::do_flash_swap_with_exact_target(
+					&BOB,
+					&[DOT, AUSD],
+					250_000_000_000_000,
+					200_000_000_000_000,
+					|| Tokens::transfer(DOT, &BOB, &ALICE, Tokens::free_balance(DOT, &BOB))
+				),
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+
+			assert_ok!(DexModule::do_flash_swap_with_exact_target(
+				&BOB,
+				&[DOT, AUSD],
+				250_000_000_000_000,
+				200_000_000_000_000,
+				|| {
+					// the target amount is received and the pool is updated before paying
+					assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
+					assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
+					assert_eq!(
+						DexModule::get_liquidity(AUSD, DOT),
+						(250_000_000_000_000, 201_010_101_010_102)
+					);
+					Ok(())
+				}
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::FlashSwap(
+				BOB,
+				vec![DOT, AUSD],
+				vec![101_010_101_010_102, 250_000_000_000_000],
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(250_000_000_000_000, 201_010_101_010_102)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				250_000_000_000_000
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 201_010_101_010_102);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_250_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_898_989_898_989_898);
+		});
+}
+
+#[test]
+fn flash_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::flash_swap(
+					Origin::signed(BOB),
+					vec![DOT, AUSD],
+					250_000_000_000_000,
+					200_000_000_000_000,
+					Box::new(mock::Call::DexModule(crate::Call::swap_with_exact_supply(
+						vec![AUSD, ACA],
+						250_000_000_000_000,
+						0
+					)))
+				),
+				Error::<Runtime>::MustBeEnabled
+			);
+
+			assert_ok!(DexModule::flash_swap(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				250_000_000_000_000,
+				200_000_000_000_000,
+				Box::new(mock::Call::DexModule(crate::Call::swap_with_exact_supply(
+					vec![AUSD, DOT],
+					250_000_000_000_000,
+					0
+				)))
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![250_000_000_000_000, 100_000_000_000_000],
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::FlashSwap(
+				BOB,
+				vec![DOT, AUSD],
+				vec![101_010_101_010_102, 250_000_000_000_000],
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(500_000_000_000_000, 101_010_101_010_102)
+			);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_998_989_898_989_898);
+
+			// the dispatch class of the callback doesn't apply to the flash swap
+			let operational_callback =
+				mock::Call::DexModule(crate::Call::enable_trading_pair(AUSD, BTC, PoolKind::ConstantProduct));
+			assert_eq!(
+				operational_callback.get_dispatch_info().class,
+				DispatchClass::Operational
+			);
+			assert_eq!(
+				crate::Call::<Runtime>::flash_swap(vec![DOT, AUSD], 1, 1, Box::new(operational_callback))
+					.get_dispatch_info()
+					.class,
+				DispatchClass::Normal
+			);
+		});
+}
+
+#[test]
+fn get_trading_paths_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			let mut paths = DexModule::get_trading_paths(DOT, BTC);
+			paths.sort();
+			assert_eq!(paths, vec![vec![DOT, AUSD, BTC], vec![DOT, BTC]]);
+
+			assert_eq!(DexModule::get_trading_paths(DOT, DOT), Vec::<Vec<CurrencyId>>::new());
+			assert_eq!(DexModule::get_trading_paths(DOT, ACA), Vec::<Vec<CurrencyId>>::new());
+
+			assert_ok!(DexModule::disable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				BTC
+			));
+			assert_eq!(DexModule::get_trading_paths(DOT, BTC), vec![vec![DOT, AUSD, BTC]]);
+		});
+}
+
+#[test]
+fn get_trading_paths_searches_at_most_max_trading_pairs() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			MaxTradingPairs::set(0);
+			assert_eq!(DexModule::get_trading_paths(DOT, BTC), Vec::<Vec<CurrencyId>>::new());
+
+			MaxTradingPairs::set(TradingPairStatuses::<Runtime>::iter().count() as u32);
+			let mut paths = DexModule::get_trading_paths(DOT, BTC);
+			paths.sort();
+			assert_eq!(paths, vec![vec![DOT, AUSD, BTC], vec![DOT, BTC]]);
+		});
+}
+
+#[test]
+fn get_best_price_swap_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				100_000,
+				10_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				10_000,
+				50,
+				0,
+				false,
+			));
+
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_supply(DOT, BTC, 1_000),
+				Some((vec![DOT, AUSD, BTC], 8))
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_supply(DOT, AUSD, 1_000),
+				Some((vec![DOT, AUSD], 9_008))
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_supply(DOT, BTC, 100),
+				None
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_supply(DOT, ACA, 1_000),
+				None
+			);
+
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_target(DOT, BTC, 8),
+				Some((vec![DOT, AUSD, BTC], 896))
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_target(DOT, AUSD, 1_000),
+				Some((vec![DOT, AUSD], 103))
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_target(DOT, BTC, 1_000),
+				None
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path_with_exact_target(DOT, ACA, 1_000),
+				None
+			);
+		});
+}
+
+#[test]
+fn swap_by_best_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				100_000,
+				10_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				10_000,
+				50,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_path(Origin::signed(BOB), DOT, ACA, 1_000, 0),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_path(Origin::signed(BOB), DOT, BTC, 1_000, 9),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+			assert_ok!(DexModule::swap_with_exact_supply_by_best_path(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				1_000,
+				8
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				vec![1_000, 9_008, 8],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (90_992, 11_000));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_009_008, 992));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (10_000, 50));
+
+			assert_noop!(
+				DexModule::swap_with_exact_target_by_best_path(Origin::signed(BOB), DOT, ACA, 1_000, 1_000),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_ok!(DexModule::swap_with_exact_target_by_best_path(
+				Origin::signed(BOB),
+				DOT,
+				AUSD,
+				1_000,
+				1_000
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD],
+				vec![124, 1_000],
+			)));
+		});
+}
+
+#[test]
+fn swap_through_stable_asset_pool_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				100_000,
+				10_000,
+				0,
+				false,
+			));
+			assert_ok!(Tokens::deposit(ACA, &STABLE_ASSET_POOL, 1_000_000));
+			assert_eq!(DexModule::get_trading_paths(AUSD, ACA), Vec::<Vec<CurrencyId>>::new());
+
+			MockStableAssetPairs::set(vec![(DOT, ACA)]);
+			assert_eq!(DexModule::get_trading_paths(DOT, ACA), vec![vec![DOT, ACA]]);
+			assert_eq!(
+				DexModule::get_target_amounts(&vec![AUSD, DOT, ACA], 10_000),
+				Ok(vec![10_000, 900, 900])
+			);
+			assert_eq!(
+				DexModule::get_supply_amounts(&vec![AUSD, DOT, ACA], 900),
+				Ok(vec![9_991, 900, 900])
+			);
+
+			assert_ok!(DexModule::swap_with_exact_supply_by_best_path(
+				Origin::signed(BOB),
+				AUSD,
+				ACA,
+				10_000,
+				900
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT, ACA],
+				vec![10_000, 900, 900],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (110_000, 9_100));
+			assert_eq!(Tokens::free_balance(ACA, &BOB), 900);
+			assert_eq!(Tokens::free_balance(ACA, &STABLE_ASSET_POOL), 999_100);
+			assert_eq!(Tokens::free_balance(DOT, &STABLE_ASSET_POOL), 900);
+			assert_eq!(Tokens::free_balance(ACA, &DexModule::account_id()), 0);
+		});
+}
+
 #[test]
 fn initialize_added_liquidity_pools_genesis_work() {
 	ExtBuilder::default()
@@ -60,6 +60,11 @@ pub trait WeightInfo {
 	fn remove_liquidity_by_unstake() -> Weight;
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
+	fn swap_with_exact_supply_by_best_path(u: u32, p: u32, ) -> Weight;
+	fn swap_with_exact_target_by_best_path(u: u32, p: u32, ) -> Weight;
+	fn flash_swap(u: u32, ) -> Weight;
+	fn set_exchange_fee() -> Weight;
+	fn set_protocol_fee_share() -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
@@ -92,13 +97,13 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(296_383_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(13 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
 	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
@@ -112,27 +117,68 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(339_614_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(12 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
 	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
 			.saturating_add((488_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(155_993_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_supply_by_best_path(u: u32, p: u32, ) -> Weight {
+		(150_251_000 as Weight)
+			.saturating_add((24_108_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_path(u: u32, p: u32, ) -> Weight {
+		(151_012_000 as Weight)
+			.saturating_add((24_362_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		(162_471_000 as Weight)
+			.saturating_add((702_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_344_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_share() -> Weight {
+		(14_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
 
@@ -165,13 +211,13 @@ impl WeightInfo for () {
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(296_383_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
 	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
@@ -185,26 +231,67 @@ impl WeightInfo for () {
 	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(339_614_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
 	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
 			.saturating_add((488_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(155_993_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_supply_by_best_path(u: u32, p: u32, ) -> Weight {
+		(150_251_000 as Weight)
+			.saturating_add((24_108_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_path(u: u32, p: u32, ) -> Weight {
+		(151_012_000 as Weight)
+			.saturating_add((24_362_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		(162_471_000 as Weight)
+			.saturating_add((702_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_344_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_share() -> Weight {
+		(14_213_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 }
@@ -173,6 +173,11 @@ pub mod module {
 					Error::<T>::ExistUnhandledDebit,
 				);
 			}
+			// there's no debit in multi-collateral CDP
+			ensure!(
+				<loans::Pallet<T>>::total_multi_collateral_position().debit.is_zero(),
+				Error::<T>::ExistUnhandledDebit,
+			);
 
 			// Open refund stage
 			CanRefund::<T>::put(true);
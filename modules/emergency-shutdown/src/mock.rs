@@ -173,6 +173,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		unimplemented!()
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -181,6 +198,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		unimplemented!()
 	}
 
+	fn get_total_debit_in_auction() -> Self::Balance {
+		unimplemented!()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		unimplemented!()
+	}
+
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
@@ -199,6 +199,10 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
 	fn set_origin(origin: AccountIdOf<T>) {
 		T::EVM::set_origin(origin);
 	}
+
+	fn log(address: H160, topics: Vec<H256>, data: Vec<u8>) {
+		T::EVM::log(address, topics, data);
+	}
 }
 
 impl<T: Config> Pallet<T> {
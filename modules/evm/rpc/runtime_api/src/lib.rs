@@ -20,7 +20,7 @@
 #![allow(clippy::all)]
 
 use ethereum_types::H160;
-use primitives::evm::{CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::evm::{CallInfo, CreateInfo, EstimateResourcesRequest, TraceConfig, TraceInfo};
 use sp_runtime::{
 	codec::Codec,
 	traits::{MaybeDisplay, MaybeFromStr},
@@ -28,6 +28,7 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
@@ -51,5 +52,30 @@ sp_api::decl_runtime_apis! {
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
 
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
+
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError>;
+
+		fn trace_create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError>;
+
+		/// Apply the extrinsic on the current state, and trace the EVM executions in it.
+		fn trace_extrinsic(extrinsic: Vec<u8>, trace_config: TraceConfig) -> Result<TraceInfo, sp_runtime::DispatchError>;
+
+		/// The maximum gas of an EVM execution in a block.
+		fn block_gas_limit() -> u64;
 	}
 }
@@ -25,7 +25,10 @@ use sp_core::Bytes;
 
 pub use rpc_impl_EVMApi::gen_server::EVMApi as EVMApiServer;
 
-use crate::call_request::{CallRequest, EstimateResourcesResponse};
+use crate::{
+	call_request::{CallRequest, EstimateResourcesResponse},
+	trace::{TraceOptions, TraceResponse},
+};
 
 /// EVM rpc interface.
 #[rpc(server)]
@@ -42,4 +45,19 @@ pub trait EVMApi<BlockHash> {
 		unsigned_extrinsic: Bytes,
 		at: Option<BlockHash>,
 	) -> Result<EstimateResourcesResponse>;
+
+	/// Trace the execution of the call without changing the state.
+	#[rpc(name = "debug_traceCall")]
+	fn trace_call(&self, _: CallRequest, options: Option<TraceOptions>, at: Option<BlockHash>)
+		-> Result<TraceResponse>;
+
+	/// Trace the EVM call of the extrinsic by re-executing the block which
+	/// includes it.
+	#[rpc(name = "debug_traceTransaction")]
+	fn trace_transaction(
+		&self,
+		extrinsic_hash: BlockHash,
+		block_hash: BlockHash,
+		options: Option<TraceOptions>,
+	) -> Result<TraceResponse>;
 }
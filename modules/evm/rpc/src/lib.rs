@@ -22,16 +22,19 @@ use ethereum_types::{H160, U256};
 use frame_support::log;
 use jsonrpc_core::{Error, ErrorCode, Result, Value};
 use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
+use primitives::evm::{Trace, TraceConfig, TraceInfo};
 use rustc_hex::ToHex;
+use sc_client_api::BlockBackend;
 use sc_rpc_api::DenyUnsafe;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
-use sp_core::{Bytes, Decode};
+use sp_core::{Bytes, Decode, Encode};
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{
 	codec::Codec,
 	generic::BlockId,
-	traits::{self, Block as BlockT, MaybeDisplay, MaybeFromStr},
+	traits::{self, Block as BlockT, Hash as HashT, Header as HeaderT, MaybeDisplay, MaybeFromStr},
 	SaturatedConversion,
 };
 use std::convert::{TryFrom, TryInto};
@@ -40,11 +43,13 @@ use std::{marker::PhantomData, sync::Arc};
 use call_request::{CallRequest, EstimateResourcesResponse};
 pub use module_evm::{ExitError, ExitReason};
 pub use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use trace::{StructLogsResponse, TraceOptions, TraceResponse};
 
 pub use crate::evm_api::{EVMApi as EVMApiT, EVMApiServer};
 
 mod call_request;
 mod evm_api;
+mod trace;
 
 fn internal_err<T: ToString>(message: T) -> Error {
 	Error {
@@ -126,12 +131,35 @@ fn to_u128(val: NumberOrHex) -> std::result::Result<u128, ()> {
 	val.into_u256().try_into().map_err(|_| ())
 }
 
+fn trace_config(options: Option<TraceOptions>) -> Result<TraceConfig> {
+	options.unwrap_or_default().into_trace_config().map_err(|tracer| Error {
+		code: ErrorCode::InvalidParams,
+		message: format!("Invalid parameter tracer: {:?}", tracer),
+		data: None,
+	})
+}
+
+fn trace_response(info: TraceInfo) -> Result<TraceResponse> {
+	match info.trace {
+		Trace::StructLogs(struct_logs) => Ok(TraceResponse::StructLogs(StructLogsResponse {
+			gas: info.used_gas,
+			storage: info.used_storage,
+			failed: !info.exit_reason.is_succeed(),
+			return_value: Bytes(info.output),
+			struct_logs: struct_logs.into_iter().map(Into::into).collect(),
+		})),
+		Trace::CallTracer(Some(call)) => Ok(TraceResponse::CallTracer(call.into())),
+		Trace::CallTracer(None) => Err(internal_err("no call traced")),
+	}
+}
+
 impl<B, C, Balance> EVMApiT<<B as BlockT>::Hash> for EVMApi<B, C, Balance>
 where
 	B: BlockT,
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + BlockBackend<B> + Send + Sync + 'static,
 	C::Api: EVMRuntimeRPCApi<B, Balance>,
 	C::Api: TransactionPaymentApi<B, Balance>,
+	C::Api: BlockBuilder<B>,
 	Balance: Codec + MaybeDisplay + MaybeFromStr + Default + Send + Sync + 'static + TryFrom<u128> + Into<U256>,
 {
 	fn call(&self, request: CallRequest, at: Option<<B as BlockT>::Hash>) -> Result<Bytes> {
@@ -424,6 +452,129 @@ where
 			})
 		}
 	}
+
+	fn trace_call(
+		&self,
+		request: CallRequest,
+		options: Option<TraceOptions>,
+		at: Option<<B as BlockT>::Hash>,
+	) -> Result<TraceResponse> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let trace_config = trace_config(options)?;
+		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let CallRequest {
+			from,
+			to,
+			gas_limit,
+			storage_limit,
+			value,
+			data,
+		} = request;
+
+		let api = self.client.runtime_api();
+
+		let gas_limit = match gas_limit {
+			Some(gas_limit) => gas_limit,
+			None => api
+				.block_gas_limit(&BlockId::Hash(hash))
+				.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?,
+		};
+		let storage_limit = storage_limit.unwrap_or_else(u32::max_value); // TODO: set a limit
+		let data = data.map(|d| d.0).unwrap_or_default();
+
+		let balance_value = if let Some(value) = value {
+			to_u128(value).and_then(|v| TryInto::<Balance>::try_into(v).map_err(|_| ()))
+		} else {
+			Ok(Default::default())
+		};
+
+		let balance_value = balance_value.map_err(|_| Error {
+			code: ErrorCode::InvalidParams,
+			message: format!("Invalid parameter value: {:?}", value),
+			data: None,
+		})?;
+
+		let info = match to {
+			Some(to) => api.trace_call(
+				&BlockId::Hash(hash),
+				from.unwrap_or_default(),
+				to,
+				data,
+				balance_value,
+				gas_limit,
+				storage_limit,
+				trace_config,
+			),
+			None => api.trace_create(
+				&BlockId::Hash(hash),
+				from.unwrap_or_default(),
+				data,
+				balance_value,
+				gas_limit,
+				storage_limit,
+				trace_config,
+			),
+		}
+		.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+		.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+
+		trace_response(info)
+	}
+
+	fn trace_transaction(
+		&self,
+		extrinsic_hash: <B as BlockT>::Hash,
+		block_hash: <B as BlockT>::Hash,
+		options: Option<TraceOptions>,
+	) -> Result<TraceResponse> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let trace_config = trace_config(options)?;
+		let block_id = BlockId::Hash(block_hash);
+
+		let header = self
+			.client
+			.header(block_id)
+			.map_err(|err| internal_err(format!("blockchain error: {:?}", err)))?
+			.ok_or_else(|| internal_err("block not found"))?;
+		let extrinsics = self
+			.client
+			.block_body(&block_id)
+			.map_err(|err| internal_err(format!("blockchain error: {:?}", err)))?
+			.ok_or_else(|| internal_err("block body not found"))?;
+		let index = extrinsics
+			.iter()
+			.position(|xt| <<B as BlockT>::Header as HeaderT>::Hashing::hash_of(xt) == extrinsic_hash)
+			.ok_or_else(|| Error {
+				code: ErrorCode::InvalidParams,
+				message: format!(
+					"Invalid parameter extrinsic_hash: {:?} is not in the block",
+					extrinsic_hash
+				),
+				data: None,
+			})?;
+
+		// re-execute the block on the parent state until the extrinsic
+		let parent_id = BlockId::Hash(*header.parent_hash());
+		let api = self.client.runtime_api();
+		api.initialize_block(&parent_id, &header)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?;
+		for xt in extrinsics[..index].iter() {
+			// the failed extrinsics are included in the block too
+			let _ = api
+				.apply_extrinsic(&parent_id, xt.clone())
+				.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?;
+		}
+
+		let info = api
+			.trace_extrinsic(&parent_id, extrinsics[index].encode(), trace_config)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+
+		trace_response(info)
+	}
 }
 
 #[test]
@@ -0,0 +1,331 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Request options and responses of the tracing rpc.
+
+use ethereum_types::{H160, H256, U256};
+use module_evm::ExitReason;
+use primitives::evm::{CallTrace, CallType, StructLog, TraceConfig, TracerKind};
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+use std::collections::BTreeMap;
+
+use crate::decode_revert_message;
+
+/// Trace options
+#[derive(Debug, Default, PartialEq, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceOptions {
+	/// `callTracer` for the call frames, struct logs if not set
+	pub tracer: Option<String>,
+	/// Don't return the stack of struct logs
+	pub disable_stack: Option<bool>,
+	/// Don't return the memory of struct logs
+	pub disable_memory: Option<bool>,
+	/// Don't return the storage of struct logs
+	pub disable_storage: Option<bool>,
+	/// The maximum number of struct logs to return
+	pub limit: Option<u32>,
+}
+
+impl TraceOptions {
+	/// Convert to the trace config of runtime, `Err` with the unknown tracer.
+	pub fn into_trace_config(self) -> Result<TraceConfig, String> {
+		let tracer = match self.tracer.as_deref() {
+			None | Some("structLogger") => TracerKind::StructLogs,
+			Some("callTracer") => TracerKind::CallTracer,
+			Some(tracer) => return Err(tracer.to_string()),
+		};
+
+		Ok(TraceConfig {
+			tracer,
+			disable_stack: self.disable_stack.unwrap_or_default(),
+			disable_memory: self.disable_memory.unwrap_or_default(),
+			disable_storage: self.disable_storage.unwrap_or_default(),
+			limit: self.limit.unwrap_or_default(),
+		})
+	}
+}
+
+/// Trace response
+#[derive(Debug, Eq, PartialEq, Serialize, Clone)]
+#[serde(untagged)]
+pub enum TraceResponse {
+	/// Struct logs
+	StructLogs(StructLogsResponse),
+	/// Call frames
+	CallTracer(CallTraceResponse),
+}
+
+/// Struct logs response
+#[derive(Debug, Eq, PartialEq, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StructLogsResponse {
+	/// Used gas
+	pub gas: U256,
+	/// Used storage
+	pub storage: i32,
+	/// The execution is not succeed
+	pub failed: bool,
+	/// Output
+	pub return_value: Bytes,
+	/// Struct logs
+	pub struct_logs: Vec<StructLogResponse>,
+}
+
+/// Struct log
+#[derive(Debug, Eq, PartialEq, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StructLogResponse {
+	/// Program counter
+	pub pc: u64,
+	/// Opcode name
+	pub op: String,
+	/// Gas left
+	pub gas: u64,
+	/// Gas cost of the opcode
+	pub gas_cost: u64,
+	/// Call depth
+	pub depth: u32,
+	/// Stack
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub stack: Option<Vec<H256>>,
+	/// Memory in words of 32 bytes
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub memory: Option<Vec<H256>>,
+	/// Storage accessed so far
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub storage: Option<BTreeMap<H256, H256>>,
+}
+
+impl From<StructLog> for StructLogResponse {
+	fn from(log: StructLog) -> Self {
+		Self {
+			pc: log.pc,
+			op: opcode_name(log.op),
+			gas: log.gas,
+			gas_cost: log.gas_cost,
+			depth: log.depth,
+			stack: log.stack,
+			memory: log.memory.map(|memory| {
+				memory
+					.chunks(32)
+					.map(|chunk| {
+						let mut word = [0u8; 32];
+						word[..chunk.len()].copy_from_slice(chunk);
+						H256(word)
+					})
+					.collect()
+			}),
+			storage: log.storage.map(|storage| storage.into_iter().collect()),
+		}
+	}
+}
+
+/// Call frame
+#[derive(Debug, Eq, PartialEq, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallTraceResponse {
+	/// Call type
+	#[serde(rename = "type")]
+	pub call_type: String,
+	/// From
+	pub from: H160,
+	/// To
+	pub to: H160,
+	/// Value
+	pub value: U256,
+	/// Gas limit
+	pub gas: U256,
+	/// Used gas
+	pub gas_used: U256,
+	/// Used storage
+	pub storage: i32,
+	/// Input
+	pub input: Bytes,
+	/// Output
+	pub output: Bytes,
+	/// Error of the failed call
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+	/// The callee is a precompile
+	pub precompile: bool,
+	/// Sub calls
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub calls: Vec<CallTraceResponse>,
+}
+
+impl From<CallTrace> for CallTraceResponse {
+	fn from(call: CallTrace) -> Self {
+		let call_type = match call.call_type {
+			CallType::Call => "CALL",
+			CallType::CallCode => "CALLCODE",
+			CallType::DelegateCall => "DELEGATECALL",
+			CallType::StaticCall => "STATICCALL",
+			CallType::Create => "CREATE",
+			CallType::Create2 => "CREATE2",
+		};
+		let error = match call.exit_reason {
+			None => Some("call not finished".to_string()),
+			Some(reason) => exit_error(&reason, &call.output),
+		};
+
+		Self {
+			call_type: call_type.to_string(),
+			from: call.from,
+			to: call.to,
+			value: call.value,
+			gas: call.gas.into(),
+			gas_used: call.gas_used.into(),
+			storage: call.used_storage,
+			input: Bytes(call.input),
+			output: Bytes(call.output),
+			error,
+			precompile: call.is_precompile,
+			calls: call.calls.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// The error message of the failed execution, `None` if succeed.
+pub fn exit_error(reason: &ExitReason, output: &[u8]) -> Option<String> {
+	match reason {
+		ExitReason::Succeed(_) => None,
+		ExitReason::Error(e) => Some(format!("execution error: {:?}", e)),
+		ExitReason::Revert(_) => Some(
+			decode_revert_message(output).map_or("execution revert".into(), |msg| format!("execution revert: {}", msg)),
+		),
+		ExitReason::Fatal(e) => Some(format!("execution fatal: {:?}", e)),
+	}
+}
+
+/// The mnemonic of opcode.
+pub fn opcode_name(opcode: u8) -> String {
+	let name = match opcode {
+		0x00 => "STOP",
+		0x01 => "ADD",
+		0x02 => "MUL",
+		0x03 => "SUB",
+		0x04 => "DIV",
+		0x05 => "SDIV",
+		0x06 => "MOD",
+		0x07 => "SMOD",
+		0x08 => "ADDMOD",
+		0x09 => "MULMOD",
+		0x0a => "EXP",
+		0x0b => "SIGNEXTEND",
+		0x10 => "LT",
+		0x11 => "GT",
+		0x12 => "SLT",
+		0x13 => "SGT",
+		0x14 => "EQ",
+		0x15 => "ISZERO",
+		0x16 => "AND",
+		0x17 => "OR",
+		0x18 => "XOR",
+		0x19 => "NOT",
+		0x1a => "BYTE",
+		0x1b => "SHL",
+		0x1c => "SHR",
+		0x1d => "SAR",
+		0x20 => "SHA3",
+		0x30 => "ADDRESS",
+		0x31 => "BALANCE",
+		0x32 => "ORIGIN",
+		0x33 => "CALLER",
+		0x34 => "CALLVALUE",
+		0x35 => "CALLDATALOAD",
+		0x36 => "CALLDATASIZE",
+		0x37 => "CALLDATACOPY",
+		0x38 => "CODESIZE",
+		0x39 => "CODECOPY",
+		0x3a => "GASPRICE",
+		0x3b => "EXTCODESIZE",
+		0x3c => "EXTCODECOPY",
+		0x3d => "RETURNDATASIZE",
+		0x3e => "RETURNDATACOPY",
+		0x3f => "EXTCODEHASH",
+		0x40 => "BLOCKHASH",
+		0x41 => "COINBASE",
+		0x42 => "TIMESTAMP",
+		0x43 => "NUMBER",
+		0x44 => "DIFFICULTY",
+		0x45 => "GASLIMIT",
+		0x46 => "CHAINID",
+		0x47 => "SELFBALANCE",
+		0x50 => "POP",
+		0x51 => "MLOAD",
+		0x52 => "MSTORE",
+		0x53 => "MSTORE8",
+		0x54 => "SLOAD",
+		0x55 => "SSTORE",
+		0x56 => "JUMP",
+		0x57 => "JUMPI",
+		0x58 => "PC",
+		0x59 => "MSIZE",
+		0x5a => "GAS",
+		0x5b => "JUMPDEST",
+		0x60..=0x7f => return format!("PUSH{}", opcode - 0x5f),
+		0x80..=0x8f => return format!("DUP{}", opcode - 0x7f),
+		0x90..=0x9f => return format!("SWAP{}", opcode - 0x8f),
+		0xa0..=0xa4 => return format!("LOG{}", opcode - 0xa0),
+		0xf0 => "CREATE",
+		0xf1 => "CALL",
+		0xf2 => "CALLCODE",
+		0xf3 => "RETURN",
+		0xf4 => "DELEGATECALL",
+		0xf5 => "CREATE2",
+		0xfa => "STATICCALL",
+		0xfd => "REVERT",
+		0xfe => "INVALID",
+		0xff => "SUICIDE",
+		_ => return format!("opcode 0x{:02x} not defined", opcode),
+	};
+	name.to_string()
+}
+
+#[test]
+fn opcode_name_should_work() {
+	assert_eq!(opcode_name(0x00), "STOP");
+	assert_eq!(opcode_name(0x60), "PUSH1");
+	assert_eq!(opcode_name(0x7f), "PUSH32");
+	assert_eq!(opcode_name(0x80), "DUP1");
+	assert_eq!(opcode_name(0x9f), "SWAP16");
+	assert_eq!(opcode_name(0xa4), "LOG4");
+	assert_eq!(opcode_name(0x0c), "opcode 0x0c not defined");
+}
+
+#[test]
+fn trace_options_should_work() {
+	let options: TraceOptions =
+		serde_json::from_str(r#"{"tracer": "callTracer", "disableStack": true, "limit": 10}"#).unwrap();
+	assert_eq!(
+		options.into_trace_config(),
+		Ok(TraceConfig {
+			tracer: TracerKind::CallTracer,
+			disable_stack: true,
+			disable_memory: false,
+			disable_storage: false,
+			limit: 10,
+		})
+	);
+
+	let options: TraceOptions = serde_json::from_str(r#"{"tracer": "prestateTracer"}"#).unwrap();
+	assert_eq!(options.into_trace_config(), Err("prestateTracer".to_string()));
+}
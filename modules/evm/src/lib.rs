@@ -1177,6 +1177,11 @@ impl<T: Config> EVMTrait<T::AccountId> for Pallet<T> {
 	fn set_origin(origin: T::AccountId) {
 		ExtrinsicOrigin::<T>::set(Some(origin));
 	}
+
+	/// Emit an EVM log from outside of the EVM execution, e.g. by precompiles.
+	fn log(address: EvmAddress, topics: Vec<H256>, data: Vec<u8>) {
+		Pallet::<T>::deposit_event(Event::<T>::Log(Log { address, topics, data }));
+	}
 }
 
 impl<T: Config> EVMStateRentTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
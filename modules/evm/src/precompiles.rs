@@ -20,10 +20,16 @@
 
 use evm::{Context, ExitError, ExitSucceed};
 use impl_trait_for_tuples::impl_for_tuples;
-use primitive_types::H160;
+use num::{BigUint, Zero};
+use primitive_types::{H160, U256};
 use ripemd160::Digest;
 use sp_runtime::SaturatedConversion;
-use sp_std::{cmp::min, marker::PhantomData, vec::Vec};
+use sp_std::{
+	cmp::{max, min},
+	marker::PhantomData,
+	vec,
+	vec::Vec,
+};
 use tiny_keccak::Hasher;
 
 /// Custom precompiles to be used by EVM engine.
@@ -78,25 +84,74 @@ impl Precompiles for Tuple {
 	}
 }
 
-pub struct EvmPrecompiles<ECRecover, Sha256, Ripemd160, Identity, ECRecoverPublicKey, Sha3FIPS256, Sha3FIPS512>(
+pub struct EvmPrecompiles<
+	ECRecover,
+	Sha256,
+	Ripemd160,
+	Identity,
+	Modexp,
+	Bn128Add,
+	Bn128Mul,
+	Bn128Pairing,
+	Blake2F,
+	ECRecoverPublicKey,
+	Sha3FIPS256,
+	Sha3FIPS512,
+>(
 	PhantomData<(
 		ECRecover,
 		Sha256,
 		Ripemd160,
 		Identity,
+		Modexp,
+		Bn128Add,
+		Bn128Mul,
+		Bn128Pairing,
+		Blake2F,
 		ECRecoverPublicKey,
 		Sha3FIPS256,
 		Sha3FIPS512,
 	)>,
 );
 
-impl<ECRecover, Sha256, Ripemd160, Identity, ECRecoverPublicKey, Sha3FIPS256, Sha3FIPS512> Precompiles
-	for EvmPrecompiles<ECRecover, Sha256, Ripemd160, Identity, ECRecoverPublicKey, Sha3FIPS256, Sha3FIPS512>
+impl<
+		ECRecover,
+		Sha256,
+		Ripemd160,
+		Identity,
+		Modexp,
+		Bn128Add,
+		Bn128Mul,
+		Bn128Pairing,
+		Blake2F,
+		ECRecoverPublicKey,
+		Sha3FIPS256,
+		Sha3FIPS512,
+	> Precompiles
+	for EvmPrecompiles<
+		ECRecover,
+		Sha256,
+		Ripemd160,
+		Identity,
+		Modexp,
+		Bn128Add,
+		Bn128Mul,
+		Bn128Pairing,
+		Blake2F,
+		ECRecoverPublicKey,
+		Sha3FIPS256,
+		Sha3FIPS512,
+	>
 where
 	ECRecover: Precompile,
 	Sha256: Precompile,
 	Ripemd160: Precompile,
 	Identity: Precompile,
+	Modexp: Precompile,
+	Bn128Add: Precompile,
+	Bn128Mul: Precompile,
+	Bn128Pairing: Precompile,
+	Blake2F: Precompile,
 	ECRecoverPublicKey: Precompile,
 	Sha3FIPS256: Precompile,
 	Sha3FIPS512: Precompile,
@@ -117,6 +172,16 @@ where
 			Some(Ripemd160::execute(input, target_gas, context))
 		} else if address == H160::from_low_u64_be(4) {
 			Some(Identity::execute(input, target_gas, context))
+		} else if address == H160::from_low_u64_be(5) {
+			Some(Modexp::execute(input, target_gas, context))
+		} else if address == H160::from_low_u64_be(6) {
+			Some(Bn128Add::execute(input, target_gas, context))
+		} else if address == H160::from_low_u64_be(7) {
+			Some(Bn128Mul::execute(input, target_gas, context))
+		} else if address == H160::from_low_u64_be(8) {
+			Some(Bn128Pairing::execute(input, target_gas, context))
+		} else if address == H160::from_low_u64_be(9) {
+			Some(Blake2F::execute(input, target_gas, context))
 		}
 		// Non-standard precompile starts with 128
 		else if address == H160::from_low_u64_be(128) {
@@ -229,6 +294,351 @@ impl Precompile for Sha256 {
 	}
 }
 
+/// Copy `input[offset..]` into `target`, leaving the missing bytes as zero.
+fn read_input(input: &[u8], target: &mut [u8], offset: usize) {
+	if offset >= input.len() {
+		return;
+	}
+	let len = min(target.len(), input.len() - offset);
+	target[..len].copy_from_slice(&input[offset..offset + len]);
+}
+
+/// Fixed gas cost
+fn ensure_cost(target_gas: Option<u64>, cost: u64) -> Result<u64, ExitError> {
+	if let Some(target_gas) = target_gas {
+		if cost > target_gas {
+			return Err(ExitError::OutOfGas);
+		}
+	}
+
+	Ok(cost)
+}
+
+/// The modexp precompile, priced as in EIP-2565.
+pub struct Modexp;
+
+impl Modexp {
+	fn calculate_gas(base_len: u64, exp_len: u64, mod_len: u64, exp_head: U256) -> u64 {
+		let words = max(base_len, mod_len).saturating_add(7) / 8;
+		let multiplication_complexity = words.saturating_mul(words);
+
+		let head_bits = (exp_head.bits() as u64).saturating_sub(1);
+		let iteration_count = if exp_len <= 32 {
+			head_bits
+		} else {
+			8u64.saturating_mul(exp_len - 32).saturating_add(head_bits)
+		};
+
+		max(
+			200,
+			multiplication_complexity.saturating_mul(max(iteration_count, 1)) / 3,
+		)
+	}
+}
+
+impl Precompile for Modexp {
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		let read_len = |offset: usize| {
+			let mut buf = [0u8; 32];
+			read_input(input, &mut buf, offset);
+			U256::from_big_endian(&buf)
+		};
+		let (base_len, exp_len, mod_len) = (read_len(0), read_len(32), read_len(64));
+
+		if base_len.is_zero() && mod_len.is_zero() {
+			let cost = ensure_cost(target_gas, 200)?;
+			return Ok((ExitSucceed::Returned, Vec::new(), cost));
+		}
+
+		// lengths that do not fit in u64 can never be paid for
+		let max_len = U256::from(u64::MAX);
+		if base_len > max_len || exp_len > max_len || mod_len > max_len {
+			return Err(ExitError::OutOfGas);
+		}
+		let (base_len, exp_len, mod_len) = (base_len.low_u64(), exp_len.low_u64(), mod_len.low_u64());
+
+		let exp_offset = 96usize.saturating_add(base_len.saturated_into());
+		let mut exp_head = [0u8; 32];
+		let exp_head_len = min(exp_len, 32) as usize;
+		read_input(input, &mut exp_head[..exp_head_len], exp_offset);
+		let exp_head = U256::from_big_endian(&exp_head[..exp_head_len]);
+
+		let cost = ensure_cost(target_gas, Self::calculate_gas(base_len, exp_len, mod_len, exp_head))?;
+
+		let (base_len, exp_len, mod_len): (usize, usize, usize) = (
+			base_len.saturated_into(),
+			exp_len.saturated_into(),
+			mod_len.saturated_into(),
+		);
+		let mut base = vec![0u8; base_len];
+		read_input(input, &mut base, 96);
+		let mut exponent = vec![0u8; exp_len];
+		read_input(input, &mut exponent, exp_offset);
+		let mut modulus = vec![0u8; mod_len];
+		read_input(input, &mut modulus, exp_offset.saturating_add(exp_len));
+
+		let modulus = BigUint::from_bytes_be(&modulus);
+		let mut output = vec![0u8; mod_len];
+		if !modulus.is_zero() {
+			let result = BigUint::from_bytes_be(&base).modpow(&BigUint::from_bytes_be(&exponent), &modulus);
+			if !result.is_zero() {
+				let bytes = result.to_bytes_be();
+				output[mod_len - bytes.len()..].copy_from_slice(&bytes);
+			}
+		}
+
+		Ok((ExitSucceed::Returned, output, cost))
+	}
+}
+
+fn read_fq(input: &[u8], offset: usize) -> Result<bn::Fq, ExitError> {
+	let mut buf = [0u8; 32];
+	read_input(input, &mut buf, offset);
+	bn::Fq::from_slice(&buf).map_err(|_| ExitError::Other("Invalid field element".into()))
+}
+
+fn read_g1(input: &[u8], offset: usize) -> Result<bn::G1, ExitError> {
+	use bn::{AffineG1, Fq, Group, G1};
+
+	let x = read_fq(input, offset)?;
+	let y = read_fq(input, offset + 32)?;
+
+	if x == Fq::zero() && y == Fq::zero() {
+		Ok(G1::zero())
+	} else {
+		AffineG1::new(x, y)
+			.map(Into::into)
+			.map_err(|_| ExitError::Other("Invalid curve point".into()))
+	}
+}
+
+fn encode_g1(point: bn::G1) -> Result<Vec<u8>, ExitError> {
+	let mut output = [0u8; 64];
+	if let Some(point) = bn::AffineG1::from_jacobian(point) {
+		point
+			.x()
+			.to_big_endian(&mut output[0..32])
+			.map_err(|_| ExitError::Other("Cannot encode curve point".into()))?;
+		point
+			.y()
+			.to_big_endian(&mut output[32..64])
+			.map_err(|_| ExitError::Other("Cannot encode curve point".into()))?;
+	}
+
+	Ok(output.to_vec())
+}
+
+/// The bn128 (alt_bn128) point addition precompile, priced as in EIP-1108.
+pub struct Bn128Add;
+
+impl Precompile for Bn128Add {
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		let cost = ensure_cost(target_gas, 150)?;
+
+		let p1 = read_g1(input, 0)?;
+		let p2 = read_g1(input, 64)?;
+
+		Ok((ExitSucceed::Returned, encode_g1(p1 + p2)?, cost))
+	}
+}
+
+/// The bn128 (alt_bn128) scalar multiplication precompile, priced as in
+/// EIP-1108.
+pub struct Bn128Mul;
+
+impl Precompile for Bn128Mul {
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		let cost = ensure_cost(target_gas, 6_000)?;
+
+		let p = read_g1(input, 0)?;
+		// the scalar is not required to be reduced, `interpret` takes it modulo the
+		// group order
+		let mut scalar = [0u8; 64];
+		read_input(input, &mut scalar[32..], 64);
+		let fr = bn::Fr::interpret(&scalar);
+
+		Ok((ExitSucceed::Returned, encode_g1(p * fr)?, cost))
+	}
+}
+
+/// The bn128 (alt_bn128) pairing check precompile, priced as in EIP-1108.
+pub struct Bn128Pairing;
+
+impl Precompile for Bn128Pairing {
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		use bn::{AffineG2, Fq2, Group, Gt, G2};
+
+		if input.len() % 192 != 0 {
+			return Err(ExitError::Other("Invalid input length".into()));
+		}
+
+		let pairs_len = input.len() / 192;
+		let cost = ensure_cost(
+			target_gas,
+			(pairs_len as u64).saturating_mul(34_000).saturating_add(45_000),
+		)?;
+
+		let mut pairs = Vec::with_capacity(pairs_len);
+		for offset in (0..input.len()).step_by(192) {
+			let a = read_g1(input, offset)?;
+
+			// G2 coordinates are encoded with the imaginary part first
+			let bx = Fq2::new(read_fq(input, offset + 96)?, read_fq(input, offset + 64)?);
+			let by = Fq2::new(read_fq(input, offset + 160)?, read_fq(input, offset + 128)?);
+			let b = if bx == Fq2::zero() && by == Fq2::zero() {
+				G2::zero()
+			} else {
+				AffineG2::new(bx, by)
+					.map_err(|_| ExitError::Other("Invalid curve point".into()))?
+					.into()
+			};
+
+			pairs.push((a, b));
+		}
+
+		let mut output = [0u8; 32];
+		if pairs.is_empty() || bn::pairing_batch(&pairs) == Gt::one() {
+			output[31] = 1;
+		}
+
+		Ok((ExitSucceed::Returned, output.to_vec(), cost))
+	}
+}
+
+const BLAKE2F_INPUT_LEN: usize = 213;
+
+const BLAKE2B_IV: [u64; 8] = [
+	0x6a09e667f3bcc908,
+	0xbb67ae8584caa73b,
+	0x3c6ef372fe94f82b,
+	0xa54ff53a5f1d36f1,
+	0x510e527fade682d1,
+	0x9b05688c2b3e6c1f,
+	0x1f83d9abfb41bd6b,
+	0x5be0cd19137e2179,
+];
+
+const BLAKE2B_SIGMA: [[usize; 16]; 10] = [
+	[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+	[14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+	[11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+	[7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+	[9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+	[2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+	[12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+	[13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+	[6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+	[10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The BLAKE2b mixing function.
+#[allow(clippy::many_single_char_names)]
+fn blake2b_g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+	v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+	v[d] = (v[d] ^ v[a]).rotate_right(32);
+	v[c] = v[c].wrapping_add(v[d]);
+	v[b] = (v[b] ^ v[c]).rotate_right(24);
+	v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+	v[d] = (v[d] ^ v[a]).rotate_right(16);
+	v[c] = v[c].wrapping_add(v[d]);
+	v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// The BLAKE2b compression function `F` as specified in EIP-152.
+fn blake2b_compress(h: &mut [u64; 8], m: &[u64; 16], t: [u64; 2], f: bool, rounds: u32) {
+	let mut v = [0u64; 16];
+	v[..8].copy_from_slice(h);
+	v[8..].copy_from_slice(&BLAKE2B_IV);
+
+	v[12] ^= t[0];
+	v[13] ^= t[1];
+	if f {
+		v[14] = !v[14];
+	}
+
+	for i in 0..rounds as usize {
+		let s = &BLAKE2B_SIGMA[i % 10];
+		blake2b_g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+		blake2b_g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+		blake2b_g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+		blake2b_g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+		blake2b_g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+		blake2b_g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+		blake2b_g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+		blake2b_g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+	}
+
+	for (i, word) in h.iter_mut().enumerate() {
+		*word ^= v[i] ^ v[i + 8];
+	}
+}
+
+/// The blake2f precompile, as specified in EIP-152.
+pub struct Blake2F;
+
+impl Precompile for Blake2F {
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		if input.len() != BLAKE2F_INPUT_LEN {
+			return Err(ExitError::Other("Invalid input length".into()));
+		}
+
+		let mut rounds = [0u8; 4];
+		rounds.copy_from_slice(&input[0..4]);
+		let rounds = u32::from_be_bytes(rounds);
+		let cost = ensure_cost(target_gas, rounds.into())?;
+
+		let read_u64 = |offset: usize| {
+			let mut buf = [0u8; 8];
+			buf.copy_from_slice(&input[offset..offset + 8]);
+			u64::from_le_bytes(buf)
+		};
+
+		let mut h = [0u64; 8];
+		for (i, word) in h.iter_mut().enumerate() {
+			*word = read_u64(4 + i * 8);
+		}
+		let mut m = [0u64; 16];
+		for (i, word) in m.iter_mut().enumerate() {
+			*word = read_u64(68 + i * 8);
+		}
+		let t = [read_u64(196), read_u64(204)];
+		let f = match input[212] {
+			0 => false,
+			1 => true,
+			_ => return Err(ExitError::Other("Invalid final block indicator".into())),
+		};
+
+		blake2b_compress(&mut h, &m, t, f, rounds);
+
+		let mut output = Vec::with_capacity(64);
+		for word in h.iter() {
+			output.extend_from_slice(&word.to_le_bytes());
+		}
+
+		Ok((ExitSucceed::Returned, output, cost))
+	}
+}
+
 /// The ecrecover precompile.
 pub struct ECRecoverPublicKey;
 
@@ -299,6 +709,15 @@ impl Precompile for Sha3FIPS512 {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use hex_literal::hex;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: Default::default(),
+			apparent_value: Default::default(),
+		}
+	}
 
 	#[test]
 	fn sha3_ipfs_256_should_works() -> std::result::Result<(), ExitError> {
@@ -355,4 +774,442 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn modexp_works() {
+		// EIP-198 example: 3 ** (p - 1) % p, p = secp256k1 field modulus
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000020
+			03
+			fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e
+			fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+			"
+		);
+		let (_, out, cost) = Modexp::execute(&input, None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!("0000000000000000000000000000000000000000000000000000000000000001")
+		);
+		// ceil(32 / 8) ** 2 * 255 / 3
+		assert_eq!(cost, 1360);
+
+		assert_eq!(
+			Modexp::execute(&input, Some(1359), &context()),
+			Err(ExitError::OutOfGas)
+		);
+
+		// zero modulus
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+			03
+			05
+			0000
+			"
+		);
+		let (_, out, cost) = Modexp::execute(&input, None, &context()).unwrap();
+		assert_eq!(out, vec![0u8; 2]);
+		assert_eq!(cost, 200);
+
+		// empty base and modulus
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000000
+			ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+			0000000000000000000000000000000000000000000000000000000000000000
+			"
+		);
+		assert_eq!(
+			Modexp::execute(&input, None, &context()),
+			Ok((ExitSucceed::Returned, vec![], 200))
+		);
+	}
+
+	#[test]
+	fn modexp_gas_works() {
+		// minimum gas: 2 ** 3 % 5
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000001
+			02
+			03
+			05
+			"
+		);
+		assert_eq!(
+			Modexp::execute(&input, None, &context()),
+			Ok((ExitSucceed::Returned, vec![3], 200))
+		);
+		assert_eq!(Modexp::execute(&input, Some(199), &context()), Err(ExitError::OutOfGas));
+
+		// zero-length exponent: 2 ** 0 % (2 ** 1600 - 1), the iteration count is
+		// at least 1
+		let mut input = vec![0u8; 96];
+		input[31] = 1;
+		input[95] = 200;
+		input.push(2);
+		input.extend_from_slice(&[0xff; 200]);
+		let mut expected = vec![0u8; 200];
+		expected[199] = 1;
+		// ceil(200 / 8) ** 2 / 3
+		assert_eq!(
+			Modexp::execute(&input, None, &context()),
+			Ok((ExitSucceed::Returned, expected, 208))
+		);
+
+		// exponent longer than 32 bytes: 3 ** (2 ** 256) % p, p = secp256k1 field
+		// modulus
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000021
+			0000000000000000000000000000000000000000000000000000000000000020
+			03
+			010000000000000000000000000000000000000000000000000000000000000000
+			fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+			"
+		);
+		// ceil(32 / 8) ** 2 * (8 * (33 - 32) + 248) / 3
+		assert_eq!(
+			Modexp::execute(&input, None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!("916862ff316bf1b6c28bdfac0efa0ec51c65b605ef223ca1396ae5af135dee8d").to_vec(),
+				1365
+			))
+		);
+
+		// exponent longer than 32 bytes with zero head: 3 ** 2 % p
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000021
+			0000000000000000000000000000000000000000000000000000000000000020
+			03
+			000000000000000000000000000000000000000000000000000000000000000002
+			fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+			"
+		);
+		// max(200, ceil(32 / 8) ** 2 * 8 / 3)
+		assert_eq!(
+			Modexp::execute(&input, None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!("0000000000000000000000000000000000000000000000000000000000000009").to_vec(),
+				200
+			))
+		);
+	}
+
+	#[test]
+	fn bn128_add_works() {
+		// G1 + 2 * G1
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+			030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3
+			15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4
+			"
+		);
+		let (_, out, cost) = Bn128Add::execute(&input, None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!(
+				"
+				0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0
+				2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261
+				"
+			)
+		);
+		assert_eq!(cost, 150);
+
+		// the point at infinity is the identity
+		let (_, out, _) = Bn128Add::execute(&[], None, &context()).unwrap();
+		assert_eq!(out, vec![0u8; 64]);
+
+		// not on curve
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000003
+			"
+		);
+		assert_eq!(
+			Bn128Add::execute(&input, None, &context()),
+			Err(ExitError::Other("Invalid curve point".into()))
+		);
+		assert_eq!(Bn128Add::execute(&[], Some(149), &context()), Err(ExitError::OutOfGas));
+	}
+
+	#[test]
+	fn bn128_mul_works() {
+		// 3 * G1 * 12345678901234567890
+		let input = hex!(
+			"
+			0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0
+			2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261
+			000000000000000000000000000000000000000000000000ab54a98ceb1f0ad2
+			"
+		);
+		let (_, out, cost) = Bn128Mul::execute(&input, None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!(
+				"
+				26f9b64540e3f9eba9f502198142763e3bbe48febc08b15495e512d969c9e747
+				2a524210142bccf9e184a8f9ebb2806226aedfc7cdb82b3698f86de40b29ff75
+				"
+			)
+		);
+		assert_eq!(cost, 6_000);
+		assert_eq!(
+			Bn128Mul::execute(&input, Some(5_999), &context()),
+			Err(ExitError::OutOfGas)
+		);
+
+		// not on curve
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000003
+			0000000000000000000000000000000000000000000000000000000000000002
+			"
+		);
+		assert_eq!(
+			Bn128Mul::execute(&input, None, &context()),
+			Err(ExitError::Other("Invalid curve point".into()))
+		);
+
+		// coordinate not less than the field modulus
+		let input = hex!(
+			"
+			30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+			0000000000000000000000000000000000000000000000000000000000000002
+			0000000000000000000000000000000000000000000000000000000000000002
+			"
+		);
+		assert_eq!(
+			Bn128Mul::execute(&input, None, &context()),
+			Err(ExitError::Other("Invalid field element".into()))
+		);
+	}
+
+	#[test]
+	fn bn128_pairing_works() {
+		// e(G1, G2) * e(-G1, G2) == 1
+		let input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+			198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2
+			1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed
+			090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b
+			12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa
+			0000000000000000000000000000000000000000000000000000000000000001
+			30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45
+			198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2
+			1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed
+			090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b
+			12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa
+			"
+		);
+		let (_, out, cost) = Bn128Pairing::execute(&input, None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!("0000000000000000000000000000000000000000000000000000000000000001")
+		);
+		assert_eq!(cost, 45_000 + 2 * 34_000);
+
+		// e(G1, G2) != 1
+		let (_, out, cost) = Bn128Pairing::execute(&input[..192], None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!("0000000000000000000000000000000000000000000000000000000000000000")
+		);
+		assert_eq!(cost, 45_000 + 34_000);
+
+		// empty input
+		let (_, out, cost) = Bn128Pairing::execute(&[], None, &context()).unwrap();
+		assert_eq!(
+			out,
+			hex!("0000000000000000000000000000000000000000000000000000000000000001")
+		);
+		assert_eq!(cost, 45_000);
+
+		assert_eq!(
+			Bn128Pairing::execute(&input, Some(45_000 + 2 * 34_000 - 1), &context()),
+			Err(ExitError::OutOfGas)
+		);
+
+		// the input length must be a multiple of 192
+		for len in &[64, 191, 193, 383] {
+			assert_eq!(
+				Bn128Pairing::execute(&input[..*len], None, &context()),
+				Err(ExitError::Other("Invalid input length".into()))
+			);
+		}
+
+		// G1 point not on curve
+		let mut invalid_input = input;
+		invalid_input[63] = 3;
+		assert_eq!(
+			Bn128Pairing::execute(&invalid_input, None, &context()),
+			Err(ExitError::Other("Invalid curve point".into()))
+		);
+
+		// G2 point not on curve
+		let invalid_input = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000002
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000002
+			"
+		);
+		assert_eq!(
+			Bn128Pairing::execute(&invalid_input, None, &context()),
+			Err(ExitError::Other("Invalid curve point".into()))
+		);
+	}
+
+	fn blake2f_input(rounds: u32, f: &[u8]) -> Vec<u8> {
+		let mut input = rounds.to_be_bytes().to_vec();
+		input.extend_from_slice(&hex!(
+			"
+			48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5
+			d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b
+			6162630000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000000
+			03000000000000000000000000000000
+			"
+		));
+		input.extend_from_slice(f);
+		input
+	}
+
+	#[test]
+	fn blake2f_works() {
+		// EIP-152 test vectors 0 - 2: invalid input length
+		let input = blake2f_input(12, &[1]);
+		for len in &[0, 212] {
+			assert_eq!(
+				Blake2F::execute(&input[..*len], None, &context()),
+				Err(ExitError::Other("Invalid input length".into()))
+			);
+		}
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(12, &[1, 0]), None, &context()),
+			Err(ExitError::Other("Invalid input length".into()))
+		);
+
+		// EIP-152 test vector 3: invalid final block indicator
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(12, &[2]), None, &context()),
+			Err(ExitError::Other("Invalid final block indicator".into()))
+		);
+
+		// EIP-152 test vector 4
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(0, &[1]), None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!(
+					"
+					08c9bcf367e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5
+					d282e6ad7f520e511f6c3e2b8c68059b9442be0454267ce079217e1319cde05b
+					"
+				)
+				.to_vec(),
+				0
+			))
+		);
+
+		// EIP-152 test vector 5
+		let input = blake2f_input(12, &[1]);
+		assert_eq!(
+			Blake2F::execute(&input, None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!(
+					"
+					ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1
+					7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923
+					"
+				)
+				.to_vec(),
+				12
+			))
+		);
+		assert_eq!(Blake2F::execute(&input, Some(11), &context()), Err(ExitError::OutOfGas));
+
+		// EIP-152 test vector 6
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(12, &[0]), None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!(
+					"
+					75ab69d3190a562c51aef8d88f1c2775876944407270c42c9844252c26d28752
+					98743e7f6d5ea2f2d3e8d226039cd31b4e426ac4f2d3d666a610c2116fde4735
+					"
+				)
+				.to_vec(),
+				12
+			))
+		);
+
+		// EIP-152 test vector 7
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(1, &[1]), None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!(
+					"
+					b63a380cb2897d521994a85234ee2c181b5f844d2c624c002677e9703449d2fb
+					a551b3a8333bcdf5f2f7e08993d53923de3d64fcc68c034e717b9293fed7a421
+					"
+				)
+				.to_vec(),
+				1
+			))
+		);
+
+		// EIP-152 test vector 8 is charged before the rounds are run
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(u32::MAX, &[1]), Some(u32::MAX as u64 - 1), &context()),
+			Err(ExitError::OutOfGas)
+		);
+	}
+
+	// EIP-152 test vector 8 runs 2 ** 32 - 1 rounds, run it with `--ignored`.
+	#[test]
+	#[ignore]
+	fn blake2f_max_rounds_works() {
+		assert_eq!(
+			Blake2F::execute(&blake2f_input(u32::MAX, &[1]), None, &context()),
+			Ok((
+				ExitSucceed::Returned,
+				hex!(
+					"
+					fc59093aafa9ab43daae0e914c57635c5402d8e3d2130eb9b3cc181de7f0ecf9
+					b22bf99a7815ce16419e200e01846e6b5df8cc7703041bbceb571de6631d2615
+					"
+				)
+				.to_vec(),
+				u32::MAX as u64
+			))
+		);
+	}
 }
@@ -20,7 +20,10 @@
 
 use crate::{
 	precompiles::Precompiles,
-	runner::storage_meter::{StorageMeter, StorageMeterHandler},
+	runner::{
+		storage_meter::{StorageMeter, StorageMeterHandler},
+		tracing::Tracer,
+	},
 	AccountInfo, AccountStorages, Accounts, AddressMapping, Codes, Config, ContractInfo, Error, Event, Log, Pallet,
 	Vicinity, RESERVE_ID_DEVELOPER_DEPOSIT, RESERVE_ID_STORAGE_DEPOSIT,
 };
@@ -32,13 +35,15 @@ use frame_support::{
 	traits::{BalanceStatus, Currency, ExistenceRequirement, Get, NamedReservableCurrency},
 };
 use primitive_types::{H160, H256, U256};
-use primitives::{H160_PREFIX_DEXSHARE, H160_PREFIX_TOKEN, PREDEPLOY_ADDRESS_START, SYSTEM_CONTRACT_ADDRESS_PREFIX};
+use primitives::{
+	evm::CallType, H160_PREFIX_DEXSHARE, H160_PREFIX_TOKEN, PREDEPLOY_ADDRESS_START, SYSTEM_CONTRACT_ADDRESS_PREFIX,
+};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
 	traits::{One, Saturating, UniqueSaturatedInto, Zero},
 	DispatchError, DispatchResult, SaturatedConversion, TransactionOutcome,
 };
-use sp_std::{cmp::min, convert::Infallible, marker::PhantomData, prelude::*, rc::Rc};
+use sp_std::{cell::RefCell, cmp::min, convert::Infallible, marker::PhantomData, prelude::*, rc::Rc};
 
 /// Storage key size and storage value size.
 pub const STORAGE_SIZE: u32 = 64;
@@ -49,6 +54,9 @@ pub struct Handler<'vicinity, 'config, 'meter, T: Config> {
 	pub gasometer: Gasometer<'config>,
 	pub storage_meter: StorageMeter<'meter>,
 	pub is_static: bool,
+	/// Records the execution if tracing is enabled, shared by all the sub
+	/// calls.
+	pub tracer: Option<Rc<RefCell<Tracer>>>,
 	_marker: PhantomData<T>,
 }
 
@@ -70,6 +78,7 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 			is_static,
 			gasometer: Gasometer::new(gas_limit, config),
 			storage_meter,
+			tracer: None,
 			_marker: PhantomData,
 		}
 	}
@@ -81,6 +90,7 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 		contract: H160,
 		is_static: bool,
 		config: &'config EvmRuntimeConfig,
+		tracer: Option<Rc<RefCell<Tracer>>>,
 		f: F,
 	) -> Result<R, DispatchError> {
 		frame_support::storage::with_transaction(|| {
@@ -91,6 +101,7 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 			};
 
 			let mut substate = Handler::new(vicinity, gas_limit, storage_meter, is_static, config);
+			substate.tracer = tracer;
 
 			match f(&mut substate) {
 				TransactionOutcome::Commit(r) => match substate.storage_meter.finish() {
@@ -122,8 +133,16 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 			};
 
 			let mut substate = Handler::new(vicinity, gas_limit, storage_meter, is_static, config);
+			substate.tracer = self.tracer.clone();
+
+			let outcome = f(&mut substate, &mut self.gasometer);
+			if let Some(tracer) = &substate.tracer {
+				tracer
+					.borrow_mut()
+					.record_used_resources(substate.gasometer.total_used_gas(), substate.used_storage());
+			}
 
-			match f(&mut substate, &mut self.gasometer) {
+			match outcome {
 				TransactionOutcome::Commit(r) => match substate.storage_meter.finish() {
 					Ok(_) => TransactionOutcome::Commit(Ok(r)),
 					Err(e) => TransactionOutcome::Rollback(Err(e)),
@@ -160,11 +179,15 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 			apparent_value: value,
 		};
 
-		let mut runtime = Runtime::new(Rc::new(code), Rc::new(input), context, self.config);
+		let code = Rc::new(code);
+		let mut runtime = Runtime::new(code.clone(), Rc::new(input), context, self.config);
 
-		let reason = match runtime.run(self) {
-			Capture::Exit(s) => s,
-			Capture::Trap(_) => unreachable!("Trap is Infallible"),
+		let reason = match self.tracer.clone() {
+			Some(tracer) => self.run_with_tracer(&mut runtime, &code, address, &tracer),
+			None => match runtime.run(self) {
+				Capture::Exit(s) => s,
+				Capture::Trap(_) => unreachable!("Trap is Infallible"),
+			},
 		};
 
 		match reason {
@@ -178,6 +201,70 @@ impl<'vicinity, 'config, 'meter, T: Config> Handler<'vicinity, 'config, 'meter,
 		}
 	}
 
+	/// Execute the runtime step by step, and record each step to the tracer.
+	fn run_with_tracer(
+		&mut self,
+		runtime: &mut Runtime,
+		code: &[u8],
+		address: H160,
+		tracer: &RefCell<Tracer>,
+	) -> ExitReason {
+		loop {
+			let gas_before = self.gasometer.gas();
+			let step = match runtime.machine().position() {
+				Ok(pc) => code.get(*pc).map(|opcode| (*pc, Opcode(*opcode))),
+				Err(_) => None,
+			};
+
+			let mut log_index = None;
+			let mut storage_key = None;
+			let mut stored_value = None;
+			if let Some((pc, opcode)) = step {
+				let machine = runtime.machine();
+				log_index = tracer.borrow_mut().start_step(
+					pc,
+					opcode,
+					gas_before,
+					machine.stack().data(),
+					machine.memory().data(),
+				);
+				if opcode == Opcode::SLOAD || opcode == Opcode::SSTORE {
+					storage_key = machine.stack().peek(0).ok();
+				}
+				if opcode == Opcode::SSTORE {
+					stored_value = machine.stack().peek(1).ok();
+				}
+			}
+
+			let exit_reason = match runtime.step(self) {
+				Ok(()) => None,
+				Err(Capture::Exit(s)) => Some(s),
+				Err(Capture::Trap(_)) => unreachable!("Trap is Infallible"),
+			};
+
+			if let Some(index) = log_index {
+				let storage_access = match (storage_key, stored_value) {
+					(Some(key), Some(value)) => Some((key, value)),
+					// the loaded value of SLOAD is on the top of stack
+					(Some(key), None) if exit_reason.is_none() => {
+						runtime.machine().stack().peek(0).ok().map(|value| (key, value))
+					}
+					_ => None,
+				};
+				tracer.borrow_mut().finish_step(
+					index,
+					address,
+					gas_before.saturating_sub(self.gasometer.gas()),
+					storage_access,
+				);
+			}
+
+			if let Some(s) = exit_reason {
+				return s;
+			}
+		}
+	}
+
 	fn transfer(transfer: Transfer) -> Result<(), ExitError> {
 		let source = T::AddressMapping::get_account_id(&transfer.source);
 		let target = T::AddressMapping::get_account_id(&transfer.target);
@@ -505,6 +592,10 @@ impl<'vicinity, 'config, 'meter, T: Config> HandlerT for Handler<'vicinity, 'con
 		target_gas = min(target_gas, after_gas);
 		try_or_fail!(self.gasometer.record_cost(target_gas));
 
+		let call_type = match scheme {
+			CreateScheme::Create2 { .. } => CallType::Create2,
+			_ => CallType::Create,
+		};
 		let maybe_address = Self::create_address(scheme);
 		let address = if let Err(e) = maybe_address {
 			return Capture::Exit((ExitReason::Error(e), None, Vec::new()));
@@ -513,59 +604,74 @@ impl<'vicinity, 'config, 'meter, T: Config> HandlerT for Handler<'vicinity, 'con
 		};
 		Self::inc_nonce(caller);
 
-		let origin = &self.vicinity.origin;
+		if let Some(tracer) = &self.tracer {
+			tracer
+				.borrow_mut()
+				.enter_call(call_type, caller, address, init_code.clone(), value, target_gas);
+		}
 
-		self.run_sub_transaction(
-			self.vicinity,
-			target_gas,
-			address,
-			self.is_static,
-			self.config,
-			|substate, gasometer| {
-				try_or_rollback!(Self::transfer(Transfer {
-					source: caller,
-					target: address,
-					value,
-				}));
-
-				let (reason, out) = substate.execute(caller, address, value, init_code, Vec::new());
-
-				match reason {
-					ExitReason::Succeed(s) => match substate.gasometer.record_deposit(out.len()) {
-						Ok(()) => {
-							try_or_rollback!(gasometer.record_stipend(substate.gasometer.gas()));
-							try_or_rollback!(gasometer.record_refund(substate.gasometer.refunded_gas()));
+		let origin = &self.vicinity.origin;
 
-							Handler::<T>::inc_nonce(address);
-							try_or_rollback!(substate
-								.storage_meter
-								.charge((out.len() as u32).saturating_add(T::NewContractExtraBytes::get()))
-								.map_err(|_| ExitError::OutOfGas));
-							match <Pallet<T>>::on_contract_initialization(&address, origin, out) {
-								Ok(()) => {
-									TransactionOutcome::Commit(Capture::Exit((s.into(), Some(address), Vec::new())))
+		let result = self
+			.run_sub_transaction(
+				self.vicinity,
+				target_gas,
+				address,
+				self.is_static,
+				self.config,
+				|substate, gasometer| {
+					try_or_rollback!(Self::transfer(Transfer {
+						source: caller,
+						target: address,
+						value,
+					}));
+
+					let (reason, out) = substate.execute(caller, address, value, init_code, Vec::new());
+
+					match reason {
+						ExitReason::Succeed(s) => match substate.gasometer.record_deposit(out.len()) {
+							Ok(()) => {
+								try_or_rollback!(gasometer.record_stipend(substate.gasometer.gas()));
+								try_or_rollback!(gasometer.record_refund(substate.gasometer.refunded_gas()));
+
+								Handler::<T>::inc_nonce(address);
+								try_or_rollback!(substate
+									.storage_meter
+									.charge((out.len() as u32).saturating_add(T::NewContractExtraBytes::get()))
+									.map_err(|_| ExitError::OutOfGas));
+								match <Pallet<T>>::on_contract_initialization(&address, origin, out) {
+									Ok(()) => {
+										TransactionOutcome::Commit(Capture::Exit((s.into(), Some(address), Vec::new())))
+									}
+									Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new()))),
 								}
-								Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new()))),
 							}
+							Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new()))),
+						},
+						ExitReason::Revert(r) => TransactionOutcome::Rollback(Capture::Exit((r.into(), None, out))),
+						ExitReason::Error(e) => {
+							TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new())))
+						}
+						ExitReason::Fatal(e) => {
+							gasometer.fail();
+							TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new())))
 						}
-						Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new()))),
-					},
-					ExitReason::Revert(r) => TransactionOutcome::Rollback(Capture::Exit((r.into(), None, out))),
-					ExitReason::Error(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new()))),
-					ExitReason::Fatal(e) => {
-						gasometer.fail();
-						TransactionOutcome::Rollback(Capture::Exit((e.into(), None, Vec::new())))
 					}
-				}
-			},
-		)
-		.unwrap_or_else(|x| {
-			Capture::Exit((
-				ExitReason::Error(ExitError::Other(Into::<&'static str>::into(x).into())),
-				None,
-				Vec::new(),
-			))
-		})
+				},
+			)
+			.unwrap_or_else(|x| {
+				Capture::Exit((
+					ExitReason::Error(ExitError::Other(Into::<&'static str>::into(x).into())),
+					None,
+					Vec::new(),
+				))
+			});
+
+		if let (Some(tracer), Capture::Exit((reason, _, out))) = (&self.tracer, &result) {
+			tracer.borrow_mut().exit_call(reason.clone(), out.clone());
+		}
+
+		result
 	}
 
 	fn call(
@@ -608,75 +714,102 @@ impl<'vicinity, 'config, 'meter, T: Config> HandlerT for Handler<'vicinity, 'con
 
 		let code = self.code(code_address);
 
-		self.run_sub_transaction(
-			self.vicinity,
-			target_gas,
-			context.address,
-			self.is_static || is_static,
-			self.config,
-			|substate, gasometer| {
-				if let Some(transfer) = transfer {
-					try_or_rollback!(Self::transfer(transfer));
-				}
+		if let Some(tracer) = &self.tracer {
+			let (call_type, from) = if is_static {
+				(CallType::StaticCall, context.caller)
+			} else if context.address == code_address {
+				(CallType::Call, context.caller)
+			} else if transfer.is_some() {
+				(CallType::CallCode, context.address)
+			} else {
+				(CallType::DelegateCall, context.address)
+			};
+			let value = transfer.as_ref().map_or_else(U256::zero, |transfer| transfer.value);
+			tracer
+				.borrow_mut()
+				.enter_call(call_type, from, code_address, input.clone(), value, target_gas);
+		}
+
+		let result = self
+			.run_sub_transaction(
+				self.vicinity,
+				target_gas,
+				context.address,
+				self.is_static || is_static,
+				self.config,
+				|substate, gasometer| {
+					if let Some(transfer) = transfer {
+						try_or_rollback!(Self::transfer(transfer));
+					}
+
+					try_or_rollback!(gasometer.record_cost(target_gas));
+
+					if let Some(ret) = T::Precompiles::execute(code_address, &input, Some(target_gas), &context) {
+						if let Some(tracer) = &substate.tracer {
+							tracer.borrow_mut().mark_precompile();
+						}
+
+						log::debug!(
+							target: "evm",
+							"handler: call-result: precompile result {:?}",
+							ret
+						);
+
+						return match ret {
+							Ok((s, out, cost)) => {
+								// TODO: write some test to make sure following 3 lines is correct
+								try_or_rollback!(substate.gasometer.record_cost(cost));
+								try_or_rollback!(gasometer.record_stipend(substate.gasometer.gas()));
+								try_or_rollback!(gasometer.record_refund(substate.gasometer.refunded_gas()));
+								// precompile contract cost 0
+								// try_or_rollback!(self.storage_meter.record_cost(0));
+								TransactionOutcome::Commit(Capture::Exit((s.into(), out)))
+							}
+							Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new()))),
+						};
+					}
 
-				try_or_rollback!(gasometer.record_cost(target_gas));
+					let (reason, out) = substate.execute(
+						context.caller,
+						context.address,
+						context.apparent_value,
+						code.clone(),
+						input,
+					);
 
-				if let Some(ret) = T::Precompiles::execute(code_address, &input, Some(target_gas), &context) {
 					log::debug!(
 						target: "evm",
-						"handler: call-result: precompile result {:?}",
-						ret
+						"handler: call-result: reason {:?} out {:?} gas_left {:?}",
+						reason, out, substate.gas_left()
 					);
 
-					return match ret {
-						Ok((s, out, cost)) => {
-							// TODO: write some test to make sure following 3 lines is correct
-							try_or_rollback!(substate.gasometer.record_cost(cost));
+					match reason {
+						ExitReason::Succeed(s) => {
 							try_or_rollback!(gasometer.record_stipend(substate.gasometer.gas()));
 							try_or_rollback!(gasometer.record_refund(substate.gasometer.refunded_gas()));
-							// precompile contract cost 0
-							// try_or_rollback!(self.storage_meter.record_cost(0));
 							TransactionOutcome::Commit(Capture::Exit((s.into(), out)))
 						}
-						Err(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new()))),
-					};
-				}
-
-				let (reason, out) = substate.execute(
-					context.caller,
-					context.address,
-					context.apparent_value,
-					code.clone(),
-					input,
-				);
-
-				log::debug!(
-					target: "evm",
-					"handler: call-result: reason {:?} out {:?} gas_left {:?}",
-					reason, out, substate.gas_left()
-				);
-
-				match reason {
-					ExitReason::Succeed(s) => {
-						try_or_rollback!(gasometer.record_stipend(substate.gasometer.gas()));
-						try_or_rollback!(gasometer.record_refund(substate.gasometer.refunded_gas()));
-						TransactionOutcome::Commit(Capture::Exit((s.into(), out)))
-					}
-					ExitReason::Revert(r) => TransactionOutcome::Rollback(Capture::Exit((r.into(), out))),
-					ExitReason::Error(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new()))),
-					ExitReason::Fatal(e) => {
-						gasometer.fail();
-						TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new())))
+						ExitReason::Revert(r) => TransactionOutcome::Rollback(Capture::Exit((r.into(), out))),
+						ExitReason::Error(e) => TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new()))),
+						ExitReason::Fatal(e) => {
+							gasometer.fail();
+							TransactionOutcome::Rollback(Capture::Exit((e.into(), Vec::new())))
+						}
 					}
-				}
-			},
-		)
-		.unwrap_or_else(|x| {
-			Capture::Exit((
-				ExitReason::Error(ExitError::Other(Into::<&'static str>::into(x).into())),
-				Vec::new(),
-			))
-		})
+				},
+			)
+			.unwrap_or_else(|x| {
+				Capture::Exit((
+					ExitReason::Error(ExitError::Other(Into::<&'static str>::into(x).into())),
+					Vec::new(),
+				))
+			});
+
+		if let (Some(tracer), Capture::Exit((reason, out))) = (&self.tracer, &result) {
+			tracer.borrow_mut().exit_call(reason.clone(), out.clone());
+		}
+
+		result
 	}
 
 	fn pre_validate(&mut self, context: &Context, opcode: Opcode, stack: &Stack) -> Result<(), ExitError> {
@@ -18,6 +18,7 @@
 
 pub mod handler;
 pub mod storage_meter;
+pub mod tracing;
 
 use crate::{AddressMapping, BalanceOf, CallInfo, Config, CreateInfo, Error, Pallet, Vicinity};
 use evm::{CreateScheme, ExitError, ExitReason};
@@ -29,9 +30,11 @@ use frame_support::{
 };
 use handler::Handler;
 use primitive_types::{H160, H256, U256};
+use primitives::evm::{CallType, TraceConfig, TraceInfo};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{traits::Zero, DispatchError, DispatchResult, SaturatedConversion, TransactionOutcome};
-use sp_std::{marker::PhantomData, vec::Vec};
+use sp_std::{cell::RefCell, marker::PhantomData, rc::Rc, vec::Vec};
+use tracing::Tracer;
 
 #[derive(Default)]
 pub struct Runner<T: Config> {
@@ -82,13 +85,31 @@ impl<T: Config> Runner<T> {
 
 		Handler::<T>::inc_nonce(source);
 
-		Handler::<T>::run_transaction(
+		let tracer = tracing::current();
+		if let Some(tracer) = &tracer {
+			let call_type = if salt.is_some() {
+				CallType::Create2
+			} else {
+				CallType::Create
+			};
+			tracer.borrow_mut().enter_call(
+				call_type,
+				source,
+				address,
+				init.clone(),
+				U256::from(value.saturated_into::<u128>()),
+				gas_limit,
+			);
+		}
+
+		let create_info = Handler::<T>::run_transaction(
 			&vicinity,
 			gas_limit,
 			storage_limit,
 			address,
 			false,
 			config,
+			tracer.clone(),
 			|substate| {
 				if let Err(e) = Self::transfer(source, address, value) {
 					return TransactionOutcome::Rollback(Err(e));
@@ -154,7 +175,36 @@ impl<T: Config> Runner<T> {
 
 				TransactionOutcome::Commit(Ok(create_info))
 			},
-		)?
+		)
+		.and_then(|create_info| create_info);
+
+		if let Some(tracer) = &tracer {
+			match &create_info {
+				Ok(create_info) => Self::trace_exit(
+					tracer,
+					&create_info.exit_reason,
+					&create_info.output,
+					create_info.used_gas,
+					create_info.used_storage,
+				),
+				Err(_) => tracer.borrow_mut().discard_call(),
+			}
+		}
+
+		create_info
+	}
+
+	/// Finish the root call of tracer.
+	fn trace_exit(
+		tracer: &RefCell<Tracer>,
+		exit_reason: &ExitReason,
+		output: &[u8],
+		used_gas: U256,
+		used_storage: i32,
+	) {
+		let mut tracer = tracer.borrow_mut();
+		tracer.record_used_resources(used_gas.low_u64(), used_storage);
+		tracer.exit_call(exit_reason.clone(), output.to_vec());
 	}
 
 	fn transfer(source: H160, target: H160, value: BalanceOf<T>) -> DispatchResult {
@@ -203,41 +253,80 @@ impl<T: Config> Runner<T> {
 
 		Handler::<T>::inc_nonce(sender);
 
-		Handler::<T>::run_transaction(&vicinity, gas_limit, storage_limit, target, false, config, |substate| {
-			if let Err(e) = Self::transfer(sender, target, value) {
-				return TransactionOutcome::Rollback(Err(e));
-			}
+		let tracer = tracing::current();
+		if let Some(tracer) = &tracer {
+			tracer.borrow_mut().enter_call(
+				CallType::Call,
+				sender,
+				target,
+				input.clone(),
+				U256::from(value.saturated_into::<u128>()),
+				gas_limit,
+			);
+		}
 
-			let code = substate.code(target);
-			let transaction_cost = gasometer::call_transaction_cost(&code);
-			if substate.gasometer.record_transaction(transaction_cost).is_err() {
-				return TransactionOutcome::Rollback(Err(DispatchError::Other("OutOfGas")));
-			}
+		let call_info = Handler::<T>::run_transaction(
+			&vicinity,
+			gas_limit,
+			storage_limit,
+			target,
+			false,
+			config,
+			tracer.clone(),
+			|substate| {
+				if let Err(e) = Self::transfer(sender, target, value) {
+					return TransactionOutcome::Rollback(Err(e));
+				}
 
-			let (reason, out) =
-				substate.execute(sender, target, U256::from(value.saturated_into::<u128>()), code, input);
+				let code = substate.code(target);
+				let transaction_cost = gasometer::call_transaction_cost(&code);
+				if substate.gasometer.record_transaction(transaction_cost).is_err() {
+					return TransactionOutcome::Rollback(Err(DispatchError::Other("OutOfGas")));
+				}
 
-			let call_info = CallInfo {
-				exit_reason: reason.clone(),
-				output: out,
-				used_gas: U256::from(substate.used_gas()),
-				used_storage: substate.used_storage(),
-			};
+				let (reason, out) =
+					substate.execute(sender, target, U256::from(value.saturated_into::<u128>()), code, input);
 
-			log::debug!(
-				target: "evm",
-				"call-result: call_info {:?}",
-				call_info
-			);
+				let call_info = CallInfo {
+					exit_reason: reason.clone(),
+					output: out,
+					used_gas: U256::from(substate.used_gas()),
+					used_storage: substate.used_storage(),
+				};
 
-			if !reason.is_succeed() {
-				return TransactionOutcome::Rollback(Ok(call_info));
+				log::debug!(
+					target: "evm",
+					"call-result: call_info {:?}",
+					call_info
+				);
+
+				if !reason.is_succeed() {
+					return TransactionOutcome::Rollback(Ok(call_info));
+				}
+
+				TransactionOutcome::Commit(Ok(call_info))
+			},
+		)
+		.and_then(|call_info| call_info);
+
+		if let Some(tracer) = &tracer {
+			match &call_info {
+				Ok(call_info) => Self::trace_exit(
+					tracer,
+					&call_info.exit_reason,
+					&call_info.output,
+					call_info.used_gas,
+					call_info.used_storage,
+				),
+				Err(_) => tracer.borrow_mut().discard_call(),
 			}
+		}
 
-			TransactionOutcome::Commit(Ok(call_info))
-		})?
+		call_info
 	}
+}
 
+impl<T: Config> Runner<T> {
 	pub fn create(
 		source: H160,
 		init: Vec<u8>,
@@ -302,4 +391,51 @@ impl<T: Config> Runner<T> {
 			config,
 		)
 	}
+
+	/// Execute `f` with a new tracer installed, and take the trace of the EVM
+	/// executions in it. The result of the trace is of the last root call,
+	/// `None` if there is no EVM execution.
+	pub fn trace<R>(trace_config: TraceConfig, f: impl FnOnce() -> R) -> (R, Option<TraceInfo>) {
+		let tracer = Rc::new(RefCell::new(Tracer::new(trace_config)));
+		let result = tracing::using(tracer.clone(), f);
+		let trace_info = tracer.borrow_mut().take_trace_info();
+		(result, trace_info)
+	}
+
+	/// Execute the call like `call` and record the trace of the execution.
+	pub fn trace_call(
+		sender: H160,
+		origin: H160,
+		target: H160,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		config: &evm::Config,
+		trace_config: TraceConfig,
+	) -> Result<TraceInfo, DispatchError> {
+		let (call_info, trace_info) = Self::trace(trace_config, || {
+			Self::call(sender, origin, target, input, value, gas_limit, storage_limit, config)
+		});
+		call_info?;
+		trace_info.ok_or(DispatchError::Other("no EVM execution is traced"))
+	}
+
+	/// Execute the creation like `create` and record the trace of the
+	/// execution.
+	pub fn trace_create(
+		source: H160,
+		init: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		config: &evm::Config,
+		trace_config: TraceConfig,
+	) -> Result<TraceInfo, DispatchError> {
+		let (create_info, trace_info) = Self::trace(trace_config, || {
+			Self::create(source, init, value, gas_limit, storage_limit, config)
+		});
+		create_info?;
+		trace_info.ok_or(DispatchError::Other("no EVM execution is traced"))
+	}
 }
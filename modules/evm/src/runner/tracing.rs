@@ -0,0 +1,210 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Opt-in tracer of the EVM execution, used by the tracing RPCs only.
+//!
+//! The tracer is installed by `using` for the duration of a closure, so that
+//! all EVM executions in it are recorded, including those dispatched by an
+//! extrinsic.
+
+use evm::{ExitReason, Opcode};
+use primitive_types::{H160, H256, U256};
+use primitives::evm::{CallTrace, CallType, StructLog, Trace, TraceConfig, TraceInfo, TracerKind};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, mem, prelude::*, rc::Rc};
+
+/// The maximum number of struct logs recorded in a trace.
+pub const MAX_STRUCT_LOGS: u32 = 100_000;
+
+/// The maximum bytes of the stacks, memories and storages recorded in the
+/// struct logs of a trace.
+pub const MAX_STRUCT_LOGS_BYTES: usize = 16 * 1024 * 1024;
+
+environmental::environmental!(tracer: Rc<RefCell<Tracer>>);
+
+/// Execute `f` with `tracer` installed.
+pub fn using<R>(tracer: Rc<RefCell<Tracer>>, f: impl FnOnce() -> R) -> R {
+	let mut tracer = tracer;
+	tracer::using(&mut tracer, f)
+}
+
+/// The tracer installed by `using`, if any.
+pub fn current() -> Option<Rc<RefCell<Tracer>>> {
+	tracer::with(|tracer| tracer.clone())
+}
+
+pub struct Tracer {
+	config: TraceConfig,
+	struct_logs: Vec<StructLog>,
+	/// The bytes of the stacks, memories and storages in `struct_logs`.
+	struct_logs_bytes: usize,
+	/// The storage accessed by SLOAD and SSTORE of each contract.
+	storages: BTreeMap<H160, BTreeMap<H256, H256>>,
+	/// The unfinished call frames, the innermost is the last one.
+	call_stack: Vec<CallTrace>,
+	root_call: Option<CallTrace>,
+}
+
+impl Tracer {
+	pub fn new(config: TraceConfig) -> Self {
+		Self {
+			config,
+			struct_logs: Vec::new(),
+			struct_logs_bytes: 0,
+			storages: BTreeMap::new(),
+			call_stack: Vec::new(),
+			root_call: None,
+		}
+	}
+
+	/// Whether to record the struct log of the next step. The recording stops
+	/// once the number of struct logs reaches the limit of config, or the
+	/// recorded bytes exceed `MAX_STRUCT_LOGS_BYTES`.
+	fn records_struct_logs(&self) -> bool {
+		let limit = match self.config.limit {
+			0 => MAX_STRUCT_LOGS,
+			limit => limit.min(MAX_STRUCT_LOGS),
+		};
+		self.config.tracer == TracerKind::StructLogs
+			&& self.struct_logs.len() < limit as usize
+			&& self.struct_logs_bytes <= MAX_STRUCT_LOGS_BYTES
+	}
+
+	/// The depth of the current call, starts from 1.
+	pub fn depth(&self) -> u32 {
+		self.call_stack.len() as u32
+	}
+
+	pub fn enter_call(&mut self, call_type: CallType, from: H160, to: H160, input: Vec<u8>, value: U256, gas: u64) {
+		self.call_stack.push(CallTrace {
+			call_type,
+			from,
+			to,
+			input,
+			value,
+			gas,
+			gas_used: 0,
+			used_storage: 0,
+			is_precompile: false,
+			exit_reason: None,
+			output: Vec::new(),
+			calls: Vec::new(),
+		});
+	}
+
+	/// Mark the current call as a precompile call.
+	pub fn mark_precompile(&mut self) {
+		if let Some(call) = self.call_stack.last_mut() {
+			call.is_precompile = true;
+		}
+	}
+
+	/// Record the gas and storage used by the current call.
+	pub fn record_used_resources(&mut self, gas_used: u64, used_storage: i32) {
+		if let Some(call) = self.call_stack.last_mut() {
+			call.gas_used = gas_used;
+			call.used_storage = used_storage;
+		}
+	}
+
+	/// Drop the current call, which fails to execute.
+	pub fn discard_call(&mut self) {
+		self.call_stack.pop();
+	}
+
+	/// Finish the current call and attach it to its parent call.
+	pub fn exit_call(&mut self, exit_reason: ExitReason, output: Vec<u8>) {
+		if let Some(mut call) = self.call_stack.pop() {
+			call.exit_reason = Some(exit_reason);
+			call.output = output;
+			match self.call_stack.last_mut() {
+				Some(parent) => parent.calls.push(call),
+				None => self.root_call = Some(call),
+			}
+		}
+	}
+
+	/// Record the step before executing `opcode`, returns the index of the
+	/// struct log to be completed by `finish_step`.
+	pub fn start_step(&mut self, pc: usize, opcode: Opcode, gas: u64, stack: &[H256], memory: &[u8]) -> Option<usize> {
+		if !self.records_struct_logs() {
+			return None;
+		}
+
+		let stack = (!self.config.disable_stack).then(|| stack.to_vec());
+		let memory = (!self.config.disable_memory).then(|| memory.to_vec());
+		self.struct_logs_bytes = self
+			.struct_logs_bytes
+			.saturating_add(stack.as_ref().map_or(0, |stack| stack.len().saturating_mul(32)))
+			.saturating_add(memory.as_ref().map_or(0, |memory| memory.len()));
+		self.struct_logs.push(StructLog {
+			pc: pc as u64,
+			op: opcode.0,
+			gas,
+			gas_cost: 0,
+			depth: self.depth(),
+			stack,
+			memory,
+			storage: None,
+		});
+		Some(self.struct_logs.len() - 1)
+	}
+
+	/// Complete the struct log of the step with the gas cost, and the storage
+	/// accessed by SLOAD or SSTORE of `address`.
+	pub fn finish_step(&mut self, index: usize, address: H160, gas_cost: u64, storage_access: Option<(H256, H256)>) {
+		let storage = match storage_access {
+			Some((key, value)) if !self.config.disable_storage => {
+				let storage = self.storages.entry(address).or_default();
+				storage.insert(key, value);
+				self.struct_logs_bytes = self.struct_logs_bytes.saturating_add(storage.len().saturating_mul(64));
+				Some(storage.iter().map(|(key, value)| (*key, *value)).collect())
+			}
+			_ => None,
+		};
+
+		if let Some(log) = self.struct_logs.get_mut(index) {
+			log.gas_cost = gas_cost;
+			log.storage = storage;
+		}
+	}
+
+	/// Take the recorded trace out of the tracer.
+	pub fn take_trace(&mut self) -> Trace {
+		match self.config.tracer {
+			TracerKind::StructLogs => Trace::StructLogs(mem::take(&mut self.struct_logs)),
+			TracerKind::CallTracer => Trace::CallTracer(self.root_call.take()),
+		}
+	}
+
+	/// Take the recorded trace with the result of the last root call out of
+	/// the tracer, `None` if no call is finished.
+	pub fn take_trace_info(&mut self) -> Option<TraceInfo> {
+		let root_call = self.root_call.as_ref()?;
+		let exit_reason = root_call.exit_reason.clone()?;
+		let output = root_call.output.clone();
+		let used_gas = U256::from(root_call.gas_used);
+		let used_storage = root_call.used_storage;
+		Some(TraceInfo {
+			exit_reason,
+			output,
+			used_gas,
+			used_storage,
+			trace: self.take_trace(),
+		})
+	}
+}
@@ -23,6 +23,7 @@ use mock::{Event, *};
 
 use crate::runner::handler::{Handler, STORAGE_SIZE};
 use frame_support::{assert_err, assert_noop, assert_ok};
+use primitives::evm::{CallTrace, CallType, Trace, TraceConfig, TracerKind};
 use sp_core::{
 	bytes::{from_hex, to_hex},
 	H160,
@@ -146,6 +147,159 @@ fn should_create_and_call_contract() {
 	});
 }
 
+#[test]
+fn trace_call_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// multiply(2, 3)
+	let multiply = from_hex(
+		"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+	).unwrap();
+	let trace_config = |tracer, limit| TraceConfig {
+		tracer,
+		disable_stack: false,
+		disable_memory: false,
+		disable_storage: false,
+		limit,
+	};
+
+	new_test_ext().execute_with(|| {
+		let result =
+			Runner::<Test>::create(alice(), contract, 0, 1000000, 1000000, <Test as Config>::config()).unwrap();
+		let contract_address = result.address;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		let call_info = Runner::<Test>::call(
+			alice(),
+			alice(),
+			contract_address,
+			multiply.clone(),
+			0,
+			1000000,
+			1000000,
+			<Test as Config>::config(),
+		)
+		.unwrap();
+
+		let trace_info = Runner::<Test>::trace_call(
+			alice(),
+			alice(),
+			contract_address,
+			multiply.clone(),
+			0,
+			1000000,
+			1000000,
+			<Test as Config>::config(),
+			trace_config(TracerKind::StructLogs, 0),
+		)
+		.unwrap();
+		// tracing doesn't change the execution
+		assert_eq!(trace_info.exit_reason, call_info.exit_reason);
+		assert_eq!(trace_info.output, call_info.output);
+		assert_eq!(trace_info.used_gas, call_info.used_gas);
+
+		let struct_logs = match trace_info.trace {
+			Trace::StructLogs(logs) => logs,
+			_ => panic!("expect struct logs"),
+		};
+		// PUSH1 0x80, PUSH1 0x40, MSTORE
+		assert_eq!(
+			struct_logs[..3]
+				.iter()
+				.map(|log| (log.pc, log.op, log.depth))
+				.collect::<Vec<_>>(),
+			vec![(0, 0x60, 1), (2, 0x60, 1), (4, 0x52, 1)]
+		);
+		assert_eq!(struct_logs[0].gas_cost, 3);
+		assert_eq!(struct_logs[0].gas, struct_logs[1].gas + 3);
+		assert_eq!(struct_logs[0].stack, Some(vec![]));
+		assert_eq!(struct_logs[0].memory, Some(vec![]));
+		assert_eq!(
+			struct_logs[2].stack,
+			Some(vec![H256::from_low_u64_be(0x80), H256::from_low_u64_be(0x40)])
+		);
+		assert_eq!(struct_logs[2].storage, None);
+		// RETURN
+		assert_eq!(struct_logs.last().map(|log| log.op), Some(0xf3));
+
+		// the struct logs stop at the limit
+		let trace_info = Runner::<Test>::trace_call(
+			alice(),
+			alice(),
+			contract_address,
+			multiply.clone(),
+			0,
+			1000000,
+			1000000,
+			<Test as Config>::config(),
+			trace_config(TracerKind::StructLogs, 2),
+		)
+		.unwrap();
+		assert_eq!(trace_info.used_gas, call_info.used_gas);
+		assert!(matches!(trace_info.trace, Trace::StructLogs(logs) if logs.len() == 2));
+
+		// the calls executed in the closure are traced
+		let (result, trace_info) = Runner::<Test>::trace(trace_config(TracerKind::StructLogs, 0), || {
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				contract_address,
+				multiply.clone(),
+				0,
+				1000000,
+				1000000,
+				<Test as Config>::config(),
+			)
+		});
+		assert_eq!(result.map(|info| info.output), Ok(call_info.output.clone()));
+		assert_eq!(trace_info.map(|info| info.used_gas), Some(call_info.used_gas));
+		assert_eq!(
+			Runner::<Test>::trace(trace_config(TracerKind::StructLogs, 0), || ()),
+			((), None)
+		);
+
+		let trace_info = Runner::<Test>::trace_call(
+			alice(),
+			alice(),
+			contract_address,
+			multiply.clone(),
+			0,
+			1000000,
+			1000000,
+			<Test as Config>::config(),
+			trace_config(TracerKind::CallTracer, 0),
+		)
+		.unwrap();
+		assert_eq!(
+			trace_info.trace,
+			Trace::CallTracer(Some(CallTrace {
+				call_type: CallType::Call,
+				from: alice(),
+				to: contract_address,
+				input: multiply,
+				value: U256::zero(),
+				gas: 1000000,
+				gas_used: call_info.used_gas.as_u64(),
+				used_storage: 0,
+				is_precompile: false,
+				exit_reason: Some(ExitReason::Succeed(ExitSucceed::Returned)),
+				output: call_info.output,
+				calls: vec![],
+			}))
+		);
+	});
+}
+
 #[test]
 fn create_reverts_with_message() {
 	// pragma solidity ^0.5.0;
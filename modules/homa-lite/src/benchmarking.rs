@@ -148,6 +148,7 @@ mod benchmark_mock {
 		type WeightInfo = ();
 		type AddressMapping = MockAddressMapping;
 		type EVMBridge = ();
+		type HeldCurrencyIds = ();
 	}
 
 	parameter_types! {
@@ -26,7 +26,7 @@ pub mod weights;
 
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{ExchangeRate, ExchangeRateProvider, Ratio};
+use module_support::{ExchangeRate, ExchangeRateProvider, HomaManager, Ratio};
 use orml_traits::{arithmetic::Signed, MultiCurrency, MultiCurrencyExtended, XcmTransfer};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
@@ -155,60 +155,7 @@ pub mod module {
 		#[transactional]
 		pub fn mint(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// Ensure the amount is above the minimum, after the MintFee is deducted.
-			ensure!(
-				amount > T::MinimumMintThreshold::get().saturating_add(T::MintFee::get()),
-				Error::<T>::MintAmountBelowMinimumThreshold
-			);
-
-			// Ensure the total amount staked doesn't exceed the cap.
-			let new_total_staked = Self::total_staking_currency()
-				.checked_add(amount)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(
-				new_total_staked <= Self::staking_currency_mint_cap(),
-				Error::<T>::ExceededStakingCurrencyMintCap
-			);
-
-			let staking_currency = T::StakingCurrencyId::get();
-
-			// ensure the user has enough funds on their account.
-			T::Currency::ensure_can_withdraw(staking_currency, &who, amount)?;
-
-			// Gets the current exchange rate
-			let exchange_rate = Self::get_staking_exchange_rate();
-
-			// Calculate how much Liquid currency is to be minted.
-			// liquid_to_mint = ( (staked_amount - MintFee) * liquid_total / staked_total ) * (1 -
-			// MaxRewardPerEra)
-			let mut liquid_to_mint = exchange_rate
-				.checked_mul_int(
-					amount
-						.checked_sub(T::MintFee::get())
-						.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
-				)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			liquid_to_mint = liquid_to_mint
-				.checked_sub(T::MaxRewardPerEra::get().mul(liquid_to_mint))
-				.expect("Max rewards cannot be above 100%; qed");
-
-			// All checks pass. Proceed with Xcm transfer.
-			T::XcmTransfer::transfer(
-				who.clone(),
-				staking_currency,
-				amount,
-				T::SovereignSubAccountLocation::get(),
-				Self::xcm_dest_weight(),
-			)?;
-
-			// Mint the liquid currency into the user's account.
-			T::Currency::deposit(T::LiquidCurrencyId::get(), &who, liquid_to_mint)?;
-
-			TotalStakingCurrency::<T>::put(new_total_staked);
-
-			Self::deposit_event(Event::<T>::Minted(who, amount, liquid_to_mint));
-
+			Self::do_mint(who, amount)?;
 			Ok(())
 		}
 
@@ -301,6 +248,66 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Mint liquid currency by staking `amount` of staking currency of `who`, return the amount
+	/// of liquid currency minted.
+	fn do_mint(who: T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		// Ensure the amount is above the minimum, after the MintFee is deducted.
+		ensure!(
+			amount > T::MinimumMintThreshold::get().saturating_add(T::MintFee::get()),
+			Error::<T>::MintAmountBelowMinimumThreshold
+		);
+
+		// Ensure the total amount staked doesn't exceed the cap.
+		let new_total_staked = Self::total_staking_currency()
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			new_total_staked <= Self::staking_currency_mint_cap(),
+			Error::<T>::ExceededStakingCurrencyMintCap
+		);
+
+		let staking_currency = T::StakingCurrencyId::get();
+
+		// ensure the user has enough funds on their account.
+		T::Currency::ensure_can_withdraw(staking_currency, &who, amount)?;
+
+		// Gets the current exchange rate
+		let exchange_rate = Self::get_staking_exchange_rate();
+
+		// Calculate how much Liquid currency is to be minted.
+		// liquid_to_mint = ( (staked_amount - MintFee) * liquid_total / staked_total ) * (1 -
+		// MaxRewardPerEra)
+		let mut liquid_to_mint = exchange_rate
+			.checked_mul_int(
+				amount
+					.checked_sub(T::MintFee::get())
+					.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		liquid_to_mint = liquid_to_mint
+			.checked_sub(T::MaxRewardPerEra::get().mul(liquid_to_mint))
+			.expect("Max rewards cannot be above 100%; qed");
+
+		// All checks pass. Proceed with Xcm transfer.
+		T::XcmTransfer::transfer(
+			who.clone(),
+			staking_currency,
+			amount,
+			T::SovereignSubAccountLocation::get(),
+			Self::xcm_dest_weight(),
+		)?;
+
+		// Mint the liquid currency into the user's account.
+		T::Currency::deposit(T::LiquidCurrencyId::get(), &who, liquid_to_mint)?;
+
+		TotalStakingCurrency::<T>::put(new_total_staked);
+
+		Self::deposit_event(Event::<T>::Minted(who, amount, liquid_to_mint));
+
+		Ok(liquid_to_mint)
+	}
+
 	pub fn get_staking_exchange_rate() -> ExchangeRate {
 		let staking_total = Self::total_staking_currency();
 		let liquid_total = T::Currency::total_issuance(T::LiquidCurrencyId::get());
@@ -316,3 +323,14 @@ impl<T: Config> ExchangeRateProvider for LiquidExchangeProvider<T> {
 			.unwrap_or_default()
 	}
 }
+
+impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
+	#[transactional]
+	fn mint(who: T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		Self::do_mint(who, amount)
+	}
+
+	fn get_exchange_rate() -> ExchangeRate {
+		LiquidExchangeProvider::<T>::get_exchange_rate()
+	}
+}
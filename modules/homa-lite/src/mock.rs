@@ -167,6 +167,7 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type HeldCurrencyIds = ();
 }
 
 parameter_types! {
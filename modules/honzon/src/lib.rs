@@ -37,7 +37,7 @@ use sp_runtime::{
 	DispatchResult,
 };
 use sp_std::vec::Vec;
-use support::EmergencyShutdown;
+use support::{EmergencyShutdown, ExchangeRate, HonzonManager, Ratio};
 
 mod mock;
 mod tests;
@@ -139,12 +139,32 @@ pub mod module {
 			debit_adjustment: Amount,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment)
+		}
+
+		/// Adjust the multi-collateral loan of caller by specific
+		/// `collateral_adjustments` and `debit_adjustment`
+		///
+		/// - `collateral_adjustments`: list of collateral currency id and signed amount, positive
+		///   means to deposit collateral currency into CDP, negative means withdraw collateral
+		///   currency from CDP.
+		/// - `debit_adjustment`: signed amount, positive means to issue some amount of stablecoin
+		///   to caller according to the debit adjustment, negative means caller will payback some
+		///   amount of stablecoin to CDP according to to the debit adjustment.
+		#[pallet::weight(<T as Config>::WeightInfo::adjust_multi_collateral_loan(collateral_adjustments.len() as u32))]
+		#[transactional]
+		pub fn adjust_multi_collateral_loan(
+			origin: OriginFor<T>,
+			collateral_adjustments: Vec<(CurrencyId, Amount)>,
+			debit_adjustment: Amount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
 			// not allowed to adjust the debit after system shutdown
 			if !debit_adjustment.is_zero() {
 				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
 			}
-			<cdp_engine::Pallet<T>>::adjust_position(&who, currency_id, collateral_adjustment, debit_adjustment)?;
+			<cdp_engine::Pallet<T>>::adjust_multi_collateral_position(&who, &collateral_adjustments, debit_adjustment)?;
 			Ok(())
 		}
 
@@ -166,14 +186,7 @@ pub mod module {
 			maybe_path: Option<Vec<CurrencyId>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			<cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(
-				who,
-				currency_id,
-				max_collateral_amount,
-				maybe_path.as_deref(),
-			)?;
-			Ok(())
+			Self::do_close_loan_has_debit_by_dex(who, currency_id, max_collateral_amount, maybe_path.as_deref())
 		}
 
 		/// Transfer the whole CDP of `from` under `currency_id` to caller's CDP
@@ -262,6 +275,31 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_adjust_loan(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		// not allowed to adjust the debit after system shutdown
+		if !debit_adjustment.is_zero() {
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		}
+		<cdp_engine::Pallet<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
+		Ok(())
+	}
+
+	fn do_close_loan_has_debit_by_dex(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+		max_collateral_amount: Balance,
+		maybe_path: Option<&[CurrencyId]>,
+	) -> DispatchResult {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		<cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(who, currency_id, max_collateral_amount, maybe_path)?;
+		Ok(())
+	}
+
 	/// Check if `from` has the authorization of `to` under `currency_id`
 	fn check_authorization(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		ensure!(
@@ -271,3 +309,36 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pallet<T> {
+	fn adjust_loan(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		Self::do_adjust_loan(who, currency_id, collateral_adjustment, debit_adjustment)
+	}
+
+	fn close_loan_by_dex(who: T::AccountId, currency_id: CurrencyId, max_collateral_amount: Balance) -> DispatchResult {
+		Self::do_close_loan_has_debit_by_dex(who, currency_id, max_collateral_amount, None)
+	}
+
+	fn get_position(who: &T::AccountId, currency_id: CurrencyId) -> (Balance, Balance) {
+		let position = <loans::Pallet<T>>::positions(currency_id, who);
+		(position.collateral, position.debit)
+	}
+
+	fn get_collateral_ratio(who: &T::AccountId, currency_id: CurrencyId) -> Option<Ratio> {
+		let position = <loans::Pallet<T>>::positions(currency_id, who);
+		<cdp_engine::Pallet<T>>::get_collateral_ratio(currency_id, position.collateral, position.debit)
+	}
+
+	fn get_liquidation_ratio(currency_id: CurrencyId) -> Ratio {
+		<cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id)
+	}
+
+	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate {
+		<cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id)
+	}
+}
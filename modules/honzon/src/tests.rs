@@ -98,6 +98,7 @@ fn transfer_loan_from_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_ok!(HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB));
@@ -128,6 +129,7 @@ fn adjust_loan_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -135,6 +137,38 @@ fn adjust_loan_should_work() {
 	});
 }
 
+#[test]
+fn adjust_multi_collateral_loan_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_multi_collateral_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_multi_collateral_loan(
+			Origin::signed(ALICE),
+			vec![(BTC, 100), (DOT, 100)],
+			50
+		));
+		assert_eq!(
+			LoansModule::multi_collateral_positions(ALICE).collateral_list(),
+			vec![(DOT, 100), (BTC, 100)]
+		);
+		assert_eq!(LoansModule::multi_collateral_positions(ALICE).debit, 50);
+	});
+}
+
 #[test]
 fn on_emergency_shutdown_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -143,6 +177,10 @@ fn on_emergency_shutdown_should_work() {
 			HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50),
 			Error::<Runtime>::AlreadyShutdown,
 		);
+		assert_noop!(
+			HonzonModule::adjust_multi_collateral_loan(Origin::signed(ALICE), vec![(BTC, 100)], 50),
+			Error::<Runtime>::AlreadyShutdown,
+		);
 		assert_noop!(
 			HonzonModule::transfer_loan_from(Origin::signed(ALICE), BTC, BOB),
 			Error::<Runtime>::AlreadyShutdown,
@@ -165,6 +203,7 @@ fn close_loan_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -180,3 +219,44 @@ fn close_loan_has_debit_by_dex_work() {
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 	});
 }
+
+#[test]
+fn honzon_manager_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(<HonzonModule as HonzonManager<_, _, _, _>>::adjust_loan(
+			&ALICE, BTC, 100, 50
+		));
+		assert_eq!(HonzonModule::get_position(&ALICE, BTC), (100, 50));
+		assert_eq!(
+			HonzonModule::get_collateral_ratio(&ALICE, BTC),
+			Some(Ratio::saturating_from_rational(2, 1))
+		);
+		assert_eq!(
+			HonzonModule::get_liquidation_ratio(BTC),
+			Ratio::saturating_from_rational(3, 2)
+		);
+		assert_eq!(
+			HonzonModule::get_debit_exchange_rate(BTC),
+			DefaultDebitExchangeRate::get()
+		);
+
+		assert_ok!(HonzonModule::close_loan_by_dex(ALICE, BTC, 100));
+		assert_eq!(HonzonModule::get_position(&ALICE, BTC), (0, 0));
+
+		mock_shutdown();
+		assert_noop!(
+			<HonzonModule as HonzonManager<_, _, _, _>>::adjust_loan(&ALICE, BTC, 100, 50),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
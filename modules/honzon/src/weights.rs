@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn adjust_loan() -> Weight;
 	fn transfer_loan_from() -> Weight;
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight;
+	fn adjust_multi_collateral_loan(c: u32, ) -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -75,20 +76,29 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn adjust_loan() -> Weight {
 		(160_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(24 as Weight))
-			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
 	fn transfer_loan_from() -> Weight {
 		(114_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(21 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(23 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight {
 		(114_000_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn adjust_multi_collateral_loan(c: u32, ) -> Weight {
+		(109_371_000 as Weight)
+			// Standard Error: 82_000
+			.saturating_add((31_042_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
 }
 
@@ -110,19 +120,28 @@ impl WeightInfo for () {
 	}
 	fn adjust_loan() -> Weight {
 		(160_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(24 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(25 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
 	}
 	fn transfer_loan_from() -> Weight {
 		(114_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(23 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight {
 		(114_000_000 as Weight)
 			// Standard Error: 138_000
 			.saturating_add((654_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn adjust_multi_collateral_loan(c: u32, ) -> Weight {
+		(109_371_000 as Weight)
+			// Standard Error: 82_000
+			.saturating_add((31_042_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
 }
@@ -0,0 +1,119 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! RPC interface for the incentives module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_incentives_rpc_runtime_api::{PoolEmission, RewardBalance};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as IncentivesClient;
+pub use module_incentives_rpc_runtime_api::IncentivesApi as IncentivesRuntimeApi;
+
+#[rpc]
+pub trait IncentivesApi<BlockHash, AccountId, PoolId, Balance> {
+	#[rpc(name = "incentives_getPendingRewards")]
+	fn get_pending_rewards(
+		&self,
+		pool_id: PoolId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<RewardBalance<Balance>>>;
+
+	#[rpc(name = "incentives_getPoolEmission")]
+	fn get_pool_emission(&self, pool_id: PoolId, at: Option<BlockHash>) -> Result<PoolEmission<Balance>>;
+}
+
+/// A struct that implements the [`IncentivesApi`].
+pub struct Incentives<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Incentives<C, B> {
+	/// Create new `Incentives` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Incentives {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, PoolId, Balance> IncentivesApi<<Block as BlockT>::Hash, AccountId, PoolId, Balance>
+	for Incentives<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: IncentivesRuntimeApi<Block, AccountId, PoolId, Balance>,
+	AccountId: Codec,
+	PoolId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_pending_rewards(
+		&self,
+		pool_id: PoolId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<RewardBalance<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_pending_rewards(&at, pool_id, who).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get pending rewards.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_pool_emission(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> Result<PoolEmission<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_pool_emission(&at, pool_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get pool emission.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
@@ -49,6 +49,33 @@
 //! 1. LoansIncentive/DexIncentive/HomaIncentive/DexSaving: the fixed blocks is
 //! period(AccumulatePeriod), and on the beginning of each period will accumulate reward.
 //! 2. HomaValidatorAllowance: transfer rewards into the vault account.
+//!
+//! Extra rewards:
+//! Besides the reward currency of the pool, incentive pools can carry extra reward currencies with
+//! independent reward amount per period. Extra rewards are funded by any account into the module
+//! account, accumulated from the fund on the beginning of each period, and paid out together with
+//! the pool reward when claiming. Vesting only applies to the reward currency of the pool. An extra
+//! reward currency takes a slot of `MaxExtraRewardCurrencies` while it is emitted by the fixed
+//! reward amount or campaigns, and is removed once its accumulated rewards are claimed.
+//!
+//! Campaigns:
+//! Anyone can create a campaign to incentivize a pool with a total budget which is escrowed up
+//! front, and `CampaignDeposit` is reserved until the campaign is closed. Only currencies which
+//! have a `MinCampaignBudget` are accepted as campaign rewards, the budget must reach it and the
+//! duration must not exceed `MaxCampaignDuration`. The budget is emitted from the start block to
+//! the end block following the emission curve, and accumulated to the pool on the beginning of
+//! each period. Emission of periods in which the pool has no shares is skipped, and the remaining
+//! budget is refunded to the funder when the campaign is closed.
+//!
+//! Reward boost:
+//! Users can lock native currency for a chosen duration to get escrow power, which decays linearly
+//! until the lock expires. For pools with `BoostConfigs`, the share of user in orml rewards is
+//! multiplied up to `max_multiplier` according to the ratio of escrow power to the share. The boost
+//! is re-evaluated when the share is updated, rewards are claimed or `refresh_boost` is called.
+//!
+//! Reward vesting:
+//! For pools with non-zero `RewardVestingPeriods`, claimed rewards are not deducted but released
+//! linearly over the vesting period instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -56,14 +83,22 @@
 
 use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use orml_traits::{Happened, MultiCurrency, RewardHandler};
+use orml_traits::{
+	GetByKey, Happened, LockIdentifier, MultiCurrency, MultiLockableCurrency, MultiReservableCurrency, RewardHandler,
+};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, MaybeDisplay, One, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber, RuntimeDebug,
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, MaybeDisplay, One, Saturating, UniqueSaturatedInto, Zero},
+	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use support::{
+	CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, ExchangeRate, IncentivesManager, PriceProvider, Rate,
 };
-use sp_std::{fmt::Debug, vec::Vec};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate};
 
 mod mock;
 mod tests;
@@ -72,8 +107,12 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+pub const BOOST_LOCK_ID: LockIdentifier = *b"aca/inbo";
+
 /// PoolId for various rewards pools
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum PoolId<AccountId> {
 	/// Rewards pool(NativeCurrencyId) for users who open CDP
 	LoansIncentive(CurrencyId),
@@ -94,6 +133,85 @@ pub enum PoolId<AccountId> {
 	HomaValidatorAllowance(AccountId),
 }
 
+/// Accumulated extra rewards of pool in a currency
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct ExtraRewardInfo {
+	/// Total rewards
+	pub total_rewards: Balance,
+	/// Total withdrawn rewards
+	pub total_withdrawn_rewards: Balance,
+	/// The number of fixed reward amount and campaigns emitting the currency, the currency takes
+	/// a slot of `MaxExtraRewardCurrencies` while it is not zero
+	pub sources: u32,
+	/// The generation in which the currency is added to the pool, the withdrawn rewards of
+	/// accounts recorded in other generations are stale
+	pub generation: u32,
+}
+
+/// Campaign id
+pub type CampaignId = u32;
+
+/// Emission curve of campaign budget
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum EmissionCurve {
+	/// Emit the same amount every block.
+	Constant,
+	/// The emission decreases linearly to zero at the end block.
+	LinearDecay,
+}
+
+/// Incentive campaign
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Campaign<AccountId, RelaychainAccountId, BlockNumber> {
+	/// The account which funds the budget and receives the refund
+	pub funder: AccountId,
+	/// The incentive pool
+	pub pool_id: PoolId<RelaychainAccountId>,
+	/// The reward currency
+	pub currency_id: CurrencyId,
+	/// Total budget
+	pub budget: Balance,
+	/// The block number when emission starts
+	pub start: BlockNumber,
+	/// The block number when emission ends
+	pub end: BlockNumber,
+	/// The emission curve
+	pub emission: EmissionCurve,
+	/// The emission scheduled so far, including the skipped emission
+	pub scheduled: Balance,
+	/// The budget which has not been accumulated to the pool
+	pub remaining: Balance,
+}
+
+/// Boost params of rewards pool
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct BoostConfig {
+	/// The multiplier of share when the account is fully boosted, must not be less than one.
+	pub max_multiplier: Rate,
+	/// The escrow power required per unit of share to get fully boosted.
+	pub escrow_per_share: Rate,
+}
+
+/// Native currency locked for boost
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct BoostLock<BlockNumber> {
+	/// Locked amount
+	pub amount: Balance,
+	/// The block number from which the lock can be released
+	pub unlock_at: BlockNumber,
+}
+
+/// Claimed rewards which are released linearly
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct VestingReward<BlockNumber> {
+	/// The amount which has not been released yet
+	pub locked: Balance,
+	/// The block number of last release
+	pub start: BlockNumber,
+	/// The block number when all locked amount is released
+	pub end: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -135,7 +253,8 @@ pub mod module {
 		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
 		/// Currency for transfer/issue assets
-		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		type Currency: MultiLockableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId>;
 
 		/// DEX to supply liquidity info
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
@@ -143,10 +262,44 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The price source to estimate the APR of pools.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// The debit exchange rate of loans to estimate the value of loans incentive shares.
+		type DebitExchangeRate: GetByKey<CurrencyId, ExchangeRate>;
+
+		/// The number of blocks per year to estimate the APR of pools.
+		#[pallet::constant]
+		type BlocksPerYear: Get<Self::BlockNumber>;
+
 		/// The module id, keep DexShare LP.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The maximum number of extra reward currencies of a pool.
+		#[pallet::constant]
+		type MaxExtraRewardCurrencies: Get<u32>;
+
+		/// The maximum number of campaigns which exist at the same time.
+		#[pallet::constant]
+		type MaxCampaigns: Get<u32>;
+
+		/// The minimum budget of campaign in the reward currency, `None` if the currency is not
+		/// accepted as campaign reward.
+		type MinCampaignBudget: GetByKey<CurrencyId, Option<Balance>>;
+
+		/// The maximum number of blocks from the start to the end of campaign.
+		#[pallet::constant]
+		type MaxCampaignDuration: Get<Self::BlockNumber>;
+
+		/// The deposit of native currency reserved from the funder until the campaign is closed.
+		#[pallet::constant]
+		type CampaignDeposit: Get<Balance>;
+
+		/// The maximum period which native currency can be locked for boost.
+		#[pallet::constant]
+		type MaxBoostLockPeriod: Get<Self::BlockNumber>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -161,11 +314,37 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// Invalid boost config
+		InvalidBoostConfig,
+		/// Invalid lock period
+		InvalidLockPeriod,
+		/// Lock amount is zero
+		ZeroLockAmount,
+		/// The account has no boost lock
+		BoostLockNotFound,
+		/// The boost lock has not expired
+		BoostLockNotExpired,
+		/// Invalid extra reward currency
+		InvalidRewardCurrency,
+		/// Extra reward currencies of pool exceed the limit
+		TooManyRewardCurrencies,
+		/// Invalid campaign params
+		InvalidCampaign,
+		/// Campaign not found
+		CampaignNotFound,
+		/// Campaigns exceed the limit
+		TooManyCampaigns,
+		/// The campaign has not ended
+		CampaignNotEnded,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", PoolId<T::RelaychainAccountId> = "PoolId")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		PoolId<T::RelaychainAccountId> = "PoolId",
+		T::BlockNumber = "BlockNumber"
+	)]
 	pub enum Event<T: Config> {
 		/// Deposit DEX share. \[who, dex_share_type, deposit_amount\]
 		DepositDexShare(T::AccountId, CurrencyId, Balance),
@@ -185,6 +364,42 @@ pub mod module {
 		SavingRewardRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
 		/// Payout deduction rate updated. \[pool_id, deduction_rate\]
 		PayoutDeductionRateUpdated(PoolId<T::RelaychainAccountId>, Rate),
+		/// Boost config updated. \[pool_id, boost_config\]
+		BoostConfigUpdated(PoolId<T::RelaychainAccountId>, Option<BoostConfig>),
+		/// Reward vesting period updated. \[pool_id, vesting_period\]
+		RewardVestingPeriodUpdated(PoolId<T::RelaychainAccountId>, T::BlockNumber),
+		/// Lock native currency for boost. \[who, total_locked_amount, unlock_at\]
+		BoostLocked(T::AccountId, Balance, T::BlockNumber),
+		/// Unlock native currency of boost. \[who, unlocked_amount\]
+		BoostUnlocked(T::AccountId, Balance),
+		/// Share boost updated. \[who, pool_id, raw_share, boosted_share\]
+		BoostUpdated(T::AccountId, PoolId<T::RelaychainAccountId>, Balance, Balance),
+		/// Extra incentive reward amount updated. \[pool_id, reward_currency_id,
+		/// reward_amount_per_period\]
+		ExtraIncentiveRewardAmountUpdated(PoolId<T::RelaychainAccountId>, CurrencyId, Balance),
+		/// Fund extra rewards. \[funder, pool_id, reward_currency_id, amount\]
+		ExtraRewardsFunded(T::AccountId, PoolId<T::RelaychainAccountId>, CurrencyId, Balance),
+		/// Campaign created. \[campaign_id, funder, pool_id, reward_currency_id, budget, start, end\]
+		CampaignCreated(
+			CampaignId,
+			T::AccountId,
+			PoolId<T::RelaychainAccountId>,
+			CurrencyId,
+			Balance,
+			T::BlockNumber,
+			T::BlockNumber,
+		),
+		/// Campaign closed. \[campaign_id, refund_amount\]
+		CampaignClosed(CampaignId, Balance),
+		/// Claimed rewards start vesting. \[who, pool_id, reward_currency_id, vesting_amount,
+		/// vesting_end\]
+		VestingRewardsAdded(
+			T::AccountId,
+			PoolId<T::RelaychainAccountId>,
+			CurrencyId,
+			Balance,
+			T::BlockNumber,
+		),
 	}
 
 	/// Mapping from pool to its fixed reward amount per period.
@@ -226,6 +441,162 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// Mapping from pool and extra reward currency to the fixed reward amount per period.
+	///
+	/// ExtraIncentiveRewardAmounts: double_map PoolId, CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn extra_incentive_reward_amounts)]
+	pub type ExtraIncentiveRewardAmounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		CurrencyId,
+		Balance,
+		ValueQuery,
+	>;
+
+	/// The remaining funds for extra rewards which are kept in the module account.
+	///
+	/// ExtraRewardFunds: double_map PoolId, CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn extra_reward_funds)]
+	pub type ExtraRewardFunds<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		CurrencyId,
+		Balance,
+		ValueQuery,
+	>;
+
+	/// The accumulated extra rewards of pool.
+	///
+	/// ExtraRewardPools: map PoolId => BTreeMap<CurrencyId, ExtraRewardInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn extra_reward_pools)]
+	pub type ExtraRewardPools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, BTreeMap<CurrencyId, ExtraRewardInfo>, ValueQuery>;
+
+	/// The withdrawn extra rewards of account in pool, with the generation of the extra reward
+	/// currency in which they are recorded.
+	///
+	/// ExtraWithdrawnRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, (u32, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn extra_withdrawn_rewards)]
+	pub type ExtraWithdrawnRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		T::AccountId,
+		BTreeMap<CurrencyId, (u32, Balance)>,
+		ValueQuery,
+	>;
+
+	/// The generation of the next extra reward currency added to pools.
+	///
+	/// NextExtraRewardGeneration: u32
+	#[pallet::storage]
+	#[pallet::getter(fn next_extra_reward_generation)]
+	pub type NextExtraRewardGeneration<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The pending extra rewards amount.
+	///
+	/// PendingExtraRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_extra_rewards)]
+	pub type PendingExtraRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		T::AccountId,
+		BTreeMap<CurrencyId, Balance>,
+		ValueQuery,
+	>;
+
+	/// The incentive campaigns.
+	///
+	/// Campaigns: map CampaignId => Option<Campaign>
+	#[pallet::storage]
+	#[pallet::getter(fn campaigns)]
+	pub type Campaigns<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		Campaign<T::AccountId, T::RelaychainAccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The id of next campaign.
+	///
+	/// NextCampaignId: CampaignId
+	#[pallet::storage]
+	#[pallet::getter(fn next_campaign_id)]
+	pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
+
+	/// The number of existing campaigns.
+	///
+	/// CampaignCount: u32
+	#[pallet::storage]
+	#[pallet::getter(fn campaign_count)]
+	pub type CampaignCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping from pool to its boost config.
+	///
+	/// BoostConfigs: map PoolId => Option<BoostConfig>
+	#[pallet::storage]
+	#[pallet::getter(fn boost_configs)]
+	pub type BoostConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, BoostConfig, OptionQuery>;
+
+	/// Mapping from pool to its reward vesting period, zero means rewards are paid out instantly.
+	///
+	/// RewardVestingPeriods: map PoolId => BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn reward_vesting_periods)]
+	pub type RewardVestingPeriods<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId<T::RelaychainAccountId>, T::BlockNumber, ValueQuery>;
+
+	/// The native currency locked by accounts for boost.
+	///
+	/// BoostLocks: map AccountId => Option<BoostLock>
+	#[pallet::storage]
+	#[pallet::getter(fn boost_locks)]
+	pub type BoostLocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoostLock<T::BlockNumber>, OptionQuery>;
+
+	/// The raw share of account in pools, only exists when the share in orml rewards is boosted.
+	///
+	/// RawShares: double_map AccountId, PoolId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn raw_shares)]
+	pub type RawShares<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Balance,
+		OptionQuery,
+	>;
+
+	/// The claimed rewards which are vesting.
+	///
+	/// VestingRewards: double_map PoolId, AccountId => Option<VestingReward>
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_rewards)]
+	pub type VestingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId<T::RelaychainAccountId>,
+		Twox64Concat,
+		T::AccountId,
+		VestingReward<T::BlockNumber>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -236,7 +607,6 @@ pub mod module {
 			if !T::EmergencyShutdown::is_shutdown() && now % T::AccumulatePeriod::get() == Zero::zero() {
 				let mut count: u32 = 0;
 				let native_currency_id = T::NativeCurrencyId::get();
-				let stable_currency_id = T::StableCurrencyId::get();
 
 				for (pool_id, pool_info) in orml_rewards::Pools::<T>::iter() {
 					if !pool_info.total_shares.is_zero() {
@@ -269,50 +639,38 @@ pub mod module {
 										}
 									}
 								}
+
+								count += Self::accumulate_extra_rewards(&pool_id);
 							}
 
 							PoolId::DexSaving(lp_currency_id) => {
 								count += 1;
-								let dex_saving_reward_rate = Self::dex_saving_reward_rate(pool_id.clone());
-
-								if !dex_saving_reward_rate.is_zero() {
-									if let Some((currency_id_a, currency_id_b)) =
-										lp_currency_id.split_dex_share_currency_id()
-									{
-										// accumulate saving reward only for liquidity pool of stable currency id
-										let dex_saving_reward_base = if currency_id_a == stable_currency_id {
-											T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
-										} else if currency_id_b == stable_currency_id {
-											T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
-										} else {
-											Zero::zero()
-										};
-										let dex_saving_reward_amount =
-											dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base);
-
-										// issue stable coin without backing.
-										if !dex_saving_reward_amount.is_zero() {
-											let res = T::CDPTreasury::issue_debit(
-												&Self::account_id(),
+								let dex_saving_reward_amount = Self::dex_saving_reward_amount(
+									lp_currency_id,
+									Self::dex_saving_reward_rate(pool_id.clone()),
+								);
+
+								// issue stable coin without backing.
+								if !dex_saving_reward_amount.is_zero() {
+									let res = T::CDPTreasury::issue_debit(
+										&Self::account_id(),
+										dex_saving_reward_amount,
+										false,
+									);
+									match res {
+										Ok(_) => {
+											<orml_rewards::Pallet<T>>::accumulate_reward(
+												&pool_id,
 												dex_saving_reward_amount,
-												false,
 											);
-											match res {
-												Ok(_) => {
-													<orml_rewards::Pallet<T>>::accumulate_reward(
-														&pool_id,
-														dex_saving_reward_amount,
-													);
-												}
-												Err(e) => {
-													log::warn!(
-														target: "incentives",
-														"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
-														This is unexpected but should be safe",
-														dex_saving_reward_amount, Self::account_id(), e
-													);
-												}
-											}
+										}
+										Err(e) => {
+											log::warn!(
+												target: "incentives",
+												"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
+												This is unexpected but should be safe",
+												dex_saving_reward_amount, Self::account_id(), e
+											);
 										}
 									}
 								}
@@ -323,6 +681,8 @@ pub mod module {
 					}
 				}
 
+				count += Self::accumulate_campaign_rewards(now);
+
 				T::WeightInfo::on_initialize(count)
 			} else {
 				0
@@ -352,46 +712,7 @@ pub mod module {
 		#[transactional]
 		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
-
-			let pending_reward: Balance = PendingRewards::<T>::take(&pool_id, &who);
-			if !pending_reward.is_zero() {
-				let currency_id = match pool_id {
-					PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
-						T::NativeCurrencyId::get()
-					}
-					PoolId::DexSaving(_) => T::StableCurrencyId::get(),
-					PoolId::HomaValidatorAllowance(_) => T::LiquidCurrencyId::get(),
-				};
-
-				// calculate actual rewards and deduction amount
-				let (actual_amount, deduction_amount) = {
-					let deduction_amount = Self::payout_deduction_rates(&pool_id)
-						.saturating_mul_int(pending_reward)
-						.min(pending_reward);
-					if !deduction_amount.is_zero() {
-						// re-accumulate deduction to rewards pool if deduction amount is not zero
-						<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, deduction_amount);
-					}
-					(pending_reward.saturating_sub(deduction_amount), deduction_amount)
-				};
-
-				// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
-				// affect the process, ignore the result to continue. if it fails, just the user will not
-				// be rewarded, there will not increase user balance.
-				T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
-
-				Self::deposit_event(Event::ClaimRewards(
-					who,
-					pool_id,
-					currency_id,
-					actual_amount,
-					deduction_amount,
-				));
-			}
-
-			Ok(())
+			Self::do_claim_rewards(who, pool_id)
 		}
 
 		#[pallet::weight(<T as Config>::WeightInfo::update_incentive_rewards(updates.len() as u32))]
@@ -481,6 +802,317 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Update the extra reward amount per period of incentive pools, zero amount stops the
+		/// extra rewards of the currency.
+		///
+		/// - `updates`: Vec<(pool_id, reward_currency_id, reward_amount_per_period)>
+		#[pallet::weight(<T as Config>::WeightInfo::update_extra_incentive_rewards(updates.len() as u32))]
+		#[transactional]
+		pub fn update_extra_incentive_rewards(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, CurrencyId, Balance)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, currency_id, amount) in updates {
+				match pool_id {
+					PoolId::DexIncentive(lp_currency_id) => {
+						ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+					_ => {
+						return Err(Error::<T>::InvalidPoolId.into());
+					}
+				}
+				ensure!(
+					currency_id != Self::reward_currency_id(&pool_id),
+					Error::<T>::InvalidRewardCurrency
+				);
+
+				if amount.is_zero() {
+					if !ExtraIncentiveRewardAmounts::<T>::take(&pool_id, currency_id).is_zero() {
+						Self::release_extra_reward_currency(&pool_id, currency_id);
+					}
+				} else {
+					if !ExtraIncentiveRewardAmounts::<T>::contains_key(&pool_id, currency_id) {
+						Self::register_extra_reward_currency(&pool_id, currency_id)?;
+					}
+					ExtraIncentiveRewardAmounts::<T>::insert(&pool_id, currency_id, amount);
+				}
+				Self::deposit_event(Event::ExtraIncentiveRewardAmountUpdated(pool_id, currency_id, amount));
+			}
+			Ok(())
+		}
+
+		/// Fund the extra rewards of incentive pool, anyone can fund the extra reward currencies
+		/// configured for the pool.
+		///
+		/// - `pool_id`: the incentive pool.
+		/// - `currency_id`: the extra reward currency.
+		/// - `amount`: the amount to fund.
+		#[pallet::weight(<T as Config>::WeightInfo::fund_extra_rewards())]
+		#[transactional]
+		pub fn fund_extra_rewards(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T::RelaychainAccountId>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				ExtraIncentiveRewardAmounts::<T>::contains_key(&pool_id, currency_id),
+				Error::<T>::InvalidRewardCurrency
+			);
+
+			T::Currency::transfer(currency_id, &who, &Self::account_id(), amount)?;
+			ExtraRewardFunds::<T>::mutate(&pool_id, currency_id, |fund| *fund = fund.saturating_add(amount));
+
+			Self::deposit_event(Event::ExtraRewardsFunded(who, pool_id, currency_id, amount));
+			Ok(())
+		}
+
+		/// Create a campaign to incentivize the pool, the budget is transferred from the caller
+		/// to the module account and `CampaignDeposit` is reserved until the campaign is closed.
+		///
+		/// - `pool_id`: the incentive pool.
+		/// - `currency_id`: the reward currency, must have a `MinCampaignBudget`. Extra reward
+		///   currency is added to the pool if it is not the reward currency of the pool.
+		/// - `budget`: the total budget.
+		/// - `start`: the block number when emission starts.
+		/// - `end`: the block number when emission ends.
+		/// - `emission`: the emission curve.
+		#[pallet::weight(<T as Config>::WeightInfo::create_campaign())]
+		#[transactional]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T::RelaychainAccountId>,
+			currency_id: CurrencyId,
+			#[pallet::compact] budget: Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			emission: EmissionCurve,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			match pool_id {
+				PoolId::DexIncentive(lp_currency_id) => {
+					ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+				}
+				PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+				_ => {
+					return Err(Error::<T>::InvalidPoolId.into());
+				}
+			}
+			let min_budget = T::MinCampaignBudget::get(&currency_id).ok_or(Error::<T>::InvalidRewardCurrency)?;
+			ensure!(
+				!budget.is_zero()
+					&& budget >= min_budget
+					&& start >= <frame_system::Pallet<T>>::block_number()
+					&& end > start && end.saturating_sub(start) <= T::MaxCampaignDuration::get(),
+				Error::<T>::InvalidCampaign
+			);
+			let campaign_count = Self::campaign_count();
+			ensure!(campaign_count < T::MaxCampaigns::get(), Error::<T>::TooManyCampaigns);
+			if currency_id != Self::reward_currency_id(&pool_id) {
+				Self::register_extra_reward_currency(&pool_id, currency_id)?;
+			}
+
+			T::Currency::reserve(T::NativeCurrencyId::get(), &who, T::CampaignDeposit::get())?;
+			T::Currency::transfer(currency_id, &who, &Self::account_id(), budget)?;
+
+			let campaign_id = Self::next_campaign_id();
+			NextCampaignId::<T>::put(campaign_id.saturating_add(1));
+			CampaignCount::<T>::put(campaign_count.saturating_add(1));
+			Campaigns::<T>::insert(
+				campaign_id,
+				Campaign {
+					funder: who.clone(),
+					pool_id: pool_id.clone(),
+					currency_id,
+					budget,
+					start,
+					end,
+					emission,
+					scheduled: Zero::zero(),
+					remaining: budget,
+				},
+			);
+
+			Self::deposit_event(Event::CampaignCreated(
+				campaign_id,
+				who,
+				pool_id,
+				currency_id,
+				budget,
+				start,
+				end,
+			));
+			Ok(())
+		}
+
+		/// Close the campaign, refund the remaining budget and unreserve the deposit of the funder.
+		/// The funder can close the campaign after the emission has ended, `UpdateOrigin` can close
+		/// it at any time.
+		///
+		/// - `campaign_id`: the campaign to close.
+		#[pallet::weight(<T as Config>::WeightInfo::close_campaign())]
+		#[transactional]
+		pub fn close_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResult {
+			let campaign = Self::campaigns(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			if let Err(origin) = T::UpdateOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(who == campaign.funder, DispatchError::BadOrigin);
+				ensure!(campaign.scheduled >= campaign.budget, Error::<T>::CampaignNotEnded);
+			}
+
+			T::Currency::transfer(
+				campaign.currency_id,
+				&Self::account_id(),
+				&campaign.funder,
+				campaign.remaining,
+			)?;
+			T::Currency::unreserve(T::NativeCurrencyId::get(), &campaign.funder, T::CampaignDeposit::get());
+			Campaigns::<T>::remove(campaign_id);
+			CampaignCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			if campaign.currency_id != Self::reward_currency_id(&campaign.pool_id) {
+				Self::release_extra_reward_currency(&campaign.pool_id, campaign.currency_id);
+			}
+
+			Self::deposit_event(Event::CampaignClosed(campaign_id, campaign.remaining));
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::update_boost_configs(updates.len() as u32))]
+		#[transactional]
+		pub fn update_boost_configs(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, Option<BoostConfig>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, boost_config) in updates {
+				match pool_id {
+					PoolId::DexIncentive(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					PoolId::LoansIncentive(_) | PoolId::HomaIncentive => {}
+					_ => {
+						return Err(Error::<T>::InvalidPoolId.into());
+					}
+				}
+				if let Some(config) = boost_config {
+					ensure!(config.max_multiplier >= Rate::one(), Error::<T>::InvalidBoostConfig);
+					BoostConfigs::<T>::insert(&pool_id, config);
+				} else {
+					BoostConfigs::<T>::remove(&pool_id);
+				}
+				Self::deposit_event(Event::BoostConfigUpdated(pool_id, boost_config));
+			}
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::update_reward_vesting_periods(updates.len() as u32))]
+		#[transactional]
+		pub fn update_reward_vesting_periods(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId<T::RelaychainAccountId>, T::BlockNumber)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, vesting_period) in updates {
+				match pool_id {
+					PoolId::DexSaving(currency_id) | PoolId::DexIncentive(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+					}
+					_ => {}
+				}
+				RewardVestingPeriods::<T>::insert(&pool_id, vesting_period);
+				Self::deposit_event(Event::RewardVestingPeriodUpdated(pool_id, vesting_period));
+			}
+			Ok(())
+		}
+
+		/// Lock native currency to boost the share in rewards pools. Locking again adds the amount
+		/// and extends the lock, the unlock block cannot be earlier than the existing one.
+		///
+		/// The boost takes effect when the share is updated, rewards are claimed or `refresh_boost`
+		/// is called.
+		///
+		/// - `amount`: the additional amount to lock.
+		/// - `lock_period`: the lock period from the current block.
+		#[pallet::weight(<T as Config>::WeightInfo::lock_for_boost())]
+		#[transactional]
+		pub fn lock_for_boost(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			lock_period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!lock_period.is_zero() && lock_period <= T::MaxBoostLockPeriod::get(),
+				Error::<T>::InvalidLockPeriod
+			);
+
+			let native_currency_id = T::NativeCurrencyId::get();
+			let unlock_at = <frame_system::Pallet<T>>::block_number().saturating_add(lock_period);
+			let mut lock = Self::boost_locks(&who).unwrap_or_default();
+			ensure!(unlock_at >= lock.unlock_at, Error::<T>::InvalidLockPeriod);
+
+			lock.amount = lock.amount.saturating_add(amount);
+			lock.unlock_at = unlock_at;
+			ensure!(!lock.amount.is_zero(), Error::<T>::ZeroLockAmount);
+			ensure!(
+				T::Currency::free_balance(native_currency_id, &who) >= lock.amount,
+				Error::<T>::NotEnough
+			);
+
+			T::Currency::set_lock(BOOST_LOCK_ID, native_currency_id, &who, lock.amount)?;
+			BoostLocks::<T>::insert(&who, lock);
+
+			Self::deposit_event(Event::BoostLocked(who, lock.amount, unlock_at));
+			Ok(())
+		}
+
+		/// Unlock the expired boost lock, all boosts of the caller are removed.
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_boost())]
+		#[transactional]
+		pub fn unlock_boost(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lock = Self::boost_locks(&who).ok_or(Error::<T>::BoostLockNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= lock.unlock_at,
+				Error::<T>::BoostLockNotExpired
+			);
+
+			T::Currency::remove_lock(BOOST_LOCK_ID, T::NativeCurrencyId::get(), &who)?;
+			BoostLocks::<T>::remove(&who);
+
+			for (pool_id, raw_share) in RawShares::<T>::drain_prefix(&who) {
+				Self::set_share(&who, &pool_id, raw_share);
+			}
+
+			Self::deposit_event(Event::BoostUnlocked(who, lock.amount));
+			Ok(())
+		}
+
+		/// Re-evaluate the boost of the share for an account, anyone can call it to apply the
+		/// latest escrow power or boost config.
+		///
+		/// - `who`: the account whose share will be refreshed.
+		/// - `pool_id`: the rewards pool.
+		#[pallet::weight(<T as Config>::WeightInfo::refresh_boost())]
+		#[transactional]
+		pub fn refresh_boost(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			pool_id: PoolId<T::RelaychainAccountId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let raw_share = Self::raw_share(&pool_id, &who);
+			Self::update_share(&who, &pool_id, raw_share);
+			let boosted_share = <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(&pool_id, &who).0;
+
+			Self::deposit_event(Event::BoostUpdated(who, pool_id, raw_share, boosted_share));
+			Ok(())
+		}
 	}
 }
 
@@ -488,6 +1120,613 @@ impl<T: Config> Pallet<T> {
 	pub fn account_id() -> T::AccountId {
 		T::PalletId::get().into_account()
 	}
+
+	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
+		<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
+		Self::claim_extra_rewards(&who, &pool_id);
+		// re-evaluate the boost after rewards are settled
+		Self::update_share(&who, &pool_id, Self::raw_share(&pool_id, &who));
+
+		let currency_id = Self::reward_currency_id(&pool_id);
+
+		// release the vested rewards before adding new vesting rewards
+		Self::release_vesting_rewards(&who, &pool_id, currency_id)?;
+
+		let pending_reward: Balance = PendingRewards::<T>::take(&pool_id, &who);
+		if !pending_reward.is_zero() {
+			let vesting_period = Self::reward_vesting_periods(&pool_id);
+
+			if vesting_period.is_zero() {
+				// calculate actual rewards and deduction amount
+				let (actual_amount, deduction_amount) = {
+					let deduction_amount = Self::payout_deduction_rates(&pool_id)
+						.saturating_mul_int(pending_reward)
+						.min(pending_reward);
+					if !deduction_amount.is_zero() {
+						// re-accumulate deduction to rewards pool if deduction amount is not zero
+						<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, deduction_amount);
+					}
+					(pending_reward.saturating_sub(deduction_amount), deduction_amount)
+				};
+
+				// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should
+				// not affect the process, ignore the result to continue. if it fails, just the user will not
+				// be rewarded, there will not increase user balance.
+				T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
+
+				Self::deposit_event(Event::ClaimRewards(
+					who.clone(),
+					pool_id.clone(),
+					currency_id,
+					actual_amount,
+					deduction_amount,
+				));
+			} else {
+				// vesting rewards are not deducted, the unreleased part restarts vesting with the new rewards
+				let now = <frame_system::Pallet<T>>::block_number();
+				let end = now.saturating_add(vesting_period);
+				VestingRewards::<T>::mutate(&pool_id, &who, |maybe_vesting| {
+					let locked = maybe_vesting.map_or(Zero::zero(), |vesting| vesting.locked);
+					*maybe_vesting = Some(VestingReward {
+						locked: locked.saturating_add(pending_reward),
+						start: now,
+						end,
+					});
+				});
+
+				Self::deposit_event(Event::VestingRewardsAdded(
+					who.clone(),
+					pool_id.clone(),
+					currency_id,
+					pending_reward,
+					end,
+				));
+			}
+		}
+
+		// extra rewards are paid out instantly, the deduction is re-accumulated to extra rewards. a
+		// failed payout is kept pending and doesn't block the other rewards, it can be claimed again
+		// later.
+		let deduction_rate = Self::payout_deduction_rates(&pool_id);
+		let mut unpaid_extra_rewards: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+		for (extra_currency_id, pending_extra_reward) in PendingExtraRewards::<T>::take(&pool_id, &who) {
+			let deduction_amount = deduction_rate
+				.saturating_mul_int(pending_extra_reward)
+				.min(pending_extra_reward);
+			let actual_amount = pending_extra_reward.saturating_sub(deduction_amount);
+
+			if T::Currency::transfer(extra_currency_id, &Self::account_id(), &who, actual_amount).is_err() {
+				unpaid_extra_rewards.insert(extra_currency_id, pending_extra_reward);
+				continue;
+			}
+			if !deduction_amount.is_zero() {
+				Self::accumulate_extra_reward(&pool_id, extra_currency_id, deduction_amount);
+			}
+
+			Self::deposit_event(Event::ClaimRewards(
+				who.clone(),
+				pool_id.clone(),
+				extra_currency_id,
+				actual_amount,
+				deduction_amount,
+			));
+		}
+		if !unpaid_extra_rewards.is_empty() {
+			PendingExtraRewards::<T>::insert(&pool_id, &who, unpaid_extra_rewards);
+		}
+
+		Ok(())
+	}
+
+	fn reward_currency_id(pool_id: &PoolId<T::RelaychainAccountId>) -> CurrencyId {
+		match pool_id {
+			PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => T::NativeCurrencyId::get(),
+			PoolId::DexSaving(_) => T::StableCurrencyId::get(),
+			PoolId::HomaValidatorAllowance(_) => T::LiquidCurrencyId::get(),
+		}
+	}
+
+	/// The share of account in the pool without boost.
+	pub fn raw_share(pool_id: &PoolId<T::RelaychainAccountId>, who: &T::AccountId) -> Balance {
+		Self::raw_shares(who, pool_id)
+			.unwrap_or_else(|| <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(pool_id, who).0)
+	}
+
+	/// The escrow power of the boost lock, which decays linearly to zero when the lock expires.
+	pub fn escrow_power(who: &T::AccountId) -> Balance {
+		let now = <frame_system::Pallet<T>>::block_number();
+		match Self::boost_locks(who) {
+			Some(lock) if lock.unlock_at > now => {
+				let remaining: u128 = (lock.unlock_at - now).unique_saturated_into();
+				let max_period: u128 = T::MaxBoostLockPeriod::get().unique_saturated_into();
+				Rate::checked_from_rational(remaining, max_period)
+					.unwrap_or_else(Rate::one)
+					.min(Rate::one())
+					.saturating_mul_int(lock.amount)
+			}
+			_ => Zero::zero(),
+		}
+	}
+
+	/// The multiplier of the raw share in the pool, which is
+	/// `1 + (max_multiplier - 1) * min(1, escrow_power / (escrow_per_share * raw_share))`.
+	pub fn boost_multiplier(pool_id: &PoolId<T::RelaychainAccountId>, who: &T::AccountId, raw_share: Balance) -> Rate {
+		match Self::boost_configs(pool_id) {
+			Some(config) if !raw_share.is_zero() => {
+				let required_power = config.escrow_per_share.saturating_mul_int(raw_share);
+				let boost_ratio = if required_power.is_zero() {
+					Rate::one()
+				} else {
+					Rate::checked_from_rational(Self::escrow_power(who), required_power)
+						.unwrap_or_else(Rate::one)
+						.min(Rate::one())
+				};
+				Rate::one().saturating_add(
+					config
+						.max_multiplier
+						.saturating_sub(Rate::one())
+						.saturating_mul(boost_ratio),
+				)
+			}
+			_ => Rate::one(),
+		}
+	}
+
+	/// Set the raw share of account in the pool, the share in orml rewards is boosted.
+	fn update_share(who: &T::AccountId, pool_id: &PoolId<T::RelaychainAccountId>, raw_share: Balance) {
+		let boosted_share = Self::boost_multiplier(pool_id, who, raw_share).saturating_mul_int(raw_share);
+		if boosted_share > raw_share {
+			RawShares::<T>::insert(who, pool_id, raw_share);
+		} else {
+			RawShares::<T>::remove(who, pool_id);
+		}
+		Self::set_share(who, pool_id, boosted_share);
+	}
+
+	/// Add share to orml rewards, extra rewards are inflated in the same way as orml rewards so
+	/// that the new share does not take the accumulated rewards.
+	fn add_share(who: &T::AccountId, pool_id: &PoolId<T::RelaychainAccountId>, add_amount: Balance) {
+		if add_amount.is_zero() {
+			return;
+		}
+
+		let total_shares = <orml_rewards::Pallet<T>>::pools(pool_id).total_shares;
+		if !total_shares.is_zero() {
+			let mut extra_rewards = Self::extra_reward_pools(pool_id);
+			if !extra_rewards.is_empty() {
+				ExtraWithdrawnRewards::<T>::mutate(pool_id, who, |withdrawn_rewards| {
+					for (currency_id, info) in extra_rewards.iter_mut() {
+						let reward_inflation =
+							multiply_by_rational(info.total_rewards, add_amount, total_shares).unwrap_or_default();
+						info.total_rewards = info.total_rewards.saturating_add(reward_inflation);
+						let withdrawn = Self::withdrawn_extra_reward(withdrawn_rewards, *currency_id, info.generation);
+						*withdrawn = withdrawn.saturating_add(reward_inflation);
+					}
+				});
+				ExtraRewardPools::<T>::insert(pool_id, extra_rewards);
+			}
+		}
+
+		<orml_rewards::Pallet<T>>::add_share(who, pool_id, add_amount);
+	}
+
+	/// Remove share from orml rewards, extra rewards are claimed before removing.
+	fn remove_share(who: &T::AccountId, pool_id: &PoolId<T::RelaychainAccountId>, remove_amount: Balance) {
+		if remove_amount.is_zero() {
+			return;
+		}
+
+		Self::claim_extra_rewards(who, pool_id);
+
+		let share = <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(pool_id, who).0;
+		if !share.is_zero() {
+			let remove_amount = remove_amount.min(share);
+			let mut extra_rewards = Self::extra_reward_pools(pool_id);
+			if !extra_rewards.is_empty() {
+				ExtraWithdrawnRewards::<T>::mutate_exists(pool_id, who, |maybe_withdrawn_rewards| {
+					if let Some(withdrawn_rewards) = maybe_withdrawn_rewards {
+						for (currency_id, (generation, withdrawn)) in withdrawn_rewards.iter_mut() {
+							// the withdrawn rewards of other generations are stale
+							if let Some(info) = extra_rewards
+								.get_mut(currency_id)
+								.filter(|info| info.generation == *generation)
+							{
+								let withdrawn_to_remove =
+									multiply_by_rational(*withdrawn, remove_amount, share).unwrap_or_default();
+								info.total_rewards = info.total_rewards.saturating_sub(withdrawn_to_remove);
+								info.total_withdrawn_rewards =
+									info.total_withdrawn_rewards.saturating_sub(withdrawn_to_remove);
+								*withdrawn = withdrawn.saturating_sub(withdrawn_to_remove);
+							}
+						}
+						if remove_amount == share {
+							*maybe_withdrawn_rewards = None;
+						}
+					}
+				});
+				ExtraRewardPools::<T>::insert(pool_id, extra_rewards);
+			}
+		}
+
+		<orml_rewards::Pallet<T>>::remove_share(who, pool_id, remove_amount);
+	}
+
+	/// Set share in orml rewards.
+	fn set_share(who: &T::AccountId, pool_id: &PoolId<T::RelaychainAccountId>, new_share: Balance) {
+		let share = <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(pool_id, who).0;
+		if new_share > share {
+			Self::add_share(who, pool_id, new_share.saturating_sub(share));
+		} else {
+			Self::remove_share(who, pool_id, share.saturating_sub(new_share));
+		}
+	}
+
+	/// Move the extra rewards of account in pool to pending extra rewards.
+	fn claim_extra_rewards(who: &T::AccountId, pool_id: &PoolId<T::RelaychainAccountId>) {
+		let mut extra_rewards = Self::extra_reward_pools(pool_id);
+		if extra_rewards.is_empty() {
+			return;
+		}
+		let share = <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(pool_id, who).0;
+		if share.is_zero() {
+			return;
+		}
+		let total_shares = <orml_rewards::Pallet<T>>::pools(pool_id).total_shares;
+
+		let mut withdrawn_rewards = Self::extra_withdrawn_rewards(pool_id, who);
+		let mut pending_rewards = Self::pending_extra_rewards(pool_id, who);
+		for (currency_id, info) in extra_rewards.iter_mut() {
+			let withdrawn = Self::withdrawn_extra_reward(&mut withdrawn_rewards, *currency_id, info.generation);
+			let reward_to_withdraw = multiply_by_rational(info.total_rewards, share, total_shares)
+				.unwrap_or_default()
+				.saturating_sub(*withdrawn);
+			if !reward_to_withdraw.is_zero() {
+				info.total_withdrawn_rewards = info.total_withdrawn_rewards.saturating_add(reward_to_withdraw);
+				*withdrawn = withdrawn.saturating_add(reward_to_withdraw);
+				let pending = pending_rewards.entry(*currency_id).or_default();
+				*pending = pending.saturating_add(reward_to_withdraw);
+			}
+		}
+		Self::prune_extra_rewards(&mut extra_rewards);
+		withdrawn_rewards.retain(|currency_id, _| extra_rewards.contains_key(currency_id));
+		ExtraRewardPools::<T>::insert(pool_id, extra_rewards);
+		ExtraWithdrawnRewards::<T>::insert(pool_id, who, withdrawn_rewards);
+		PendingExtraRewards::<T>::insert(pool_id, who, pending_rewards);
+	}
+
+	/// The withdrawn extra reward of the currency in the generation, the stale one recorded in
+	/// another generation is reset.
+	fn withdrawn_extra_reward(
+		withdrawn_rewards: &mut BTreeMap<CurrencyId, (u32, Balance)>,
+		currency_id: CurrencyId,
+		generation: u32,
+	) -> &mut Balance {
+		let (withdrawn_generation, withdrawn) = withdrawn_rewards
+			.entry(currency_id)
+			.or_insert((generation, Zero::zero()));
+		if *withdrawn_generation != generation {
+			*withdrawn_generation = generation;
+			*withdrawn = Zero::zero();
+		}
+		withdrawn
+	}
+
+	/// The extra reward info of the currency newly added to the pool.
+	fn new_extra_reward_info() -> ExtraRewardInfo {
+		let generation = Self::next_extra_reward_generation();
+		NextExtraRewardGeneration::<T>::put(generation.saturating_add(1));
+		ExtraRewardInfo {
+			generation,
+			..Default::default()
+		}
+	}
+
+	/// Remove the extra reward currencies which are not emitted anymore and whose accumulated
+	/// rewards have been withdrawn except the dust.
+	fn prune_extra_rewards(extra_rewards: &mut BTreeMap<CurrencyId, ExtraRewardInfo>) {
+		extra_rewards.retain(|currency_id, info| {
+			!info.sources.is_zero()
+				|| info.total_rewards.saturating_sub(info.total_withdrawn_rewards)
+					>= T::Currency::minimum_balance(*currency_id).max(One::one())
+		});
+	}
+
+	/// Add an emission source of the extra reward currency to the pool, the currency takes a slot
+	/// of `MaxExtraRewardCurrencies` if it is not emitted yet.
+	fn register_extra_reward_currency(
+		pool_id: &PoolId<T::RelaychainAccountId>,
+		currency_id: CurrencyId,
+	) -> DispatchResult {
+		ExtraRewardPools::<T>::try_mutate(pool_id, |rewards| -> DispatchResult {
+			if rewards.get(&currency_id).map_or(true, |info| info.sources.is_zero()) {
+				ensure!(
+					(rewards.values().filter(|info| !info.sources.is_zero()).count() as u32)
+						< T::MaxExtraRewardCurrencies::get(),
+					Error::<T>::TooManyRewardCurrencies
+				);
+			}
+			let info = rewards.entry(currency_id).or_insert_with(Self::new_extra_reward_info);
+			info.sources = info.sources.saturating_add(1);
+			Ok(())
+		})
+	}
+
+	/// Remove an emission source of the extra reward currency from the pool, the slot is released
+	/// when the last source ends.
+	fn release_extra_reward_currency(pool_id: &PoolId<T::RelaychainAccountId>, currency_id: CurrencyId) {
+		ExtraRewardPools::<T>::mutate_exists(pool_id, |maybe_rewards| {
+			if let Some(rewards) = maybe_rewards {
+				if let Some(info) = rewards.get_mut(&currency_id) {
+					info.sources = info.sources.saturating_sub(1);
+				}
+				Self::prune_extra_rewards(rewards);
+				if rewards.is_empty() {
+					*maybe_rewards = None;
+				}
+			}
+		});
+	}
+
+	fn accumulate_extra_reward(pool_id: &PoolId<T::RelaychainAccountId>, currency_id: CurrencyId, amount: Balance) {
+		ExtraRewardPools::<T>::mutate(pool_id, |rewards| {
+			let info = rewards.entry(currency_id).or_insert_with(Self::new_extra_reward_info);
+			info.total_rewards = info.total_rewards.saturating_add(amount);
+		});
+	}
+
+	/// The unclaimed rewards of account in pool. The deduction is excluded unless the rewards of
+	/// the pool are vesting.
+	pub fn pending_rewards_of(
+		pool_id: &PoolId<T::RelaychainAccountId>,
+		who: &T::AccountId,
+	) -> Vec<(CurrencyId, Balance)> {
+		let total_shares = <orml_rewards::Pallet<T>>::pools(pool_id).total_shares;
+		let (share, withdrawn_reward) = <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(pool_id, who);
+		let unclaimed = |total_rewards: Balance, withdrawn: Balance| -> Balance {
+			if share.is_zero() {
+				Zero::zero()
+			} else {
+				multiply_by_rational(total_rewards, share, total_shares)
+					.unwrap_or_default()
+					.saturating_sub(withdrawn)
+			}
+		};
+		let deduction_rate = Self::payout_deduction_rates(pool_id);
+		let deduct = |amount: Balance| -> Balance {
+			amount.saturating_sub(deduction_rate.saturating_mul_int(amount).min(amount))
+		};
+
+		let mut rewards = Vec::new();
+		let pending_reward = unclaimed(
+			<orml_rewards::Pallet<T>>::pools(pool_id).total_rewards,
+			withdrawn_reward,
+		)
+		.saturating_add(Self::pending_rewards(pool_id, who));
+		let pending_reward = if Self::reward_vesting_periods(pool_id).is_zero() {
+			deduct(pending_reward)
+		} else {
+			pending_reward
+		};
+		if !pending_reward.is_zero() {
+			rewards.push((Self::reward_currency_id(pool_id), pending_reward));
+		}
+
+		let withdrawn_extra_rewards = Self::extra_withdrawn_rewards(pool_id, who);
+		let pending_extra_rewards = Self::pending_extra_rewards(pool_id, who);
+		for (currency_id, info) in Self::extra_reward_pools(pool_id) {
+			let withdrawn_extra_reward = withdrawn_extra_rewards
+				.get(&currency_id)
+				.filter(|(generation, _)| *generation == info.generation)
+				.map_or(Zero::zero(), |(_, withdrawn)| *withdrawn);
+			let pending_extra_reward = unclaimed(info.total_rewards, withdrawn_extra_reward)
+				.saturating_add(pending_extra_rewards.get(&currency_id).copied().unwrap_or_default());
+			let pending_extra_reward = deduct(pending_extra_reward);
+			if !pending_extra_reward.is_zero() {
+				rewards.push((currency_id, pending_extra_reward));
+			}
+		}
+
+		rewards
+	}
+
+	/// The rewards accumulated to the pool in the next period, including the extra rewards and
+	/// the emission of campaigns.
+	pub fn pool_emission_per_period(pool_id: &PoolId<T::RelaychainAccountId>) -> Vec<(CurrencyId, Balance)> {
+		let mut emission: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+		let mut add_emission = |currency_id: CurrencyId, amount: Balance| {
+			if !amount.is_zero() {
+				let total = emission.entry(currency_id).or_default();
+				*total = total.saturating_add(amount);
+			}
+		};
+
+		match pool_id {
+			PoolId::LoansIncentive(_) | PoolId::DexIncentive(_) | PoolId::HomaIncentive => {
+				add_emission(T::NativeCurrencyId::get(), Self::incentive_reward_amount(pool_id));
+				for (currency_id, amount) in ExtraIncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+					add_emission(currency_id, amount.min(Self::extra_reward_funds(pool_id, currency_id)));
+				}
+			}
+			PoolId::DexSaving(lp_currency_id) => {
+				add_emission(
+					T::StableCurrencyId::get(),
+					Self::dex_saving_reward_amount(*lp_currency_id, Self::dex_saving_reward_rate(pool_id)),
+				);
+			}
+			PoolId::HomaValidatorAllowance(_) => {}
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let next = now.saturating_add(T::AccumulatePeriod::get());
+		for campaign in Campaigns::<T>::iter_values().filter(|campaign| campaign.pool_id == *pool_id) {
+			let amount = Self::campaign_emission(&campaign, next)
+				.saturating_sub(Self::campaign_emission(&campaign, now))
+				.min(campaign.remaining);
+			add_emission(campaign.currency_id, amount);
+		}
+
+		emission.into_iter().collect()
+	}
+
+	/// The estimated APR of pool, which is the value of rewards emitted in a year divided by the
+	/// value of total shares. As boosted shares are counted, it is the APR of unboosted share.
+	/// Returns `None` if the prices are unavailable or the pool has no shares.
+	pub fn pool_apr(pool_id: &PoolId<T::RelaychainAccountId>) -> Option<Rate> {
+		let total_shares = <orml_rewards::Pallet<T>>::pools(pool_id).total_shares;
+		let shares_value = match pool_id {
+			PoolId::DexIncentive(lp_currency_id) | PoolId::DexSaving(lp_currency_id) => {
+				T::PriceSource::get_price(*lp_currency_id)?.saturating_mul_int(total_shares)
+			}
+			PoolId::LoansIncentive(currency_id) => {
+				let debit_value = T::DebitExchangeRate::get(currency_id).saturating_mul_int(total_shares);
+				T::PriceSource::get_price(T::StableCurrencyId::get())?.saturating_mul_int(debit_value)
+			}
+			PoolId::HomaValidatorAllowance(_) => {
+				T::PriceSource::get_price(T::LiquidCurrencyId::get())?.saturating_mul_int(total_shares)
+			}
+			PoolId::HomaIncentive => return None,
+		};
+
+		let mut emission_value: Balance = Zero::zero();
+		for (currency_id, amount) in Self::pool_emission_per_period(pool_id) {
+			emission_value =
+				emission_value.saturating_add(T::PriceSource::get_price(currency_id)?.saturating_mul_int(amount));
+		}
+		let periods_per_year: u128 = (T::BlocksPerYear::get() / T::AccumulatePeriod::get()).unique_saturated_into();
+
+		Rate::checked_from_rational(emission_value.saturating_mul(periods_per_year), shares_value)
+	}
+
+	/// The saving reward amount of the period for dex liquidity pool of stable currency.
+	fn dex_saving_reward_amount(lp_currency_id: CurrencyId, dex_saving_reward_rate: Rate) -> Balance {
+		let stable_currency_id = T::StableCurrencyId::get();
+		if dex_saving_reward_rate.is_zero() {
+			return Zero::zero();
+		}
+
+		if let Some((currency_id_a, currency_id_b)) = lp_currency_id.split_dex_share_currency_id() {
+			// accumulate saving reward only for liquidity pool of stable currency id
+			let dex_saving_reward_base = if currency_id_a == stable_currency_id {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
+			} else if currency_id_b == stable_currency_id {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
+			} else {
+				Zero::zero()
+			};
+			dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base)
+		} else {
+			Zero::zero()
+		}
+	}
+
+	/// The emission of campaign from the start block to `now` following the emission curve.
+	pub fn campaign_emission(
+		campaign: &Campaign<T::AccountId, T::RelaychainAccountId, T::BlockNumber>,
+		now: T::BlockNumber,
+	) -> Balance {
+		let duration: u128 = campaign.end.saturating_sub(campaign.start).unique_saturated_into();
+		let elapsed: u128 = now
+			.min(campaign.end)
+			.saturating_sub(campaign.start)
+			.unique_saturated_into();
+
+		let emission = match campaign.emission {
+			EmissionCurve::Constant => multiply_by_rational(campaign.budget, elapsed, duration),
+			// budget * elapsed * (2 * duration - elapsed) / duration^2
+			EmissionCurve::LinearDecay => multiply_by_rational(campaign.budget, elapsed, duration)
+				.and_then(|v| multiply_by_rational(v, duration.saturating_mul(2).saturating_sub(elapsed), duration)),
+		};
+		emission.unwrap_or_default().min(campaign.budget)
+	}
+
+	/// Accumulate the emission of campaigns to pools, return the count of running campaigns.
+	fn accumulate_campaign_rewards(now: T::BlockNumber) -> u32 {
+		let mut count: u32 = 0;
+		for (campaign_id, mut campaign) in Campaigns::<T>::iter() {
+			if now <= campaign.start || campaign.scheduled >= campaign.budget {
+				continue;
+			}
+			count += 1;
+
+			let scheduled = Self::campaign_emission(&campaign, now);
+			let amount = scheduled.saturating_sub(campaign.scheduled).min(campaign.remaining);
+			campaign.scheduled = scheduled;
+
+			// skip the emission if the pool has no shares, it will be refunded
+			if !amount.is_zero()
+				&& !<orml_rewards::Pallet<T>>::pools(&campaign.pool_id)
+					.total_shares
+					.is_zero()
+			{
+				campaign.remaining = campaign.remaining.saturating_sub(amount);
+				if campaign.currency_id == Self::reward_currency_id(&campaign.pool_id) {
+					<orml_rewards::Pallet<T>>::accumulate_reward(&campaign.pool_id, amount);
+				} else {
+					Self::accumulate_extra_reward(&campaign.pool_id, campaign.currency_id, amount);
+				}
+			}
+			Campaigns::<T>::insert(campaign_id, campaign);
+		}
+		count
+	}
+
+	/// Accumulate extra rewards of the period from funds, return the count of extra reward
+	/// currencies.
+	fn accumulate_extra_rewards(pool_id: &PoolId<T::RelaychainAccountId>) -> u32 {
+		let mut count: u32 = 0;
+		for (currency_id, amount) in ExtraIncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+			count += 1;
+			let fund = Self::extra_reward_funds(pool_id, currency_id);
+			let reward_amount = amount.min(fund);
+			if !reward_amount.is_zero() {
+				ExtraRewardFunds::<T>::insert(pool_id, currency_id, fund.saturating_sub(reward_amount));
+				Self::accumulate_extra_reward(pool_id, currency_id, reward_amount);
+			}
+		}
+		count
+	}
+
+	/// Transfer the released part of vesting rewards to the account.
+	fn release_vesting_rewards(
+		who: &T::AccountId,
+		pool_id: &PoolId<T::RelaychainAccountId>,
+		currency_id: CurrencyId,
+	) -> DispatchResult {
+		if let Some(mut vesting) = Self::vesting_rewards(pool_id, who) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let released = if now >= vesting.end {
+				vesting.locked
+			} else if now <= vesting.start {
+				Zero::zero()
+			} else {
+				let elapsed: u128 = (now - vesting.start).unique_saturated_into();
+				let duration: u128 = (vesting.end - vesting.start).unique_saturated_into();
+				Rate::checked_from_rational(elapsed, duration)
+					.unwrap_or_default()
+					.saturating_mul_int(vesting.locked)
+			};
+
+			if !released.is_zero() {
+				vesting.locked = vesting.locked.saturating_sub(released);
+				vesting.start = now;
+				if vesting.locked.is_zero() {
+					VestingRewards::<T>::remove(pool_id, who);
+				} else {
+					VestingRewards::<T>::insert(pool_id, who, vesting);
+				}
+
+				T::Currency::transfer(currency_id, &Self::account_id(), who, released)?;
+				Self::deposit_event(Event::ClaimRewards(
+					who.clone(),
+					pool_id.clone(),
+					currency_id,
+					released,
+					Zero::zero(),
+				));
+			}
+		}
+		Ok(())
+	}
 }
 
 impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
@@ -495,12 +1734,13 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
 
 		T::Currency::transfer(lp_currency_id, who, &Self::account_id(), amount)?;
-		<orml_rewards::Pallet<T>>::add_share(
+		let incentive_pool_id = PoolId::DexIncentive(lp_currency_id);
+		Self::update_share(
 			who,
-			&PoolId::DexIncentive(lp_currency_id),
-			amount.unique_saturated_into(),
+			&incentive_pool_id,
+			Self::raw_share(&incentive_pool_id, who).saturating_add(amount),
 		);
-		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::DexSaving(lp_currency_id), amount);
+		Self::add_share(who, &PoolId::DexSaving(lp_currency_id), amount);
 
 		Self::deposit_event(Event::DepositDexShare(who.clone(), lp_currency_id, amount));
 		Ok(())
@@ -508,50 +1748,61 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 	fn do_withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+		let incentive_pool_id = PoolId::DexIncentive(lp_currency_id);
+		let incentive_raw_share = Self::raw_share(&incentive_pool_id, who);
 		ensure!(
-			<orml_rewards::Pallet<T>>::share_and_withdrawn_reward(&PoolId::DexIncentive(lp_currency_id), &who).0
-				>= amount && <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(
-				&PoolId::DexSaving(lp_currency_id),
-				&who
-			)
-			.0 >= amount,
+			incentive_raw_share >= amount
+				&& <orml_rewards::Pallet<T>>::share_and_withdrawn_reward(&PoolId::DexSaving(lp_currency_id), &who).0
+					>= amount,
 			Error::<T>::NotEnough,
 		);
 
 		T::Currency::transfer(lp_currency_id, &Self::account_id(), who, amount)?;
-		<orml_rewards::Pallet<T>>::remove_share(
-			who,
-			&PoolId::DexIncentive(lp_currency_id),
-			amount.unique_saturated_into(),
-		);
-		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::DexSaving(lp_currency_id), amount);
+		Self::update_share(who, &incentive_pool_id, incentive_raw_share.saturating_sub(amount));
+		Self::remove_share(who, &PoolId::DexSaving(lp_currency_id), amount);
 
 		Self::deposit_event(Event::WithdrawDexShare(who.clone(), lp_currency_id, amount));
 		Ok(())
 	}
 }
 
+impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId<T::RelaychainAccountId>> for Pallet<T> {
+	fn deposit_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Self::do_deposit_dex_share(who, lp_currency_id, amount)
+	}
+
+	fn withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Self::do_withdraw_dex_share(who, lp_currency_id, amount)
+	}
+
+	#[transactional]
+	fn claim_rewards(who: T::AccountId, pool_id: PoolId<T::RelaychainAccountId>) -> DispatchResult {
+		Self::do_claim_rewards(who, pool_id)
+	}
+
+	fn get_pending_rewards(pool_id: PoolId<T::RelaychainAccountId>, who: T::AccountId) -> Vec<(CurrencyId, Balance)> {
+		Self::pending_rewards_of(&pool_id, &who)
+	}
+}
+
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpdateLoan<T> {
 	fn happened(info: &(T::AccountId, CurrencyId, Amount, Balance)) {
 		let (who, currency_id, adjustment, previous_amount) = info;
 		let pool_id: PoolId<T::RelaychainAccountId> = PoolId::LoansIncentive(*currency_id);
 
-		// Only when a pool has incentive, shares will be updated.
-		// If the incentive has not been opened before, users did something that should update share after
-		// opening the incentive, TODO: do Pool::LoansIncentive(KSM) migration
-		if !Pallet::<T>::incentive_reward_amount(&pool_id).is_zero() {
-			let adjustment_abs =
-				sp_std::convert::TryInto::<Balance>::try_into(adjustment.saturating_abs()).unwrap_or_default();
+		// shares are always updated, the pool may carry extra rewards or campaigns without
+		// native incentive.
+		let adjustment_abs =
+			sp_std::convert::TryInto::<Balance>::try_into(adjustment.saturating_abs()).unwrap_or_default();
 
-			let new_share_amount = if adjustment.is_positive() {
-				previous_amount.saturating_add(adjustment_abs)
-			} else {
-				previous_amount.saturating_sub(adjustment_abs)
-			};
+		let new_share_amount = if adjustment.is_positive() {
+			previous_amount.saturating_add(adjustment_abs)
+		} else {
+			previous_amount.saturating_sub(adjustment_abs)
+		};
 
-			<orml_rewards::Pallet<T>>::set_share(who, &PoolId::LoansIncentive(*currency_id), new_share_amount);
-		}
+		Pallet::<T>::update_share(who, &pool_id, new_share_amount);
 	}
 }
 
@@ -559,7 +1810,7 @@ pub struct OnIncreaseGuarantee<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, T::RelaychainAccountId, Balance)> for OnIncreaseGuarantee<T> {
 	fn happened(info: &(T::AccountId, T::RelaychainAccountId, Balance)) {
 		let (who, validator, increment) = info;
-		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *increment);
+		Pallet::<T>::add_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *increment);
 	}
 }
 
@@ -567,7 +1818,7 @@ pub struct OnDecreaseGuarantee<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, T::RelaychainAccountId, Balance)> for OnDecreaseGuarantee<T> {
 	fn happened(info: &(T::AccountId, T::RelaychainAccountId, Balance)) {
 		let (who, validator, decrement) = info;
-		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *decrement);
+		Pallet::<T>::remove_share(who, &PoolId::HomaValidatorAllowance(validator.clone()), *decrement);
 	}
 }
 
@@ -32,7 +32,7 @@ use primitives::{DexShare, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
 use sp_std::cell::RefCell;
-pub use support::{CDPTreasury, DEXManager, Price, Ratio};
+pub use support::{CDPTreasury, DEXManager, ExchangeRate, Price, Ratio};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -149,6 +149,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
 		unimplemented!()
 	}
@@ -182,6 +186,26 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_price_swap_path_with_exact_supply(
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		unimplemented!()
+	}
+
+	fn get_best_price_swap_path_with_exact_target(
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		unimplemented!()
+	}
+
+	fn get_time_weighted_average_price(_: CurrencyId, _: CurrencyId, _: u32) -> Option<ExchangeRate> {
+		unimplemented!()
+	}
+
 	fn swap_with_exact_supply(
 		_: &AccountId,
 		_: &[CurrencyId],
@@ -200,6 +224,16 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		_: &AccountId,
+		_: &[CurrencyId],
+		_: Balance,
+		_: Balance,
+		_: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
 	fn add_liquidity(
 		_: &AccountId,
 		_: CurrencyId,
@@ -240,6 +274,35 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			ACA => Some(Price::saturating_from_rational(1, 2)),
+			AUSD => Some(Price::one()),
+			DOT => Some(Price::saturating_from_integer(10)),
+			BTC_AUSD_LP => Some(Price::saturating_from_integer(2)),
+			_ => None,
+		}
+	}
+}
+
+parameter_type_with_key! {
+	pub DebitExchangeRate: |_currency_id: CurrencyId| -> ExchangeRate {
+		ExchangeRate::saturating_from_rational(1, 10)
+	};
+}
+
+parameter_type_with_key! {
+	pub MinCampaignBudget: |currency_id: CurrencyId| -> Option<Balance> {
+		if *currency_id == BTC {
+			None
+		} else {
+			Some(50)
+		}
+	};
+}
+
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
@@ -254,6 +317,12 @@ parameter_types! {
 	pub const StableCurrencyId: CurrencyId = AUSD;
 	pub const LiquidCurrencyId: CurrencyId = LDOT;
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const MaxExtraRewardCurrencies: u32 = 2;
+	pub const BlocksPerYear: BlockNumber = 1000;
+	pub const MaxCampaigns: u32 = 2;
+	pub const MaxCampaignDuration: BlockNumber = 100;
+	pub const CampaignDeposit: Balance = 10;
+	pub const MaxBoostLockPeriod: BlockNumber = 1000;
 }
 
 ord_parameter_types! {
@@ -273,7 +342,16 @@ impl Config for Runtime {
 	type Currency = TokensModule;
 	type DEX = MockDEX;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type PriceSource = MockPriceSource;
+	type DebitExchangeRate = DebitExchangeRate;
+	type BlocksPerYear = BlocksPerYear;
 	type PalletId = IncentivesPalletId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	type MaxCampaigns = MaxCampaigns;
+	type MinCampaignBudget = MinCampaignBudget;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type CampaignDeposit = CampaignDeposit;
+	type MaxBoostLockPeriod = MaxBoostLockPeriod;
 	type WeightInfo = ();
 }
 
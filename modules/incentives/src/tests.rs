@@ -400,26 +400,22 @@ fn on_update_loan_works() {
 			(0, 0)
 		);
 
-		// will not update shares when LoansIncentives type pool without incentive
+		// update shares even if the LoansIncentive pool has no native incentive, so that extra
+		// rewards and campaigns of the pool are distributed to all the loans
 		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC, 100, 0));
 		assert_eq!(
 			RewardsModule::pools(PoolId::LoansIncentive(BTC)),
 			PoolInfo {
-				total_shares: 0,
+				total_shares: 100,
 				total_rewards: 0,
 				total_withdrawn_rewards: 0
 			}
 		);
 		assert_eq!(
 			RewardsModule::share_and_withdrawn_reward(PoolId::LoansIncentive(BTC), ALICE::get()),
-			(0, 0)
+			(100, 0)
 		);
 
-		assert_ok!(IncentivesModule::update_incentive_rewards(
-			Origin::signed(Root::get()),
-			vec![(PoolId::LoansIncentive(BTC), 1000),],
-		));
-
 		// share will be updated even if the adjustment is zero
 		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC, 0, 100));
 		assert_eq!(
@@ -972,3 +968,1127 @@ fn on_initialize_should_work() {
 		assert_eq!(RewardsModule::pools(PoolId::DexSaving(DOT_AUSD_LP)).total_rewards, 8);
 	});
 }
+
+#[test]
+fn update_boost_configs_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let config = BoostConfig {
+			max_multiplier: Rate::saturating_from_rational(25, 10),
+			escrow_per_share: Rate::one(),
+		};
+		assert_noop!(
+			IncentivesModule::update_boost_configs(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_boost_configs(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(DOT), Some(config))]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::update_boost_configs(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexSaving(DOT_AUSD_LP), Some(config))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_boost_configs(
+				Origin::signed(Root::get()),
+				vec![(
+					PoolId::DexIncentive(DOT_AUSD_LP),
+					Some(BoostConfig {
+						max_multiplier: Rate::saturating_from_rational(9, 10),
+						escrow_per_share: Rate::one(),
+					})
+				)]
+			),
+			Error::<Runtime>::InvalidBoostConfig
+		);
+
+		assert_eq!(IncentivesModule::boost_configs(PoolId::DexIncentive(DOT_AUSD_LP)), None);
+		assert_ok!(IncentivesModule::update_boost_configs(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(DOT_AUSD_LP), Some(config))]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostConfigUpdated(
+			PoolId::DexIncentive(DOT_AUSD_LP),
+			Some(config),
+		)));
+		assert_eq!(
+			IncentivesModule::boost_configs(PoolId::DexIncentive(DOT_AUSD_LP)),
+			Some(config)
+		);
+
+		assert_ok!(IncentivesModule::update_boost_configs(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexIncentive(DOT_AUSD_LP), None)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostConfigUpdated(
+			PoolId::DexIncentive(DOT_AUSD_LP),
+			None,
+		)));
+		assert_eq!(IncentivesModule::boost_configs(PoolId::DexIncentive(DOT_AUSD_LP)), None);
+	});
+}
+
+#[test]
+fn update_reward_vesting_periods_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_reward_vesting_periods(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_vesting_periods(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexSaving(DOT), 100)]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		assert_eq!(IncentivesModule::reward_vesting_periods(PoolId::LoansIncentive(BTC)), 0);
+		assert_ok!(IncentivesModule::update_reward_vesting_periods(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), 100)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardVestingPeriodUpdated(
+			PoolId::LoansIncentive(BTC),
+			100,
+		)));
+		assert_eq!(
+			IncentivesModule::reward_vesting_periods(PoolId::LoansIncentive(BTC)),
+			100
+		);
+	});
+}
+
+#[test]
+fn lock_for_boost_and_unlock_boost_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 1000));
+
+		assert_noop!(
+			IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 500, 0),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_noop!(
+			IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 500, 1001),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_noop!(
+			IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 0, 100),
+			Error::<Runtime>::ZeroLockAmount
+		);
+		assert_noop!(
+			IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 1001, 100),
+			Error::<Runtime>::NotEnough
+		);
+
+		assert_ok!(IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 500, 100));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostLocked(
+			ALICE::get(),
+			500,
+			101,
+		)));
+		assert_eq!(
+			IncentivesModule::boost_locks(ALICE::get()),
+			Some(BoostLock {
+				amount: 500,
+				unlock_at: 101
+			})
+		);
+		assert_eq!(TokensModule::accounts(ALICE::get(), ACA).frozen, 500);
+		assert_eq!(IncentivesModule::escrow_power(&ALICE::get()), 50);
+
+		// cannot shorten the lock
+		assert_noop!(
+			IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 300, 50),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_ok!(IncentivesModule::lock_for_boost(Origin::signed(ALICE::get()), 300, 200));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostLocked(
+			ALICE::get(),
+			800,
+			201,
+		)));
+		assert_eq!(TokensModule::accounts(ALICE::get(), ACA).frozen, 800);
+		assert_eq!(IncentivesModule::escrow_power(&ALICE::get()), 160);
+
+		System::set_block_number(101);
+		assert_eq!(IncentivesModule::escrow_power(&ALICE::get()), 80);
+		assert_noop!(
+			IncentivesModule::unlock_boost(Origin::signed(ALICE::get())),
+			Error::<Runtime>::BoostLockNotExpired
+		);
+
+		System::set_block_number(201);
+		assert_eq!(IncentivesModule::escrow_power(&ALICE::get()), 0);
+		assert_ok!(IncentivesModule::unlock_boost(Origin::signed(ALICE::get())));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostUnlocked(ALICE::get(), 800)));
+		assert_eq!(IncentivesModule::boost_locks(ALICE::get()), None);
+		assert_eq!(TokensModule::accounts(ALICE::get(), ACA).frozen, 0);
+		assert_noop!(
+			IncentivesModule::unlock_boost(Origin::signed(ALICE::get())),
+			Error::<Runtime>::BoostLockNotFound
+		);
+	});
+}
+
+#[test]
+fn boost_share_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(IncentivesModule::update_boost_configs(
+			Origin::signed(Root::get()),
+			vec![(
+				pool_id.clone(),
+				Some(BoostConfig {
+					max_multiplier: Rate::saturating_from_rational(25, 10),
+					escrow_per_share: Rate::one(),
+				})
+			)]
+		));
+		assert_ok!(IncentivesModule::lock_for_boost(
+			Origin::signed(ALICE::get()),
+			1000,
+			1000
+		));
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(IncentivesModule::raw_shares(ALICE::get(), pool_id.clone()), Some(100));
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(pool_id.clone(), ALICE::get()),
+			(250, 0)
+		);
+		// dex saving is not boosted
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(PoolId::DexSaving(BTC_AUSD_LP), ALICE::get()),
+			(100, 0)
+		);
+
+		// withdraw is limited by the raw share
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(Origin::signed(ALICE::get()), BTC_AUSD_LP, 101),
+			Error::<Runtime>::NotEnough
+		);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			50
+		));
+		assert_eq!(IncentivesModule::raw_shares(ALICE::get(), pool_id.clone()), Some(50));
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(pool_id.clone(), ALICE::get()),
+			(125, 0)
+		);
+
+		// escrow power decays, the boost is updated by refresh
+		System::set_block_number(976);
+		assert_eq!(IncentivesModule::escrow_power(&ALICE::get()), 25);
+		assert_eq!(
+			IncentivesModule::boost_multiplier(&pool_id, &ALICE::get(), 50),
+			Rate::saturating_from_rational(175, 100)
+		);
+		assert_ok!(IncentivesModule::refresh_boost(
+			Origin::signed(BOB::get()),
+			ALICE::get(),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::BoostUpdated(
+			ALICE::get(),
+			pool_id.clone(),
+			50,
+			87,
+		)));
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(pool_id.clone(), ALICE::get()),
+			(87, 0)
+		);
+
+		// unlock removes all boosts
+		System::set_block_number(1001);
+		assert_ok!(IncentivesModule::unlock_boost(Origin::signed(ALICE::get())));
+		assert_eq!(IncentivesModule::raw_shares(ALICE::get(), pool_id.clone()), None);
+		assert_eq!(
+			RewardsModule::share_and_withdrawn_reward(pool_id.clone(), ALICE::get()),
+			(50, 0)
+		);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			50
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 100);
+	});
+}
+
+#[test]
+fn claim_rewards_with_vesting_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::LoansIncentive(BTC);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_payout_deduction_rates(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), Rate::saturating_from_rational(50, 100))]
+		));
+		assert_ok!(IncentivesModule::update_reward_vesting_periods(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), 100)]
+		));
+
+		RewardsModule::add_share(&ALICE::get(), &pool_id, 100);
+		RewardsModule::accumulate_reward(&pool_id, 1000);
+
+		// rewards are vesting instead of deducted
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::VestingRewardsAdded(
+			ALICE::get(),
+			pool_id.clone(),
+			ACA,
+			1000,
+			101,
+		)));
+		assert_eq!(
+			IncentivesModule::vesting_rewards(pool_id.clone(), ALICE::get()),
+			Some(VestingReward {
+				locked: 1000,
+				start: 1,
+				end: 101
+			})
+		);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_eq!(RewardsModule::pools(pool_id.clone()).total_rewards, 1000);
+
+		System::set_block_number(51);
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			pool_id.clone(),
+			ACA,
+			500,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 500);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9500);
+
+		// new rewards restart vesting together with the unreleased rewards
+		RewardsModule::accumulate_reward(&pool_id, 1000);
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::VestingRewardsAdded(
+			ALICE::get(),
+			pool_id.clone(),
+			ACA,
+			1000,
+			151,
+		)));
+		assert_eq!(
+			IncentivesModule::vesting_rewards(pool_id.clone(), ALICE::get()),
+			Some(VestingReward {
+				locked: 1500,
+				start: 51,
+				end: 151
+			})
+		);
+
+		System::set_block_number(200);
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			pool_id.clone(),
+			ACA,
+			1500,
+			0,
+		)));
+		assert_eq!(IncentivesModule::vesting_rewards(pool_id, ALICE::get()), None);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 2000);
+	});
+}
+
+#[test]
+fn update_extra_incentive_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_extra_incentive_rewards(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_extra_incentive_rewards(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexSaving(BTC_AUSD_LP), DOT, 100)]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_extra_incentive_rewards(
+				Origin::signed(Root::get()),
+				vec![(PoolId::DexIncentive(DOT), DOT, 100)]
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			IncentivesModule::update_extra_incentive_rewards(
+				Origin::signed(Root::get()),
+				vec![(PoolId::LoansIncentive(BTC), ACA, 100)]
+			),
+			Error::<Runtime>::InvalidRewardCurrency
+		);
+
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![
+				(PoolId::LoansIncentive(BTC), DOT, 100),
+				(PoolId::LoansIncentive(BTC), AUSD, 200)
+			]
+		));
+		System::assert_last_event(Event::IncentivesModule(
+			crate::Event::ExtraIncentiveRewardAmountUpdated(PoolId::LoansIncentive(BTC), AUSD, 200),
+		));
+		assert_eq!(
+			IncentivesModule::extra_incentive_reward_amounts(PoolId::LoansIncentive(BTC), DOT),
+			100
+		);
+		assert_eq!(
+			IncentivesModule::extra_incentive_reward_amounts(PoolId::LoansIncentive(BTC), AUSD),
+			200
+		);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(PoolId::LoansIncentive(BTC)).len(),
+			2
+		);
+		assert_noop!(
+			IncentivesModule::update_extra_incentive_rewards(
+				Origin::signed(Root::get()),
+				vec![(PoolId::LoansIncentive(BTC), LDOT, 100)]
+			),
+			Error::<Runtime>::TooManyRewardCurrencies
+		);
+
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), DOT, 0)]
+		));
+		assert_eq!(
+			IncentivesModule::extra_incentive_reward_amounts(PoolId::LoansIncentive(BTC), DOT),
+			0
+		);
+		// the slot is released as there are no accumulated rewards to claim
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(PoolId::LoansIncentive(BTC)).len(),
+			1
+		);
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), LDOT, 100)]
+		));
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(PoolId::LoansIncentive(BTC)).get(&LDOT),
+			Some(&ExtraRewardInfo {
+				total_rewards: 0,
+				total_withdrawn_rewards: 0,
+				sources: 1,
+				generation: 2,
+			})
+		);
+	});
+}
+
+#[test]
+fn fund_extra_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_noop!(
+			IncentivesModule::fund_extra_rewards(Origin::signed(BOB::get()), PoolId::LoansIncentive(BTC), DOT, 100),
+			Error::<Runtime>::InvalidRewardCurrency
+		);
+
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::LoansIncentive(BTC), DOT, 100)]
+		));
+		assert_ok!(IncentivesModule::fund_extra_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::LoansIncentive(BTC),
+			DOT,
+			300
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ExtraRewardsFunded(
+			BOB::get(),
+			PoolId::LoansIncentive(BTC),
+			DOT,
+			300,
+		)));
+		assert_eq!(
+			IncentivesModule::extra_reward_funds(PoolId::LoansIncentive(BTC), DOT),
+			300
+		);
+		assert_eq!(TokensModule::free_balance(DOT, &BOB::get()), 700);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 300);
+	});
+}
+
+#[test]
+fn extra_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 100));
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), DOT, 100)]
+		));
+		assert_ok!(IncentivesModule::fund_extra_rewards(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			250
+		));
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone()).get(&DOT),
+			Some(&ExtraRewardInfo {
+				total_rewards: 100,
+				total_withdrawn_rewards: 0,
+				sources: 1,
+				generation: 0,
+			})
+		);
+		assert_eq!(IncentivesModule::extra_reward_funds(pool_id.clone(), DOT), 150);
+
+		// bob deposits after rewards accumulated, which does not take the accumulated rewards
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone()).get(&DOT),
+			Some(&ExtraRewardInfo {
+				total_rewards: 200,
+				total_withdrawn_rewards: 0,
+				sources: 1,
+				generation: 0,
+			})
+		);
+		assert_eq!(
+			IncentivesModule::extra_withdrawn_rewards(pool_id.clone(), BOB::get()).get(&DOT),
+			Some(&(0, 100))
+		);
+
+		// accumulate until the fund is exhausted
+		IncentivesModule::on_initialize(20);
+		IncentivesModule::on_initialize(30);
+		IncentivesModule::on_initialize(40);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone()).get(&DOT),
+			Some(&ExtraRewardInfo {
+				total_rewards: 350,
+				total_withdrawn_rewards: 0,
+				sources: 1,
+				generation: 0,
+			})
+		);
+		assert_eq!(IncentivesModule::extra_reward_funds(pool_id.clone(), DOT), 0);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			pool_id.clone(),
+			DOT,
+			175,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 175);
+
+		// remove share settles the extra rewards
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(
+			IncentivesModule::pending_extra_rewards(pool_id.clone(), BOB::get()).get(&DOT),
+			Some(&75)
+		);
+		assert!(IncentivesModule::extra_withdrawn_rewards(pool_id.clone(), BOB::get()).is_empty());
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone()).get(&DOT),
+			Some(&ExtraRewardInfo {
+				total_rewards: 175,
+				total_withdrawn_rewards: 75,
+				sources: 1,
+				generation: 0,
+			})
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			BOB::get(),
+			pool_id,
+			DOT,
+			75,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(DOT, &BOB::get()), 825);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 0);
+	});
+}
+
+#[test]
+fn claim_rewards_keeps_failed_extra_rewards_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 100));
+		assert_ok!(TokensModule::deposit(DOT, &VAULT::get(), 50));
+		PendingRewards::<Runtime>::insert(&pool_id, ALICE::get(), 100);
+		PendingExtraRewards::<Runtime>::insert(&pool_id, ALICE::get(), {
+			let mut pending = BTreeMap::new();
+			pending.insert(DOT, 50);
+			pending.insert(LDOT, 30);
+			pending
+		});
+
+		// the vault has no LDOT, the LDOT payout fails but doesn't block the other rewards
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 50);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 0);
+		assert_eq!(IncentivesModule::pending_rewards(&pool_id, ALICE::get()), 0);
+		assert_eq!(
+			IncentivesModule::pending_extra_rewards(&pool_id, ALICE::get())
+				.into_iter()
+				.collect::<Vec<_>>(),
+			vec![(LDOT, 30)]
+		);
+
+		// the failed payout can be claimed again later
+		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 30));
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			pool_id.clone(),
+			LDOT,
+			30,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 30);
+		assert!(IncentivesModule::pending_extra_rewards(&pool_id, ALICE::get()).is_empty());
+	});
+}
+
+#[test]
+fn create_campaign_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 20));
+
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				PoolId::DexSaving(BTC_AUSD_LP),
+				DOT,
+				500,
+				5,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				DOT,
+				0,
+				5,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidCampaign
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				DOT,
+				500,
+				0,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidCampaign
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				DOT,
+				500,
+				5,
+				5,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidCampaign
+		);
+		// the currency is not accepted as campaign reward
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				BTC,
+				500,
+				5,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidRewardCurrency
+		);
+		// budget is less than the min budget
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				DOT,
+				40,
+				5,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidCampaign
+		);
+		// duration exceeds the max duration
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id.clone(),
+				DOT,
+				500,
+				5,
+				106,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::InvalidCampaign
+		);
+
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			500,
+			5,
+			45,
+			EmissionCurve::Constant
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::CampaignCreated(
+			0,
+			BOB::get(),
+			pool_id.clone(),
+			DOT,
+			500,
+			5,
+			45,
+		)));
+		assert_eq!(
+			IncentivesModule::campaigns(0),
+			Some(Campaign {
+				funder: BOB::get(),
+				pool_id: pool_id.clone(),
+				currency_id: DOT,
+				budget: 500,
+				start: 5,
+				end: 45,
+				emission: EmissionCurve::Constant,
+				scheduled: 0,
+				remaining: 500,
+			})
+		);
+		assert_eq!(IncentivesModule::next_campaign_id(), 1);
+		assert_eq!(IncentivesModule::campaign_count(), 1);
+		assert!(IncentivesModule::extra_reward_pools(pool_id.clone()).contains_key(&DOT));
+		assert_eq!(TokensModule::free_balance(DOT, &BOB::get()), 500);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 500);
+		assert_eq!(TokensModule::reserved_balance(ACA, &BOB::get()), 10);
+
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			100,
+			5,
+			45,
+			EmissionCurve::LinearDecay
+		));
+		assert_eq!(IncentivesModule::campaign_count(), 2);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone())
+				.get(&DOT)
+				.unwrap()
+				.sources,
+			2
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(BOB::get()),
+				pool_id,
+				DOT,
+				100,
+				5,
+				45,
+				EmissionCurve::Constant
+			),
+			Error::<Runtime>::TooManyCampaigns
+		);
+	});
+}
+
+#[test]
+fn campaign_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 1010));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			ACA,
+			1000,
+			5,
+			45,
+			EmissionCurve::Constant
+		));
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(RewardsModule::pools(pool_id.clone()).total_rewards, 125);
+		IncentivesModule::on_initialize(20);
+		assert_eq!(RewardsModule::pools(pool_id.clone()).total_rewards, 375);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().scheduled, 375);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 625);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			pool_id.clone(),
+			ACA,
+			375,
+			0,
+		)));
+
+		System::set_block_number(20);
+		assert_noop!(
+			IncentivesModule::close_campaign(Origin::signed(BOB::get()), 0),
+			Error::<Runtime>::CampaignNotEnded
+		);
+		assert_noop!(
+			IncentivesModule::close_campaign(Origin::signed(ALICE::get()), 0),
+			BadOrigin
+		);
+
+		// emission is skipped when the pool has no shares
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		IncentivesModule::on_initialize(30);
+		IncentivesModule::on_initialize(50);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().scheduled, 1000);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 625);
+
+		System::set_block_number(50);
+		assert_ok!(IncentivesModule::close_campaign(Origin::signed(BOB::get()), 0));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::CampaignClosed(0, 625)));
+		assert_eq!(IncentivesModule::campaigns(0), None);
+		assert_eq!(IncentivesModule::campaign_count(), 0);
+		// the deposit is unreserved
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 635);
+		assert_eq!(TokensModule::reserved_balance(ACA, &BOB::get()), 0);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 375);
+		assert_noop!(
+			IncentivesModule::close_campaign(Origin::signed(BOB::get()), 0),
+			Error::<Runtime>::CampaignNotFound
+		);
+	});
+}
+
+#[test]
+fn campaign_with_linear_decay_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 10));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			1000,
+			10,
+			50,
+			EmissionCurve::LinearDecay
+		));
+		let campaign = IncentivesModule::campaigns(0).unwrap();
+		assert_eq!(IncentivesModule::campaign_emission(&campaign, 10), 0);
+		assert_eq!(IncentivesModule::campaign_emission(&campaign, 30), 750);
+		assert_eq!(IncentivesModule::campaign_emission(&campaign, 50), 1000);
+		assert_eq!(IncentivesModule::campaign_emission(&campaign, 60), 1000);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().scheduled, 0);
+		IncentivesModule::on_initialize(20);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone())
+				.get(&DOT)
+				.unwrap()
+				.total_rewards,
+			437
+		);
+		IncentivesModule::on_initialize(30);
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone())
+				.get(&DOT)
+				.unwrap()
+				.total_rewards,
+			750
+		);
+
+		// governance can close the campaign at any time
+		assert_ok!(IncentivesModule::close_campaign(Origin::signed(Root::get()), 0));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::CampaignClosed(0, 250)));
+		assert_eq!(TokensModule::free_balance(DOT, &BOB::get()), 250);
+
+		// the currency is kept until the accumulated rewards are claimed
+		assert_eq!(
+			IncentivesModule::extra_reward_pools(pool_id.clone())
+				.get(&DOT)
+				.unwrap()
+				.sources,
+			0
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 750);
+		assert!(!IncentivesModule::extra_reward_pools(pool_id.clone()).contains_key(&DOT));
+		assert!(IncentivesModule::extra_withdrawn_rewards(pool_id, ALICE::get()).is_empty());
+	});
+}
+
+#[test]
+fn pending_rewards_of_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 100));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 300));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), 100)]
+		));
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), DOT, 10)]
+		));
+		assert_ok!(IncentivesModule::fund_extra_rewards(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			15
+		));
+		assert_ok!(IncentivesModule::update_payout_deduction_rates(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), Rate::saturating_from_rational(10, 100))]
+		));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			300
+		));
+		assert_eq!(IncentivesModule::pending_rewards_of(&pool_id, &ALICE::get()), vec![]);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			IncentivesModule::pending_rewards_of(&pool_id, &ALICE::get()),
+			vec![(ACA, 23), (DOT, 2)]
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards_of(&pool_id, &BOB::get()),
+			vec![(ACA, 68), (DOT, 7)]
+		);
+
+		// the deduction is excluded for vesting rewards
+		assert_ok!(IncentivesModule::update_reward_vesting_periods(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), 100)]
+		));
+		assert_eq!(
+			IncentivesModule::pending_rewards_of(&pool_id, &ALICE::get()),
+			vec![(ACA, 25), (DOT, 2)]
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			pool_id.clone()
+		));
+		assert_eq!(IncentivesModule::pending_rewards_of(&pool_id, &ALICE::get()), vec![]);
+	});
+}
+
+#[test]
+fn pool_emission_and_apr_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::DexIncentive(BTC_AUSD_LP);
+		assert_ok!(TokensModule::deposit(DOT, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(AUSD, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 10));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 400));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), 100), (PoolId::LoansIncentive(BTC), 100)]
+		));
+		assert_ok!(IncentivesModule::update_extra_incentive_rewards(
+			Origin::signed(Root::get()),
+			vec![(pool_id.clone(), DOT, 10)]
+		));
+		assert_ok!(IncentivesModule::fund_extra_rewards(
+			Origin::signed(BOB::get()),
+			pool_id.clone(),
+			DOT,
+			15
+		));
+		assert_ok!(IncentivesModule::update_dex_saving_rewards(
+			Origin::signed(Root::get()),
+			vec![(PoolId::DexSaving(BTC_AUSD_LP), Rate::saturating_from_rational(1, 100))]
+		));
+
+		// no shares
+		assert_eq!(IncentivesModule::pool_apr(&pool_id), None);
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			400
+		));
+		assert_eq!(
+			IncentivesModule::pool_emission_per_period(&pool_id),
+			vec![(ACA, 100), (DOT, 10)]
+		);
+		IncentivesModule::on_initialize(10);
+		// the extra rewards are limited by the remaining fund
+		assert_eq!(
+			IncentivesModule::pool_emission_per_period(&pool_id),
+			vec![(ACA, 100), (DOT, 5)]
+		);
+		// (100 * 0.5 + 5 * 10) * 100 / (400 * 2)
+		assert_eq!(
+			IncentivesModule::pool_apr(&pool_id),
+			Some(Rate::saturating_from_rational(125, 10))
+		);
+
+		// dex saving: 1% of 500 stable currency in liquidity pool
+		assert_eq!(
+			IncentivesModule::pool_emission_per_period(&PoolId::DexSaving(BTC_AUSD_LP)),
+			vec![(AUSD, 5)]
+		);
+		assert_eq!(
+			IncentivesModule::pool_apr(&PoolId::DexSaving(BTC_AUSD_LP)),
+			Some(Rate::saturating_from_rational(625, 1000))
+		);
+
+		// loans incentive with campaign, shares are valued by debit exchange rate
+		RewardsModule::add_share(&ALICE::get(), &PoolId::LoansIncentive(BTC), 1000);
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(BOB::get()),
+			PoolId::LoansIncentive(BTC),
+			AUSD,
+			1000,
+			1,
+			101,
+			EmissionCurve::Constant
+		));
+		assert_eq!(
+			IncentivesModule::pool_emission_per_period(&PoolId::LoansIncentive(BTC)),
+			vec![(ACA, 100), (AUSD, 100)]
+		);
+		// (100 * 0.5 + 100 * 1) * 100 / (1000 * 0.1 * 1)
+		assert_eq!(
+			IncentivesModule::pool_apr(&PoolId::LoansIncentive(BTC)),
+			Some(Rate::saturating_from_integer(150))
+		);
+
+		assert_eq!(IncentivesModule::pool_apr(&PoolId::HomaIncentive), None);
+	});
+}
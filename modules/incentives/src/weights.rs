@@ -56,6 +56,15 @@ pub trait WeightInfo {
 	fn update_dex_saving_rewards(c: u32, ) -> Weight;
 	fn update_payout_deduction_rates(c: u32, ) -> Weight;
 	fn add_allowance() -> Weight;
+	fn update_boost_configs(c: u32, ) -> Weight;
+	fn update_reward_vesting_periods(c: u32, ) -> Weight;
+	fn lock_for_boost() -> Weight;
+	fn unlock_boost() -> Weight;
+	fn refresh_boost() -> Weight;
+	fn update_extra_incentive_rewards(c: u32, ) -> Weight;
+	fn fund_extra_rewards() -> Weight;
+	fn create_campaign() -> Weight;
+	fn close_campaign() -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -104,6 +113,55 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(2_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn update_boost_configs(c: u32, ) -> Weight {
+		(3_692_000 as Weight)
+			// Standard Error: 455_000
+			.saturating_add((17_687_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_vesting_periods(c: u32, ) -> Weight {
+		(3_512_000 as Weight)
+			// Standard Error: 398_000
+			.saturating_add((17_309_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn lock_for_boost() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn unlock_boost() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn refresh_boost() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn update_extra_incentive_rewards(c: u32, ) -> Weight {
+		(3_904_000 as Weight)
+			// Standard Error: 412_000
+			.saturating_add((21_846_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	fn fund_extra_rewards() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn create_campaign() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn close_campaign() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -151,4 +209,53 @@ impl WeightInfo for () {
 		(2_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn update_boost_configs(c: u32, ) -> Weight {
+		(3_692_000 as Weight)
+			// Standard Error: 455_000
+			.saturating_add((17_687_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_reward_vesting_periods(c: u32, ) -> Weight {
+		(3_512_000 as Weight)
+			// Standard Error: 398_000
+			.saturating_add((17_309_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn lock_for_boost() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn unlock_boost() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn refresh_boost() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn update_extra_incentive_rewards(c: u32, ) -> Weight {
+		(3_904_000 as Weight)
+			// Standard Error: 412_000
+			.saturating_add((21_846_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	fn fund_extra_rewards() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn create_campaign() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn close_campaign() -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
@@ -28,14 +28,15 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{log, pallet_prelude::*, traits::OnRuntimeUpgrade, transactional, PalletId};
 use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{AccountIdConversion, Convert, Zero},
-	ArithmeticError, DispatchResult, RuntimeDebug,
+	ArithmeticError, DispatchError, DispatchResult, RuntimeDebug,
 };
-use sp_std::{convert::TryInto, result};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*, result};
 use support::{CDPTreasury, RiskManager};
 
 mod mock;
@@ -43,6 +44,10 @@ mod tests;
 
 pub use module::*;
 
+/// The maximum number of positions whose debit principal is migrated in a
+/// block by `MigrateDebitPrincipals`.
+pub const MAX_MIGRATED_DEBIT_PRINCIPALS_PER_BLOCK: u32 = 500;
+
 /// A collateralized debit position.
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, MaxEncodedLen)]
 pub struct Position {
@@ -52,6 +57,25 @@ pub struct Position {
 	pub debit: Balance,
 }
 
+/// A collateralized debit position backed by a basket of collaterals.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, Default)]
+pub struct MultiCollateralPosition {
+	/// The amounts of collaterals.
+	pub collaterals: BTreeMap<CurrencyId, Balance>,
+	/// The amount of debit.
+	pub debit: Balance,
+}
+
+impl MultiCollateralPosition {
+	/// The amounts of collaterals as a list.
+	pub fn collateral_list(&self) -> Vec<(CurrencyId, Balance)> {
+		self.collaterals
+			.iter()
+			.map(|(currency_id, amount)| (*currency_id, *amount))
+			.collect()
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -106,6 +130,12 @@ pub mod module {
 		ConfiscateCollateralAndDebit(T::AccountId, CurrencyId, Balance, Balance),
 		/// Transfer loan. \[from, to, currency_id\]
 		TransferLoan(T::AccountId, T::AccountId, CurrencyId),
+		/// Multi-collateral position updated. \[owner, collateral_adjustments,
+		/// debit_adjustment\]
+		MultiCollateralPositionUpdated(T::AccountId, Vec<(CurrencyId, Amount)>, Amount),
+		/// Confiscate multi-collateral CDP's collateral assets and eliminate
+		/// its debit. \[owner, confiscated_collaterals, deduct_debit_amount\]
+		ConfiscateMultiCollateralAndDebit(T::AccountId, Vec<(CurrencyId, Balance)>, Balance),
 	}
 
 	/// The collateralized debit positions, map from
@@ -125,11 +155,51 @@ pub mod module {
 	#[pallet::getter(fn total_positions)]
 	pub type TotalPositions<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Position, ValueQuery>;
 
+	/// The debit value issued to the collateralized debit positions and not
+	/// paid back yet, excluding the accrued interest, map from
+	/// CollateralType -> Owner -> Principal
+	///
+	/// DebitPrincipals: double_map CurrencyId, AccountId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_principals)]
+	pub type DebitPrincipals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, Balance, OptionQuery>;
+
+	/// The raw key of the last position in `Positions` whose debit principal
+	/// has been migrated by `MigrateDebitPrincipals`, empty if the migration
+	/// hasn't migrated any position. `None` if no migration is in progress.
+	///
+	/// DebitPrincipalsMigrationCursor: Option<Vec<u8>>
+	#[pallet::storage]
+	pub type DebitPrincipalsMigrationCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The collateralized debit positions backed by a basket of collaterals,
+	/// map from Owner -> MultiCollateralPosition
+	///
+	/// MultiCollateralPositions: map AccountId => MultiCollateralPosition
+	#[pallet::storage]
+	#[pallet::getter(fn multi_collateral_positions)]
+	pub type MultiCollateralPositions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, MultiCollateralPosition, ValueQuery>;
+
+	/// The total of all multi-collateral positions
+	///
+	/// TotalMultiCollateralPosition: MultiCollateralPosition
+	#[pallet::storage]
+	#[pallet::getter(fn total_multi_collateral_position)]
+	pub type TotalMultiCollateralPosition<T: Config> = StorageValue<_, MultiCollateralPosition, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Migrate the debit principals of the positions started by
+		/// `MigrateDebitPrincipals`.
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			Self::migrate_debit_principals(MAX_MIGRATED_DEBIT_PRINCIPALS_PER_BLOCK)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
@@ -255,6 +325,9 @@ impl<T: Config> Pallet<T> {
 		let collateral_adjustment = Self::amount_try_from_balance(collateral)?;
 		let debit_adjustment = Self::amount_try_from_balance(debit)?;
 
+		let from_principal = Self::debit_principals(currency_id, from);
+		let to_principal = Self::debit_principals(currency_id, to);
+
 		Self::update_loan(
 			from,
 			currency_id,
@@ -263,10 +336,47 @@ impl<T: Config> Pallet<T> {
 		)?;
 		Self::update_loan(to, currency_id, collateral_adjustment, debit_adjustment)?;
 
+		// carry the debit principal over to keep the interest accrued on the transferred debit,
+		// rather than recording the current debit value as the principal of `to`.
+		if let Some(principal) = from_principal.filter(|_| !debit.is_zero()) {
+			let to_principal =
+				to_principal.unwrap_or_else(|| T::RiskManager::get_bad_debt_value(currency_id, to_debit));
+			DebitPrincipals::<T>::insert(currency_id, to, to_principal.saturating_add(principal));
+		}
+
 		Self::deposit_event(Event::TransferLoan(from.clone(), to.clone(), currency_id));
 		Ok(())
 	}
 
+	/// mutate the record of debit principal by the debit adjustment, the
+	/// principal increases by the value of the issued debit and decreases in
+	/// proportion to the paid back debit.
+	fn update_debit_principal(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		debit: Balance,
+		new_debit: Balance,
+		debit_adjustment: Amount,
+	) {
+		if new_debit.is_zero() {
+			DebitPrincipals::<T>::remove(currency_id, who);
+			return;
+		}
+
+		// start tracking the position opened before the principal was recorded from now on
+		let principal = Self::debit_principals(currency_id, who)
+			.unwrap_or_else(|| T::RiskManager::get_bad_debt_value(currency_id, debit));
+		let new_principal = if debit_adjustment.is_positive() {
+			principal.saturating_add(T::RiskManager::get_bad_debt_value(
+				currency_id,
+				new_debit.saturating_sub(debit),
+			))
+		} else {
+			multiply_by_rational(principal, new_debit, debit).unwrap_or(principal)
+		};
+		DebitPrincipals::<T>::insert(currency_id, who, new_principal);
+	}
+
 	/// mutate records of collaterals and debits
 	fn update_loan(
 		who: &T::AccountId,
@@ -312,6 +422,9 @@ impl<T: Config> Pallet<T> {
 			// and the data will been messed up, before migration or calibration,
 			// it is forbidden to turn on incentives for pool LoansIncentive(KSM).
 			T::OnUpdateLoan::happened(&(who.clone(), currency_id, collateral_adjustment, p.collateral));
+			if !debit_adjustment.is_zero() {
+				Self::update_debit_principal(who, currency_id, p.debit, new_debit, debit_adjustment);
+			}
 			p.collateral = new_collateral;
 			p.debit = new_debit;
 
@@ -359,6 +472,230 @@ impl<T: Config> Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// adjust the multi-collateral position.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn adjust_multi_collateral_position(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		// mutate collaterals and debit
+		// Note: if a new position, will inc consumer
+		Self::update_multi_collateral_loan(who, collateral_adjustments, debit_adjustment)?;
+
+		let module_account = Self::account_id();
+		for (currency_id, collateral_adjustment) in collateral_adjustments {
+			let collateral_balance_adjustment = Self::balance_try_from_amount_abs(*collateral_adjustment)?;
+			if collateral_adjustment.is_positive() {
+				T::Currency::transfer(*currency_id, who, &module_account, collateral_balance_adjustment)?;
+			} else if collateral_adjustment.is_negative() {
+				T::Currency::transfer(*currency_id, &module_account, who, collateral_balance_adjustment)?;
+			}
+		}
+
+		let debit_balance_adjustment = Self::balance_try_from_amount_abs(debit_adjustment)?;
+		if debit_adjustment.is_positive() {
+			// check debit cap when increase debit
+			T::RiskManager::check_multi_collateral_debit_cap(Self::total_multi_collateral_position().debit)?;
+
+			// issue debit with collateral backed by cdp treasury
+			T::CDPTreasury::issue_debit(
+				who,
+				T::RiskManager::get_multi_collateral_debit_value(debit_balance_adjustment),
+				true,
+			)?;
+		} else if debit_adjustment.is_negative() {
+			// repay debit
+			// burn debit by cdp treasury
+			T::CDPTreasury::burn_debit(
+				who,
+				T::RiskManager::get_multi_collateral_debit_value(debit_balance_adjustment),
+			)?;
+		}
+
+		// ensure pass risk check
+		let position = Self::multi_collateral_positions(who);
+		T::RiskManager::check_multi_collateral_position_valid(
+			&position.collateral_list(),
+			position.debit,
+			collateral_adjustments
+				.iter()
+				.any(|(_, collateral_adjustment)| collateral_adjustment.is_negative())
+				|| debit_adjustment.is_positive(),
+		)?;
+
+		Self::deposit_event(Event::MultiCollateralPositionUpdated(
+			who.clone(),
+			collateral_adjustments.to_vec(),
+			debit_adjustment,
+		));
+		Ok(())
+	}
+
+	/// confiscate collaterals and debit of the multi-collateral position to
+	/// cdp treasury.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn confiscate_multi_collateral_and_debit(
+		who: &T::AccountId,
+		collateral_confiscates: &[(CurrencyId, Balance)],
+		debit_decrease: Balance,
+	) -> DispatchResult {
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = Vec::new();
+		for (currency_id, collateral_confiscate) in collateral_confiscates {
+			// transfer collateral to cdp treasury
+			T::CDPTreasury::deposit_collateral(&Self::account_id(), *currency_id, *collateral_confiscate)?;
+			collateral_adjustments.push((
+				*currency_id,
+				Self::amount_try_from_balance(*collateral_confiscate)?.saturating_neg(),
+			));
+		}
+
+		// deposit debit to cdp treasury
+		let bad_debt_value = T::RiskManager::get_multi_collateral_debit_value(debit_decrease);
+		T::CDPTreasury::on_system_debit(bad_debt_value)?;
+
+		// update loan
+		Self::update_multi_collateral_loan(
+			who,
+			&collateral_adjustments,
+			Self::amount_try_from_balance(debit_decrease)?.saturating_neg(),
+		)?;
+
+		Self::deposit_event(Event::ConfiscateMultiCollateralAndDebit(
+			who.clone(),
+			collateral_confiscates.to_vec(),
+			debit_decrease,
+		));
+		Ok(())
+	}
+
+	/// mutate records of collaterals and debit of multi-collateral positions
+	fn update_multi_collateral_loan(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		<MultiCollateralPositions<T>>::try_mutate_exists(who, |may_be_position| -> DispatchResult {
+			let mut p = may_be_position.take().unwrap_or_default();
+
+			// increase account ref if new position
+			if p.collaterals.is_empty() && p.debit.is_zero() {
+				if frame_system::Pallet::<T>::inc_consumers(who).is_err() {
+					// No providers for the locks. This is impossible under normal circumstances
+					// since the funds that are under the lock will themselves be stored in the
+					// account and therefore will need a reference.
+					log::warn!(
+						"Warning: Attempt to introduce lock consumer reference, yet no providers. \
+						This is unexpected but should be safe."
+					);
+				}
+			}
+
+			Self::adjust_multi_collateral_position_record(&mut p, collateral_adjustments, debit_adjustment)?;
+
+			if p.collaterals.is_empty() && p.debit.is_zero() {
+				// decrease account ref if zero position
+				frame_system::Pallet::<T>::dec_consumers(who);
+
+				// remove position storage if zero position
+				*may_be_position = None;
+			} else {
+				*may_be_position = Some(p);
+			}
+
+			Ok(())
+		})?;
+
+		TotalMultiCollateralPosition::<T>::try_mutate(|total_position| -> DispatchResult {
+			Self::adjust_multi_collateral_position_record(total_position, collateral_adjustments, debit_adjustment)
+		})
+	}
+
+	/// apply the adjustments to the multi-collateral position record, the
+	/// collateral of zero amount will be removed.
+	fn adjust_multi_collateral_position_record(
+		position: &mut MultiCollateralPosition,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		for (currency_id, collateral_adjustment) in collateral_adjustments {
+			let collateral = position.collaterals.get(currency_id).copied().unwrap_or_default();
+			let new_collateral = Self::balance_checked_adjust(collateral, *collateral_adjustment)?;
+			if new_collateral.is_zero() {
+				position.collaterals.remove(currency_id);
+			} else {
+				position.collaterals.insert(*currency_id, new_collateral);
+			}
+		}
+		position.debit = Self::balance_checked_adjust(position.debit, debit_adjustment)?;
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Apply the signed `adjustment` to `balance`.
+	fn balance_checked_adjust(balance: Balance, adjustment: Amount) -> result::Result<Balance, DispatchError> {
+		let adjustment_balance = Self::balance_try_from_amount_abs(adjustment)?;
+		if adjustment.is_positive() {
+			balance
+				.checked_add(adjustment_balance)
+				.ok_or_else(|| ArithmeticError::Overflow.into())
+		} else {
+			balance
+				.checked_sub(adjustment_balance)
+				.ok_or_else(|| ArithmeticError::Underflow.into())
+		}
+	}
+
+	/// Record the debit principal of at most `limit` positions without one by
+	/// their current debit value, continuing from
+	/// `DebitPrincipalsMigrationCursor`. The cursor is removed once all the
+	/// positions have been migrated.
+	fn migrate_debit_principals(limit: u32) -> Weight {
+		let start_key = match DebitPrincipalsMigrationCursor::<T>::get() {
+			Some(start_key) => start_key,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let mut iterator = if start_key.is_empty() {
+			Positions::<T>::iter()
+		} else {
+			Positions::<T>::iter_from(start_key)
+		};
+
+		let mut count: u32 = 0;
+		let mut finished = true;
+		for (currency_id, who, Position { debit, .. }) in &mut iterator {
+			if !debit.is_zero() && !DebitPrincipals::<T>::contains_key(currency_id, &who) {
+				DebitPrincipals::<T>::insert(
+					currency_id,
+					&who,
+					T::RiskManager::get_bad_debt_value(currency_id, debit),
+				);
+			}
+
+			count = count.saturating_add(1);
+			if count >= limit {
+				finished = false;
+				break;
+			}
+		}
+
+		if finished {
+			DebitPrincipalsMigrationCursor::<T>::kill();
+		} else {
+			DebitPrincipalsMigrationCursor::<T>::put(iterator.last_raw_key().to_vec());
+		}
+
+		// read the cursor, the position, the principal and the debit exchange rate of each
+		// position, and write the principals and the cursor.
+		let count = Weight::from(count);
+		T::DbWeight::get().reads_writes(count.saturating_mul(3).saturating_add(1), count.saturating_add(1))
+	}
+
 	/// Convert `Balance` to `Amount`.
 	fn amount_try_from_balance(b: Balance) -> result::Result<Amount, Error<T>> {
 		TryInto::<Amount>::try_into(b).map_err(|_| Error::<T>::AmountConvertFailed)
@@ -369,3 +706,32 @@ impl<T: Config> Pallet<T> {
 		TryInto::<Balance>::try_into(a.saturating_abs()).map_err(|_| Error::<T>::AmountConvertFailed)
 	}
 }
+
+/// Record the debit principal of the positions opened before `DebitPrincipals`
+/// was introduced by their current debit value, so that the accrued interest is
+/// tracked from the upgrade on. The debit exchange rate at which their debit
+/// was issued isn't recorded, so the interest accrued before the upgrade can't
+/// be derived: it's counted in the principal, and the accrued interest of these
+/// positions reads as 0 after the upgrade.
+///
+/// The upgrade only starts the migration, the positions are migrated in the
+/// `on_initialize` of the following blocks, at most
+/// `MAX_MIGRATED_DEBIT_PRINCIPALS_PER_BLOCK` positions per block. Positions
+/// that already have a principal are skipped, remove it from the runtime once
+/// it has been applied.
+pub struct MigrateDebitPrincipals<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateDebitPrincipals<T> {
+	fn on_runtime_upgrade() -> Weight {
+		DebitPrincipalsMigrationCursor::<T>::put(Vec::<u8>::new());
+		T::DbWeight::get().writes(1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		ensure!(
+			DebitPrincipalsMigrationCursor::<T>::exists(),
+			"debit principals migration is not started"
+		);
+		Ok(())
+	}
+}
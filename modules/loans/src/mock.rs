@@ -141,6 +141,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -149,6 +166,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Default::default()
 	}
 
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
@@ -221,6 +246,35 @@ impl RiskManager<AccountId, CurrencyId, Balance, Balance> for MockRiskManager {
 			(_, _) => Ok(()),
 		}
 	}
+
+	fn get_multi_collateral_debit_value(debit_balance: Balance) -> Balance {
+		debit_balance / Balance::from(2u64)
+	}
+
+	fn check_multi_collateral_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		_debit_balance: Balance,
+		check_required_ratio: bool,
+	) -> DispatchResult {
+		if collaterals.iter().any(|(currency_id, _)| *currency_id == DOT) {
+			if check_required_ratio {
+				Err(sp_runtime::DispatchError::Other(
+					"mock below required collateral ratio error",
+				))
+			} else {
+				Err(sp_runtime::DispatchError::Other("mock below liquidation ratio error"))
+			}
+		} else {
+			Ok(())
+		}
+	}
+
+	fn check_multi_collateral_debit_cap(total_debit_balance: Balance) -> DispatchResult {
+		match total_debit_balance {
+			1000 => Err(sp_runtime::DispatchError::Other("mock exceed debit value cap error")),
+			_ => Ok(()),
+		}
+	}
 }
 
 thread_local! {
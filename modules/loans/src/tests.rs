@@ -157,6 +157,29 @@ fn update_loan_should_work() {
 	});
 }
 
+#[test]
+fn update_debit_principal_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), None);
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 400, 300));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), Some(150));
+
+		// collateral adjustment does not change principal
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, -100, 0));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), Some(150));
+
+		// paid back debit decreases principal in proportion
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 0, -100));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), Some(100));
+
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 0, 100));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), Some(150));
+
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 0, -300));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), None);
+	});
+}
+
 #[test]
 fn transfer_loan_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -177,6 +200,47 @@ fn transfer_loan_should_work() {
 	});
 }
 
+#[test]
+fn transfer_loan_carries_debit_principal() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 400, 500));
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 100, 600));
+		assert_eq!(LoansModule::debit_principals(BTC, &BOB), Some(300));
+
+		// the debit of ALICE has accrued interest
+		DebitPrincipals::<Runtime>::insert(BTC, &ALICE, 200);
+
+		assert_ok!(LoansModule::transfer_loan(&ALICE, &BOB, BTC));
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), None);
+		assert_eq!(LoansModule::debit_principals(BTC, &BOB), Some(500));
+	});
+}
+
+#[test]
+fn migrate_debit_principals_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 400, 500));
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 100, 600));
+		assert_ok!(LoansModule::update_loan(&BOB, DOT, 100, 0));
+		DebitPrincipals::<Runtime>::remove(BTC, &ALICE);
+		DebitPrincipals::<Runtime>::insert(BTC, &BOB, 200);
+
+		assert_eq!(DebitPrincipalsMigrationCursor::<Runtime>::get(), None);
+		MigrateDebitPrincipals::<Runtime>::on_runtime_upgrade();
+		assert_eq!(DebitPrincipalsMigrationCursor::<Runtime>::get(), Some(vec![]));
+
+		// migrate at most 2 of the 3 positions
+		LoansModule::migrate_debit_principals(2);
+		assert!(DebitPrincipalsMigrationCursor::<Runtime>::get().is_some());
+
+		LoansModule::migrate_debit_principals(2);
+		assert_eq!(DebitPrincipalsMigrationCursor::<Runtime>::get(), None);
+		assert_eq!(LoansModule::debit_principals(BTC, &ALICE), Some(250));
+		assert_eq!(LoansModule::debit_principals(BTC, &BOB), Some(200));
+		assert_eq!(LoansModule::debit_principals(DOT, &BOB), None);
+	});
+}
+
 #[test]
 fn confiscate_collateral_and_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -219,3 +283,120 @@ fn loan_updated_updated_when_adjust_collateral() {
 		assert_eq!(DOT_SHARES.with(|v| *v.borrow().get(&BOB).unwrap_or(&0)), 200);
 	});
 }
+
+#[test]
+fn adjust_multi_collateral_position_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// mock can't pass liquidation ratio check
+		assert_noop!(
+			LoansModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 500), (DOT, 500)], 0),
+			sp_runtime::DispatchError::Other("mock below liquidation ratio error")
+		);
+
+		// mock can't pass required ratio check
+		assert_noop!(
+			LoansModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 500), (DOT, 500)], 1),
+			sp_runtime::DispatchError::Other("mock below required collateral ratio error")
+		);
+
+		// mock exceed debit value cap
+		assert_noop!(
+			LoansModule::adjust_multi_collateral_position(&ALICE, &[(BTC, 1000)], 1000),
+			sp_runtime::DispatchError::Other("mock exceed debit value cap error")
+		);
+
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		// success
+		assert_ok!(LoansModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 500)],
+			300
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 500);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 500);
+		assert_eq!(
+			LoansModule::multi_collateral_positions(&ALICE).collateral_list(),
+			vec![(BTC, 500)]
+		);
+		assert_eq!(LoansModule::multi_collateral_positions(&ALICE).debit, 300);
+		assert_eq!(
+			LoansModule::total_multi_collateral_position().collateral_list(),
+			vec![(BTC, 500)]
+		);
+		assert_eq!(LoansModule::total_multi_collateral_position().debit, 300);
+		assert_eq!(LoansModule::positions(BTC, &ALICE), Default::default());
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 150);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 + 1);
+		System::assert_last_event(Event::LoansModule(crate::Event::MultiCollateralPositionUpdated(
+			ALICE,
+			vec![(BTC, 500)],
+			300,
+		)));
+
+		// close the position
+		assert_ok!(LoansModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, -500)],
+			-300
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1000);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
+		assert!(!<MultiCollateralPositions<Runtime>>::contains_key(&ALICE));
+		assert_eq!(LoansModule::total_multi_collateral_position(), Default::default());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0);
+	});
+}
+
+#[test]
+fn update_multi_collateral_loan_underflow_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// collateral underflow
+		assert_noop!(
+			LoansModule::update_multi_collateral_loan(&ALICE, &[(BTC, -100)], 0),
+			ArithmeticError::Underflow,
+		);
+
+		// debit underflow
+		assert_noop!(
+			LoansModule::update_multi_collateral_loan(&ALICE, &[], -100),
+			ArithmeticError::Underflow,
+		);
+	});
+}
+
+#[test]
+fn confiscate_multi_collateral_and_debit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LoansModule::adjust_multi_collateral_position(
+			&ALICE,
+			&[(BTC, 500)],
+			300
+		));
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(BTC), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+
+		assert_ok!(LoansModule::confiscate_multi_collateral_and_debit(
+			&ALICE,
+			&[(BTC, 300)],
+			200
+		));
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(BTC), 300);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(
+			LoansModule::multi_collateral_positions(&ALICE).collateral_list(),
+			vec![(BTC, 200)]
+		);
+		assert_eq!(LoansModule::multi_collateral_positions(&ALICE).debit, 100);
+		assert_eq!(LoansModule::total_multi_collateral_position().debit, 100);
+		System::assert_last_event(Event::LoansModule(crate::Event::ConfiscateMultiCollateralAndDebit(
+			ALICE,
+			vec![(BTC, 300)],
+			200,
+		)));
+	});
+}
@@ -178,6 +178,7 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
+	type HeldCurrencyIds = ();
 }
 
 parameter_types! {
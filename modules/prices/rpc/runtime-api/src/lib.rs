@@ -0,0 +1,64 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for prices module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::CurrencyId;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::FixedU128;
+use sp_std::prelude::*;
+
+/// The aggregates of price snapshots over a blocks window.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PriceAggregates {
+	pub min: FixedU128,
+	pub max: FixedU128,
+	pub average: FixedU128,
+	/// The count of price snapshots in the window.
+	pub count: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait PricesApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		fn get_price_snapshot_at(
+			currency_id: CurrencyId,
+			block_number: BlockNumber,
+		) -> Option<(BlockNumber, FixedU128)>;
+
+		fn get_price_snapshots(
+			currency_id: CurrencyId,
+		) -> Vec<(BlockNumber, FixedU128)>;
+
+		fn get_price_snapshot_aggregates(
+			currency_id: CurrencyId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<PriceAggregates>;
+	}
+}
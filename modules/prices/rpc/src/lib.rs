@@ -0,0 +1,154 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the prices module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_prices_rpc_runtime_api::PriceAggregates;
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, FixedU128};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as PricesClient;
+pub use module_prices_rpc_runtime_api::PricesApi as PricesRuntimeApi;
+
+#[rpc]
+pub trait PricesApi<BlockHash, BlockNumber> {
+	#[rpc(name = "prices_getPriceSnapshotAt")]
+	fn get_price_snapshot_at(
+		&self,
+		currency_id: CurrencyId,
+		block_number: BlockNumber,
+		at: Option<BlockHash>,
+	) -> Result<Option<(BlockNumber, FixedU128)>>;
+
+	#[rpc(name = "prices_getPriceSnapshots")]
+	fn get_price_snapshots(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(BlockNumber, FixedU128)>>;
+
+	#[rpc(name = "prices_getPriceSnapshotAggregates")]
+	fn get_price_snapshot_aggregates(
+		&self,
+		currency_id: CurrencyId,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<BlockHash>,
+	) -> Result<Option<PriceAggregates>>;
+}
+
+/// A struct that implements the [`PricesApi`].
+pub struct Prices<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Prices<C, B> {
+	/// Create new `Prices` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Prices {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, BlockNumber> PricesApi<<Block as BlockT>::Hash, BlockNumber> for Prices<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
+	BlockNumber: Codec,
+{
+	fn get_price_snapshot_at(
+		&self,
+		currency_id: CurrencyId,
+		block_number: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<(BlockNumber, FixedU128)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_price_snapshot_at(&at, currency_id, block_number)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get price snapshot.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_price_snapshots(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(BlockNumber, FixedU128)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_price_snapshots(&at, currency_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get price snapshots.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_price_snapshot_aggregates(
+		&self,
+		currency_id: CurrencyId,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<PriceAggregates>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_price_snapshot_aggregates(&at, currency_id, from, to)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get price snapshot aggregates.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
@@ -25,18 +25,35 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - aggregate the prices from multiple weighted sources of a currency
+//!   - record the price snapshots periodically in a bounded ring buffer for
+//!     historical queries
+//!   - guard the oracle price with staleness limit and max deviation per block,
+//!     reject the price jumping beyond the bound as soon as it arrives and trip the circuit
+//!     breaker to lock the last accepted price
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{OnRuntimeUpgrade, UnixTime},
+	transactional,
+};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
-use primitives::{Balance, CurrencyId};
+use orml_oracle::TimestampedValue;
+use orml_traits::{Change, DataFeeder, DataProvider, DataProviderExtended, MultiCurrency};
+use primitives::{Balance, CurrencyId, DataProviderId, Moment};
 use sp_core::U256;
-use sp_runtime::{traits::CheckedMul, FixedPointNumber};
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{Bounded, CheckedMul, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{
+	CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceCircuitBreaker, PriceProvider,
+	Ratio,
+};
 
 mod mock;
 mod tests;
@@ -45,6 +62,65 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The price with the timestamp it was fed by oracle.
+pub type TimestampedPrice = TimestampedValue<Price, Moment>;
+
+// typedef to help polkadot.js disambiguate Change with different generic
+// parameters
+type ChangeOptionRatio = Change<Option<Ratio>>;
+type ChangeOptionMoment = Change<Option<Moment>>;
+
+/// The source to access the price of a currency from.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum PriceSource {
+	/// The oracle instance specified by the data provider id.
+	Oracle(DataProviderId),
+	/// The time-weighted average price against stable currency on DEX over
+	/// the last blocks window.
+	DexTwap(u32),
+	/// The price of staking currency multiplied by the liquid staking exchange
+	/// rate, only available for liquid currency.
+	LiquidStaking,
+	/// The fixed price for 1 unit, usually as the fallback.
+	Fixed(Price),
+}
+
+/// The method to aggregate the prices from multiple sources.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum AggregationMethod {
+	/// Take the first available price by the priority of sources.
+	Priority,
+	/// Take the median of available prices.
+	Median,
+	/// Take the median of available prices weighted by their sources.
+	WeightedMedian,
+}
+
+/// The price sources of a currency.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct PriceSourceSet {
+	/// The sources and their weights, sorted by the priority.
+	pub sources: Vec<(PriceSource, u32)>,
+	/// The method to aggregate the prices.
+	pub aggregation: AggregationMethod,
+	/// The minimum count of available prices, otherwise the aggregated price
+	/// is invalid.
+	pub min_sources: u32,
+}
+
+/// The aggregates of price snapshots over a blocks window.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct PriceAggregates {
+	/// The min price.
+	pub min: Price,
+	/// The max price.
+	pub max: Price,
+	/// The arithmetic average of prices.
+	pub average: Price,
+	/// The count of price snapshots.
+	pub count: u32,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -54,7 +130,29 @@ pub mod module {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The data source, such as Oracle.
-		type Source: DataProvider<CurrencyId, Price> + DataFeeder<CurrencyId, Price, Self::AccountId>;
+		type Source: DataProvider<CurrencyId, Price>
+			+ DataProviderExtended<CurrencyId, TimestampedPrice>
+			+ DataFeeder<CurrencyId, Price, Self::AccountId>;
+
+		/// The oracle instances which can be the price sources.
+		type OracleSources: DataProvider<(DataProviderId, CurrencyId), TimestampedPrice>;
+
+		/// The max count of price sources of a currency.
+		#[pallet::constant]
+		type MaxPriceSources: Get<u32>;
+
+		/// The currencies whose price snapshots will be recorded.
+		#[pallet::constant]
+		type PriceSnapshotCurrencyIds: Get<Vec<CurrencyId>>;
+
+		/// The interval (in blocks) to record the price snapshots.
+		#[pallet::constant]
+		type PriceSnapshotInterval: Get<Self::BlockNumber>;
+
+		/// The max count of price snapshots kept for a currency, the oldest
+		/// snapshot will be overwritten when exceeded.
+		#[pallet::constant]
+		type MaxPriceSnapshots: Get<u32>;
 
 		/// The stable currency id, it should be AUSD in Acala.
 		#[pallet::constant]
@@ -75,6 +173,12 @@ pub mod module {
 		/// The origin which may lock and unlock prices feed to system.
 		type LockOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The origin which may update the price safety params.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Time used for checking the staleness of the oracle price.
+		type UnixTime: UnixTime;
+
 		/// The provider of the exchange rate between liquid currency and
 		/// staking currency.
 		type LiquidStakingExchangeRateProvider: ExchangeRateProvider;
@@ -98,6 +202,10 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The circuit breaker of the currency is not tripped
+		CircuitBreakerNotTripped,
+		/// The price sources are invalid
+		InvalidPriceSources,
 	}
 
 	#[pallet::event]
@@ -107,6 +215,20 @@ pub mod module {
 		LockPrice(CurrencyId, Price),
 		/// Unlock price. \[currency_id\]
 		UnlockPrice(CurrencyId),
+		/// The max deviation per block of price has been updated. \[currency_id,
+		/// new_max_deviation_per_block\]
+		MaxDeviationPerBlockUpdated(CurrencyId, Option<Ratio>),
+		/// The max staleness of oracle price has been updated. \[currency_id,
+		/// new_max_staleness\]
+		MaxStalenessUpdated(CurrencyId, Option<Moment>),
+		/// The circuit breaker is tripped because the price jumps beyond the bound.
+		/// \[currency_id, last_accepted_price, new_price\]
+		CircuitBreakerTripped(CurrencyId, Price, Price),
+		/// The circuit breaker has been reset. \[currency_id\]
+		CircuitBreakerReset(CurrencyId),
+		/// The price sources has been updated. \[currency_id,
+		/// new_price_sources\]
+		PriceSourcesUpdated(CurrencyId, Option<PriceSourceSet>),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -116,11 +238,95 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// The max deviation ratio of the price per block, the circuit breaker will
+	/// be tripped if the price jumps beyond it.
+	///
+	/// MaxDeviationPerBlock: map CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn max_deviation_per_block)]
+	pub type MaxDeviationPerBlock<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
+	/// The max age (in milliseconds) of the oracle price, the stale price will
+	/// be treated as invalid.
+	///
+	/// MaxStaleness: map CurrencyId => Option<Moment>
+	#[pallet::storage]
+	#[pallet::getter(fn max_staleness)]
+	pub type MaxStaleness<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Moment, OptionQuery>;
+
+	/// The last price accepted by the deviation check and the block number it
+	/// was accepted at.
+	///
+	/// LastAcceptedPrice: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn last_accepted_price)]
+	pub type LastAcceptedPrice<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// The currencies whose circuit breaker has been tripped, and the block
+	/// number it was tripped at.
+	///
+	/// TrippedCircuitBreakers: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn tripped_circuit_breakers)]
+	pub type TrippedCircuitBreakers<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+	/// The price sources of currency, the price will be aggregated from them
+	/// if set.
+	///
+	/// PriceSources: map CurrencyId => Option<PriceSourceSet>
+	#[pallet::storage]
+	#[pallet::getter(fn price_sources)]
+	pub type PriceSources<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, PriceSourceSet, OptionQuery>;
+
+	/// The ring buffer of price snapshots of currency, the slot index is
+	/// bounded by `MaxPriceSnapshots`.
+	///
+	/// PriceSnapshots: double_map CurrencyId, u32 => Option<(BlockNumber, Price)>
+	#[pallet::storage]
+	#[pallet::getter(fn price_snapshots)]
+	pub type PriceSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, u32, (T::BlockNumber, Price), OptionQuery>;
+
+	/// The slot index of the ring buffer to record the next price snapshot.
+	///
+	/// NextPriceSnapshotIndex: map CurrencyId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn next_price_snapshot_index)]
+	pub type NextPriceSnapshotIndex<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Check the price of currencies which have max deviation per block,
+		/// trip the circuit breaker if the price jumps beyond the bound. And
+		/// record the price snapshots every `PriceSnapshotInterval` blocks.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut count: u32 = 0;
+			for (currency_id, max_deviation_per_block) in MaxDeviationPerBlock::<T>::iter() {
+				count = count.saturating_add(1);
+				if TrippedCircuitBreakers::<T>::contains_key(currency_id) {
+					continue;
+				}
+				if let Some(price) = Self::access_unchecked_price(currency_id) {
+					Self::check_price_deviation(currency_id, price, max_deviation_per_block, now);
+				}
+			}
+			let mut weight = T::WeightInfo::on_initialize(count);
+
+			let interval = T::PriceSnapshotInterval::get();
+			if !interval.is_zero() && (now % interval).is_zero() {
+				let currency_ids = T::PriceSnapshotCurrencyIds::get();
+				for currency_id in currency_ids.iter() {
+					Self::record_price_snapshot(*currency_id, now);
+				}
+				weight = weight.saturating_add(T::WeightInfo::record_price_snapshots(currency_ids.len() as u32));
+			}
+			weight
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -149,15 +355,117 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Update the price safety params of specific currency
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `max_deviation_per_block`: max deviation ratio of price per block, `None` means do not
+		///   update, `Some(None)` means update it to `None`.
+		/// - `max_staleness`: max age of oracle price in milliseconds, `None` means do not update,
+		///   `Some(None)` means update it to `None`.
+		#[pallet::weight((T::WeightInfo::set_price_safety_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_safety_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			max_deviation_per_block: ChangeOptionRatio,
+			max_staleness: ChangeOptionMoment,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Change::NewValue(update) = max_deviation_per_block {
+				match update {
+					Some(ratio) => MaxDeviationPerBlock::<T>::insert(currency_id, ratio),
+					None => {
+						MaxDeviationPerBlock::<T>::remove(currency_id);
+						LastAcceptedPrice::<T>::remove(currency_id);
+					}
+				}
+				Self::deposit_event(Event::MaxDeviationPerBlockUpdated(currency_id, update));
+			}
+			if let Change::NewValue(update) = max_staleness {
+				match update {
+					Some(moment) => MaxStaleness::<T>::insert(currency_id, moment),
+					None => MaxStaleness::<T>::remove(currency_id),
+				}
+				Self::deposit_event(Event::MaxStalenessUpdated(currency_id, update));
+			}
+			Ok(())
+		}
+
+		/// Reset the tripped circuit breaker, the deviation check will restart
+		/// from the next accepted price. The locked price is kept, use
+		/// `unlock_price` to release it.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		#[pallet::weight((T::WeightInfo::reset_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn reset_circuit_breaker(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			TrippedCircuitBreakers::<T>::take(currency_id).ok_or(Error::<T>::CircuitBreakerNotTripped)?;
+			LastAcceptedPrice::<T>::remove(currency_id);
+			Self::deposit_event(Event::CircuitBreakerReset(currency_id));
+			Ok(())
+		}
+
+		/// Update the price sources of specific currency
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `price_sources`: the new price sources, `None` means access the price by default.
+		#[pallet::weight((T::WeightInfo::set_price_sources(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_sources(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			price_sources: Option<PriceSourceSet>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			match price_sources {
+				Some(ref price_sources) => {
+					ensure!(
+						Self::is_valid_price_sources(currency_id, price_sources),
+						Error::<T>::InvalidPriceSources
+					);
+					PriceSources::<T>::insert(currency_id, price_sources);
+				}
+				None => PriceSources::<T>::remove(currency_id),
+			}
+			Self::deposit_event(Event::PriceSourcesUpdated(currency_id, price_sources));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	/// access the exchange rate of specific currency to USD,
-	/// it always access the real-time price directly.
+	/// it always access the real-time price directly. The price jumps beyond
+	/// the max deviation bound is rejected as soon as it arrives, before the
+	/// circuit breaker is tripped on the next block.
 	///
 	/// Note: this returns the price for 1 basic unit
 	fn access_price(currency_id: CurrencyId) -> Option<Price> {
+		let price = Self::access_unchecked_price(currency_id)?;
+		if let Some(max_deviation_per_block) = Self::max_deviation_per_block(currency_id) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			if Self::exceeded_deviation(currency_id, price, max_deviation_per_block, now).is_some() {
+				return None;
+			}
+		}
+		Some(price)
+	}
+
+	/// access the real-time price without checking the max deviation bound.
+	fn access_unchecked_price(currency_id: CurrencyId) -> Option<Price> {
+		if let Some(price_sources) = Self::price_sources(currency_id) {
+			// aggregate the prices from the configured sources
+			return Self::aggregate_price(currency_id, price_sources);
+		}
+
 		let maybe_price = if currency_id == T::GetStableCurrencyId::get() {
 			// if is stable currency, use fixed price
 			Some(T::StableCurrencyFixedPrice::get())
@@ -181,24 +489,298 @@ impl<T: Config> Pallet<T> {
 			};
 		} else {
 			// get real-time price from oracle
-			T::Source::get(&currency_id)
+			Self::access_oracle_price(currency_id)
 		};
 
-		let maybe_adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into());
+		// return the price for 1 basic unit
+		maybe_price.and_then(|price| Self::price_for_basic_unit(currency_id, price))
+	}
+
+	/// convert the price for 1 unit to the price for 1 basic unit
+	fn price_for_basic_unit(currency_id: CurrencyId, price: Price) -> Option<Price> {
+		let adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into())?;
+		Price::checked_from_rational(price.into_inner(), adjustment_multiplier)
+	}
+
+	/// access the price from oracle, the price whose age exceeds the max
+	/// staleness will be treated as invalid.
+	fn access_oracle_price(currency_id: CurrencyId) -> Option<Price> {
+		match Self::max_staleness(currency_id) {
+			Some(max_staleness) => Self::filter_stale_price(
+				<T::Source as DataProviderExtended<CurrencyId, TimestampedPrice>>::get_no_op(&currency_id)?,
+				max_staleness,
+			),
+			None => T::Source::get(&currency_id),
+		}
+	}
+
+	fn filter_stale_price(timestamped_price: TimestampedPrice, max_staleness: Moment) -> Option<Price> {
+		let TimestampedValue { value, timestamp } = timestamped_price;
+		let now: Moment = T::UnixTime::now().as_millis().saturated_into();
+		if now.saturating_sub(timestamp) > max_staleness {
+			None
+		} else {
+			Some(value)
+		}
+	}
+
+	/// access the price for 1 basic unit from the specific source
+	fn access_price_from_source(currency_id: CurrencyId, source: PriceSource) -> Option<Price> {
+		match source {
+			PriceSource::Oracle(provider_id) => {
+				let timestamped_price = T::OracleSources::get(&(provider_id, currency_id))?;
+				let price = match Self::max_staleness(currency_id) {
+					Some(max_staleness) => Self::filter_stale_price(timestamped_price, max_staleness)?,
+					None => timestamped_price.value,
+				};
+				Self::price_for_basic_unit(currency_id, price)
+			}
+			PriceSource::DexTwap(window) => {
+				let stable_currency_id = T::GetStableCurrencyId::get();
+				let average_price = T::DEX::get_time_weighted_average_price(currency_id, stable_currency_id, window)?;
+				average_price.checked_mul(&Self::access_price(stable_currency_id)?)
+			}
+			PriceSource::LiquidStaking => Self::access_price(T::GetStakingCurrencyId::get())
+				.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate())),
+			PriceSource::Fixed(price) => Self::price_for_basic_unit(currency_id, price),
+		}
+	}
+
+	/// aggregate the prices from the available sources by the aggregation
+	/// method
+	fn aggregate_price(currency_id: CurrencyId, price_sources: PriceSourceSet) -> Option<Price> {
+		let mut prices: Vec<(Price, u32)> = price_sources
+			.sources
+			.iter()
+			.filter_map(|(source, weight)| Self::access_price_from_source(currency_id, *source).map(|p| (p, *weight)))
+			.collect();
+		if prices.is_empty() || (prices.len() as u32) < price_sources.min_sources {
+			return None;
+		}
+
+		match price_sources.aggregation {
+			AggregationMethod::Priority => prices.first().map(|(price, _)| *price),
+			AggregationMethod::Median => {
+				prices.sort_by_key(|(price, _)| *price);
+				let mid_index = prices.len() / 2;
+				if prices.len() % 2 == 1 {
+					Some(prices[mid_index].0)
+				} else {
+					// take the average of the two middle prices
+					let (lower, upper) = (prices[mid_index - 1].0, prices[mid_index].0);
+					Some(Price::from_inner(
+						lower.into_inner().saturating_add(upper.into_inner()) / 2,
+					))
+				}
+			}
+			AggregationMethod::WeightedMedian => {
+				prices.sort_by_key(|(price, _)| *price);
+				let total_weight: u64 = prices.iter().map(|(_, weight)| u64::from(*weight)).sum();
+				let mut accumulated_weight: u64 = 0;
+				prices
+					.into_iter()
+					.find(|(_, weight)| {
+						accumulated_weight = accumulated_weight.saturating_add(u64::from(*weight));
+						accumulated_weight.saturating_mul(2) >= total_weight
+					})
+					.map(|(price, _)| price)
+			}
+		}
+	}
+
+	fn is_valid_price_sources(currency_id: CurrencyId, price_sources: &PriceSourceSet) -> bool {
+		let sources_count = price_sources.sources.len() as u32;
+		!price_sources.sources.is_empty()
+			&& sources_count <= T::MaxPriceSources::get()
+			&& !price_sources.min_sources.is_zero()
+			&& price_sources.min_sources <= sources_count
+			&& price_sources.sources.iter().all(|(source, weight)| {
+				!weight.is_zero()
+					&& match source {
+						// avoid the circular dependency when access price
+						PriceSource::DexTwap(window) => {
+							currency_id != T::GetStableCurrencyId::get() && !window.is_zero()
+						}
+						PriceSource::LiquidStaking => currency_id == T::GetLiquidCurrencyId::get(),
+						_ => true,
+					}
+			})
+	}
+
+	/// Return the last accepted price if the deviation of the price from it
+	/// exceeds the bound accumulated by the elapsed blocks.
+	fn exceeded_deviation(
+		currency_id: CurrencyId,
+		price: Price,
+		max_deviation_per_block: Ratio,
+		now: T::BlockNumber,
+	) -> Option<Price> {
+		let (last_price, last_block) = Self::last_accepted_price(currency_id)?;
+		if last_price.is_zero() {
+			return None;
+		}
+
+		let elapsed_blocks: u32 = now.saturating_sub(last_block).saturated_into::<u32>().max(1);
+		let max_deviation = max_deviation_per_block.saturating_mul(Ratio::saturating_from_integer(elapsed_blocks));
+		let deviation = Ratio::checked_from_rational(
+			price
+				.saturating_sub(last_price)
+				.max(last_price.saturating_sub(price))
+				.into_inner(),
+			last_price.into_inner(),
+		)
+		.unwrap_or_else(Ratio::max_value);
 
-		if let (Some(price), Some(adjustment_multiplier)) = (maybe_price, maybe_adjustment_multiplier) {
-			// return the price for 1 basic unit
-			Price::checked_from_rational(price.into_inner(), adjustment_multiplier)
+		if deviation > max_deviation {
+			Some(last_price)
 		} else {
 			None
 		}
 	}
+
+	/// Compare the price with the last accepted price, trip the circuit breaker
+	/// and lock the last accepted price if the deviation exceeds the bound.
+	fn check_price_deviation(
+		currency_id: CurrencyId,
+		price: Price,
+		max_deviation_per_block: Ratio,
+		now: T::BlockNumber,
+	) {
+		if let Some(last_price) = Self::exceeded_deviation(currency_id, price, max_deviation_per_block, now) {
+			TrippedCircuitBreakers::<T>::insert(currency_id, now);
+			// do not override the price locked before
+			if !LockedPrice::<T>::contains_key(currency_id) {
+				LockedPrice::<T>::insert(currency_id, last_price);
+				Self::deposit_event(Event::LockPrice(currency_id, last_price));
+			}
+			Self::deposit_event(Event::CircuitBreakerTripped(currency_id, last_price, price));
+			return;
+		}
+
+		LastAcceptedPrice::<T>::insert(currency_id, (price, now));
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Record the price used by system as a snapshot, the locked price takes
+	/// precedence over the real-time price.
+	fn record_price_snapshot(currency_id: CurrencyId, now: T::BlockNumber) {
+		let max_snapshots = T::MaxPriceSnapshots::get();
+		if max_snapshots.is_zero() {
+			return;
+		}
+
+		if let Some(price) = Self::locked_price(currency_id).or_else(|| Self::access_price(currency_id)) {
+			// clamp the index in case `MaxPriceSnapshots` has been lowered
+			let index = Self::next_price_snapshot_index(currency_id) % max_snapshots;
+			PriceSnapshots::<T>::insert(currency_id, index, (now, price));
+			NextPriceSnapshotIndex::<T>::insert(currency_id, index.saturating_add(1) % max_snapshots);
+		}
+	}
+
+	/// Get all the price snapshots of the currency, sorted by block number.
+	pub fn get_price_snapshots(currency_id: CurrencyId) -> Vec<(T::BlockNumber, Price)> {
+		let mut snapshots: Vec<(T::BlockNumber, Price)> =
+			PriceSnapshots::<T>::iter_prefix_values(currency_id).collect();
+		snapshots.sort_by_key(|(block_number, _)| *block_number);
+		snapshots
+	}
+
+	/// Get the latest price snapshot recorded at or before the block number.
+	pub fn get_price_snapshot_at(
+		currency_id: CurrencyId,
+		block_number: T::BlockNumber,
+	) -> Option<(T::BlockNumber, Price)> {
+		PriceSnapshots::<T>::iter_prefix_values(currency_id)
+			.filter(|(snapshot_block_number, _)| *snapshot_block_number <= block_number)
+			.max_by_key(|(snapshot_block_number, _)| *snapshot_block_number)
+	}
+
+	/// Get the aggregates of the price snapshots recorded within the blocks
+	/// window `[from, to]`.
+	pub fn get_price_snapshot_aggregates(
+		currency_id: CurrencyId,
+		from: T::BlockNumber,
+		to: T::BlockNumber,
+	) -> Option<PriceAggregates> {
+		let prices: Vec<Price> = PriceSnapshots::<T>::iter_prefix_values(currency_id)
+			.filter(|(block_number, _)| from <= *block_number && *block_number <= to)
+			.map(|(_, price)| price)
+			.collect();
+		let min = *prices.iter().min()?;
+		let max = *prices.iter().max()?;
+		let count = prices.len() as u32;
+		let total = prices
+			.iter()
+			.fold(0u128, |total, price| total.saturating_add(price.into_inner()));
+
+		Some(PriceAggregates {
+			min,
+			max,
+			average: Price::from_inner(total / u128::from(count)),
+			count,
+		})
+	}
+}
+
+/// Prune the price snapshots recorded in the slots out of the range of
+/// `MaxPriceSnapshots` after it has been lowered, and clamp the next slot
+/// index.
+pub struct PruneOutOfRangePriceSnapshots<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for PruneOutOfRangePriceSnapshots<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let max_snapshots = T::MaxPriceSnapshots::get();
+		let mut reads: Weight = 0;
+		let mut writes: Weight = 0;
+
+		let out_of_range_slots: Vec<(CurrencyId, u32)> = PriceSnapshots::<T>::iter_keys()
+			.inspect(|_| reads = reads.saturating_add(1))
+			.filter(|(_, index)| *index >= max_snapshots)
+			.collect();
+		for (currency_id, index) in out_of_range_slots {
+			PriceSnapshots::<T>::remove(currency_id, index);
+			writes = writes.saturating_add(1);
+		}
+
+		let out_of_range_indexes: Vec<CurrencyId> = NextPriceSnapshotIndex::<T>::iter()
+			.inspect(|_| reads = reads.saturating_add(1))
+			.filter(|(_, index)| *index >= max_snapshots)
+			.map(|(currency_id, _)| currency_id)
+			.collect();
+		for currency_id in out_of_range_indexes {
+			NextPriceSnapshotIndex::<T>::remove(currency_id);
+			writes = writes.saturating_add(1);
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		let max_snapshots = T::MaxPriceSnapshots::get();
+		ensure!(
+			PriceSnapshots::<T>::iter_keys().all(|(_, index)| index < max_snapshots),
+			"price snapshot slot is out of range"
+		);
+		ensure!(
+			NextPriceSnapshotIndex::<T>::iter_values().all(|index| index < max_snapshots),
+			"next price snapshot index is out of range"
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> PriceCircuitBreaker<CurrencyId> for Pallet<T> {
+	fn is_tripped(currency_id: CurrencyId) -> bool {
+		TrippedCircuitBreakers::<T>::contains_key(currency_id)
+	}
 }
 
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
-	/// Record the real-time price from oracle as the locked price
+	/// Record the real-time price from oracle as the locked price, the
+	/// explicit lock is not restricted by the max deviation bound.
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult {
-		let price = Self::access_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
+		let price = Self::access_unchecked_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
 		LockedPrice::<T>::insert(currency_id, price);
 		Pallet::<T>::deposit_event(Event::LockPrice(currency_id, price));
 		Ok(())
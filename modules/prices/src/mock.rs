@@ -24,14 +24,15 @@ use super::*;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
 use orml_traits::{parameter_type_with_key, DataFeeder};
-use primitives::{currency::DexShare, Amount, TokenSymbol};
+use primitives::Moment;
+use primitives::{currency::DexShare, Amount, DataProviderId, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{IdentityLookup, One as OneT, Zero},
 	DispatchError, FixedPointNumber,
 };
-use sp_std::cell::RefCell;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 use support::{mocks::MockCurrencyIdMapping, ExchangeRate};
 
 pub type AccountId = u128;
@@ -82,12 +83,25 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static ORACLE_TIMESTAMP: RefCell<Moment> = RefCell::new(0);
+	static ORACLE_SOURCE_PRICES: RefCell<BTreeMap<(DataProviderId, CurrencyId), Price>> = RefCell::new(BTreeMap::new());
 }
 
 pub fn mock_oracle_update() {
 	CHANGED.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_oracle_timestamp(timestamp: Moment) {
+	ORACLE_TIMESTAMP.with(|v| *v.borrow_mut() = timestamp)
+}
+
+pub fn mock_oracle_source_price(provider_id: DataProviderId, currency_id: CurrencyId, price: Option<Price>) {
+	ORACLE_SOURCE_PRICES.with(|v| match price {
+		Some(price) => v.borrow_mut().insert((provider_id, currency_id), price),
+		None => v.borrow_mut().remove(&(provider_id, currency_id)),
+	});
+}
+
 pub struct MockDataProvider;
 impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	fn get(currency_id: &CurrencyId) -> Option<Price> {
@@ -113,6 +127,31 @@ impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	}
 }
 
+impl DataProviderExtended<CurrencyId, TimestampedPrice> for MockDataProvider {
+	fn get_no_op(currency_id: &CurrencyId) -> Option<TimestampedPrice> {
+		Self::get(currency_id).map(|value| TimestampedPrice {
+			value,
+			timestamp: ORACLE_TIMESTAMP.with(|v| *v.borrow()),
+		})
+	}
+
+	fn get_all_values() -> Vec<(CurrencyId, Option<TimestampedPrice>)> {
+		unimplemented!()
+	}
+}
+
+pub struct MockOracleSources;
+impl DataProvider<(DataProviderId, CurrencyId), TimestampedPrice> for MockOracleSources {
+	fn get(key: &(DataProviderId, CurrencyId)) -> Option<TimestampedPrice> {
+		ORACLE_SOURCE_PRICES
+			.with(|v| v.borrow().get(key).cloned())
+			.map(|value| TimestampedPrice {
+				value,
+				timestamp: ORACLE_TIMESTAMP.with(|v| *v.borrow()),
+			})
+	}
+}
+
 impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	fn feed_value(_: AccountId, _: CurrencyId, _: Price) -> sp_runtime::DispatchResult {
 		Ok(())
@@ -151,6 +190,33 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_price_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		unimplemented!()
+	}
+
+	fn get_best_price_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		unimplemented!()
+	}
+
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		_window: u32,
+	) -> Option<ExchangeRate> {
+		match (currency_id_a, currency_id_b) {
+			(DOT, AUSD) => Some(ExchangeRate::saturating_from_integer(9000)),
+			_ => None,
+		}
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -169,6 +235,16 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_max_supply_amount: Balance,
+		_callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -212,6 +288,17 @@ impl orml_tokens::Config for Runtime {
 	type DustRemovalWhitelist = ();
 }
 
+parameter_types! {
+	pub const MinimumPeriod: Moment = 1000;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -221,16 +308,27 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub StableCurrencyFixedPrice: Price = Price::one();
+	pub const MaxPriceSources: u32 = 3;
+	pub PriceSnapshotCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
+	pub const PriceSnapshotInterval: BlockNumber = 10;
+	pub const MaxPriceSnapshots: u32 = 3;
 }
 
 impl Config for Runtime {
 	type Event = Event;
 	type Source = MockDataProvider;
+	type OracleSources = MockOracleSources;
+	type MaxPriceSources = MaxPriceSources;
+	type PriceSnapshotCurrencyIds = PriceSnapshotCurrencyIds;
+	type PriceSnapshotInterval = PriceSnapshotInterval;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
 	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type UnixTime = Timestamp;
 	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
 	type DEX = MockDEX;
 	type Currency = Tokens;
@@ -250,6 +348,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		PricesModule: prices::{Pallet, Storage, Call, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 	}
 );
 
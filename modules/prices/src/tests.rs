@@ -508,3 +508,561 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(BTC, KSM), None);
 	});
 }
+
+#[test]
+fn set_price_safety_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			PricesModule::set_price_safety_params(
+				Origin::signed(5),
+				BTC,
+				Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+				Change::NewValue(Some(3_600_000)),
+			),
+			BadOrigin
+		);
+
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+			Change::NewValue(Some(3_600_000)),
+		));
+		System::assert_has_event(Event::PricesModule(crate::Event::MaxDeviationPerBlockUpdated(
+			BTC,
+			Some(Ratio::saturating_from_rational(1, 10)),
+		)));
+		System::assert_has_event(Event::PricesModule(crate::Event::MaxStalenessUpdated(
+			BTC,
+			Some(3_600_000),
+		)));
+		assert_eq!(
+			PricesModule::max_deviation_per_block(BTC),
+			Some(Ratio::saturating_from_rational(1, 10))
+		);
+		assert_eq!(PricesModule::max_staleness(BTC), Some(3_600_000));
+
+		PricesModule::on_initialize(1);
+		assert!(PricesModule::last_accepted_price(BTC).is_some());
+
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(None),
+			Change::NoChange,
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::MaxDeviationPerBlockUpdated(
+			BTC, None,
+		)));
+		assert_eq!(PricesModule::max_deviation_per_block(BTC), None);
+		assert_eq!(PricesModule::last_accepted_price(BTC), None);
+		assert_eq!(PricesModule::max_staleness(BTC), Some(3_600_000));
+	});
+}
+
+#[test]
+fn access_price_with_max_staleness() {
+	ExtBuilder::default().build().execute_with(|| {
+		Timestamp::set_timestamp(10_000);
+		mock_oracle_timestamp(8_000);
+
+		let btc_price = PricesModule::access_price(BTC);
+		let ldot_price = PricesModule::access_price(LDOT);
+		assert!(btc_price.is_some());
+		assert!(ldot_price.is_some());
+
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(2_000)),
+		));
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			DOT,
+			Change::NoChange,
+			Change::NewValue(Some(1_999)),
+		));
+		assert_eq!(PricesModule::access_price(BTC), btc_price);
+
+		// the price of DOT is stale, and the price of LDOT depends on it
+		assert_eq!(PricesModule::access_price(DOT), None);
+		assert_eq!(PricesModule::access_price(LDOT), None);
+
+		Timestamp::set_timestamp(10_001);
+		assert_eq!(PricesModule::access_price(BTC), None);
+
+		// stale price cannot be locked
+		assert_noop!(
+			PricesModule::lock_price(Origin::signed(1), BTC),
+			Error::<Runtime>::AccessPriceFailed
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+			Change::NoChange,
+		));
+		let old_price = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some((old_price, 1)));
+		assert!(!PricesModule::is_tripped(BTC));
+
+		// the price of BTC drops 20% in one block
+		mock_oracle_update();
+		let new_price = PricesModule::access_unchecked_price(BTC).unwrap();
+		// the price beyond the bound is rejected before the circuit breaker is tripped
+		assert_eq!(PricesModule::access_price(BTC), None);
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(BTC), None);
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(BTC), None);
+		PricesModule::on_initialize(2);
+		System::assert_has_event(Event::PricesModule(crate::Event::LockPrice(BTC, old_price)));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerTripped(
+			BTC, old_price, new_price,
+		)));
+		assert!(PricesModule::is_tripped(BTC));
+		assert_eq!(PricesModule::tripped_circuit_breakers(BTC), Some(2));
+		assert_eq!(PricesModule::locked_price(BTC), Some(old_price));
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some((old_price, 1)));
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(BTC), Some(old_price));
+
+		// tripped breaker will not be checked again
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::tripped_circuit_breakers(BTC), Some(2));
+
+		assert_noop!(PricesModule::reset_circuit_breaker(Origin::signed(5), BTC), BadOrigin);
+		assert_noop!(
+			PricesModule::reset_circuit_breaker(Origin::signed(1), DOT),
+			Error::<Runtime>::CircuitBreakerNotTripped
+		);
+		assert_ok!(PricesModule::reset_circuit_breaker(Origin::signed(1), BTC));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerReset(BTC)));
+		assert!(!PricesModule::is_tripped(BTC));
+		assert_eq!(PricesModule::last_accepted_price(BTC), None);
+		// the locked price is kept after reset
+		assert_eq!(PricesModule::locked_price(BTC), Some(old_price));
+
+		PricesModule::on_initialize(4);
+		assert!(!PricesModule::is_tripped(BTC));
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some((new_price, 4)));
+	});
+}
+
+#[test]
+fn circuit_breaker_bound_accumulates_by_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+			Change::NoChange,
+		));
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			DOT,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+			Change::NoChange,
+		));
+		let old_dot_price = PricesModule::access_price(DOT).unwrap();
+		PricesModule::on_initialize(1);
+
+		// the price of BTC drops 20% and the price of DOT drops 90% in two blocks
+		mock_oracle_update();
+		let new_btc_price = PricesModule::access_unchecked_price(BTC).unwrap();
+		let new_dot_price = PricesModule::access_unchecked_price(DOT).unwrap();
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), DOT));
+		System::set_block_number(3);
+		assert_eq!(PricesModule::access_price(BTC), Some(new_btc_price));
+		assert_eq!(PricesModule::access_price(DOT), None);
+		PricesModule::on_initialize(3);
+
+		assert!(!PricesModule::is_tripped(BTC));
+		assert_eq!(PricesModule::last_accepted_price(BTC), Some((new_btc_price, 3)));
+
+		// the locked price will not be overridden by the circuit breaker
+		assert!(PricesModule::is_tripped(DOT));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerTripped(
+			DOT,
+			old_dot_price,
+			new_dot_price,
+		)));
+		assert_eq!(PricesModule::locked_price(DOT), Some(new_dot_price));
+	});
+}
+
+#[test]
+fn set_price_sources_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let price_sources = PriceSourceSet {
+			sources: vec![
+				(PriceSource::Oracle(DataProviderId::Acala), 1),
+				(PriceSource::Fixed(Price::saturating_from_integer(45000)), 1),
+			],
+			aggregation: AggregationMethod::Priority,
+			min_sources: 1,
+		};
+		assert_noop!(
+			PricesModule::set_price_sources(Origin::signed(5), BTC, Some(price_sources.clone())),
+			BadOrigin
+		);
+
+		let invalid_price_sources = vec![
+			// empty sources
+			PriceSourceSet {
+				sources: vec![],
+				aggregation: AggregationMethod::Median,
+				min_sources: 1,
+			},
+			// exceed max price sources
+			PriceSourceSet {
+				sources: vec![(PriceSource::Oracle(DataProviderId::Acala), 1); 4],
+				aggregation: AggregationMethod::Median,
+				min_sources: 1,
+			},
+			// zero min sources
+			PriceSourceSet {
+				sources: vec![(PriceSource::Oracle(DataProviderId::Acala), 1)],
+				aggregation: AggregationMethod::Median,
+				min_sources: 0,
+			},
+			// min sources exceed the count of sources
+			PriceSourceSet {
+				sources: vec![(PriceSource::Oracle(DataProviderId::Acala), 1)],
+				aggregation: AggregationMethod::Median,
+				min_sources: 2,
+			},
+			// zero weight
+			PriceSourceSet {
+				sources: vec![(PriceSource::Oracle(DataProviderId::Acala), 0)],
+				aggregation: AggregationMethod::WeightedMedian,
+				min_sources: 1,
+			},
+			// liquid staking source for non-liquid currency
+			PriceSourceSet {
+				sources: vec![(PriceSource::LiquidStaking, 1)],
+				aggregation: AggregationMethod::Priority,
+				min_sources: 1,
+			},
+			// zero window of DEX TWAP
+			PriceSourceSet {
+				sources: vec![(PriceSource::DexTwap(0), 1)],
+				aggregation: AggregationMethod::Priority,
+				min_sources: 1,
+			},
+		];
+		for invalid in invalid_price_sources {
+			assert_noop!(
+				PricesModule::set_price_sources(Origin::signed(1), BTC, Some(invalid)),
+				Error::<Runtime>::InvalidPriceSources
+			);
+		}
+		// DEX TWAP source for stable currency
+		assert_noop!(
+			PricesModule::set_price_sources(
+				Origin::signed(1),
+				AUSD,
+				Some(PriceSourceSet {
+					sources: vec![(PriceSource::DexTwap(10), 1)],
+					aggregation: AggregationMethod::Priority,
+					min_sources: 1,
+				})
+			),
+			Error::<Runtime>::InvalidPriceSources
+		);
+
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			BTC,
+			Some(price_sources.clone())
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcesUpdated(
+			BTC,
+			Some(price_sources.clone()),
+		)));
+		assert_eq!(PricesModule::price_sources(BTC), Some(price_sources));
+
+		assert_ok!(PricesModule::set_price_sources(Origin::signed(1), BTC, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcesUpdated(BTC, None)));
+		assert_eq!(PricesModule::price_sources(BTC), None);
+	});
+}
+
+#[test]
+fn access_price_by_priority_of_sources() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			BTC,
+			Some(PriceSourceSet {
+				sources: vec![
+					(PriceSource::Oracle(DataProviderId::Acala), 1),
+					(PriceSource::Fixed(Price::saturating_from_integer(45000)), 1),
+				],
+				aggregation: AggregationMethod::Priority,
+				min_sources: 1,
+			})
+		));
+
+		// fallback to the fixed price
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(45000))
+		);
+
+		mock_oracle_source_price(DataProviderId::Acala, BTC, Some(Price::saturating_from_integer(48000)));
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(48000))
+		);
+
+		// the stale oracle price is unavailable
+		Timestamp::set_timestamp(10_000);
+		assert_ok!(PricesModule::set_price_safety_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(1_000)),
+		));
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(45000))
+		);
+	});
+}
+
+#[test]
+fn access_price_by_median_of_sources() {
+	ExtBuilder::default().build().execute_with(|| {
+		mock_oracle_source_price(DataProviderId::Acala, BTC, Some(Price::saturating_from_integer(48000)));
+		mock_oracle_source_price(
+			DataProviderId::Aggregated,
+			BTC,
+			Some(Price::saturating_from_integer(50000)),
+		);
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			BTC,
+			Some(PriceSourceSet {
+				sources: vec![
+					(PriceSource::Oracle(DataProviderId::Acala), 1),
+					(PriceSource::Oracle(DataProviderId::Aggregated), 1),
+					(PriceSource::Fixed(Price::saturating_from_integer(45000)), 1),
+				],
+				aggregation: AggregationMethod::Median,
+				min_sources: 2,
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(48000))
+		);
+
+		// take the average of the two middle prices
+		mock_oracle_source_price(DataProviderId::Acala, BTC, None);
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(47500))
+		);
+
+		// the count of available prices is below the min sources
+		mock_oracle_source_price(DataProviderId::Aggregated, BTC, None);
+		assert_eq!(PricesModule::access_price(BTC), None);
+	});
+}
+
+#[test]
+fn access_price_by_weighted_median_of_sources() {
+	ExtBuilder::default().build().execute_with(|| {
+		mock_oracle_source_price(DataProviderId::Acala, BTC, Some(Price::saturating_from_integer(48000)));
+		mock_oracle_source_price(
+			DataProviderId::Aggregated,
+			BTC,
+			Some(Price::saturating_from_integer(50000)),
+		);
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			BTC,
+			Some(PriceSourceSet {
+				sources: vec![
+					(PriceSource::Oracle(DataProviderId::Acala), 1),
+					(PriceSource::Oracle(DataProviderId::Aggregated), 3),
+					(PriceSource::Fixed(Price::saturating_from_integer(45000)), 1),
+				],
+				aggregation: AggregationMethod::WeightedMedian,
+				min_sources: 1,
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(50000))
+		);
+
+		mock_oracle_source_price(DataProviderId::Aggregated, BTC, None);
+		assert_eq!(
+			PricesModule::access_price(BTC),
+			PricesModule::price_for_basic_unit(BTC, Price::saturating_from_integer(45000))
+		);
+	});
+}
+
+#[test]
+fn access_price_from_dex_twap_and_liquid_staking_sources() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			DOT,
+			Some(PriceSourceSet {
+				sources: vec![(PriceSource::DexTwap(10), 1)],
+				aggregation: AggregationMethod::Priority,
+				min_sources: 1,
+			})
+		));
+		assert_ok!(PricesModule::set_price_sources(
+			Origin::signed(1),
+			LDOT,
+			Some(PriceSourceSet {
+				sources: vec![(PriceSource::LiquidStaking, 1)],
+				aggregation: AggregationMethod::Priority,
+				min_sources: 1,
+			})
+		));
+
+		// 9000 AUSD basic units for 1 DOT basic unit
+		let dot_price = PricesModule::access_price(AUSD)
+			.unwrap()
+			.saturating_mul(Price::saturating_from_integer(9000));
+		assert_eq!(PricesModule::access_price(DOT), Some(dot_price));
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(dot_price.saturating_mul(Price::saturating_from_rational(1, 2)))
+		);
+	});
+}
+
+#[test]
+fn record_price_snapshots_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let btc_price_1 = PricesModule::access_price(BTC).unwrap();
+		let dot_price_1 = PricesModule::access_price(DOT).unwrap();
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), DOT));
+
+		// not the snapshot interval
+		PricesModule::on_initialize(5);
+		assert_eq!(PricesModule::get_price_snapshots(BTC), vec![]);
+
+		PricesModule::on_initialize(10);
+		PricesModule::on_initialize(20);
+		mock_oracle_update();
+		let btc_price_2 = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(30);
+		assert_eq!(
+			PricesModule::get_price_snapshots(BTC),
+			vec![(10, btc_price_1), (20, btc_price_1), (30, btc_price_2)]
+		);
+		assert_eq!(PricesModule::next_price_snapshot_index(BTC), 0);
+
+		// the locked price takes precedence
+		assert_eq!(
+			PricesModule::get_price_snapshots(DOT),
+			vec![(10, dot_price_1), (20, dot_price_1), (30, dot_price_1)]
+		);
+
+		// the currency not in the snapshot list
+		assert_eq!(PricesModule::get_price_snapshots(ACA), vec![]);
+
+		// overwrite the oldest snapshot
+		PricesModule::on_initialize(40);
+		assert_eq!(PricesModule::price_snapshots(BTC, 0), Some((40, btc_price_2)));
+		assert_eq!(PricesModule::next_price_snapshot_index(BTC), 1);
+		assert_eq!(
+			PricesModule::get_price_snapshots(BTC),
+			vec![(20, btc_price_1), (30, btc_price_2), (40, btc_price_2)]
+		);
+	});
+}
+
+#[test]
+fn record_price_snapshot_clamps_out_of_range_index() {
+	ExtBuilder::default().build().execute_with(|| {
+		let btc_price = PricesModule::access_price(BTC).unwrap();
+		// the index recorded before `MaxPriceSnapshots` was lowered
+		NextPriceSnapshotIndex::<Runtime>::insert(BTC, 4);
+
+		PricesModule::on_initialize(10);
+		assert_eq!(PricesModule::price_snapshots(BTC, 1), Some((10, btc_price)));
+		assert_eq!(PricesModule::price_snapshots(BTC, 4), None);
+		assert_eq!(PricesModule::next_price_snapshot_index(BTC), 2);
+	});
+}
+
+#[test]
+fn prune_out_of_range_price_snapshots_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let btc_price = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(10);
+		// the slots recorded before `MaxPriceSnapshots` was lowered
+		PriceSnapshots::<Runtime>::insert(BTC, 3, (20, btc_price));
+		PriceSnapshots::<Runtime>::insert(BTC, 4, (30, btc_price));
+		NextPriceSnapshotIndex::<Runtime>::insert(BTC, 5);
+		NextPriceSnapshotIndex::<Runtime>::insert(DOT, 1);
+
+		assert_eq!(
+			PruneOutOfRangePriceSnapshots::<Runtime>::on_runtime_upgrade(),
+			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(6, 3)
+		);
+		assert_eq!(PricesModule::get_price_snapshots(BTC), vec![(10, btc_price)]);
+		assert_eq!(PricesModule::next_price_snapshot_index(BTC), 0);
+		assert_eq!(PricesModule::next_price_snapshot_index(DOT), 1);
+	});
+}
+
+#[test]
+fn query_price_snapshots_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let btc_price_1 = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(10);
+		PricesModule::on_initialize(20);
+		mock_oracle_update();
+		let btc_price_2 = PricesModule::access_price(BTC).unwrap();
+		PricesModule::on_initialize(30);
+
+		assert_eq!(PricesModule::get_price_snapshot_at(BTC, 9), None);
+		assert_eq!(PricesModule::get_price_snapshot_at(BTC, 10), Some((10, btc_price_1)));
+		assert_eq!(PricesModule::get_price_snapshot_at(BTC, 29), Some((20, btc_price_1)));
+		assert_eq!(PricesModule::get_price_snapshot_at(BTC, 100), Some((30, btc_price_2)));
+
+		assert_eq!(PricesModule::get_price_snapshot_aggregates(BTC, 0, 9), None);
+		assert_eq!(
+			PricesModule::get_price_snapshot_aggregates(BTC, 10, 30),
+			Some(PriceAggregates {
+				min: btc_price_2,
+				max: btc_price_1,
+				average: Price::from_inner((btc_price_1.into_inner() * 2 + btc_price_2.into_inner()) / 3),
+				count: 3,
+			})
+		);
+		assert_eq!(
+			PricesModule::get_price_snapshot_aggregates(BTC, 11, 20),
+			Some(PriceAggregates {
+				min: btc_price_1,
+				max: btc_price_1,
+				average: btc_price_1,
+				count: 1,
+			})
+		);
+	});
+}
@@ -0,0 +1,927 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Stable Asset Module
+//!
+//! ## Overview
+//!
+//! StableSwap pools for pegged assets like LDOT/DOT and KSM/LKSM, the swap
+//! mechanism refers to the design of Curve. A pool holds two or more assets,
+//! whose balances are normalized to the same decimals by their precisions, and
+//! prices them by the StableSwap invariant with the amplification coefficient
+//! `A`: the higher `A` is, the closer the price stays to 1:1 until the pool is
+//! heavily imbalanced. The liquidity of a pool is represented by its pool token
+//! `CurrencyId::StableAssetPoolToken(pool_id)`.
+//!
+//! The `ListingOrigin` creates pools and ramps `A` of a pool linearly to a
+//! future value. Anyone can mint pool token by depositing assets, swap between
+//! the assets of a pool, and redeem pool token for assets in proportion or for
+//! a single asset. The swap fee is kept in the pool for the liquidity providers,
+//! the mint and redeem fees are paid in pool token to the fee recipient. Like
+//! Curve, the imbalanced part of a mint or a single asset redemption is charged
+//! the swap fee scaled by `n / (4 * (n - 1))` as if it were swapped, which is
+//! also kept in the pool.
+//!
+//! The pools are routable by the DEX module through `StableAssetRouter`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use primitives::{Balance, CurrencyId, StableAssetPoolId};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, prelude::*, vec};
+use support::{Rate, StableAssetRouter};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The maximum amplification coefficient.
+pub const MAX_A: Balance = 1_000_000;
+
+/// The maximum multiple that a ramping can increase or decrease the
+/// amplification coefficient by.
+pub const MAX_A_CHANGE: Balance = 10;
+
+/// The maximum iterations of Newton's method to solve the invariant.
+const MAX_ITERATIONS: u32 = 255;
+
+/// The stable asset pool.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct PoolInfo<AccountId, BlockNumber> {
+	/// The assets in the pool.
+	pub assets: Vec<CurrencyId>,
+	/// The multipliers to normalize the balances of assets to the same
+	/// decimals.
+	pub precisions: Vec<Balance>,
+	/// The balances of assets in the pool.
+	pub balances: Vec<Balance>,
+	/// The fee rate of minting, charged in pool token.
+	pub mint_fee: Rate,
+	/// The fee rate of swap, charged in target asset and kept in the pool. It
+	/// is also charged on the imbalanced part of mint and single asset
+	/// redemption.
+	pub swap_fee: Rate,
+	/// The fee rate of redeeming, charged in pool token.
+	pub redeem_fee: Rate,
+	/// The account which receives the mint and redeem fees.
+	pub fee_recipient: AccountId,
+	/// The amplification coefficient at `a_block`.
+	pub a: Balance,
+	/// The block number when the ramping of amplification coefficient starts.
+	pub a_block: BlockNumber,
+	/// The amplification coefficient at `future_a_block`.
+	pub future_a: Balance,
+	/// The block number when the ramping of amplification coefficient ends.
+	pub future_a_block: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency for transfer assets and issue pool token.
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The maximum number of assets in a pool.
+		#[pallet::constant]
+		type MaxPoolAssets: Get<u32>;
+
+		/// The stable asset module id, keep all assets in pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The origin which may create pools and ramp the amplification
+		/// coefficient.
+		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pool is not found
+		PoolNotFound,
+		/// The assets of pool are invalid
+		InvalidPoolAssets,
+		/// The precisions of assets are invalid
+		InvalidPrecisions,
+		/// The fee rate is invalid
+		InvalidFee,
+		/// The amplification coefficient is invalid
+		InvalidAmplification,
+		/// The ramping block is invalid
+		InvalidRampingBlock,
+		/// The asset index is invalid
+		InvalidAssetIndex,
+		/// The amounts don't match the assets of pool
+		InvalidAmounts,
+		/// The first mint must deposit all the assets of pool
+		InitialMintRequiresAllAssets,
+		/// The mint amount is less than min_mint_amount
+		InsufficientMintAmount,
+		/// The target amount is less than min_target_amount
+		InsufficientTargetAmount,
+		/// The redeem amount is less than min_redeem_amount
+		InsufficientRedeemAmount,
+		/// Liquidity is not enough
+		InsufficientLiquidity,
+		/// The invariant of pool can't be solved
+		InvariantCalculationFailed,
+		/// No pool can swap the currencies for the amounts
+		NoAvailablePool,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
+	pub enum Event<T: Config> {
+		/// Create stable asset pool. \[pool_id, assets\]
+		PoolCreated(StableAssetPoolId, Vec<CurrencyId>),
+		/// Ramp the amplification coefficient of pool. \[pool_id, future_a,
+		/// future_a_block\]
+		AmplificationRamped(StableAssetPoolId, Balance, T::BlockNumber),
+		/// Mint pool token. \[who, pool_id, amounts, mint_amount, fee\]
+		Minted(T::AccountId, StableAssetPoolId, Vec<Balance>, Balance, Balance),
+		/// Swap assets in pool. \[who, pool_id, supply_currency_id,
+		/// target_currency_id, supply_amount, target_amount\]
+		Swapped(
+			T::AccountId,
+			StableAssetPoolId,
+			CurrencyId,
+			CurrencyId,
+			Balance,
+			Balance,
+		),
+		/// Redeem pool token for assets in proportion. \[who, pool_id,
+		/// redeem_amount, amounts, fee\]
+		RedeemedProportion(T::AccountId, StableAssetPoolId, Balance, Vec<Balance>, Balance),
+		/// Redeem pool token for a single asset. \[who, pool_id,
+		/// redeem_amount, currency_id, amount, fee\]
+		RedeemedSingle(T::AccountId, StableAssetPoolId, Balance, CurrencyId, Balance, Balance),
+	}
+
+	/// The stable asset pools.
+	///
+	/// Pools: map StableAssetPoolId => Option<PoolInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> =
+		StorageMap<_, Twox64Concat, StableAssetPoolId, PoolInfo<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// The id of next created pool.
+	///
+	/// NextPoolId: StableAssetPoolId
+	#[pallet::storage]
+	#[pallet::getter(fn next_pool_id)]
+	pub type NextPoolId<T: Config> = StorageValue<_, StableAssetPoolId, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a stable asset pool, the pool token is
+		/// `StableAssetPoolToken(pool_id)`.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `assets`: the assets in the pool.
+		/// - `precisions`: the multipliers to normalize the balances of assets.
+		/// - `mint_fee`, `swap_fee`, `redeem_fee`: the fee rates.
+		/// - `initial_a`: the amplification coefficient.
+		/// - `fee_recipient`: the account which receives the mint and redeem
+		///   fees.
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			assets: Vec<CurrencyId>,
+			precisions: Vec<Balance>,
+			mint_fee: Rate,
+			swap_fee: Rate,
+			redeem_fee: Rate,
+			initial_a: Balance,
+			fee_recipient: T::AccountId,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(
+				assets.len() >= 2
+					&& assets.len() <= T::MaxPoolAssets::get() as usize
+					&& assets.iter().all(|currency_id| currency_id.is_token_currency_id())
+					&& assets
+						.iter()
+						.enumerate()
+						.all(|(i, currency_id)| !assets[i + 1..].contains(currency_id)),
+				Error::<T>::InvalidPoolAssets
+			);
+			ensure!(
+				precisions.len() == assets.len() && precisions.iter().all(|precision| !precision.is_zero()),
+				Error::<T>::InvalidPrecisions
+			);
+			ensure!(
+				mint_fee < Rate::one() && swap_fee < Rate::one() && redeem_fee < Rate::one(),
+				Error::<T>::InvalidFee
+			);
+			ensure!(
+				!initial_a.is_zero() && initial_a <= MAX_A,
+				Error::<T>::InvalidAmplification
+			);
+
+			let pool_id = Self::next_pool_id();
+			NextPoolId::<T>::put(pool_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			let now = <frame_system::Pallet<T>>::block_number();
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo {
+					assets: assets.clone(),
+					precisions,
+					balances: vec![Zero::zero(); assets.len()],
+					mint_fee,
+					swap_fee,
+					redeem_fee,
+					fee_recipient,
+					a: initial_a,
+					a_block: now,
+					future_a: initial_a,
+					future_a_block: now,
+				},
+			);
+
+			Self::deposit_event(Event::PoolCreated(pool_id, assets));
+			Ok(())
+		}
+
+		/// Ramp the amplification coefficient of pool linearly from the
+		/// current value to `future_a` at `future_a_block`.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `pool_id`: the pool id.
+		/// - `future_a`: the amplification coefficient at the end of ramping,
+		///   it can't be more than `MAX_A_CHANGE` times or less than
+		///   `1 / MAX_A_CHANGE` of the current value.
+		/// - `future_a_block`: the block number when the ramping ends.
+		#[pallet::weight(<T as Config>::WeightInfo::ramp_a())]
+		#[transactional]
+		pub fn ramp_a(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			future_a: Balance,
+			future_a_block: T::BlockNumber,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(future_a_block > now, Error::<T>::InvalidRampingBlock);
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+				let current_a = Self::current_a(pool);
+				ensure!(
+					!future_a.is_zero()
+						&& future_a <= MAX_A
+						&& future_a <= current_a.saturating_mul(MAX_A_CHANGE)
+						&& future_a.saturating_mul(MAX_A_CHANGE) >= current_a,
+					Error::<T>::InvalidAmplification
+				);
+
+				pool.a = current_a;
+				pool.a_block = now;
+				pool.future_a = future_a;
+				pool.future_a_block = future_a_block;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AmplificationRamped(pool_id, future_a, future_a_block));
+			Ok(())
+		}
+
+		/// Deposit assets to pool and mint pool token.
+		///
+		/// - `pool_id`: the pool id.
+		/// - `amounts`: the amounts of the assets to deposit, in the order of
+		///   the assets of pool.
+		/// - `min_mint_amount`: the minimum pool token received, the fee
+		///   excluded.
+		#[pallet::weight(<T as Config>::WeightInfo::mint())]
+		#[transactional]
+		pub fn mint(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			amounts: Vec<Balance>,
+			#[pallet::compact] min_mint_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint(&who, pool_id, amounts, min_mint_amount)
+		}
+
+		/// Swap the asset at index `i` of pool for the asset at index `j`.
+		///
+		/// - `pool_id`: the pool id.
+		/// - `i`: the index of the supply asset.
+		/// - `j`: the index of the target asset.
+		/// - `supply_amount`: the exact supply amount.
+		/// - `min_target_amount`: the acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap())]
+		#[transactional]
+		pub fn swap(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			i: u32,
+			j: u32,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool = Self::pools(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let (i, j) = (i as usize, j as usize);
+			ensure!(
+				i != j && i < pool.assets.len() && j < pool.assets.len(),
+				Error::<T>::InvalidAssetIndex
+			);
+			let target_amount =
+				Self::get_target_amount(&pool, i, j, supply_amount).ok_or(Error::<T>::InvariantCalculationFailed)?;
+			ensure!(target_amount >= min_target_amount, Error::<T>::InsufficientTargetAmount);
+			Self::do_swap(&who, pool_id, pool, i, j, supply_amount, target_amount)
+		}
+
+		/// Redeem pool token for all the assets of pool in proportion.
+		///
+		/// - `pool_id`: the pool id.
+		/// - `redeem_amount`: the pool token to redeem, the fee included.
+		/// - `min_redeem_amounts`: the acceptable minimum amounts of the
+		///   assets, in the order of the assets of pool.
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_proportion())]
+		#[transactional]
+		pub fn redeem_proportion(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			#[pallet::compact] redeem_amount: Balance,
+			min_redeem_amounts: Vec<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_proportion(&who, pool_id, redeem_amount, min_redeem_amounts)
+		}
+
+		/// Redeem pool token for the asset at index `i` of pool.
+		///
+		/// - `pool_id`: the pool id.
+		/// - `redeem_amount`: the pool token to redeem, the fee included.
+		/// - `i`: the index of the asset.
+		/// - `min_redeem_amount`: the acceptable minimum amount of the asset.
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_single())]
+		#[transactional]
+		pub fn redeem_single(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			#[pallet::compact] redeem_amount: Balance,
+			i: u32,
+			#[pallet::compact] min_redeem_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_single(&who, pool_id, redeem_amount, i as usize, min_redeem_amount)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// The amplification coefficient of pool at current block, ramped
+	/// linearly from `a` at `a_block` to `future_a` at `future_a_block`.
+	pub fn current_a(pool: &PoolInfo<T::AccountId, T::BlockNumber>) -> Balance {
+		let now = <frame_system::Pallet<T>>::block_number();
+		if now >= pool.future_a_block {
+			return pool.future_a;
+		}
+
+		let elapsed: Balance = now.saturating_sub(pool.a_block).unique_saturated_into();
+		let duration: Balance = pool.future_a_block.saturating_sub(pool.a_block).unique_saturated_into();
+		if pool.future_a > pool.a {
+			pool.a
+				.saturating_add(pool.future_a.saturating_sub(pool.a).saturating_mul(elapsed) / duration)
+		} else {
+			pool.a
+				.saturating_sub(pool.a.saturating_sub(pool.future_a).saturating_mul(elapsed) / duration)
+		}
+	}
+
+	/// The balances of assets normalized to the same decimals.
+	fn normalized_balances(pool: &PoolInfo<T::AccountId, T::BlockNumber>) -> Option<Vec<U256>> {
+		pool.balances
+			.iter()
+			.zip(pool.precisions.iter())
+			.map(|(balance, precision)| U256::from(*balance).checked_mul(U256::from(*precision)))
+			.collect()
+	}
+
+	/// Solve the StableSwap invariant `D` by Newton's method:
+	/// `A * n^n * sum(x_i) + D = A * n^n * D + D^(n+1) / (n^n * prod(x_i))`
+	fn get_d(balances: &[U256], a: Balance) -> Option<U256> {
+		let n = U256::from(balances.len());
+		let sum = balances
+			.iter()
+			.try_fold(U256::zero(), |sum, balance| sum.checked_add(*balance))?;
+		if sum.is_zero() {
+			return Some(U256::zero());
+		}
+
+		let ann = U256::from(a).checked_mul(n)?;
+		let mut d = sum;
+		for _ in 0..MAX_ITERATIONS {
+			let mut d_p = d;
+			for balance in balances {
+				d_p = d_p.checked_mul(d)?.checked_div(balance.checked_mul(n)?)?;
+			}
+			let prev_d = d;
+			// d = (ann * sum + d_p * n) * d / ((ann - 1) * d + (n + 1) * d_p)
+			let numerator = ann.checked_mul(sum)?.checked_add(d_p.checked_mul(n)?)?.checked_mul(d)?;
+			let denominator = ann
+				.checked_sub(U256::one())?
+				.checked_mul(d)?
+				.checked_add(n.checked_add(U256::one())?.checked_mul(d_p)?)?;
+			d = numerator.checked_div(denominator)?;
+
+			if Self::is_converged(d, prev_d) {
+				return Some(d);
+			}
+		}
+
+		None
+	}
+
+	/// Solve the balance of asset at index `j` by Newton's method, so that
+	/// the invariant of `balances` is `d`. The balance at index `j` in
+	/// `balances` is ignored.
+	fn get_y(balances: &[U256], j: usize, a: Balance, d: U256) -> Option<U256> {
+		let n = U256::from(balances.len());
+		let ann = U256::from(a).checked_mul(n)?;
+		let mut c = d;
+		let mut sum = U256::zero();
+		for (k, balance) in balances.iter().enumerate() {
+			if k != j {
+				sum = sum.checked_add(*balance)?;
+				c = c.checked_mul(d)?.checked_div(balance.checked_mul(n)?)?;
+			}
+		}
+		c = c.checked_mul(d)?.checked_div(ann.checked_mul(n)?)?;
+		let b = sum.checked_add(d.checked_div(ann)?)?;
+
+		let mut y = d;
+		for _ in 0..MAX_ITERATIONS {
+			let prev_y = y;
+			// y = (y^2 + c) / (2 * y + b - d)
+			let numerator = y.checked_mul(y)?.checked_add(c)?;
+			let denominator = y.checked_mul(U256::from(2u8))?.checked_add(b)?.checked_sub(d)?;
+			y = numerator.checked_div(denominator)?;
+
+			if Self::is_converged(y, prev_y) {
+				return Some(y);
+			}
+		}
+
+		None
+	}
+
+	/// The fee rate charged on the imbalanced part of mint and single asset
+	/// redemption, `swap_fee * n / (4 * (n - 1))` as Curve does.
+	fn imbalance_fee_rate(pool: &PoolInfo<T::AccountId, T::BlockNumber>) -> Rate {
+		let n = pool.assets.len() as u128;
+		Rate::saturating_from_rational(n, n.saturating_sub(1).saturating_mul(4)).saturating_mul(pool.swap_fee)
+	}
+
+	fn mul_rate(value: U256, rate: Rate) -> Option<U256> {
+		value
+			.checked_mul(U256::from(rate.into_inner()))?
+			.checked_div(U256::from(Rate::accuracy()))
+	}
+
+	fn is_converged(value: U256, prev_value: U256) -> bool {
+		if value > prev_value {
+			value - prev_value <= U256::one()
+		} else {
+			prev_value - value <= U256::one()
+		}
+	}
+
+	/// Get how much of the asset at index `j` will be got for specific supply
+	/// amount of the asset at index `i`, the swap fee excluded.
+	pub fn get_target_amount(
+		pool: &PoolInfo<T::AccountId, T::BlockNumber>,
+		i: usize,
+		j: usize,
+		supply_amount: Balance,
+	) -> Option<Balance> {
+		let a = Self::current_a(pool);
+		let mut balances = Self::normalized_balances(pool)?;
+		let d = Self::get_d(&balances, a)?;
+		let target_balance = balances[j];
+		balances[i] =
+			balances[i].checked_add(U256::from(supply_amount).checked_mul(U256::from(pool.precisions[i]))?)?;
+		let y = Self::get_y(&balances, j, a, d)?;
+
+		// minus 1 in favour of the pool
+		let target_amount: Balance = (target_balance.checked_sub(y)?.checked_sub(U256::one())?
+			/ U256::from(pool.precisions[j]))
+		.try_into()
+		.ok()?;
+		Some(target_amount.saturating_sub(pool.swap_fee.saturating_mul_int(target_amount)))
+	}
+
+	/// Get how much of the asset at index `i` will be paid for specific
+	/// target amount of the asset at index `j`, the swap fee included.
+	pub fn get_supply_amount(
+		pool: &PoolInfo<T::AccountId, T::BlockNumber>,
+		i: usize,
+		j: usize,
+		target_amount: Balance,
+	) -> Option<Balance> {
+		let a = Self::current_a(pool);
+		let mut balances = Self::normalized_balances(pool)?;
+		let d = Self::get_d(&balances, a)?;
+		let supply_balance = balances[i];
+
+		// the target amount before the swap fee, plus 1 in favour of the pool
+		let target_amount_with_fee = Rate::one()
+			.saturating_sub(pool.swap_fee)
+			.reciprocal()?
+			.checked_mul_int(target_amount)?
+			.checked_add(1)?;
+		if target_amount_with_fee >= pool.balances[j] {
+			return None;
+		}
+		balances[j] =
+			balances[j].checked_sub(U256::from(target_amount_with_fee).checked_mul(U256::from(pool.precisions[j]))?)?;
+		let x = Self::get_y(&balances, i, a, d)?;
+
+		// plus 1 in favour of the pool
+		let supply_amount: Balance = (x.checked_sub(supply_balance)? / U256::from(pool.precisions[i]))
+			.try_into()
+			.ok()?;
+		supply_amount.checked_add(1)
+	}
+
+	/// Swap `supply_amount` of the asset at index `i` for `target_amount` of
+	/// the asset at index `j`, which must not be more than the target amount
+	/// given by the pool.
+	fn do_swap(
+		who: &T::AccountId,
+		pool_id: StableAssetPoolId,
+		mut pool: PoolInfo<T::AccountId, T::BlockNumber>,
+		i: usize,
+		j: usize,
+		supply_amount: Balance,
+		target_amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			!supply_amount.is_zero() && !target_amount.is_zero(),
+			Error::<T>::InvalidAmounts
+		);
+		ensure!(target_amount < pool.balances[j], Error::<T>::InsufficientLiquidity);
+		let (supply_currency_id, target_currency_id) = (pool.assets[i], pool.assets[j]);
+		let module_account_id = Self::account_id();
+
+		T::Currency::transfer(supply_currency_id, who, &module_account_id, supply_amount)?;
+		T::Currency::transfer(target_currency_id, &module_account_id, who, target_amount)?;
+		pool.balances[i] = pool.balances[i]
+			.checked_add(supply_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		pool.balances[j] = pool.balances[j]
+			.checked_sub(target_amount)
+			.ok_or(ArithmeticError::Underflow)?;
+		Pools::<T>::insert(pool_id, pool);
+
+		Self::deposit_event(Event::Swapped(
+			who.clone(),
+			pool_id,
+			supply_currency_id,
+			target_currency_id,
+			supply_amount,
+			target_amount,
+		));
+		Ok(())
+	}
+
+	fn do_mint(
+		who: &T::AccountId,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<Balance>,
+		min_mint_amount: Balance,
+	) -> DispatchResult {
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				amounts.len() == pool.assets.len() && amounts.iter().any(|amount| !amount.is_zero()),
+				Error::<T>::InvalidAmounts
+			);
+			let pool_token = CurrencyId::StableAssetPoolToken(pool_id);
+			let total_supply = T::Currency::total_issuance(pool_token);
+			if total_supply.is_zero() {
+				ensure!(
+					amounts.iter().all(|amount| !amount.is_zero()),
+					Error::<T>::InitialMintRequiresAllAssets
+				);
+			}
+
+			let a = Self::current_a(pool);
+			let old_balances = Self::normalized_balances(pool).ok_or(Error::<T>::InvariantCalculationFailed)?;
+			let d0 = Self::get_d(&old_balances, a).ok_or(Error::<T>::InvariantCalculationFailed)?;
+			let module_account_id = Self::account_id();
+			for ((currency_id, balance), amount) in pool.assets.iter().zip(pool.balances.iter_mut()).zip(amounts.iter())
+			{
+				T::Currency::transfer(*currency_id, who, &module_account_id, *amount)?;
+				*balance = balance.checked_add(*amount).ok_or(ArithmeticError::Overflow)?;
+			}
+			let new_balances = Self::normalized_balances(pool).ok_or(Error::<T>::InvariantCalculationFailed)?;
+			let d1 = Self::get_d(&new_balances, a).ok_or(Error::<T>::InvariantCalculationFailed)?;
+			ensure!(d1 > d0, Error::<T>::InvalidAmounts);
+
+			// the pool token is issued in proportion to the increment of the invariant,
+			// after charging the imbalance fee on the difference between the new balances
+			// and the balances increased in proportion to the invariant
+			let mint_amount: Balance = if total_supply.is_zero() {
+				d1.try_into().ok()
+			} else {
+				let fee_rate = Self::imbalance_fee_rate(pool);
+				let d2 = old_balances
+					.iter()
+					.zip(new_balances.iter())
+					.map(|(old_balance, new_balance)| {
+						let ideal_balance = d1.checked_mul(*old_balance)?.checked_div(d0)?;
+						let difference = if ideal_balance > *new_balance {
+							ideal_balance - *new_balance
+						} else {
+							*new_balance - ideal_balance
+						};
+						new_balance.checked_sub(Self::mul_rate(difference, fee_rate)?)
+					})
+					.collect::<Option<Vec<U256>>>()
+					.and_then(|balances| Self::get_d(&balances, a))
+					.ok_or(Error::<T>::InvariantCalculationFailed)?;
+				U256::from(total_supply)
+					.checked_mul(d2.saturating_sub(d0))
+					.and_then(|amount| amount.checked_div(d0))
+					.and_then(|amount| amount.try_into().ok())
+			}
+			.ok_or(ArithmeticError::Overflow)?;
+			let fee = pool.mint_fee.saturating_mul_int(mint_amount);
+			let actual_mint_amount = mint_amount.saturating_sub(fee);
+			ensure!(
+				!actual_mint_amount.is_zero() && actual_mint_amount >= min_mint_amount,
+				Error::<T>::InsufficientMintAmount
+			);
+
+			T::Currency::deposit(pool_token, who, actual_mint_amount)?;
+			if !fee.is_zero() {
+				T::Currency::deposit(pool_token, &pool.fee_recipient, fee)?;
+			}
+
+			Self::deposit_event(Event::Minted(who.clone(), pool_id, amounts, actual_mint_amount, fee));
+			Ok(())
+		})
+	}
+
+	fn do_redeem_proportion(
+		who: &T::AccountId,
+		pool_id: StableAssetPoolId,
+		redeem_amount: Balance,
+		min_redeem_amounts: Vec<Balance>,
+	) -> DispatchResult {
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				!redeem_amount.is_zero() && min_redeem_amounts.len() == pool.assets.len(),
+				Error::<T>::InvalidAmounts
+			);
+			let pool_token = CurrencyId::StableAssetPoolToken(pool_id);
+			let total_supply = T::Currency::total_issuance(pool_token);
+			ensure!(redeem_amount <= total_supply, Error::<T>::InsufficientLiquidity);
+			let fee = pool.redeem_fee.saturating_mul_int(redeem_amount);
+			let actual_redeem_amount = redeem_amount.saturating_sub(fee);
+
+			let module_account_id = Self::account_id();
+			let mut amounts: Vec<Balance> = Vec::with_capacity(pool.assets.len());
+			for ((currency_id, balance), min_redeem_amount) in pool
+				.assets
+				.iter()
+				.zip(pool.balances.iter_mut())
+				.zip(min_redeem_amounts.iter())
+			{
+				let amount: Balance = U256::from(*balance)
+					.saturating_mul(U256::from(actual_redeem_amount))
+					.checked_div(U256::from(total_supply))
+					.and_then(|amount| amount.try_into().ok())
+					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(amount >= *min_redeem_amount, Error::<T>::InsufficientRedeemAmount);
+
+				T::Currency::transfer(*currency_id, &module_account_id, who, amount)?;
+				*balance = balance.checked_sub(amount).ok_or(ArithmeticError::Underflow)?;
+				amounts.push(amount);
+			}
+
+			T::Currency::withdraw(pool_token, who, redeem_amount)?;
+			if !fee.is_zero() {
+				T::Currency::deposit(pool_token, &pool.fee_recipient, fee)?;
+			}
+
+			Self::deposit_event(Event::RedeemedProportion(
+				who.clone(),
+				pool_id,
+				redeem_amount,
+				amounts,
+				fee,
+			));
+			Ok(())
+		})
+	}
+
+	fn do_redeem_single(
+		who: &T::AccountId,
+		pool_id: StableAssetPoolId,
+		redeem_amount: Balance,
+		i: usize,
+		min_redeem_amount: Balance,
+	) -> DispatchResult {
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(i < pool.assets.len(), Error::<T>::InvalidAssetIndex);
+			ensure!(!redeem_amount.is_zero(), Error::<T>::InvalidAmounts);
+			let pool_token = CurrencyId::StableAssetPoolToken(pool_id);
+			let total_supply = T::Currency::total_issuance(pool_token);
+			ensure!(redeem_amount <= total_supply, Error::<T>::InsufficientLiquidity);
+			let fee = pool.redeem_fee.saturating_mul_int(redeem_amount);
+			let actual_redeem_amount = redeem_amount.saturating_sub(fee);
+
+			// the invariant is decreased in proportion to the redeemed pool token, and
+			// the imbalance fee is charged on the difference between the balances after
+			// the redemption and the balances decreased in proportion to the invariant
+			let a = Self::current_a(pool);
+			let fee_rate = Self::imbalance_fee_rate(pool);
+			let amount: Balance = Self::normalized_balances(pool)
+				.and_then(|balances| {
+					let d0 = Self::get_d(&balances, a)?;
+					let d1 = d0.checked_sub(
+						d0.checked_mul(U256::from(actual_redeem_amount))?
+							.checked_div(U256::from(total_supply))?,
+					)?;
+					let y = Self::get_y(&balances, i, a, d1)?;
+					let reduced_balances = balances
+						.iter()
+						.enumerate()
+						.map(|(j, balance)| {
+							let expected_balance = balance.checked_mul(d1)?.checked_div(d0)?;
+							let difference = if j == i {
+								expected_balance.checked_sub(y)?
+							} else {
+								balance.checked_sub(expected_balance)?
+							};
+							balance.checked_sub(Self::mul_rate(difference, fee_rate)?)
+						})
+						.collect::<Option<Vec<U256>>>()?;
+					let y = Self::get_y(&reduced_balances, i, a, d1)?;
+
+					// minus 1 in favour of the pool
+					(reduced_balances[i].checked_sub(y)?.checked_sub(U256::one())? / U256::from(pool.precisions[i]))
+						.try_into()
+						.ok()
+				})
+				.ok_or(Error::<T>::InvariantCalculationFailed)?;
+			ensure!(amount >= min_redeem_amount, Error::<T>::InsufficientRedeemAmount);
+			ensure!(amount < pool.balances[i], Error::<T>::InsufficientLiquidity);
+
+			let currency_id = pool.assets[i];
+			T::Currency::transfer(currency_id, &Self::account_id(), who, amount)?;
+			pool.balances[i] = pool.balances[i].saturating_sub(amount);
+			T::Currency::withdraw(pool_token, who, redeem_amount)?;
+			if !fee.is_zero() {
+				T::Currency::deposit(pool_token, &pool.fee_recipient, fee)?;
+			}
+
+			Self::deposit_event(Event::RedeemedSingle(
+				who.clone(),
+				pool_id,
+				redeem_amount,
+				currency_id,
+				amount,
+				fee,
+			));
+			Ok(())
+		})
+	}
+
+	/// The pools which contain both currencies, with the indexes of the
+	/// currencies in them.
+	fn pools_of(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+	) -> Vec<(StableAssetPoolId, PoolInfo<T::AccountId, T::BlockNumber>, usize, usize)> {
+		Pools::<T>::iter()
+			.filter_map(|(pool_id, pool)| {
+				let i = pool
+					.assets
+					.iter()
+					.position(|currency_id| *currency_id == supply_currency_id)?;
+				let j = pool
+					.assets
+					.iter()
+					.position(|currency_id| *currency_id == target_currency_id)?;
+				Some((pool_id, pool, i, j))
+			})
+			.collect()
+	}
+}
+
+impl<T: Config> StableAssetRouter<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn trading_pairs() -> Vec<(CurrencyId, CurrencyId)> {
+		let mut trading_pairs: Vec<(CurrencyId, CurrencyId)> = vec![];
+		for pool in Pools::<T>::iter_values() {
+			for (i, currency_id_a) in pool.assets.iter().enumerate() {
+				for currency_id_b in pool.assets[i + 1..].iter() {
+					if !trading_pairs.contains(&(*currency_id_a, *currency_id_b)) {
+						trading_pairs.push((*currency_id_a, *currency_id_b));
+					}
+				}
+			}
+		}
+		trading_pairs
+	}
+
+	fn get_target_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Balance> {
+		Self::pools_of(supply_currency_id, target_currency_id)
+			.iter()
+			.filter_map(|(_, pool, i, j)| Self::get_target_amount(pool, *i, *j, supply_amount))
+			.max()
+	}
+
+	fn get_supply_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Balance> {
+		Self::pools_of(supply_currency_id, target_currency_id)
+			.iter()
+			.filter_map(|(_, pool, i, j)| Self::get_supply_amount(pool, *i, *j, target_amount))
+			.min()
+	}
+
+	fn swap(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		target_amount: Balance,
+	) -> DispatchResult {
+		// swap in the pool which gives the most target amount
+		let (pool_id, pool, i, j) = Self::pools_of(supply_currency_id, target_currency_id)
+			.into_iter()
+			.filter_map(|(pool_id, pool, i, j)| {
+				let max_target_amount = Self::get_target_amount(&pool, i, j, supply_amount)?;
+				Some((max_target_amount, (pool_id, pool, i, j)))
+			})
+			.filter(|(max_target_amount, _)| *max_target_amount >= target_amount)
+			.max_by_key(|(max_target_amount, _)| *max_target_amount)
+			.map(|(_, pool)| pool)
+			.ok_or(Error::<T>::NoAvailablePool)?;
+		Self::do_swap(who, pool_id, pool, i, j, supply_amount, target_amount)
+	}
+}
@@ -0,0 +1,563 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the stable asset module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{
+	Event, ExtBuilder, ListingOrigin, Origin, Runtime, StableAssetModule, System, Tokens, ALICE, AUSD, BOB, DOT,
+	FEE_RECIPIENT, LDOT, POOL_TOKEN,
+};
+use sp_runtime::traits::BadOrigin;
+
+fn create_dot_pool() {
+	assert_ok!(StableAssetModule::create_pool(
+		Origin::signed(ListingOrigin::get()),
+		vec![DOT, LDOT],
+		vec![1, 1],
+		Rate::saturating_from_rational(1, 1000),
+		Rate::saturating_from_rational(2, 1000),
+		Rate::saturating_from_rational(5, 1000),
+		100,
+		FEE_RECIPIENT,
+	));
+}
+
+#[test]
+fn create_pool_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ALICE),
+				vec![DOT, LDOT],
+				vec![1, 1],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT],
+				vec![1],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidPoolAssets
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT, LDOT, DOT],
+				vec![1, 1, 1],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidPoolAssets
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT, POOL_TOKEN],
+				vec![1, 1],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidPoolAssets
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT, LDOT],
+				vec![1, 0],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidPrecisions
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT, LDOT],
+				vec![1, 1],
+				Rate::zero(),
+				Rate::one(),
+				Rate::zero(),
+				100,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidFee
+		);
+		assert_noop!(
+			StableAssetModule::create_pool(
+				Origin::signed(ListingOrigin::get()),
+				vec![DOT, LDOT],
+				vec![1, 1],
+				Rate::zero(),
+				Rate::zero(),
+				Rate::zero(),
+				MAX_A + 1,
+				FEE_RECIPIENT,
+			),
+			Error::<Runtime>::InvalidAmplification
+		);
+
+		create_dot_pool();
+		System::assert_last_event(Event::StableAssetModule(crate::Event::PoolCreated(0, vec![DOT, LDOT])));
+		assert_eq!(StableAssetModule::next_pool_id(), 1);
+		assert_eq!(
+			StableAssetModule::pools(0),
+			Some(PoolInfo {
+				assets: vec![DOT, LDOT],
+				precisions: vec![1, 1],
+				balances: vec![0, 0],
+				mint_fee: Rate::saturating_from_rational(1, 1000),
+				swap_fee: Rate::saturating_from_rational(2, 1000),
+				redeem_fee: Rate::saturating_from_rational(5, 1000),
+				fee_recipient: FEE_RECIPIENT,
+				a: 100,
+				a_block: 1,
+				future_a: 100,
+				future_a_block: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn ramp_a_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			StableAssetModule::ramp_a(Origin::signed(ListingOrigin::get()), 0, 200, 101),
+			Error::<Runtime>::PoolNotFound
+		);
+		create_dot_pool();
+
+		assert_noop!(StableAssetModule::ramp_a(Origin::signed(ALICE), 0, 200, 101), BadOrigin);
+		assert_noop!(
+			StableAssetModule::ramp_a(Origin::signed(ListingOrigin::get()), 0, 200, 1),
+			Error::<Runtime>::InvalidRampingBlock
+		);
+		assert_noop!(
+			StableAssetModule::ramp_a(Origin::signed(ListingOrigin::get()), 0, 1001, 101),
+			Error::<Runtime>::InvalidAmplification
+		);
+		assert_noop!(
+			StableAssetModule::ramp_a(Origin::signed(ListingOrigin::get()), 0, 9, 101),
+			Error::<Runtime>::InvalidAmplification
+		);
+
+		assert_ok!(StableAssetModule::ramp_a(
+			Origin::signed(ListingOrigin::get()),
+			0,
+			200,
+			101
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::AmplificationRamped(0, 200, 101)));
+		let pool = StableAssetModule::pools(0).unwrap();
+		assert_eq!(StableAssetModule::current_a(&pool), 100);
+
+		System::set_block_number(51);
+		assert_eq!(StableAssetModule::current_a(&pool), 150);
+
+		// ramping from the current value
+		assert_ok!(StableAssetModule::ramp_a(
+			Origin::signed(ListingOrigin::get()),
+			0,
+			100,
+			151
+		));
+		let pool = StableAssetModule::pools(0).unwrap();
+		assert_eq!((pool.a, pool.a_block), (150, 51));
+		System::set_block_number(101);
+		assert_eq!(StableAssetModule::current_a(&pool), 125);
+		System::set_block_number(151);
+		assert_eq!(StableAssetModule::current_a(&pool), 100);
+	});
+}
+
+#[test]
+fn mint_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			StableAssetModule::mint(Origin::signed(ALICE), 0, vec![1_000_000_000, 1_000_000_000], 0),
+			Error::<Runtime>::PoolNotFound
+		);
+		create_dot_pool();
+
+		assert_noop!(
+			StableAssetModule::mint(Origin::signed(ALICE), 0, vec![1_000_000_000], 0),
+			Error::<Runtime>::InvalidAmounts
+		);
+		assert_noop!(
+			StableAssetModule::mint(Origin::signed(ALICE), 0, vec![1_000_000_000, 0], 0),
+			Error::<Runtime>::InitialMintRequiresAllAssets
+		);
+		assert_noop!(
+			StableAssetModule::mint(
+				Origin::signed(ALICE),
+				0,
+				vec![1_000_000_000, 1_000_000_000],
+				1_998_000_001
+			),
+			Error::<Runtime>::InsufficientMintAmount
+		);
+
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			1_998_000_000
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::Minted(
+			ALICE,
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			1_998_000_000,
+			2_000_000,
+		)));
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &ALICE), 1_998_000_000);
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &FEE_RECIPIENT), 2_000_000);
+		assert_eq!(
+			Tokens::free_balance(DOT, &StableAssetModule::account_id()),
+			1_000_000_000
+		);
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![1_000_000_000, 1_000_000_000]
+		);
+
+		// imbalanced mint gets less pool token and is charged the imbalance fee
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(BOB),
+			0,
+			vec![1_000_000_000, 0],
+			0
+		));
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &BOB), 996_149_078);
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &FEE_RECIPIENT), 2_997_146);
+		assert_eq!(Tokens::total_issuance(POOL_TOKEN), 2_997_146_224);
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![2_000_000_000, 1_000_000_000]
+		);
+	});
+}
+
+#[test]
+fn imbalance_fee_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		create_dot_pool();
+		assert_ok!(StableAssetModule::create_pool(
+			Origin::signed(ListingOrigin::get()),
+			vec![DOT, LDOT],
+			vec![1, 1],
+			Rate::saturating_from_rational(1, 1000),
+			Rate::zero(),
+			Rate::saturating_from_rational(5, 1000),
+			100,
+			FEE_RECIPIENT,
+		));
+		for pool_id in 0..2 {
+			assert_ok!(StableAssetModule::mint(
+				Origin::signed(ALICE),
+				pool_id,
+				vec![1_000_000_000, 1_000_000_000],
+				0
+			));
+		}
+
+		// balanced mint is not charged the imbalance fee
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(BOB),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			0
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::Minted(
+			BOB,
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			1_998_000_000,
+			2_000_000,
+		)));
+
+		// imbalanced mint is charged the imbalance fee only if the swap fee is set
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(BOB),
+			1,
+			vec![1_000_000_000, 0],
+			0
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::Minted(
+			BOB,
+			1,
+			vec![1_000_000_000, 0],
+			997_148_839,
+			998_146,
+		)));
+	});
+}
+
+#[test]
+fn swap_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		create_dot_pool();
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			0
+		));
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 0],
+			0
+		));
+
+		assert_noop!(
+			StableAssetModule::swap(Origin::signed(BOB), 0, 1, 1, 100_000_000, 0),
+			Error::<Runtime>::InvalidAssetIndex
+		);
+		assert_noop!(
+			StableAssetModule::swap(Origin::signed(BOB), 0, 1, 2, 100_000_000, 0),
+			Error::<Runtime>::InvalidAssetIndex
+		);
+
+		// LDOT is scarce in the pool, it is swapped for more DOT
+		let pool = StableAssetModule::pools(0).unwrap();
+		assert_eq!(
+			StableAssetModule::get_target_amount(&pool, 1, 0, 100_000_000),
+			Some(100_517_786)
+		);
+		assert_eq!(
+			StableAssetModule::get_supply_amount(&pool, 1, 0, 100_517_786),
+			Some(100_000_001)
+		);
+		assert_noop!(
+			StableAssetModule::swap(Origin::signed(BOB), 0, 1, 0, 100_000_000, 100_517_787),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+
+		assert_ok!(StableAssetModule::swap(
+			Origin::signed(BOB),
+			0,
+			1,
+			0,
+			100_000_000,
+			100_517_786
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::Swapped(
+			BOB,
+			0,
+			LDOT,
+			DOT,
+			100_000_000,
+			100_517_786,
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_100_517_786);
+		assert_eq!(Tokens::free_balance(LDOT, &BOB), 999_999_900_000_000);
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![1_899_482_214, 1_100_000_000]
+		);
+	});
+}
+
+#[test]
+fn redeem_proportion_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		create_dot_pool();
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			0
+		));
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(BOB),
+			0,
+			vec![1_000_000_000, 0],
+			0
+		));
+		assert_ok!(StableAssetModule::swap(Origin::signed(BOB), 0, 1, 0, 100_000_000, 0));
+
+		assert_noop!(
+			StableAssetModule::redeem_proportion(Origin::signed(ALICE), 0, 1_000_000_000, vec![0]),
+			Error::<Runtime>::InvalidAmounts
+		);
+		assert_noop!(
+			StableAssetModule::redeem_proportion(Origin::signed(ALICE), 0, 1_000_000_000, vec![630_594_794, 0]),
+			Error::<Runtime>::InsufficientRedeemAmount
+		);
+
+		assert_ok!(StableAssetModule::redeem_proportion(
+			Origin::signed(ALICE),
+			0,
+			1_000_000_000,
+			vec![630_594_793, 365_180_714]
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::RedeemedProportion(
+			ALICE,
+			0,
+			1_000_000_000,
+			vec![630_594_793, 365_180_714],
+			5_000_000,
+		)));
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &ALICE), 998_000_000);
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &FEE_RECIPIENT), 7_997_146);
+		assert_eq!(Tokens::total_issuance(POOL_TOKEN), 2_002_146_224);
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![1_268_887_421, 734_819_286]
+		);
+	});
+}
+
+#[test]
+fn redeem_single_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		create_dot_pool();
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			0
+		));
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(BOB),
+			0,
+			vec![1_000_000_000, 0],
+			0
+		));
+		assert_ok!(StableAssetModule::swap(Origin::signed(BOB), 0, 1, 0, 100_000_000, 0));
+
+		assert_noop!(
+			StableAssetModule::redeem_single(Origin::signed(ALICE), 0, 1_000_000_000, 2, 0),
+			Error::<Runtime>::InvalidAssetIndex
+		);
+		assert_noop!(
+			StableAssetModule::redeem_single(Origin::signed(ALICE), 0, 1_000_000_000, 1, 965_739_757),
+			Error::<Runtime>::InsufficientRedeemAmount
+		);
+
+		assert_ok!(StableAssetModule::redeem_single(
+			Origin::signed(ALICE),
+			0,
+			1_000_000_000,
+			1,
+			965_739_756
+		));
+		System::assert_last_event(Event::StableAssetModule(crate::Event::RedeemedSingle(
+			ALICE,
+			0,
+			1_000_000_000,
+			LDOT,
+			965_739_756,
+			5_000_000,
+		)));
+		assert_eq!(Tokens::free_balance(POOL_TOKEN, &ALICE), 998_000_000);
+		assert_eq!(Tokens::free_balance(LDOT, &ALICE), 999_999_965_739_756);
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![1_899_482_214, 134_260_244]
+		);
+	});
+}
+
+#[test]
+fn stable_asset_router_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		create_dot_pool();
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 1_000_000_000],
+			0
+		));
+		assert_ok!(StableAssetModule::mint(
+			Origin::signed(ALICE),
+			0,
+			vec![1_000_000_000, 0],
+			0
+		));
+
+		assert_eq!(
+			<StableAssetModule as StableAssetRouter<_, _, _>>::trading_pairs(),
+			vec![(DOT, LDOT)]
+		);
+		assert_eq!(
+			<StableAssetModule as StableAssetRouter<_, _, _>>::get_target_amount(LDOT, DOT, 100_000_000),
+			Some(100_517_786)
+		);
+		assert_eq!(
+			<StableAssetModule as StableAssetRouter<_, _, _>>::get_supply_amount(LDOT, DOT, 100_000_000),
+			Some(99_484_334)
+		);
+		assert_eq!(
+			<StableAssetModule as StableAssetRouter<_, _, _>>::get_target_amount(AUSD, DOT, 100_000_000),
+			None
+		);
+
+		assert_noop!(
+			<StableAssetModule as StableAssetRouter<_, _, _>>::swap(&BOB, LDOT, DOT, 100_000_000, 100_517_787),
+			Error::<Runtime>::NoAvailablePool
+		);
+		assert_ok!(<StableAssetModule as StableAssetRouter<_, _, _>>::swap(
+			&BOB,
+			LDOT,
+			DOT,
+			100_000_000,
+			100_000_000
+		));
+		// the rest of target amount is kept in the pool
+		assert_eq!(
+			StableAssetModule::pools(0).unwrap().balances,
+			vec![1_900_000_000, 1_100_000_000]
+		);
+	});
+}
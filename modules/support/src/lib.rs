@@ -25,7 +25,7 @@ use primitives::{
 	evm::{CallInfo, EvmAddress},
 	CurrencyId,
 };
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize},
 	transaction_validity::TransactionValidityError,
@@ -60,6 +60,16 @@ pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	) -> DispatchResult;
 
 	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: DebitBalance) -> DispatchResult;
+
+	fn get_multi_collateral_debit_value(debit_balance: DebitBalance) -> Balance;
+
+	fn check_multi_collateral_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: DebitBalance,
+		check_required_ratio: bool,
+	) -> DispatchResult;
+
+	fn check_multi_collateral_debit_cap(total_debit_balance: DebitBalance) -> DispatchResult;
 }
 
 impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountId, CurrencyId, Balance, DebitBalance>
@@ -81,6 +91,22 @@ impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountI
 	fn check_debit_cap(_currency_id: CurrencyId, _total_debit_balance: DebitBalance) -> DispatchResult {
 		Ok(())
 	}
+
+	fn get_multi_collateral_debit_value(_debit_balance: DebitBalance) -> Balance {
+		Default::default()
+	}
+
+	fn check_multi_collateral_position_valid(
+		_collaterals: &[(CurrencyId, Balance)],
+		_debit_balance: DebitBalance,
+		_check_required_ratio: bool,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_multi_collateral_debit_cap(_total_debit_balance: DebitBalance) -> DispatchResult {
+		Ok(())
+	}
 }
 
 pub trait AuctionManager<AccountId> {
@@ -94,9 +120,19 @@ pub trait AuctionManager<AccountId> {
 		amount: Self::Balance,
 		target: Self::Balance,
 	) -> DispatchResult;
+	fn new_dutch_collateral_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+	) -> DispatchResult;
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult;
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult;
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult;
 	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance;
 	fn get_total_target_in_auction() -> Self::Balance;
+	fn get_total_debit_in_auction() -> Self::Balance;
+	fn get_total_surplus_in_auction() -> Self::Balance;
 }
 
 pub trait DEXManager<AccountId, CurrencyId, Balance> {
@@ -108,6 +144,24 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 
 	fn get_swap_supply_amount(path: &[CurrencyId], target_amount: Balance) -> Option<Balance>;
 
+	fn get_best_price_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)>;
+
+	fn get_best_price_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)>;
+
+	fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: u32,
+	) -> Option<ExchangeRate>;
+
 	fn swap_with_exact_supply(
 		who: &AccountId,
 		path: &[CurrencyId],
@@ -122,6 +176,16 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 		max_supply_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError>;
 
+	/// Swap with exact target amount, `who` receives the target amount
+	/// before `callback` runs and pays the supply amount after it.
+	fn flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		who: &AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError>;
+
 	fn add_liquidity(
 		who: &AccountId,
 		currency_id_a: CurrencyId,
@@ -163,6 +227,30 @@ where
 		Some(Default::default())
 	}
 
+	fn get_best_price_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		None
+	}
+
+	fn get_best_price_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		None
+	}
+
+	fn get_time_weighted_average_price(
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_window: u32,
+	) -> Option<ExchangeRate> {
+		None
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -181,6 +269,16 @@ where
 		Ok(Default::default())
 	}
 
+	fn flash_swap_with_exact_target<F: FnOnce() -> DispatchResult>(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_max_supply_amount: Balance,
+		_callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		Ok(Default::default())
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -206,6 +304,54 @@ where
 	}
 }
 
+/// The stable asset pools which the DEX can route trading paths through.
+pub trait StableAssetRouter<AccountId, CurrencyId, Balance> {
+	/// The currency pairs which can be swapped in stable asset pools.
+	fn trading_pairs() -> Vec<(CurrencyId, CurrencyId)>;
+
+	/// Get how much target amount will be got for specific supply amount.
+	fn get_target_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Balance>;
+
+	/// Get how much supply amount will be paid for specific target amount.
+	fn get_supply_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Balance>;
+
+	/// `who` pays `supply_amount` for `target_amount`, fails if no pool gives
+	/// at least `target_amount` for `supply_amount`.
+	fn swap(
+		who: &AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		target_amount: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId, CurrencyId, Balance> StableAssetRouter<AccountId, CurrencyId, Balance> for () {
+	fn trading_pairs() -> Vec<(CurrencyId, CurrencyId)> {
+		Vec::new()
+	}
+
+	fn get_target_amount(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Balance> {
+		None
+	}
+
+	fn get_supply_amount(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Balance> {
+		None
+	}
+
+	fn swap(_: &AccountId, _: CurrencyId, _: CurrencyId, _: Balance, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("no stable asset pool"))
+	}
+}
+
 /// An abstraction of cdp treasury for Honzon Protocol.
 pub trait CDPTreasury<AccountId> {
 	type Balance;
@@ -240,6 +386,9 @@ pub trait CDPTreasury<AccountId> {
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
 
+	/// withdraw surplus(stable currency) of cdp treasury to `to`
+	fn withdraw_surplus(to: &AccountId, surplus: Self::Balance) -> DispatchResult;
+
 	/// deposit collateral assets to cdp treasury by `who`
 	fn deposit_collateral(from: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
@@ -271,6 +420,13 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 		refund_receiver: AccountId,
 		splited: bool,
 	) -> DispatchResult;
+
+	fn create_dutch_collateral_auction(
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		refund_receiver: AccountId,
+	) -> DispatchResult;
 }
 
 pub trait PriceProvider<CurrencyId> {
@@ -289,10 +445,63 @@ pub trait LockablePrice<CurrencyId> {
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
 }
 
+pub trait PriceCircuitBreaker<CurrencyId> {
+	fn is_tripped(currency_id: CurrencyId) -> bool;
+}
+
+impl<CurrencyId> PriceCircuitBreaker<CurrencyId> for () {
+	fn is_tripped(_currency_id: CurrencyId) -> bool {
+		false
+	}
+}
+
 pub trait ExchangeRateProvider {
 	fn get_exchange_rate() -> ExchangeRate;
 }
 
+pub trait InterestRateModel<CurrencyId> {
+	/// Get the interest rate per sec of CDPs under `currency_id` by the
+	/// utilization of its maximum total debit value. `None` means the model
+	/// is unavailable and the configured rate should be used.
+	fn get_interest_rate_per_sec(currency_id: CurrencyId, utilization: Ratio) -> Option<Rate>;
+}
+
+impl<CurrencyId> InterestRateModel<CurrencyId> for () {
+	fn get_interest_rate_per_sec(_currency_id: CurrencyId, _utilization: Ratio) -> Option<Rate> {
+		None
+	}
+}
+
+/// An abstraction of the Honzon protocol for managing a CDP on behalf of
+/// its owner.
+pub trait HonzonManager<AccountId, CurrencyId, Amount, Balance> {
+	/// Adjust the collateral and debit of the CDP of `who` under
+	/// `currency_id`.
+	fn adjust_loan(
+		who: &AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult;
+
+	/// Close the CDP of `who` under `currency_id` by swapping at most
+	/// `max_collateral_amount` collateral on DEX to clear its debit.
+	fn close_loan_by_dex(who: AccountId, currency_id: CurrencyId, max_collateral_amount: Balance) -> DispatchResult;
+
+	/// Get the `(collateral, debit)` of the CDP of `who` under `currency_id`.
+	fn get_position(who: &AccountId, currency_id: CurrencyId) -> (Balance, Balance);
+
+	/// Get the current collateral ratio of the CDP of `who` under
+	/// `currency_id`, `None` if the price of collateral is unavailable.
+	fn get_collateral_ratio(who: &AccountId, currency_id: CurrencyId) -> Option<Ratio>;
+
+	/// Get the liquidation ratio of `currency_id`.
+	fn get_liquidation_ratio(currency_id: CurrencyId) -> Ratio;
+
+	/// Get the exchange rate from debit to debit value of `currency_id`.
+	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate;
+}
+
 pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
 }
@@ -312,6 +521,40 @@ impl<AccountId, CurrencyId, Balance> DEXIncentives<AccountId, CurrencyId, Balanc
 	}
 }
 
+pub trait IncentivesManager<AccountId, Balance, CurrencyId, PoolId> {
+	/// Deposit `amount` of DEX share `lp_currency_id` of `who` to earn
+	/// incentives.
+	fn deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
+
+	/// Withdraw `amount` of deposited DEX share `lp_currency_id` of `who`.
+	fn withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
+
+	/// Claim the rewards of `who` in `pool_id`.
+	fn claim_rewards(who: AccountId, pool_id: PoolId) -> DispatchResult;
+
+	/// Get the unclaimed rewards of `who` in `pool_id`.
+	fn get_pending_rewards(pool_id: PoolId, who: AccountId) -> Vec<(CurrencyId, Balance)>;
+}
+
+pub trait HomaManager<AccountId, Balance> {
+	/// Mint liquid currency by staking `amount` of staking currency of
+	/// `who`, return the amount of liquid currency minted.
+	fn mint(who: AccountId, amount: Balance) -> Result<Balance, DispatchError>;
+
+	/// Get the exchange rate from liquid currency to staking currency.
+	fn get_exchange_rate() -> ExchangeRate;
+}
+
+impl<AccountId, Balance> HomaManager<AccountId, Balance> for () {
+	fn mint(_who: AccountId, _amount: Balance) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unimplemented homa manager"))
+	}
+
+	fn get_exchange_rate() -> ExchangeRate {
+		Default::default()
+	}
+}
+
 /// Return true if the call of EVM precompile contract is allowed.
 pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
@@ -334,6 +577,8 @@ pub trait EVM<AccountId> {
 	fn get_origin() -> Option<AccountId>;
 	/// Provide a method to set origin for `on_initialize`
 	fn set_origin(origin: AccountId);
+	/// Emit an EVM log from outside of the EVM execution, e.g. by precompiles.
+	fn log(address: EvmAddress, topics: Vec<H256>, data: Vec<u8>);
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug)]
@@ -373,6 +618,8 @@ pub trait EVMBridge<AccountId, Balance> {
 	fn get_origin() -> Option<AccountId>;
 	/// Provide a method to set origin for `on_initialize`
 	fn set_origin(origin: AccountId);
+	/// Emit an EVM log of `address`
+	fn log(address: EvmAddress, topics: Vec<H256>, data: Vec<u8>);
 }
 
 #[cfg(feature = "std")]
@@ -399,6 +646,18 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 		None
 	}
 	fn set_origin(_origin: AccountId) {}
+	fn log(_address: EvmAddress, _topics: Vec<H256>, _data: Vec<u8>) {}
+}
+
+/// Get the currencies an account holds balance of.
+pub trait HeldCurrencyIds<AccountId, CurrencyId> {
+	fn held_currency_ids(who: &AccountId) -> Vec<CurrencyId>;
+}
+
+impl<AccountId, CurrencyId> HeldCurrencyIds<AccountId, CurrencyId> for () {
+	fn held_currency_ids(_who: &AccountId) -> Vec<CurrencyId> {
+		Default::default()
+	}
 }
 
 /// An abstraction of EVMStateRentTrait
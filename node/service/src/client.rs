@@ -19,7 +19,7 @@
 //! Acala Client abstractions.
 
 use acala_primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Header, Nonce};
-use runtime_common::TimeStampedPrice;
+use runtime_common::{IncentivePoolId, TimeStampedPrice};
 use sc_client_api::{Backend as BackendT, BlockchainEvents, KeyIterator};
 use sp_api::{CallApiAt, NumberFor, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
@@ -41,6 +41,10 @@ pub trait RuntimeApiCollection:
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 	+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
 	+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+	+ module_dex_rpc::DexRuntimeApi<Block, Balance>
+	+ module_cdp_engine_rpc::CdpEngineRuntimeApi<Block, AccountId, Balance>
+	+ module_prices_rpc::PricesRuntimeApi<Block, BlockNumber>
+	+ module_incentives_rpc::IncentivesRuntimeApi<Block, AccountId, IncentivePoolId, Balance>
 	+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
@@ -60,6 +64,10 @@ where
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 		+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
 		+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+		+ module_dex_rpc::DexRuntimeApi<Block, Balance>
+		+ module_cdp_engine_rpc::CdpEngineRuntimeApi<Block, AccountId, Balance>
+		+ module_prices_rpc::PricesRuntimeApi<Block, BlockNumber>
+		+ module_incentives_rpc::IncentivesRuntimeApi<Block, AccountId, IncentivePoolId, Balance>
 		+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
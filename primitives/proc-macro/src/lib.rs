@@ -21,7 +21,7 @@ use proc_macro2::Literal;
 use quote::quote;
 use sha3::{Digest, Keccak256};
 use std::convert::TryInto;
-use syn::{parse_macro_input, Expr, ExprLit, Ident, ItemEnum, Lit};
+use syn::{parse_macro_input, Expr, ExprLit, Ident, ItemEnum, Lit, LitByteStr, LitStr};
 
 #[proc_macro_attribute]
 pub fn generate_function_selector(_: TokenStream, input: TokenStream) -> TokenStream {
@@ -67,6 +67,18 @@ pub fn generate_function_selector(_: TokenStream, input: TokenStream) -> TokenSt
 	.into()
 }
 
+/// Expand to the keccak256 hash of the given string literal, as a `&[u8; 32]`.
+/// Used to compute the topics of EVM events at compile time.
+#[proc_macro]
+pub fn keccak256(input: TokenStream) -> TokenStream {
+	let lit_str = parse_macro_input!(input as LitStr);
+
+	let result = Keccak256::digest(lit_str.value().as_bytes());
+	let eval = Lit::ByteStr(LitByteStr::new(result.as_slice(), proc_macro2::Span::call_site()));
+
+	quote!(#eval).into()
+}
+
 fn get_function_selector(s: &str) -> u32 {
 	// create a SHA3-256 object
 	let mut hasher = Keccak256::new();
@@ -226,7 +226,7 @@ pub enum CurrencyId {
 	DexShare(DexShare, DexShare),
 	Erc20(EvmAddress),
 	ChainSafe(chainbridge::ResourceId),
-	StableAssetPoolToken(nutsfinance_stable_asset::PoolId),
+	StableAssetPoolToken(StableAssetPoolId),
 }
 
 impl CurrencyId {
@@ -21,7 +21,7 @@ use codec::{Decode, Encode};
 use evm::ExitReason;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 
@@ -85,3 +85,99 @@ pub struct EstimateResourcesRequest {
 	/// Data
 	pub data: Option<Vec<u8>>,
 }
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TracerKind {
+	/// Record the executed opcodes step by step.
+	StructLogs,
+	/// Record the call frames, including the precompile calls.
+	CallTracer,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TraceConfig {
+	/// The kind of trace to record.
+	pub tracer: TracerKind,
+	/// Don't record the stack of struct logs.
+	pub disable_stack: bool,
+	/// Don't record the memory of struct logs.
+	pub disable_memory: bool,
+	/// Don't record the storage of struct logs.
+	pub disable_storage: bool,
+	/// The maximum number of struct logs to record, 0 for the maximum of the
+	/// runtime.
+	pub limit: u32,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StructLog {
+	/// Program counter.
+	pub pc: u64,
+	/// Opcode.
+	pub op: u8,
+	/// Gas left before executing the opcode.
+	pub gas: u64,
+	/// Gas cost of the opcode.
+	pub gas_cost: u64,
+	/// Call depth, starts from 1.
+	pub depth: u32,
+	/// Stack before executing the opcode.
+	pub stack: Option<Vec<H256>>,
+	/// Memory before executing the opcode.
+	pub memory: Option<Vec<u8>>,
+	/// The storage of the contract accessed by SLOAD and SSTORE so far.
+	pub storage: Option<Vec<(H256, H256)>>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CallType {
+	Call,
+	CallCode,
+	DelegateCall,
+	StaticCall,
+	Create,
+	Create2,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CallTrace {
+	pub call_type: CallType,
+	pub from: EvmAddress,
+	pub to: EvmAddress,
+	pub input: Vec<u8>,
+	pub value: U256,
+	/// Gas limit of the call.
+	pub gas: u64,
+	pub gas_used: u64,
+	/// Storage charged by the storage meter of the call, negative if refunded.
+	pub used_storage: i32,
+	/// The callee is a precompile.
+	pub is_precompile: bool,
+	/// `None` if the call is not finished.
+	pub exit_reason: Option<ExitReason>,
+	pub output: Vec<u8>,
+	/// Sub calls.
+	pub calls: Vec<CallTrace>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Trace {
+	StructLogs(Vec<StructLog>),
+	CallTracer(Option<CallTrace>),
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TraceInfo {
+	pub exit_reason: ExitReason,
+	pub output: Vec<u8>,
+	pub used_gas: U256,
+	pub used_storage: i32,
+	pub trace: Trace,
+}
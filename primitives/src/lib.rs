@@ -20,6 +20,7 @@
 #![allow(clippy::unnecessary_cast)]
 #![allow(clippy::upper_case_acronyms)]
 
+pub mod cdp;
 pub mod currency;
 pub mod evm;
 
@@ -84,6 +85,9 @@ pub type AuctionId = u32;
 /// Share type
 pub type Share = u128;
 
+/// Stable asset pool ID
+pub type StableAssetPoolId = u32;
+
 /// Header type.
 pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 
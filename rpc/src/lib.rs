@@ -20,8 +20,11 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, Balance, Block, CurrencyId, DataProviderId, Hash, Nonce};
-use sc_client_api::light::{Fetcher, RemoteBlockchain};
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Nonce};
+use sc_client_api::{
+	light::{Fetcher, RemoteBlockchain},
+	BlockBackend,
+};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -62,16 +65,24 @@ pub struct FullDeps<C, P> {
 pub fn create_full<C, P>(deps: FullDeps<C, P>) -> RpcExtension
 where
 	C: ProvideRuntimeApi<Block>,
-	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + BlockBackend<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, runtime_common::TimeStampedPrice>,
 	C::Api: module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>,
+	C::Api: module_dex_rpc::DexRuntimeApi<Block, Balance>,
+	C::Api: module_cdp_engine_rpc::CdpEngineRuntimeApi<Block, AccountId, Balance>,
+	C::Api: module_prices_rpc::PricesRuntimeApi<Block, BlockNumber>,
+	C::Api: module_incentives_rpc::IncentivesRuntimeApi<Block, AccountId, runtime_common::IncentivePoolId, Balance>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use module_cdp_engine_rpc::{CdpEngine, CdpEngineApi};
+	use module_dex_rpc::{Dex, DexApi};
+	use module_incentives_rpc::{Incentives, IncentivesApi};
+	use module_prices_rpc::{Prices, PricesApi};
 	use module_staking_pool_rpc::{StakingPool, StakingPoolApi};
 	use orml_oracle_rpc::{Oracle, OracleApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
@@ -97,6 +108,10 @@ where
 	// These RPCs should use an asynchronous caller instead.
 	io.extend_with(OracleApi::to_delegate(Oracle::new(client.clone())));
 	io.extend_with(StakingPoolApi::to_delegate(StakingPool::new(client.clone())));
+	io.extend_with(DexApi::to_delegate(Dex::new(client.clone())));
+	io.extend_with(CdpEngineApi::to_delegate(CdpEngine::new(client.clone())));
+	io.extend_with(PricesApi::to_delegate(Prices::new(client.clone())));
+	io.extend_with(IncentivesApi::to_delegate(Incentives::new(client.clone())));
 	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client, deny_unsafe)));
 
 	io
@@ -84,32 +84,73 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	}
 	fn add_liquidity() -> Weight {
 		(225_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(326_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(15 as Weight))
-			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+			.saturating_add(T::DbWeight::get().reads(23 as Weight))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
 	}
 	fn remove_liquidity() -> Weight {
 		(192_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(366_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(16 as Weight))
-			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+			.saturating_add(T::DbWeight::get().reads(24 as Weight))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
 	}
-	fn swap_with_exact_supply(_u: u32, ) -> Weight {
+	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(169_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
-	fn swap_with_exact_target(_u: u32, ) -> Weight {
+	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(167_300_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_supply_by_best_path(u: u32, p: u32, ) -> Weight {
+		(150_251_000 as Weight)
+			.saturating_add((24_108_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_path(u: u32, p: u32, ) -> Weight {
+		(151_012_000 as Weight)
+			.saturating_add((24_362_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		(162_471_000 as Weight)
+			.saturating_add((702_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_344_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_share() -> Weight {
+		(14_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
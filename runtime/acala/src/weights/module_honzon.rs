@@ -59,19 +59,28 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 	}
 	fn adjust_loan() -> Weight {
 		(160_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(24 as Weight))
-			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
 	fn transfer_loan_from() -> Weight {
 		(116_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(21 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(23 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	fn close_loan_has_debit_by_dex(u: u32) -> Weight {
 		(116_000_000 as Weight)
 			// Standard Error: 268_000
 			.saturating_add((1_100_000 as Weight).saturating_mul(u as Weight))
-			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn adjust_multi_collateral_loan(c: u32) -> Weight {
+		(109_371_000 as Weight)
+			// Standard Error: 82_000
+			.saturating_add((31_042_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
 }
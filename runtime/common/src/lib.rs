@@ -51,8 +51,8 @@ pub use homa::*;
 
 pub mod precompile;
 pub use precompile::{
-	AllPrecompiles, DexPrecompile, MultiCurrencyPrecompile, NFTPrecompile, OraclePrecompile, ScheduleCallPrecompile,
-	StateRentPrecompile,
+	AllPrecompiles, DexPrecompile, HomaPrecompile, HonzonPrecompile, IncentivesPrecompile, MultiCurrencyPrecompile,
+	NFTPrecompile, OraclePrecompile, ScheduleCallPrecompile, StateRentPrecompile,
 };
 pub use primitives::{
 	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KSM, KUSD, LDOT, LKSM, RENBTC},
@@ -60,6 +60,7 @@ pub use primitives::{
 };
 
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, primitives::Moment>;
+pub type IncentivePoolId = module_incentives::PoolId<AccountId>;
 
 // Priority of unsigned transactions
 parameter_types! {
@@ -68,6 +69,7 @@ parameter_types! {
 	pub const CdpEngineUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;      // 50%
 	pub const AuctionManagerUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 5; // 20%
 	pub const RenvmBridgeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 10;   // 10%
+	pub const DexOrdersUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 20;     // 5%
 }
 
 /// Check if the given `address` is a system contract.
@@ -143,6 +145,12 @@ parameter_types! {
 		.max_extrinsic
 		.expect("Normal extrinsics have weight limit configured by default; qed")
 		.saturating_sub(BlockExecutionWeight::get());
+	/// The maximum gas of an EVM execution in a block, which is converted from the
+	/// maximum weight of a normal extrinsic by `GasToWeight` at 1:1.
+	pub BlockGasLimit: u64 = RuntimeBlockWeights::get()
+		.get(DispatchClass::Normal)
+		.max_extrinsic
+		.expect("Normal extrinsics have weight limit configured by default; qed");
 }
 
 pub struct DummyNomineeFilter;
@@ -19,11 +19,14 @@
 use super::input::{Input, InputT};
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager, ExecutionMode,
+	InvokeContext, EVM as EVMT,
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Balance, CurrencyId};
 use sp_core::U256;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{DispatchError, FixedPointNumber, RuntimeDebug};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
 /// The `DEX` impl precompile.
@@ -35,10 +38,24 @@ use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
-pub struct DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex>(
-	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Dex)>,
+/// - Get time-weighted average price. Rest `input` bytes: `currency_id_a`, `currency_id_b`,
+///   `window`.
+/// - Flash swap with exact target. Rest `input` bytes: `who`, `target_amount`, `max_supply_amount`,
+///   `path_len`, `path`, `callback_input_len`, `callback_input`. `who` must be a contract, it's called
+///   with `callback_input` after receiving the target amount and must be able to pay the supply
+///   amount when the call returns. The callback is bounded by the gas left for the precompile, and
+///   the gas it uses is charged along with `FLASH_SWAP_GAS_COST`.
+pub struct DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM)>,
 );
 
+/// The gas cost of flash swaps, excluding the callback.
+pub const FLASH_SWAP_GAS_COST: u64 = 200_000;
+/// The maximum gas limit of the callback of flash swaps.
+pub const FLASH_SWAP_CALLBACK_GAS_LIMIT: u64 = 2_100_000;
+/// The storage limit of the callback of flash swaps.
+pub const FLASH_SWAP_CALLBACK_STORAGE_LIMIT: u32 = 1_000;
+
 #[primitives_proc_macro::generate_function_selector]
 #[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u32)]
@@ -47,24 +64,27 @@ pub enum Action {
 	GetLiquidityTokenAddress = "getLiquidityTokenAddress(address,address)",
 	GetSwapTargetAmount = "getSwapTargetAmount(address[],uint256)",
 	GetSwapSupplyAmount = "getSwapSupplyAmount(address[],uint256)",
+	GetTimeWeightedAveragePrice = "getTimeWeightedAveragePrice(address,address,uint256)",
 	SwapWithExactSupply = "swapWithExactSupply(address,address[],uint256,uint256)",
 	SwapWithExactTarget = "swapWithExactTarget(address,address[],uint256,uint256)",
 	AddLiquidity = "addLiquidity(address,address,address,uint256,uint256,uint256)",
 	RemoveLiquidity = "removeLiquidity(address,address,address,uint256,uint256,uint256)",
+	FlashSwap = "flashSwap(address,address[],uint256,uint256,bytes)",
 }
 
-impl<AccountId, AddressMapping, CurrencyIdMapping, Dex> Precompile
-	for DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex>
+impl<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM> Precompile
+	for DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM>
 where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
 	Dex: DEXManager<AccountId, CurrencyId, Balance>,
+	EVM: EVMT<AccountId>,
 {
 	fn execute(
 		input: &[u8],
-		_target_gas: Option<u64>,
-		_context: &Context,
+		target_gas: Option<u64>,
+		context: &Context,
 	) -> result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
 		//TODO: evaluate cost
 
@@ -157,6 +177,25 @@ where
 
 				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
 			}
+			Action::GetTimeWeightedAveragePrice => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+				let window = input.u32_at(3)?;
+				log::debug!(
+					target: "evm",
+					"dex: get_time_weighted_average_price currency_id_a: {:?}, currency_id_b: {:?}, window: {:?}",
+					currency_id_a, currency_id_b, window
+				);
+
+				let value = Dex::get_time_weighted_average_price(currency_id_a, currency_id_b, window)
+					.ok_or_else(|| ExitError::Other("Dex get_time_weighted_average_price failed".into()))?;
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value.into_inner()).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
 			Action::SwapWithExactSupply => {
 				let who = input.account_id_at(1)?;
 				// solidity abi enocde array will add an offset at input[2]
@@ -273,6 +312,72 @@ where
 
 				Ok((ExitSucceed::Returned, vec![], 0))
 			}
+			Action::FlashSwap => {
+				let who = input.account_id_at(1)?;
+				let callback_contract = input.evm_address_at(1)?;
+				// solidity abi enocde array will add an offset at input[2]
+				let target_amount = input.balance_at(3)?;
+				let max_supply_amount = input.balance_at(4)?;
+				// solidity abi enocde bytes will add an offset at input[5]
+				let path_len = input.u32_at(6)?;
+				let mut path = vec![];
+				for i in 0..path_len {
+					path.push(input.currency_id_at((7 + i) as usize)?);
+				}
+				let callback_input_len = input.u32_at((7 + path_len) as usize)?;
+				let callback_input = input.bytes_at((8 + path_len) as usize, callback_input_len as usize)?;
+				log::debug!(
+					target: "evm",
+					"dex: flash_swap who: {:?}, path: {:?}, target_amount: {:?}, max_supply_amount: {:?}, callback_input: {:?}",
+					who, path, target_amount, max_supply_amount, callback_input
+				);
+
+				let callback_gas_limit = match target_gas {
+					Some(gas) => gas
+						.checked_sub(FLASH_SWAP_GAS_COST)
+						.ok_or(ExitError::OutOfGas)?
+						.min(FLASH_SWAP_CALLBACK_GAS_LIMIT),
+					None => FLASH_SWAP_CALLBACK_GAS_LIMIT,
+				};
+				let mut callback_used_gas: u64 = 0;
+
+				let callback = || {
+					let info = EVM::execute(
+						InvokeContext {
+							contract: callback_contract,
+							sender: context.caller,
+							origin: callback_contract,
+						},
+						callback_input,
+						Default::default(),
+						callback_gas_limit,
+						FLASH_SWAP_CALLBACK_STORAGE_LIMIT,
+						ExecutionMode::Execute,
+					)?;
+					callback_used_gas = info.used_gas.low_u64();
+					if info.exit_reason.is_succeed() {
+						Ok(())
+					} else {
+						Err(DispatchError::Other("flash swap callback failed"))
+					}
+				};
+
+				let value = Dex::flash_swap_with_exact_target(&who, &path, target_amount, max_supply_amount, callback)
+					.map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((
+					ExitSucceed::Returned,
+					be_bytes.to_vec(),
+					FLASH_SWAP_GAS_COST.saturating_add(callback_used_gas),
+				))
+			}
 		}
 	}
 }
@@ -0,0 +1,128 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	ensure_cost,
+	input::{Input, InputT},
+};
+use frame_support::log;
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, HomaManager as HomaManagerT,
+	EVM as EVMT,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::Balance;
+use sp_core::{H256, U256};
+use sp_runtime::{FixedPointNumber, RuntimeDebug};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Homa` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Mint. Rest `input` bytes: `who`, `amount`. Returns the amount of liquid currency minted.
+/// - Get exchange rate. No rest `input` bytes. Returns the exchange rate from liquid currency to
+///   staking currency.
+///
+/// State changing actions are notified by EVM logs of the precompile address.
+pub struct HomaPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Homa, EVM>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Homa, EVM)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Mint = "mint(address,uint256)",
+	GetExchangeRate = "getExchangeRate()",
+}
+
+/// The gas cost of `mint`, derived from the weight of `module_homa_lite::mint` at
+/// `WEIGHT_PER_GAS`.
+pub const MINT_GAS_COST: u64 = 126_000;
+
+/// `Minted(address indexed who, uint256 amount, uint256 liquidAmount)`
+pub const MINTED: &[u8; 32] = primitives_proc_macro::keccak256!("Minted(address,uint256,uint256)");
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Homa, EVM> Precompile
+	for HomaPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Homa, EVM>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Homa: HomaManagerT<AccountId, Balance>,
+	EVM: EVMT<AccountId>,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		log::debug!(target: "evm", "homa: input: {:?}", input);
+
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::Mint => {
+				let gas_cost = ensure_cost(target_gas, MINT_GAS_COST)?;
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+				log::debug!(
+					target: "evm",
+					"homa: mint who: {:?}, amount: {:?}",
+					who, amount
+				);
+
+				let liquid_amount = Homa::mint(who, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				// log
+				let mut data = [0u8; 64];
+				U256::from(amount).to_big_endian(&mut data[..32]);
+				U256::from(liquid_amount).to_big_endian(&mut data[32..64]);
+				EVM::log(
+					context.address,
+					vec![H256::from(MINTED), H256::from(input.evm_address_at(1)?)],
+					data.to_vec(),
+				);
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(liquid_amount).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), gas_cost))
+			}
+			Action::GetExchangeRate => {
+				let value = Homa::get_exchange_rate();
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value.into_inner()).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
+		}
+	}
+}
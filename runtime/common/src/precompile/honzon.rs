@@ -0,0 +1,197 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	ensure_cost,
+	input::{Input, InputT},
+};
+use frame_support::log;
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, HonzonManager as HonzonManagerT,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Amount, Balance, CurrencyId};
+use sp_core::U256;
+use sp_runtime::{FixedPointNumber, RuntimeDebug};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Honzon` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Adjust loan. Rest `input` bytes: `who`, `currency_id`, `collateral_adjustment`,
+///   `debit_adjustment`.
+/// - Close loan by DEX. Rest `input` bytes: `who`, `currency_id`, `max_collateral_amount`.
+/// - Get position. Rest `input` bytes: `who`, `currency_id`.
+/// - Get collateral ratio. Rest `input` bytes: `who`, `currency_id`.
+/// - Get liquidation ratio. Rest `input` bytes: `currency_id`.
+/// - Get debit exchange rate. Rest `input` bytes: `currency_id`.
+pub struct HonzonPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Honzon>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Honzon)>,
+);
+
+/// The gas cost of `adjustLoan`, derived from the weight of `module_honzon::adjust_loan` at
+/// `WEIGHT_PER_GAS`.
+pub const ADJUST_LOAN_GAS_COST: u64 = 215_000;
+/// The gas cost of `closeLoanByDex`, derived from the weight of
+/// `module_honzon::close_loan_has_debit_by_dex` with the longest trading path at `WEIGHT_PER_GAS`.
+pub const CLOSE_LOAN_BY_DEX_GAS_COST: u64 = 258_000;
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	AdjustLoan = "adjustLoan(address,address,int128,int128)",
+	CloseLoanByDex = "closeLoanByDex(address,address,uint256)",
+	GetPosition = "getPosition(address,address)",
+	GetCollateralRatio = "getCollateralRatio(address,address)",
+	GetLiquidationRatio = "getLiquidationRatio(address)",
+	GetDebitExchangeRate = "getDebitExchangeRate(address)",
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Honzon> Precompile
+	for HonzonPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Honzon>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Honzon: HonzonManagerT<AccountId, CurrencyId, Amount, Balance>,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		log::debug!(target: "evm", "honzon: input: {:?}", input);
+
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::AdjustLoan => {
+				let gas_cost = ensure_cost(target_gas, ADJUST_LOAN_GAS_COST)?;
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let collateral_adjustment = input.amount_at(3)?;
+				let debit_adjustment = input.amount_at(4)?;
+				log::debug!(
+					target: "evm",
+					"honzon: adjust_loan who: {:?}, currency_id: {:?}, collateral_adjustment: {:?}, debit_adjustment: {:?}",
+					who, currency_id, collateral_adjustment, debit_adjustment
+				);
+
+				Honzon::adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok((ExitSucceed::Returned, vec![], gas_cost))
+			}
+			Action::CloseLoanByDex => {
+				let gas_cost = ensure_cost(target_gas, CLOSE_LOAN_BY_DEX_GAS_COST)?;
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let max_collateral_amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"honzon: close_loan_by_dex who: {:?}, currency_id: {:?}, max_collateral_amount: {:?}",
+					who, currency_id, max_collateral_amount
+				);
+
+				Honzon::close_loan_by_dex(who, currency_id, max_collateral_amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok((ExitSucceed::Returned, vec![], gas_cost))
+			}
+			Action::GetPosition => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				log::debug!(
+					target: "evm",
+					"honzon: get_position who: {:?}, currency_id: {:?}",
+					who, currency_id
+				);
+
+				let (collateral, debit) = Honzon::get_position(&who, currency_id);
+
+				// output
+				let mut be_bytes = [0u8; 64];
+				U256::from(collateral).to_big_endian(&mut be_bytes[..32]);
+				U256::from(debit).to_big_endian(&mut be_bytes[32..64]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
+			Action::GetCollateralRatio => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				log::debug!(
+					target: "evm",
+					"honzon: get_collateral_ratio who: {:?}, currency_id: {:?}",
+					who, currency_id
+				);
+
+				let value = Honzon::get_collateral_ratio(&who, currency_id)
+					.ok_or_else(|| ExitError::Other("Honzon get_collateral_ratio failed".into()))?;
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value.into_inner()).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
+			Action::GetLiquidationRatio => {
+				let currency_id = input.currency_id_at(1)?;
+				log::debug!(
+					target: "evm",
+					"honzon: get_liquidation_ratio currency_id: {:?}",
+					currency_id
+				);
+
+				let value = Honzon::get_liquidation_ratio(currency_id);
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value.into_inner()).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
+			Action::GetDebitExchangeRate => {
+				let currency_id = input.currency_id_at(1)?;
+				log::debug!(
+					target: "evm",
+					"honzon: get_debit_exchange_rate currency_id: {:?}",
+					currency_id
+				);
+
+				let value = Honzon::get_debit_exchange_rate(currency_id);
+
+				// output
+				let mut be_bytes = [0u8; 32];
+				U256::from(value.into_inner()).to_big_endian(&mut be_bytes[..32]);
+
+				Ok((ExitSucceed::Returned, be_bytes.to_vec(), 0))
+			}
+		}
+	}
+}
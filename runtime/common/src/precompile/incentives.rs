@@ -0,0 +1,247 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	ensure_cost,
+	input::{Input, InputT},
+	WEIGHT_PER_GAS,
+};
+use frame_support::log;
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_incentives::{PoolId, WeightInfo as WeightInfoT};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT,
+	IncentivesManager as IncentivesManagerT, EVM as EVMT,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Balance, CurrencyId};
+use sp_core::{H256, U256};
+use sp_runtime::RuntimeDebug;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Incentives` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Pools are identified by `pool_kind` and `pool_param`. `pool_kind` is the index of the
+/// `PoolId` variant, `pool_param` is the currency of the pool, or the validator of
+/// `HomaValidatorAllowance`, and is ignored by `HomaIncentive`.
+///
+/// Actions:
+/// - Deposit DEX share. Rest `input` bytes: `who`, `lp_currency_id`, `amount`.
+/// - Withdraw DEX share. Rest `input` bytes: `who`, `lp_currency_id`, `amount`.
+/// - Claim rewards. Rest `input` bytes: `who`, `pool_kind`, `pool_param`.
+/// - Get pending rewards. Rest `input` bytes: `who`, `pool_kind`, `pool_param`,
+///   `reward_currency_id`.
+///
+/// State changing actions are notified by EVM logs of the precompile address, and charge the gas
+/// derived from the weight of the matching `module_incentives` extrinsics at `WEIGHT_PER_GAS`.
+/// Claiming rewards is charged per pending reward currency.
+pub struct IncentivesPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Incentives, EVM, WeightInfo>(
+	PhantomData<(
+		AccountId,
+		AddressMapping,
+		CurrencyIdMapping,
+		Incentives,
+		EVM,
+		WeightInfo,
+	)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	DepositDexShare = "depositDexShare(address,address,uint256)",
+	WithdrawDexShare = "withdrawDexShare(address,address,uint256)",
+	ClaimRewards = "claimRewards(address,uint32,address)",
+	GetPendingRewards = "getPendingRewards(address,uint32,address,address)",
+}
+
+/// `DepositedDexShare(address indexed who, address indexed lpCurrency, uint256 amount)`
+pub const DEPOSITED_DEX_SHARE: &[u8; 32] =
+	primitives_proc_macro::keccak256!("DepositedDexShare(address,address,uint256)");
+/// `WithdrewDexShare(address indexed who, address indexed lpCurrency, uint256 amount)`
+pub const WITHDREW_DEX_SHARE: &[u8; 32] =
+	primitives_proc_macro::keccak256!("WithdrewDexShare(address,address,uint256)");
+/// `ClaimedRewards(address indexed who, uint32 indexed poolKind, address indexed poolParam)`
+pub const CLAIMED_REWARDS: &[u8; 32] = primitives_proc_macro::keccak256!("ClaimedRewards(address,uint32,address)");
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Incentives, EVM, WeightInfo> Precompile
+	for IncentivesPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Incentives, EVM, WeightInfo>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Incentives: IncentivesManagerT<AccountId, Balance, CurrencyId, PoolId<AccountId>>,
+	EVM: EVMT<AccountId>,
+	WeightInfo: WeightInfoT,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		log::debug!(target: "evm", "incentives: input: {:?}", input);
+
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::DepositDexShare => {
+				let gas_cost = ensure_cost(target_gas, WeightInfo::deposit_dex_share() / WEIGHT_PER_GAS)?;
+				let who = input.account_id_at(1)?;
+				let lp_currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"incentives: deposit_dex_share who: {:?}, lp_currency_id: {:?}, amount: {:?}",
+					who, lp_currency_id, amount
+				);
+
+				Incentives::deposit_dex_share(&who, lp_currency_id, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				EVM::log(
+					context.address,
+					vec![
+						H256::from(DEPOSITED_DEX_SHARE),
+						H256::from(input.evm_address_at(1)?),
+						H256::from(input.evm_address_at(2)?),
+					],
+					encode_balance(amount),
+				);
+
+				Ok((ExitSucceed::Returned, vec![], gas_cost))
+			}
+			Action::WithdrawDexShare => {
+				let gas_cost = ensure_cost(target_gas, WeightInfo::withdraw_dex_share() / WEIGHT_PER_GAS)?;
+				let who = input.account_id_at(1)?;
+				let lp_currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"incentives: withdraw_dex_share who: {:?}, lp_currency_id: {:?}, amount: {:?}",
+					who, lp_currency_id, amount
+				);
+
+				Incentives::withdraw_dex_share(&who, lp_currency_id, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				EVM::log(
+					context.address,
+					vec![
+						H256::from(WITHDREW_DEX_SHARE),
+						H256::from(input.evm_address_at(1)?),
+						H256::from(input.evm_address_at(2)?),
+					],
+					encode_balance(amount),
+				);
+
+				Ok((ExitSucceed::Returned, vec![], gas_cost))
+			}
+			Action::ClaimRewards => {
+				let who = input.account_id_at(1)?;
+				let pool_kind = input.u32_at(2)?;
+				let pool_id = pool_id_at(&input, 2)?;
+				log::debug!(
+					target: "evm",
+					"incentives: claim_rewards who: {:?}, pool_id: {:?}",
+					who, pool_id
+				);
+
+				// every pending reward currency is paid out by a transfer
+				let reward_currencies_count = Incentives::get_pending_rewards(pool_id.clone(), who.clone())
+					.len()
+					.max(1);
+				let gas_cost = ensure_cost(
+					target_gas,
+					WeightInfo::claim_rewards().saturating_mul(reward_currencies_count as u64) / WEIGHT_PER_GAS,
+				)?;
+
+				Incentives::claim_rewards(who, pool_id).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				EVM::log(
+					context.address,
+					vec![
+						H256::from(CLAIMED_REWARDS),
+						H256::from(input.evm_address_at(1)?),
+						H256::from_low_u64_be(pool_kind.into()),
+						H256::from(input.evm_address_at(3)?),
+					],
+					vec![],
+				);
+
+				Ok((ExitSucceed::Returned, vec![], gas_cost))
+			}
+			Action::GetPendingRewards => {
+				let who = input.account_id_at(1)?;
+				let pool_id = pool_id_at(&input, 2)?;
+				let reward_currency_id = input.currency_id_at(4)?;
+				log::debug!(
+					target: "evm",
+					"incentives: get_pending_rewards who: {:?}, pool_id: {:?}, reward_currency_id: {:?}",
+					who, pool_id, reward_currency_id
+				);
+
+				let value = Incentives::get_pending_rewards(pool_id, who)
+					.into_iter()
+					.find(|(currency_id, _)| *currency_id == reward_currency_id)
+					.map(|(_, amount)| amount)
+					.unwrap_or_default();
+
+				Ok((ExitSucceed::Returned, encode_balance(value), 0))
+			}
+		}
+	}
+}
+
+/// Read the pool id from the `pool_kind` at `index` and the `pool_param` at `index + 1`.
+fn pool_id_at<AccountId, AddressMapping, CurrencyIdMapping>(
+	input: &Input<Action, AccountId, AddressMapping, CurrencyIdMapping>,
+	index: usize,
+) -> result::Result<PoolId<AccountId>, ExitError>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+{
+	match input.u32_at(index)? {
+		0 => Ok(PoolId::LoansIncentive(input.currency_id_at(index + 1)?)),
+		1 => Ok(PoolId::DexIncentive(input.currency_id_at(index + 1)?)),
+		2 => Ok(PoolId::HomaIncentive),
+		3 => Ok(PoolId::DexSaving(input.currency_id_at(index + 1)?)),
+		4 => Ok(PoolId::HomaValidatorAllowance(input.account_id_at(index + 1)?)),
+		_ => Err(ExitError::Other("invalid pool kind".into())),
+	}
+}
+
+fn encode_balance(value: Balance) -> Vec<u8> {
+	let mut be_bytes = [0u8; 32];
+	U256::from(value).to_big_endian(&mut be_bytes[..32]);
+	be_bytes.to_vec()
+}
@@ -27,21 +27,24 @@ use frame_support::{
 	weights::IdentityFee,
 	PalletId, RuntimeDebug,
 };
-use frame_system::{EnsureRoot, EnsureSignedBy};
+use frame_system::{offchain::SendTransactionTypes, EnsureRoot, EnsureSignedBy};
 use module_support::{
-	mocks::MockAddressMapping, AddressMapping as AddressMappingT, DEXIncentives, ExchangeRate, ExchangeRateProvider,
+	mocks::MockAddressMapping, AddressMapping as AddressMappingT, AuctionManager, DEXIncentives, EmergencyShutdown,
+	ExchangeRate, ExchangeRateProvider, PriceProvider, Rate,
 };
-use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
+use orml_traits::{parameter_type_with_key, DataProvider, DataProviderExtended, MultiReservableCurrency, XcmTransfer};
 pub use primitives::{
-	evm::EvmAddress, Amount, BlockNumber, CurrencyId, DexShare, Header, Nonce, ReserveIdentifier, TokenSymbol,
-	TradingPair,
+	evm::EvmAddress, Amount, BlockNumber, CurrencyId, DataProviderId, DexShare, Header, Nonce, ReserveIdentifier,
+	TokenSymbol, TradingPair,
 };
 use sp_core::{crypto::AccountId32, H160, H256};
 use sp_runtime::{
-	traits::{BlakeTwo256, Convert, IdentityLookup, One as OneT},
-	DispatchResult, FixedPointNumber, FixedU128, Perbill,
+	testing::TestXt,
+	traits::{AccountIdConversion, BlakeTwo256, Convert, IdentityLookup, One as OneT},
+	DispatchResult, FixedPointNumber, FixedU128, Perbill, Permill,
 };
 use sp_std::{collections::btree_map::BTreeMap, convert::TryFrom, str::FromStr};
+use xcm::opaque::v0::{MultiAsset, MultiLocation};
 
 pub type AccountId = AccountId32;
 type Key = CurrencyId;
@@ -168,6 +171,7 @@ impl module_currencies::Config for Test {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type HeldCurrencyIds = ();
 }
 
 impl module_evm_bridge::Config for Test {
@@ -324,6 +328,8 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 10;
+	pub const PriceObservationLimit: u32 = 10;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -332,18 +338,288 @@ impl module_dex::Config for Test {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = ();
+}
+
+parameter_types! {
+	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
+}
+
+impl module_loans::Config for Test {
+	type Event = Event;
+	type Convert = module_cdp_engine::DebitExchangeRateConvertor<Test>;
+	type Currency = Currencies;
+	type RiskManager = CDPEngine;
+	type CDPTreasury = CDPTreasury;
+	type PalletId = LoansPalletId;
+	type OnUpdateLoan = ();
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Price> {
+		Some(Price::one())
+	}
+
+	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
+		Some(Price::one())
+	}
+}
+
+pub struct MockAuctionManager;
+impl AuctionManager<AccountId> for MockAuctionManager {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type AuctionId = u32;
+
+	fn new_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_total_target_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
+		Default::default()
+	}
+}
+
+parameter_types! {
+	pub const MaxAuctionsCount: u32 = 10_000;
+	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub CDPTreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account();
+}
+
+impl module_cdp_treasury::Config for Test {
+	type Event = Event;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type AuctionManagerHandler = MockAuctionManager;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type DEX = DexModule;
+	type MaxAuctionsCount = MaxAuctionsCount;
+	type PalletId = CDPTreasuryPalletId;
+	type TreasuryAccount = CDPTreasuryAccount;
+	type WeightInfo = ();
+}
+
+pub struct MockEmergencyShutdown;
+impl EmergencyShutdown for MockEmergencyShutdown {
+	fn is_shutdown() -> bool {
+		false
+	}
+}
+
+parameter_types! {
+	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![RENBTC];
+	pub DefaultLiquidationRatio: Ratio = Ratio::saturating_from_rational(3, 2);
+	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::one();
+	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(10, 100);
+	pub const MinimumDebitValue: Balance = 2;
+	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub MaxInterestRatePerSecChange: Rate = Rate::saturating_from_rational(1, 1000);
+	pub const CDPEngineUnsignedPriority: u64 = 1 << 20;
+	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
+		vec![AUSD],
+	];
+}
+
+impl module_cdp_engine::Config for Test {
+	type Event = Event;
+	type PriceSource = MockPriceSource;
+	type PriceCircuitBreaker = ();
+	type InterestRateModel = ();
+	type MaxInterestRatePerSecChange = MaxInterestRatePerSecChange;
+	type CollateralCurrencyIds = CollateralCurrencyIds;
+	type DefaultLiquidationRatio = DefaultLiquidationRatio;
+	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
+	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
+	type MinimumDebitValue = MinimumDebitValue;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CDPTreasury;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type UnsignedPriority = CDPEngineUnsignedPriority;
+	type EmergencyShutdown = MockEmergencyShutdown;
+	type UnixTime = Timestamp;
+	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type WeightInfo = ();
+}
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = TestXt<Call, ()>;
+}
+
+parameter_types! {
+	pub const DepositPerAuthorization: Balance = 100;
+}
+
+impl module_honzon::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type DepositPerAuthorization = DepositPerAuthorization;
+	type WeightInfo = ();
+}
+
+impl orml_rewards::Config for Test {
+	type Share = Balance;
+	type Balance = Balance;
+	type PoolId = module_incentives::PoolId<AccountId>;
+	type Handler = Incentives;
+}
+
+parameter_type_with_key! {
+	pub IncentivesDebitExchangeRate: |_currency_id: CurrencyId| -> ExchangeRate {
+		ExchangeRate::one()
+	};
+}
+
+parameter_type_with_key! {
+	pub MinCampaignBudget: |_currency_id: CurrencyId| -> Option<Balance> {
+		Some(0)
+	};
+}
+
+parameter_types! {
+	pub NativeRewardsSource: AccountId = AccountId::new([9u8; 32]);
+	pub const AccumulatePeriod: BlockNumber = 10;
+	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const IncentivesBlocksPerYear: BlockNumber = 1000;
+	pub const MaxExtraRewardCurrencies: u32 = 2;
+	pub const MaxCampaigns: u32 = 2;
+	pub const MaxCampaignDuration: BlockNumber = 100;
+	pub const CampaignDeposit: Balance = 10;
+	pub const MaxBoostLockPeriod: BlockNumber = 1000;
+}
+
+impl module_incentives::Config for Test {
+	type Event = Event;
+	type RelaychainAccountId = AccountId;
+	type NativeRewardsSource = NativeRewardsSource;
+	type AccumulatePeriod = AccumulatePeriod;
+	type NativeCurrencyId = GetNativeCurrencyId;
+	type StableCurrencyId = GetStableCurrencyId;
+	type LiquidCurrencyId = GetLiquidCurrencyId;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type CDPTreasury = CDPTreasury;
+	type Currency = Currencies;
+	type DEX = DexModule;
+	type EmergencyShutdown = MockEmergencyShutdown;
+	type PriceSource = MockPriceSource;
+	type DebitExchangeRate = IncentivesDebitExchangeRate;
+	type BlocksPerYear = IncentivesBlocksPerYear;
+	type PalletId = IncentivesPalletId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	type MaxCampaigns = MaxCampaigns;
+	type MinCampaignBudget = MinCampaignBudget;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type CampaignDeposit = CampaignDeposit;
+	type MaxBoostLockPeriod = MaxBoostLockPeriod;
+	type WeightInfo = ();
+}
+
+pub struct MockXcm;
+impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcm {
+	fn transfer(
+		_who: AccountId,
+		_currency_id: CurrencyId,
+		_amount: Balance,
+		_dest: MultiLocation,
+		_dest_weight: Weight,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn transfer_multi_asset(
+		_who: AccountId,
+		_asset: MultiAsset,
+		_dest: MultiLocation,
+		_dest_weight: Weight,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const MinimumMintThreshold: Balance = 10;
+	pub const MockXcmDestination: MultiLocation = MultiLocation::Null;
+	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 1);
+	pub const MaxRewardPerEra: Permill = Permill::zero();
+	pub const MintFee: Balance = 0;
+}
+
+impl module_homa_lite::Config for Test {
+	type Event = Event;
+	type WeightInfo = ();
+	type Currency = Currencies;
+	type StakingCurrencyId = GetStakingCurrencyId;
+	type LiquidCurrencyId = GetLiquidCurrencyId;
+	type GovernanceOrigin = EnsureSignedBy<One, AccountId>;
+	type MinimumMintThreshold = MinimumMintThreshold;
+	type XcmTransfer = MockXcm;
+	type SovereignSubAccountLocation = MockXcmDestination;
+	type DefaultExchangeRate = DefaultExchangeRate;
+	type MaxRewardPerEra = MaxRewardPerEra;
+	type MintFee = MintFee;
 }
 
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
 
 pub type EvmCurrencyIdMapping = module_evm_manager::EvmCurrencyIdMapping<Test>;
-pub type MultiCurrencyPrecompile =
-	crate::MultiCurrencyPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, Currencies>;
+pub type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<
+	AccountId,
+	MockAddressMapping,
+	EvmCurrencyIdMapping,
+	module_currencies::EvmMultiCurrency<Test>,
+>;
 
 pub type NFTPrecompile = crate::NFTPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, NFTModule>;
 pub type StateRentPrecompile =
@@ -365,7 +641,13 @@ pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	OriginCaller,
 	Test,
 >;
-pub type DexPrecompile = crate::DexPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, DexModule>;
+pub type DexPrecompile =
+	crate::DexPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, DexModule, ModuleEVM>;
+pub type HonzonPrecompile = crate::HonzonPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, Honzon>;
+pub type IncentivesPrecompile =
+	crate::IncentivesPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, Incentives, ModuleEVM, ()>;
+pub type HomaPrecompile =
+	crate::HomaPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, HomaLite, ModuleEVM>;
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice_evm_addr();
@@ -406,6 +688,9 @@ impl module_evm::Config for Test {
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -426,8 +711,19 @@ impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
 	}
 }
 
+pub struct MockOracleSources;
+impl DataProvider<(DataProviderId, CurrencyId), module_prices::TimestampedPrice> for MockOracleSources {
+	fn get(key: &(DataProviderId, CurrencyId)) -> Option<module_prices::TimestampedPrice> {
+		Oracle::get_no_op(&key.1)
+	}
+}
+
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxPriceSources: u32 = 8;
+	pub PriceSnapshotCurrencyIds: Vec<CurrencyId> = vec![DOT];
+	pub const PriceSnapshotInterval: BlockNumber = 10;
+	pub const MaxPriceSnapshots: u32 = 10;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 }
@@ -439,11 +735,18 @@ ord_parameter_types! {
 impl module_prices::Config for Test {
 	type Event = Event;
 	type Source = Oracle;
+	type OracleSources = MockOracleSources;
+	type MaxPriceSources = MaxPriceSources;
+	type PriceSnapshotCurrencyIds = PriceSnapshotCurrencyIds;
+	type PriceSnapshotInterval = PriceSnapshotInterval;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
 	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type UnixTime = Timestamp;
 	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
 	type DEX = DexModule;
 	type Currency = Currencies;
@@ -471,6 +774,10 @@ pub fn bob_evm_addr() -> EvmAddress {
 	EvmAddress::from_str("1000000000000000000000000000000000000002").unwrap()
 }
 
+pub fn flash_swap_callback_evm_addr() -> EvmAddress {
+	EvmAddress::from_str("1000000000000000000000000000000000000003").unwrap()
+}
+
 pub fn aca_evm_address() -> EvmAddress {
 	EvmAddress::try_from(ACA).unwrap()
 }
@@ -518,6 +825,13 @@ frame_support::construct_runtime!(
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		DexModule: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>},
 		ModuleEVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
+		Loans: module_loans::{Pallet, Storage, Call, Event<T>},
+		CDPTreasury: module_cdp_treasury::{Pallet, Storage, Call, Event<T>},
+		CDPEngine: module_cdp_engine::{Pallet, Storage, Call, Event<T>, Config, ValidateUnsigned},
+		Honzon: module_honzon::{Pallet, Storage, Call, Event<T>},
+		Rewards: orml_rewards::{Pallet, Storage, Call},
+		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>},
+		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -548,6 +862,16 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			code: Default::default(),
 		},
 	);
+	// the contract returns without doing anything, to be the callback of flash swaps
+	accounts.insert(
+		flash_swap_callback_evm_addr(),
+		module_evm::GenesisAccount {
+			nonce: 0,
+			balance: 0,
+			storage: Default::default(),
+			code: hex_literal::hex!("6460006000f36000526005601bf3").to_vec(),
+		},
+	);
 
 	pallet_balances::GenesisConfig::<Test>::default()
 		.assimilate_storage(&mut storage)
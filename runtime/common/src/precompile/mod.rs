@@ -28,8 +28,8 @@ use crate::is_acala_precompile;
 use frame_support::log;
 use module_evm::{
 	precompiles::{
-		ECRecover, ECRecoverPublicKey, EvmPrecompiles, Identity, Precompile, Precompiles, Ripemd160, Sha256,
-		Sha3FIPS256, Sha3FIPS512,
+		Blake2F, Bn128Add, Bn128Mul, Bn128Pairing, ECRecover, ECRecoverPublicKey, EvmPrecompiles, Identity, Modexp,
+		Precompile, Precompiles, Ripemd160, Sha256, Sha3FIPS256, Sha3FIPS512,
 	},
 	Context, ExitError, ExitSucceed,
 };
@@ -39,6 +39,9 @@ use sp_core::H160;
 use sp_std::{marker::PhantomData, prelude::*};
 
 pub mod dex;
+pub mod homa;
+pub mod honzon;
+pub mod incentives;
 pub mod input;
 pub mod multicurrency;
 pub mod nft;
@@ -47,6 +50,9 @@ pub mod schedule_call;
 pub mod state_rent;
 
 pub use dex::DexPrecompile;
+pub use homa::HomaPrecompile;
+pub use honzon::HonzonPrecompile;
+pub use incentives::IncentivesPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
@@ -61,6 +67,9 @@ pub struct AllPrecompiles<
 	OraclePrecompile,
 	ScheduleCallPrecompile,
 	DexPrecompile,
+	HonzonPrecompile,
+	IncentivesPrecompile,
+	HomaPrecompile,
 >(
 	PhantomData<(
 		PrecompileCallerFilter,
@@ -70,6 +79,9 @@ pub struct AllPrecompiles<
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	)>,
 );
 
@@ -81,6 +93,9 @@ impl<
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	> Precompiles
 	for AllPrecompiles<
 		PrecompileCallerFilter,
@@ -90,6 +105,9 @@ impl<
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	> where
 	MultiCurrencyPrecompile: Precompile,
 	NFTPrecompile: Precompile,
@@ -98,6 +116,9 @@ impl<
 	ScheduleCallPrecompile: Precompile,
 	PrecompileCallerFilter: PrecompileCallerFilterT,
 	DexPrecompile: Precompile,
+	HonzonPrecompile: Precompile,
+	IncentivesPrecompile: Precompile,
+	HomaPrecompile: Precompile,
 {
 	#[allow(clippy::type_complexity)]
 	fn execute(
@@ -106,9 +127,20 @@ impl<
 		target_gas: Option<u64>,
 		context: &Context,
 	) -> Option<core::result::Result<(ExitSucceed, Vec<u8>, u64), ExitError>> {
-		EvmPrecompiles::<ECRecover, Sha256, Ripemd160, Identity, ECRecoverPublicKey, Sha3FIPS256, Sha3FIPS512>::execute(
-			address, input, target_gas, context,
-		)
+		EvmPrecompiles::<
+			ECRecover,
+			Sha256,
+			Ripemd160,
+			Identity,
+			Modexp,
+			Bn128Add,
+			Bn128Mul,
+			Bn128Pairing,
+			Blake2F,
+			ECRecoverPublicKey,
+			Sha3FIPS256,
+			Sha3FIPS512,
+		>::execute(address, input, target_gas, context)
 		.or_else(|| {
 			if is_acala_precompile(address) && !PrecompileCallerFilter::is_allowed(context.caller) {
 				log::debug!(target: "evm", "Precompile no permission");
@@ -127,9 +159,30 @@ impl<
 				Some(ScheduleCallPrecompile::execute(input, target_gas, context))
 			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 5) {
 				Some(DexPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 6) {
+				Some(HonzonPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 7) {
+				Some(IncentivesPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 8) {
+				Some(HomaPrecompile::execute(input, target_gas, context))
 			} else {
 				None
 			}
 		})
 	}
 }
+
+/// The weight per gas, which derives the gas cost of the precompiles dispatching to pallets from
+/// the weight of the matching extrinsics.
+pub const WEIGHT_PER_GAS: u64 = 10_000;
+
+/// Ensure `target_gas` is enough for the gas `cost`, return the cost.
+fn ensure_cost(target_gas: Option<u64>, cost: u64) -> Result<u64, ExitError> {
+	if let Some(target_gas) = target_gas {
+		if cost > target_gas {
+			return Err(ExitError::OutOfGas);
+		}
+	}
+
+	Ok(cost)
+}
@@ -22,20 +22,24 @@ use super::*;
 use crate::precompile::{
 	mock::{
 		aca_evm_address, alice, alice_evm_addr, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
-		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, DexModule,
-		DexPrecompile, Event as TestEvent, MultiCurrencyPrecompile, Oracle, OraclePrecompile, Origin, Price,
-		ScheduleCallPrecompile, System, Test, ALICE, AUSD, INITIAL_BALANCE, RENBTC,
+		flash_swap_callback_evm_addr, get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address,
+		run_to_block, Balances, CDPEngine, Currencies, DexModule, DexPrecompile, Event as TestEvent, HomaLite,
+		HomaPrecompile, Honzon, HonzonPrecompile, Incentives, IncentivesPrecompile, Loans, MultiCurrencyPrecompile,
+		Oracle, OraclePrecompile, Origin, Price, ScheduleCallPrecompile, System, Test, ACA, ALICE, AUSD, DOT,
+		INITIAL_BALANCE, LDOT, LP_ACA_AUSD, RENBTC,
 	},
 	schedule_call::TaskInfo,
 };
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
-use module_evm::ExitError;
-use module_support::AddressMapping;
-use orml_traits::DataFeeder;
+use module_dex::PoolKind;
+use module_evm::{ExitError, Log};
+use module_incentives::{PoolId, WeightInfo as IncentivesWeightInfo};
+use module_support::{AddressMapping, ExchangeRate, Rate, Ratio};
+use orml_traits::{Change, DataFeeder, MultiCurrency};
 use primitives::{Balance, PREDEPLOY_ADDRESS_START};
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::FixedPointNumber;
 use std::str::FromStr;
 
@@ -58,6 +62,9 @@ pub type WithSystemContractFilter = AllPrecompiles<
 	DummyPrecompile,
 	DummyPrecompile,
 	DummyPrecompile,
+	DummyPrecompile,
+	DummyPrecompile,
+	DummyPrecompile,
 >;
 
 #[test]
@@ -112,6 +119,61 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 	);
 }
 
+#[test]
+fn precompile_dispatches_ethereum_precompiles() {
+	let mut non_system = [0u8; 20];
+	non_system[0] = 1;
+	let context = Context {
+		address: Default::default(),
+		caller: non_system.into(),
+		apparent_value: 0.into(),
+	};
+
+	// 2 ** 3 % 5
+	let modexp_input = hex!(
+		"
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000001
+		02
+		03
+		05
+		"
+	);
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(5), &modexp_input, None, &context),
+		Some(Ok((ExitSucceed::Returned, vec![3], 200)))
+	);
+
+	// the point at infinity
+	let points_input = [0u8; 128];
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(6), &points_input, None, &context),
+		Some(Ok((ExitSucceed::Returned, vec![0u8; 64], 150)))
+	);
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(7), &points_input[..96], None, &context),
+		Some(Ok((ExitSucceed::Returned, vec![0u8; 64], 6_000)))
+	);
+
+	let mut pairing_output = vec![0u8; 32];
+	pairing_output[31] = 1;
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(8), &[], None, &context),
+		Some(Ok((ExitSucceed::Returned, pairing_output, 45_000)))
+	);
+
+	let blake2f_input = [0u8; 213];
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(9), &blake2f_input, None, &context),
+		Some(Blake2F::execute(&blake2f_input, None, &context))
+	);
+	assert_eq!(
+		WithSystemContractFilter::execute(H160::from_low_u64_be(9), &[], None, &context),
+		Some(Err(ExitError::Other("Invalid input length".into())))
+	);
+}
+
 #[test]
 fn multicurrency_precompile_should_work() {
 	new_test_ext().execute_with(|| {
@@ -281,6 +343,44 @@ fn multicurrency_precompile_should_work() {
 	});
 }
 
+#[test]
+fn multicurrency_precompile_transfer_should_not_log_mirrored_transfer() {
+	new_test_ext().execute_with(|| {
+		let mut context = Context {
+			address: Default::default(),
+			caller: Default::default(),
+			apparent_value: Default::default(),
+		};
+		let evm_log_count = || {
+			System::events()
+				.iter()
+				.filter(|record| matches!(record.event, TestEvent::ModuleEVM(module_evm::Event::Log(_))))
+				.count()
+		};
+
+		let mut input = [0u8; 4 + 4 * 32];
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(multicurrency::Action::Transfer).to_be_bytes());
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// to
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// amount
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		// the mirrored token contract emits the `Transfer` log itself
+		context.caller = aca_evm_address();
+		System::reset_events();
+		assert_ok!(MultiCurrencyPrecompile::execute(&input, None, &context));
+		assert_eq!(evm_log_count(), 0);
+
+		// transfers which don't start in the EVM are still logged
+		System::reset_events();
+		assert_ok!(Currencies::transfer(Origin::signed(alice()), bob(), ACA, 1));
+		assert_eq!(evm_log_count(), 1);
+	});
+}
+
 #[test]
 fn oracle_precompile_should_work() {
 	new_test_ext().execute_with(|| {
@@ -594,7 +694,12 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 fn dex_precompile_get_liquidity_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -634,11 +739,75 @@ fn dex_precompile_get_liquidity_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_get_time_weighted_average_price_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+		run_to_block(11);
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + currency_id_a + currency_id_b + window
+		let mut input = [0u8; 5 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(dex::Action::GetTimeWeightedAveragePrice).to_be_bytes());
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// window
+		U256::from(10).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(Price::saturating_from_integer(1_000).into_inner()).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// the history before the liquidity is added is unavailable
+		U256::from(11).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		assert_noop!(
+			DexPrecompile::execute(&input, None, &context),
+			ExitError::Other("Dex get_time_weighted_average_price failed".into())
+		);
+	});
+}
+
 #[test]
 fn dex_precompile_get_liquidity_token_address_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -692,7 +861,12 @@ fn dex_precompile_get_liquidity_token_address_should_work() {
 fn dex_precompile_get_swap_target_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -741,7 +915,12 @@ fn dex_precompile_get_swap_target_amount_should_work() {
 fn dex_precompile_get_swap_supply_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -790,7 +969,12 @@ fn dex_precompile_get_swap_supply_amount_should_work() {
 fn dex_precompile_swap_with_exact_supply_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -843,7 +1027,12 @@ fn dex_precompile_swap_with_exact_supply_should_work() {
 fn dex_precompile_swap_with_exact_target_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -892,6 +1081,472 @@ fn dex_precompile_swap_with_exact_target_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_flash_swap_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		let callback_account =
+			<Test as module_evm::Config>::AddressMapping::get_account_id(&flash_swap_callback_evm_addr());
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			callback_account.clone(),
+			RENBTC,
+			1_000
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + who + target_amount + max_supply_amount + path_len +
+		// currency_id_a + currency_id_b + callback_input_len
+		let mut input = [0u8; 11 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(dex::Action::FlashSwap).to_be_bytes());
+		// who
+		U256::from(flash_swap_callback_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// skip offset
+		// target_amount
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// max_supply_amount
+		U256::from(1).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+		// skip offset
+		// path_len
+		U256::from(2).to_big_endian(&mut input[4 + 6 * 32..4 + 7 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 7 * 32..4 + 8 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 8 * 32..4 + 9 * 32]);
+		// callback_input_len
+		U256::from(0).to_big_endian(&mut input[4 + 9 * 32..4 + 10 * 32]);
+
+		// not enough gas for the swap
+		assert_eq!(
+			DexPrecompile::execute(&input, Some(dex::FLASH_SWAP_GAS_COST - 1), &context),
+			Err(ExitError::OutOfGas)
+		);
+		// not enough gas for the callback contract
+		assert_eq!(
+			DexPrecompile::execute(&input, Some(dex::FLASH_SWAP_GAS_COST + 1_000), &context),
+			Err(ExitError::Other("OutOfGas".into()))
+		);
+		assert_eq!(Currencies::free_balance(RENBTC, &callback_account), 1_000);
+		assert_eq!(Currencies::free_balance(AUSD, &callback_account), 0);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(1).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) =
+			DexPrecompile::execute(&input, Some(dex::FLASH_SWAP_GAS_COST + 100_000), &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		// the callback costs the base transaction cost at least
+		assert!(used_gas > dex::FLASH_SWAP_GAS_COST + 21_000);
+		assert!(used_gas < dex::FLASH_SWAP_GAS_COST + 100_000);
+		assert_eq!(Currencies::free_balance(RENBTC, &callback_account), 999);
+		assert_eq!(Currencies::free_balance(AUSD, &callback_account), 1);
+	});
+}
+
+#[test]
+fn honzon_precompile_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CDPEngine::set_collateral_params(
+			Origin::signed(ALICE),
+			RENBTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + who + currency_id + collateral_adjustment +
+		// debit_adjustment
+		let mut input = [0u8; 6 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(honzon::Action::AdjustLoan).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// collateral_adjustment
+		U256::from(100).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// debit_adjustment
+		U256::from(50).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		assert_noop!(
+			HonzonPrecompile::execute(&input, Some(honzon::ADJUST_LOAN_GAS_COST - 1), &context),
+			ExitError::OutOfGas
+		);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, Vec::<u8>::new());
+		assert_eq!(used_gas, honzon::ADJUST_LOAN_GAS_COST);
+
+		// array_size + action + who + currency_id
+		let mut input = [0u8; 4 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(honzon::Action::GetPosition).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let mut expected_output = [0u8; 64];
+		U256::from(100).to_big_endian(&mut expected_output[..32]);
+		U256::from(50).to_big_endian(&mut expected_output[32..64]);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// collateral ratio
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(honzon::Action::GetCollateralRatio).to_be_bytes());
+
+		let mut expected_output = [0u8; 32];
+		U256::from(Ratio::saturating_from_rational(2, 1).into_inner()).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// array_size + action + currency_id
+		let mut input = [0u8; 3 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(honzon::Action::GetLiquidationRatio).to_be_bytes());
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(Ratio::saturating_from_rational(3, 2).into_inner()).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// debit exchange rate
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(honzon::Action::GetDebitExchangeRate).to_be_bytes());
+
+		let mut expected_output = [0u8; 32];
+		U256::from(Ratio::saturating_from_integer(1).into_inner()).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+	});
+}
+
+#[test]
+fn honzon_precompile_close_loan_by_dex_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CDPEngine::set_collateral_params(
+			Origin::signed(ALICE),
+			RENBTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			PoolKind::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		assert_ok!(Honzon::adjust_loan(Origin::signed(alice()), RENBTC, 100, 50));
+		assert_eq!(Loans::positions(RENBTC, alice()).collateral, 100);
+		assert_eq!(Loans::positions(RENBTC, alice()).debit, 50);
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + who + currency_id + max_collateral_amount
+		let mut input = [0u8; 5 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(honzon::Action::CloseLoanByDex).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// max_collateral_amount
+		U256::from(100).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		assert_noop!(
+			HonzonPrecompile::execute(&input, Some(honzon::CLOSE_LOAN_BY_DEX_GAS_COST - 1), &context),
+			ExitError::OutOfGas
+		);
+
+		let (reason, output, used_gas) = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, Vec::<u8>::new());
+		assert_eq!(used_gas, honzon::CLOSE_LOAN_BY_DEX_GAS_COST);
+
+		assert_eq!(Loans::positions(RENBTC, alice()).collateral, 0);
+		assert_eq!(Loans::positions(RENBTC, alice()).debit, 0);
+	});
+}
+
+#[test]
+fn incentives_precompile_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::update_balance(Origin::root(), alice(), LP_ACA_AUSD, 1_000));
+
+		let context = Context {
+			address: H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 7),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + who + lp_currency_id + amount
+		let mut input = [0u8; 5 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(incentives::Action::DepositDexShare).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// LP_ACA_AUSD
+		U256::from_big_endian(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let deposit_gas_cost = <() as IncentivesWeightInfo>::deposit_dex_share() / WEIGHT_PER_GAS;
+		assert_noop!(
+			IncentivesPrecompile::execute(&input, Some(deposit_gas_cost - 1), &context),
+			ExitError::OutOfGas
+		);
+
+		let (reason, output, used_gas) = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, Vec::<u8>::new());
+		assert_eq!(used_gas, deposit_gas_cost);
+		assert_eq!(Currencies::free_balance(LP_ACA_AUSD, &alice()), 900);
+
+		let mut data = [0u8; 32];
+		U256::from(100).to_big_endian(&mut data[..32]);
+		let event = TestEvent::ModuleEVM(module_evm::Event::Log(Log {
+			address: context.address,
+			topics: vec![
+				H256::from(hex!("13a832e21c0cdb2e207f6e6a0f315843471f20ae7e03b8a390482fe3623db8ef")),
+				H256::from(alice_evm_addr()),
+				H256::from(lp_aca_ausd_evm_address()),
+			],
+			data: data.to_vec(),
+		}));
+		assert!(System::events().iter().any(|record| record.event == event));
+
+		orml_rewards::Pallet::<Test>::accumulate_reward(&PoolId::DexIncentive(LP_ACA_AUSD), 50);
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			Incentives::account_id(),
+			ACA,
+			1_000
+		));
+
+		// array_size + action + who + pool_kind + pool_param + reward_currency_id
+		let mut input = [0u8; 6 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(incentives::Action::GetPendingRewards).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// DexIncentive
+		U256::from(1).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// LP_ACA_AUSD
+		U256::from_big_endian(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// ACA
+		U256::from_big_endian(aca_evm_address().as_bytes()).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(50).to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+
+		// claim rewards
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(incentives::Action::ClaimRewards).to_be_bytes());
+
+		// charged for the pending ACA rewards
+		let (reason, output, used_gas) = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, Vec::<u8>::new());
+		assert_eq!(used_gas, <() as IncentivesWeightInfo>::claim_rewards() / WEIGHT_PER_GAS);
+		assert_eq!(Balances::free_balance(alice()), INITIAL_BALANCE + 50);
+
+		// invalid pool kind
+		U256::from(5).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			IncentivesPrecompile::execute(&input, None, &context),
+			ExitError::Other("invalid pool kind".into())
+		);
+
+		// array_size + action + who + lp_currency_id + amount
+		let mut input = [0u8; 5 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32]
+			.copy_from_slice(&Into::<u32>::into(incentives::Action::WithdrawDexShare).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// LP_ACA_AUSD
+		U256::from_big_endian(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let (reason, output, used_gas) = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, Vec::<u8>::new());
+		assert_eq!(
+			used_gas,
+			<() as IncentivesWeightInfo>::withdraw_dex_share() / WEIGHT_PER_GAS
+		);
+		assert_eq!(Currencies::free_balance(LP_ACA_AUSD, &alice()), 1_000);
+	});
+}
+
+#[test]
+fn homa_precompile_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::update_balance(Origin::root(), alice(), DOT, 1_000));
+		assert_ok!(HomaLite::set_minting_cap(Origin::signed(ALICE), 10_000));
+
+		let context = Context {
+			address: H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 8),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// array_size + action + who + amount
+		let mut input = [0u8; 4 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(homa::Action::Mint).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(1_000).to_big_endian(&mut expected_output[..32]);
+
+		assert_noop!(
+			HomaPrecompile::execute(&input, Some(homa::MINT_GAS_COST - 1), &context),
+			ExitError::OutOfGas
+		);
+
+		let (reason, output, used_gas) = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, homa::MINT_GAS_COST);
+		assert_eq!(Currencies::free_balance(LDOT, &alice()), 1_000);
+
+		let mut data = [0u8; 64];
+		U256::from(100).to_big_endian(&mut data[..32]);
+		U256::from(1_000).to_big_endian(&mut data[32..64]);
+		let event = TestEvent::ModuleEVM(module_evm::Event::Log(Log {
+			address: context.address,
+			topics: vec![
+				H256::from(hex!("25b428dfde728ccfaddad7e29e4ac23c24ed7fd1a6e3e3f91894a9a073f5dfff")),
+				H256::from(alice_evm_addr()),
+			],
+			data: data.to_vec(),
+		}));
+		assert!(System::events().iter().any(|record| record.event == event));
+
+		// below minimum mint threshold
+		U256::from(10).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			HomaPrecompile::execute(&input, None, &context),
+			ExitError::Other("MintAmountBelowMinimumThreshold".into())
+		);
+
+		// array_size + action
+		let mut input = [0u8; 2 * 32];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		input[1 * 32..4 + 1 * 32].copy_from_slice(&Into::<u32>::into(homa::Action::GetExchangeRate).to_be_bytes());
+
+		let mut expected_output = [0u8; 32];
+		U256::from(ExchangeRate::saturating_from_rational(1, 10).into_inner())
+			.to_big_endian(&mut expected_output[..32]);
+
+		let (reason, output, used_gas) = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output);
+		assert_eq!(used_gas, 0);
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
@@ -19,6 +19,7 @@
 use crate::integration_tests::*;
 
 use frame_support::assert_ok;
+use module_dex::PoolKind;
 use module_evm_accounts::EvmAddressMapping;
 use module_support::CurrencyIdMapping;
 use module_support::{EVMBridge as EVMBridgeT, EVM as EVMTrait};
@@ -156,6 +157,7 @@ fn dex_module_works_with_evm_contract() {
 				100,
 				1000,
 				0,
+				PoolKind::ConstantProduct
 			));
 
 			<EVM as EVMTrait<AccountId>>::set_origin(MockAddressMapping::get_account_id(&alice_evm_addr()));
@@ -414,6 +416,7 @@ fn test_multicurrency_precompile_module() {
 				100,
 				1000,
 				0,
+				PoolKind::ConstantProduct
 			));
 
 			// CurrencyId::DexShare(Erc20, Erc20)
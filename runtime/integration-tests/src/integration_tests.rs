@@ -423,6 +423,7 @@ fn liquidate_cdp() {
 				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
 				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 
 			assert_ok!(CdpEngine::adjust_position(
@@ -466,6 +467,7 @@ fn liquidate_cdp() {
 				Change::NoChange,
 				Change::NewValue(Some(Ratio::saturating_from_rational(400, 100))),
 				Change::NoChange,
+				Change::NoChange,
 			));
 
 			assert_ok!(CdpEngine::liquidate_unsafe_cdp(
@@ -689,6 +691,7 @@ fn test_honzon_module() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 			assert_ok!(CdpEngine::adjust_position(
 				&AccountId::from(ALICE),
@@ -729,6 +732,7 @@ fn test_honzon_module() {
 				Change::NoChange,
 				Change::NoChange,
 				Change::NoChange,
+				Change::NoChange,
 			));
 			assert_ok!(CdpEngine::liquidate(
 				Origin::none(),
@@ -773,6 +777,7 @@ fn test_cdp_engine_module() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 
 			let new_collateral_params = CdpEngine::collateral_params(RELAY_CHAIN_CURRENCY);
@@ -57,7 +57,8 @@ use module_evm_accounts::EvmAddressMapping;
 use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use orml_traits::{
-	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProviderExtended, MultiCurrency,
+	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProvider, DataProviderExtended,
+	MultiCurrency,
 };
 use pallet_transaction_payment::RuntimeDispatchInfo;
 
@@ -104,9 +105,10 @@ pub use sp_runtime::{Perbill, Percent, Permill, Perquintill};
 pub use authority::AuthorityConfigImpl;
 pub use constants::{fee::*, parachains, time::*};
 pub use primitives::{
-	evm::EstimateResourcesRequest, AccountId, AccountIndex, Amount, AuctionId, AuthoritysOriginId, Balance,
-	BlockNumber, CurrencyId, DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share, Signature,
-	TokenSymbol, TradingPair,
+	evm::{EstimateResourcesRequest, TraceConfig, TraceInfo},
+	TraceConfig, TraceInfo}, AccountId, AccountIndex, Amount, AuctionId, AuthoritysOriginId, Balance, BlockNumber,
+	CurrencyId, DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share, Signature, TokenSymbol,
+	TradingPair,
 };
 pub use runtime_common::{
 	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
@@ -729,6 +731,19 @@ impl DataFeeder<CurrencyId, Price, AccountId> for AggregatedDataProvider {
 	}
 }
 
+pub struct OracleSources;
+impl DataProvider<(DataProviderId, CurrencyId), TimeStampedPrice> for OracleSources {
+	fn get(key: &(DataProviderId, CurrencyId)) -> Option<TimeStampedPrice> {
+		let (provider_id, currency_id) = key;
+		match provider_id {
+			DataProviderId::Acala => AcalaOracle::get_no_op(currency_id),
+			DataProviderId::Aggregated => {
+				<AggregatedDataProvider as DataProviderExtended<_, _>>::get_no_op(currency_id)
+			}
+		}
+	}
+}
+
 parameter_type_with_key! {
 	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
 		match currency_id {
@@ -792,16 +807,26 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxPriceSources: u32 = 8;
+	pub const PriceSnapshotInterval: BlockNumber = HOURS;
+	pub const MaxPriceSnapshots: u32 = 30 * 24;
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
+	type OracleSources = OracleSources;
+	type MaxPriceSources = MaxPriceSources;
+	type PriceSnapshotCurrencyIds = CollateralCurrencyIds;
+	type PriceSnapshotInterval = PriceSnapshotInterval;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
 	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type UnixTime = Timestamp;
 	type LiquidStakingExchangeRateProvider = module_homa_lite::LiquidExchangeProvider<Runtime>;
 	type DEX = Dex;
 	type Currency = Currencies;
@@ -816,6 +841,15 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = KSM;
 }
 
+pub struct TokensHeldCurrencyIds;
+impl module_support::HeldCurrencyIds<AccountId, CurrencyId> for TokensHeldCurrencyIds {
+	fn held_currency_ids(who: &AccountId) -> Vec<CurrencyId> {
+		orml_tokens::Accounts::<Runtime>::iter_prefix(who)
+			.map(|(currency_id, _)| currency_id)
+			.collect()
+	}
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -824,6 +858,7 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
+	type HeldCurrencyIds = TokensHeldCurrencyIds;
 }
 
 parameter_types! {
@@ -907,12 +942,14 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -992,11 +1029,15 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(8, 100);
 	pub MinimumDebitValue: Balance = 20 * dollar(KUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub MaxInterestRatePerSecChange: Rate = Rate::saturating_from_rational(1, 1_000_000_000_000u128);
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
+	type InterestRateModel = ();
+	type MaxInterestRatePerSecChange = MaxInterestRatePerSecChange;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1037,6 +1078,8 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 50;
+	pub const PriceObservationLimit: u32 = 1800;
 }
 
 impl module_dex::Config for Runtime {
@@ -1044,11 +1087,16 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type Call = Call;
+	type StableAsset = ();
 }
 
 parameter_types! {
@@ -1132,6 +1180,31 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxExtraRewardCurrencies: u32 = 4;
+	pub const MaxCampaigns: u32 = 20;
+	pub const MaxCampaignDuration: BlockNumber = 365 * DAYS;
+	pub CampaignDeposit: Balance = 10 * dollar(KAR);
+	pub const MaxBoostLockPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub const IncentivesBlocksPerYear: BlockNumber = 365 * DAYS;
+}
+
+parameter_type_with_key! {
+	pub IncentivesDebitExchangeRate: |currency_id: CurrencyId| -> ExchangeRate {
+		CdpEngine::get_debit_exchange_rate(*currency_id)
+	};
+	pub MinCampaignBudget: |currency_id: CurrencyId| -> Option<Balance> {
+		// only the whitelisted tokens are accepted as campaign rewards
+		match currency_id {
+			CurrencyId::Token(symbol) => match symbol {
+				TokenSymbol::KAR |
+				TokenSymbol::KUSD |
+				TokenSymbol::KSM |
+				TokenSymbol::LKSM => Some(100 * dollar(*currency_id)),
+				_ => None,
+			},
+			_ => None,
+		}
+	};
 }
 
 impl module_incentives::Config for Runtime {
@@ -1147,7 +1220,16 @@ impl module_incentives::Config for Runtime {
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type DebitExchangeRate = IncentivesDebitExchangeRate;
+	type BlocksPerYear = IncentivesBlocksPerYear;
 	type PalletId = IncentivesPalletId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	type MaxCampaigns = MaxCampaigns;
+	type MinCampaignBudget = MinCampaignBudget;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type CampaignDeposit = CampaignDeposit;
+	type MaxBoostLockPeriod = MaxBoostLockPeriod;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1272,7 +1354,7 @@ pub type MultiCurrencyPrecompile = runtime_common::MultiCurrencyPrecompile<
 	AccountId,
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
-	Currencies,
+	module_currencies::EvmMultiCurrency<Runtime>,
 >;
 
 pub type NFTPrecompile =
@@ -1297,7 +1379,19 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	Runtime,
 >;
 pub type DexPrecompile =
-	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex, EVM>;
+pub type HonzonPrecompile =
+	runtime_common::HonzonPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Honzon>;
+pub type IncentivesPrecompile = runtime_common::IncentivesPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	Incentives,
+	EVM,
+	weights::module_incentives::WeightInfo<Runtime>,
+>;
+pub type HomaPrecompile =
+	runtime_common::HomaPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, HomaLite, EVM>;
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -1315,6 +1409,9 @@ impl module_evm::Config for Runtime {
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -1847,7 +1944,11 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
-	OnRuntimeUpgrade,
+	(
+		OnRuntimeUpgrade,
+		module_loans::MigrateDebitPrincipals<Runtime>,
+		module_prices::PruneOutOfRangePriceSnapshots<Runtime>,
+	),
 >;
 
 #[cfg(not(feature = "disable-runtime-api"))]
@@ -1971,6 +2072,125 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_best_price_swap_path_with_exact_supply(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPath<Balance>> {
+			Dex::get_best_price_swap_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+				.map(|(path, amount)| module_dex_rpc_runtime_api::SwapPath { path, amount })
+		}
+
+		fn get_best_price_swap_path_with_exact_target(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPath<Balance>> {
+			Dex::get_best_price_swap_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+				.map(|(path, amount)| module_dex_rpc_runtime_api::SwapPath { path, amount })
+		}
+
+		fn get_time_weighted_average_price(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: u32,
+		) -> Option<ExchangeRate> {
+			Dex::get_time_weighted_average_price(currency_id_a, currency_id_b, window)
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_cdp_health(
+			who: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::CDPHealth<Balance>> {
+			CdpEngine::get_cdp_health(&who, currency_id)
+		}
+
+		fn get_cdp_healths(
+			who: AccountId,
+		) -> Vec<(CurrencyId, module_cdp_engine_rpc_runtime_api::CDPHealth<Balance>)> {
+			CollateralCurrencyIds::get()
+				.into_iter()
+				.filter_map(|currency_id| {
+					CdpEngine::get_cdp_health(&who, currency_id).map(|health| (currency_id, health))
+				})
+				.collect()
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<
+		Block,
+		BlockNumber,
+	> for Runtime {
+		fn get_price_snapshot_at(
+			currency_id: CurrencyId,
+			block_number: BlockNumber,
+		) -> Option<(BlockNumber, Price)> {
+			Prices::get_price_snapshot_at(currency_id, block_number)
+		}
+
+		fn get_price_snapshots(
+			currency_id: CurrencyId,
+		) -> Vec<(BlockNumber, Price)> {
+			Prices::get_price_snapshots(currency_id)
+		}
+
+		fn get_price_snapshot_aggregates(
+			currency_id: CurrencyId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<module_prices_rpc_runtime_api::PriceAggregates> {
+			Prices::get_price_snapshot_aggregates(currency_id, from, to).map(|aggregates| {
+				module_prices_rpc_runtime_api::PriceAggregates {
+					min: aggregates.min,
+					max: aggregates.max,
+					average: aggregates.average,
+					count: aggregates.count,
+				}
+			})
+		}
+	}
+
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId<AccountId>,
+		Balance,
+	> for Runtime {
+		fn get_pending_rewards(
+			pool_id: module_incentives::PoolId<AccountId>,
+			who: AccountId,
+		) -> Vec<module_incentives_rpc_runtime_api::RewardBalance<Balance>> {
+			Incentives::pending_rewards_of(&pool_id, &who)
+				.into_iter()
+				.map(|(currency_id, amount)| module_incentives_rpc_runtime_api::RewardBalance { currency_id, amount })
+				.collect()
+		}
+
+		fn get_pool_emission(
+			pool_id: module_incentives::PoolId<AccountId>,
+		) -> module_incentives_rpc_runtime_api::PoolEmission<Balance> {
+			module_incentives_rpc_runtime_api::PoolEmission {
+				rewards_per_period: Incentives::pool_emission_per_period(&pool_id)
+					.into_iter()
+					.map(|(currency_id, amount)| {
+						module_incentives_rpc_runtime_api::RewardBalance { currency_id, amount }
+					})
+					.collect(),
+				apr: Incentives::pool_apr(&pool_id),
+			}
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2073,6 +2293,65 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			module_evm::Runner::<Runtime>::trace_call(
+				from,
+				from,
+				to,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				<Runtime as module_evm::Config>::config(),
+				trace_config,
+			)
+		}
+
+		fn trace_create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			module_evm::Runner::<Runtime>::trace_create(
+				from,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				<Runtime as module_evm::Config>::config(),
+				trace_config,
+			)
+		}
+
+		fn trace_extrinsic(
+			extrinsic: Vec<u8>,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			let utx = UncheckedExtrinsic::decode(&mut &*extrinsic)
+				.map_err(|_| sp_runtime::DispatchError::Other("Invalid parameter extrinsic, decode failed"))?;
+
+			let (result, trace_info) = module_evm::Runner::<Runtime>::trace(trace_config, || {
+				Executive::apply_extrinsic(utx)
+			});
+			result.map_err(|_| sp_runtime::DispatchError::Other("Invalid parameter extrinsic, apply failed"))?;
+			trace_info.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, no evm execution"))
+		}
+
+		fn block_gas_limit() -> u64 {
+			runtime_common::BlockGasLimit::get()
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -84,36 +84,77 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	}
 	fn add_liquidity() -> Weight {
 		(201_455_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(9 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 	fn add_liquidity_and_stake() -> Weight {
 		(298_711_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(14 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
 	}
 	fn remove_liquidity() -> Weight {
 		(172_232_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		(333_489_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(14 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
 	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(135_046_000 as Weight)
 			// Standard Error: 254_000
 			.saturating_add((932_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(136_734_000 as Weight)
 			// Standard Error: 162_000
 			.saturating_add((383_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_supply_by_best_path(u: u32, p: u32, ) -> Weight {
+		(150_251_000 as Weight)
+			.saturating_add((24_108_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_path(u: u32, p: u32, ) -> Weight {
+		(151_012_000 as Weight)
+			.saturating_add((24_362_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((12_431_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		(162_471_000 as Weight)
+			.saturating_add((702_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_344_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_share() -> Weight {
+		(14_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
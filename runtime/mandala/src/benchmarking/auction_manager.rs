@@ -18,16 +18,17 @@
 
 use crate::{
 	dollar, AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, CurrencyId, EmergencyShutdown,
-	GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime,
+	GetStableCurrencyId, GetStakingCurrencyId, Price, Rate, Ratio, Runtime, System,
 };
 
 use super::utils::feed_price;
 use frame_benchmarking::account;
 use frame_system::RawOrigin;
+use module_auction_manager::{DutchAuctionParameters, PriceDecayCurve};
 use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{traits::Bounded, FixedPointNumber};
 use sp_std::vec;
 
 const SEED: u32 = 0;
@@ -62,6 +63,60 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	// `buy_collateral` from a dutch collateral auction, worst case:
+	// target reached and remain collateral refunded
+	buy_collateral {
+		let buyer: AccountId = account("buyer", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &buyer, 200 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// create dutch collateral auction
+		AuctionManager::set_dutch_auction_params(RawOrigin::Root.into(), STAKING, Some(DutchAuctionParameters {
+			start_premium: Rate::saturating_from_rational(20, 100),
+			floor_ratio: Ratio::saturating_from_rational(80, 100),
+			curve: PriceDecayCurve::Linear { duration: 100 },
+		}))?;
+		AuctionManager::new_dutch_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+	}: _(RawOrigin::Signed(buyer), auction_id, dollar(STAKING), Price::max_value())
+
+	set_dutch_auction_params {
+	}: _(RawOrigin::Root, STAKING, Some(DutchAuctionParameters {
+		start_premium: Rate::saturating_from_rational(20, 100),
+		floor_ratio: Ratio::saturating_from_rational(80, 100),
+		curve: PriceDecayCurve::Linear { duration: 100 },
+	}))
+
+	// restart a dutch collateral auction whose price has decayed to the floor price
+	reset_dutch_collateral_auction {
+		let caller: AccountId = account("caller", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// create dutch collateral auction
+		AuctionManager::set_dutch_auction_params(RawOrigin::Root.into(), STAKING, Some(DutchAuctionParameters {
+			start_premium: Rate::saturating_from_rational(20, 100),
+			floor_ratio: Ratio::saturating_from_rational(80, 100),
+			curve: PriceDecayCurve::Linear { duration: 100 },
+		}))?;
+		AuctionManager::new_dutch_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+		System::set_block_number(System::block_number() + 100);
+	}: _(RawOrigin::Signed(caller), auction_id)
 }
 
 #[cfg(test)]
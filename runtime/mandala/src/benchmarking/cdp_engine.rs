@@ -27,6 +27,8 @@ use core::convert::TryInto;
 use frame_benchmarking::account;
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_cdp_engine::LiquidationStrategy;
+use module_dex::PoolKind;
 use module_support::DEXManager;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
@@ -53,7 +55,12 @@ fn inject_liquidity(
 	set_balance(currency_id, &maker, max_other_currency_amount.unique_saturated_into());
 	set_balance(base_currency_id, &maker, max_amount.unique_saturated_into());
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, base_currency_id);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id,
+		base_currency_id,
+		PoolKind::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -106,6 +113,7 @@ runtime_benchmarks! {
 				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(0, 100))),
 				Change::NewValue(min_debit_value * 100),
+				Change::NoChange,
 			)?;
 
 			// adjust position
@@ -135,7 +143,8 @@ runtime_benchmarks! {
 		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 		Change::NewValue(Some(Ratio::saturating_from_rational(180, 100))),
-		Change::NewValue(100_000 * dollar(STABLECOIN))
+		Change::NewValue(100_000 * dollar(STABLECOIN)),
+		Change::NewValue(Some(Ratio::saturating_from_rational(50, 100)))
 	)
 
 	set_global_params {
@@ -168,6 +177,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -182,6 +192,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 
@@ -219,6 +230,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -233,6 +245,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 	verify {
@@ -267,6 +280,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -275,6 +289,136 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, STAKING, owner_lookup)
+
+	set_multi_collateral_params {
+	}: _(
+		RawOrigin::Root,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 1000000))),
+		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+		Change::NewValue(100_000 * dollar(STABLECOIN))
+	)
+
+	liquidate_multi_collateral {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let currency_ids = CollateralCurrencyIds::get();
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_multi_collateral_debit_exchange_rate();
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		let mut feed_data: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &owner, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// set risk params
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(min_debit_value * 100),
+				Change::NoChange,
+			)?;
+
+			collateral_adjustments.push((currency_id, collateral_amount.try_into().unwrap()));
+			feed_data.push((currency_id, Price::one()));
+		}
+
+		// feed price
+		feed_price(feed_data)?;
+
+		// set multi-collateral risk params
+		CdpEngine::set_multi_collateral_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+
+		// adjust position
+		CdpEngine::adjust_multi_collateral_position(&owner, &collateral_adjustments, min_debit_amount)?;
+
+		// modify liquidation rate to make the cdp unsafe
+		for i in 0 .. c {
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_ids[i as usize],
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+			)?;
+		}
+	}: _(RawOrigin::None, owner_lookup)
+
+	settle_multi_collateral {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let currency_ids = CollateralCurrencyIds::get();
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_multi_collateral_debit_exchange_rate();
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		let mut feed_data: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &owner, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// set risk params
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(min_debit_value * 100),
+				Change::NoChange,
+			)?;
+
+			collateral_adjustments.push((currency_id, collateral_amount.try_into().unwrap()));
+			feed_data.push((currency_id, Price::one()));
+		}
+
+		// feed price
+		feed_price(feed_data)?;
+
+		// set multi-collateral risk params
+		CdpEngine::set_multi_collateral_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+
+		// adjust position
+		CdpEngine::adjust_multi_collateral_position(&owner, &collateral_adjustments, min_debit_amount)?;
+
+		// shutdown
+		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+	}: _(RawOrigin::None, owner_lookup)
+
+	set_liquidation_strategy {
+	}: _(RawOrigin::Root, STAKING, LiquidationStrategy::DutchAuction)
 }
 
 #[cfg(test)]
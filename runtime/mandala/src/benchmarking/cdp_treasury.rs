@@ -16,14 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{dollar, CdpTreasury, Currencies, CurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Runtime};
+use crate::{
+	dollar, Balance, CdpTreasury, Currencies, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId,
+	GetStakingCurrencyId, MaxAuctionsCount, Runtime,
+};
 
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::MultiCurrency;
+use orml_traits::{Change, MultiCurrency};
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 
 runtime_benchmarks! {
@@ -36,9 +41,34 @@ runtime_benchmarks! {
 	set_expected_collateral_auction_size {
 	}: _(RawOrigin::Root, STAKING, 200 * dollar(STAKING))
 
+	set_debit_and_surplus_handle_params {
+	}: _(
+		RawOrigin::Root,
+		Change::NewValue(100 * dollar(STABLECOIN)),
+		Change::NewValue(1_000 * dollar(STABLECOIN)),
+		Change::NewValue(1_000 * dollar(STABLECOIN)),
+		Change::NewValue(1_000 * dollar(NATIVE))
+	)
+
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
+
+	// create `c` debit auctions on block begin
+	on_initialize {
+		let c in 1 .. MaxAuctionsCount::get();
+
+		CdpTreasury::set_debit_and_surplus_handle_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(1_000 * dollar(STABLECOIN)),
+			Change::NewValue(1_000 * dollar(NATIVE)),
+		)?;
+		CdpTreasury::on_system_debit(c as Balance * 1_000 * dollar(STABLECOIN))?;
+	}: {
+		CdpTreasury::on_initialize(1);
+	}
 }
 
 #[cfg(test)]
@@ -17,16 +17,16 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId, Runtime,
-	TradingPathLimit,
+	dollar, AccountId, Balance, Call, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId,
+	MaxTradingPairs, Runtime, System, TradingPathLimit,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{PoolKind, TradingPairStatus, TradingPairStatuses};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrencyExtended;
-use primitives::TradingPair;
+use primitives::{evm::EvmAddress, TokenSymbol, TradingPair};
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::prelude::*;
 
@@ -55,7 +55,12 @@ fn inject_liquidity(
 		max_amount_b.unique_saturated_into(),
 	)?;
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		PoolKind::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -67,9 +72,56 @@ fn inject_liquidity(
 		deposit,
 	)?;
 
+	// move to the next block, so that the following liquidity change records a new price observation
+	System::set_block_number(System::block_number() + 1);
+
+	Ok(())
+}
+
+// turn on the protocol fee, so that the liquidity changes record the product of the pool.
+fn enable_protocol_fee() -> Result<(), &'static str> {
+	Dex::set_protocol_fee_share(RawOrigin::Root.into(), Some((1, 6)))?;
+	Ok(())
+}
+
+// swap through the trading pair to accrue the exchange fee, so that the following liquidity change
+// mints the protocol fee.
+fn accrue_exchange_fee(trading_pair: TradingPair) -> Result<(), &'static str> {
+	let trader: AccountId = account("trader", 0, SEED);
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		trading_pair.first(),
+		&trader,
+		(100 * dollar(trading_pair.first())).unique_saturated_into(),
+	)?;
+	Dex::swap_with_exact_supply(
+		RawOrigin::Signed(trader).into(),
+		vec![trading_pair.first(), trading_pair.second()],
+		10 * dollar(trading_pair.first()),
+		Default::default(),
+	)?;
+
+	// move to the next block, so that the following liquidity change records a new price observation
+	System::set_block_number(System::block_number() + 1);
+
 	Ok(())
 }
 
+// enable trading pairs without liquidity between `STABLECOIN`, `NATIVE` and
+// erc20 currencies until there're `count` trading pairs, each of them is
+// searched but not routable for the best trading path.
+fn enable_unroutable_trading_pairs(count: u32) {
+	let mut existing = TradingPairStatuses::<Runtime>::iter().count() as u32;
+	let mut i: u64 = 1;
+	while existing < count {
+		let erc20 = CurrencyId::Erc20(EvmAddress::from_low_u64_be(i));
+		let currency_id = if i % 2 == 0 { NATIVE } else { STABLECOIN };
+		let trading_pair = TradingPair::from_currency_ids(currency_id, erc20).unwrap();
+		TradingPairStatuses::<Runtime>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+		existing += 1;
+		i += 1;
+	}
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_dex }
 
@@ -79,13 +131,18 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), PoolKind::ConstantProduct)
 
 	// disable a Enabled trading pair
 	disable_trading_pair {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
 		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
-			Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+			Dex::enable_trading_pair(
+				RawOrigin::Root.into(),
+				trading_pair.first(),
+				trading_pair.second(),
+				PoolKind::ConstantProduct,
+			)?;
 		}
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
 
@@ -95,7 +152,7 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10)
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10, PoolKind::ConstantProduct)
 
 	// update parameters of a Provisioning trading pair
 	update_provisioning_parameters {
@@ -111,7 +168,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			100
+			100,
+			PoolKind::ConstantProduct,
 		)?;
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), 2 * dollar(trading_pair.first()), 2 * dollar(trading_pair.second()), 10 * dollar(trading_pair.first()), 100 * dollar(trading_pair.second()), 200)
 
@@ -130,7 +188,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			100 * dollar(trading_pair.second()),
-			0
+			0,
+			PoolKind::ConstantProduct,
 		)?;
 
 		// set balance
@@ -161,7 +220,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			0
+			0,
+			PoolKind::ConstantProduct,
 		)?;
 
 		// set balance
@@ -183,7 +243,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			10 * dollar(trading_pair.first()),
 			10 * dollar(trading_pair.second()),
-			0
+			0,
+			PoolKind::ConstantProduct,
 		)?;
 
 		// set balance
@@ -204,7 +265,7 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(whitelisted_caller()), founder, trading_pair.first(), trading_pair.second())
 
-	// add liquidity but don't staking lp
+	// worst: add liquidity which mints the protocol fee, but don't staking lp
 	add_liquidity {
 		let first_maker: AccountId = account("first_maker", 0, SEED);
 		let second_maker: AccountId = whitelisted_caller();
@@ -217,10 +278,12 @@ runtime_benchmarks! {
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &second_maker, amount_b.unique_saturated_into())?;
 
 		// first maker inject liquidity
+		enable_protocol_fee()?;
 		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, false)?;
+		accrue_exchange_fee(trading_pair)?;
 	}: add_liquidity(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, amount_b, Default::default(), false)
 
-	// worst: add liquidity and stake lp
+	// worst: add liquidity which mints the protocol fee, and stake lp
 	add_liquidity_and_stake {
 		let first_maker: AccountId = account("first_maker", 0, SEED);
 		let second_maker: AccountId = whitelisted_caller();
@@ -233,21 +296,27 @@ runtime_benchmarks! {
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &second_maker, amount_b.unique_saturated_into())?;
 
 		// first maker inject liquidity
+		enable_protocol_fee()?;
 		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, true)?;
+		accrue_exchange_fee(trading_pair)?;
 	}: add_liquidity(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, amount_b, Default::default(), true)
 
-	// remove liquidity by liquid lp share
+	// remove liquidity by liquid lp share, which mints the protocol fee
 	remove_liquidity {
 		let maker: AccountId = whitelisted_caller();
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		enable_protocol_fee()?;
 		inject_liquidity(maker.clone(), trading_pair.first(), trading_pair.second(), 100 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), false)?;
+		accrue_exchange_fee(trading_pair)?;
 	}: remove_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 50 * dollar(trading_pair.first()), Default::default(), Default::default(), false)
 
-	// remove liquidity by withdraw staking lp share
+	// remove liquidity by withdraw staking lp share, which mints the protocol fee
 	remove_liquidity_by_unstake {
 		let maker: AccountId = whitelisted_caller();
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		enable_protocol_fee()?;
 		inject_liquidity(maker.clone(), trading_pair.first(), trading_pair.second(), 100 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), true)?;
+		accrue_exchange_fee(trading_pair)?;
 	}: remove_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 50 * dollar(trading_pair.first()), Default::default(), Default::default(), true)
 
 	swap_with_exact_supply {
@@ -299,6 +368,105 @@ runtime_benchmarks! {
 
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
 	}: swap_with_exact_target(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]))
+
+	// worst: the best path goes through u - 2 intermediate currencies, and p
+	// trading pairs are searched
+	swap_with_exact_supply_by_best_path {
+		let u in 2 .. TradingPathLimit::get() as u32;
+		let p in 4 .. MaxTradingPairs::get() as u32;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let intermediates: Vec<CurrencyId> = vec![
+			CurrencyId::Token(TokenSymbol::DOT),
+			CurrencyId::Token(TokenSymbol::LDOT),
+			CurrencyId::Token(TokenSymbol::RENBTC),
+		];
+		let mut path: Vec<CurrencyId> = vec![STABLECOIN];
+		path.extend(intermediates.into_iter().take(u as usize - 2));
+		path.push(NATIVE);
+
+		inject_liquidity(maker.clone(), STABLECOIN, NATIVE, 10_000 * dollar(STABLECOIN), 10 * dollar(NATIVE), false)?;
+		for i in 1 .. path.len() {
+			if (path[i - 1], path[i]) != (STABLECOIN, NATIVE) {
+				inject_liquidity(maker.clone(), path[i - 1], path[i], 10_000 * dollar(path[i - 1]), 10_000 * dollar(path[i]), false)?;
+			}
+		}
+		enable_unroutable_trading_pairs(p);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(taker), STABLECOIN, NATIVE, 100 * dollar(STABLECOIN), 0)
+
+	// worst: the best path goes through u - 2 intermediate currencies, and p
+	// trading pairs are searched
+	swap_with_exact_target_by_best_path {
+		let u in 2 .. TradingPathLimit::get() as u32;
+		let p in 4 .. MaxTradingPairs::get() as u32;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let intermediates: Vec<CurrencyId> = vec![
+			CurrencyId::Token(TokenSymbol::DOT),
+			CurrencyId::Token(TokenSymbol::LDOT),
+			CurrencyId::Token(TokenSymbol::RENBTC),
+		];
+		let mut path: Vec<CurrencyId> = vec![STABLECOIN];
+		path.extend(intermediates.into_iter().take(u as usize - 2));
+		path.push(NATIVE);
+
+		inject_liquidity(maker.clone(), STABLECOIN, NATIVE, 10_000 * dollar(STABLECOIN), 10 * dollar(NATIVE), false)?;
+		for i in 1 .. path.len() {
+			if (path[i - 1], path[i]) != (STABLECOIN, NATIVE) {
+				inject_liquidity(maker.clone(), path[i - 1], path[i], 10_000 * dollar(path[i - 1]), 10_000 * dollar(path[i]), false)?;
+			}
+		}
+		enable_unroutable_trading_pairs(p);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(taker), STABLECOIN, NATIVE, 10 * dollar(NATIVE), 1_000 * dollar(STABLECOIN))
+
+	flash_swap {
+		let u in 2 .. TradingPathLimit::get() as u32;
+
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let mut path: Vec<CurrencyId> = vec![];
+		for i in 1 .. u {
+			if i == 1 {
+				path.push(trading_pair.first());
+				path.push(trading_pair.second());
+			} else {
+				if i % 2 == 0 {
+					path.push(trading_pair.first());
+				} else {
+					path.push(trading_pair.second());
+				}
+			}
+		}
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, trading_pair.first(), trading_pair.second(), 10_000 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), false)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
+		let callback: Call = frame_system::Call::<Runtime>::remark(vec![]).into();
+	}: _(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]), Box::new(callback))
+
+	// set the exchange fee rate of a trading pair
+	set_exchange_fee {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::enable_trading_pair(
+				RawOrigin::Root.into(),
+				trading_pair.first(),
+				trading_pair.second(),
+				PoolKind::ConstantProduct,
+			)?;
+		}
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((1, 100)))
+
+	// set the protocol fee share
+	set_protocol_fee_share {
+	}: _(RawOrigin::Root, Some((1, 6)))
 }
 
 #[cfg(test)]
@@ -0,0 +1,142 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, DexOrders, GetNativeCurrencyId, GetStableCurrencyId,
+	Price, Runtime, System, TradingPathLimit,
+};
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use module_dex::PoolKind;
+use module_dex_orders::OrderKind;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrencyExtended;
+use primitives::TokenSymbol;
+use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+
+fn inject_liquidity(
+	maker: AccountId,
+	currency_id_a: CurrencyId,
+	currency_id_b: CurrencyId,
+	max_amount_a: Balance,
+	max_amount_b: Balance,
+) -> Result<(), &'static str> {
+	// set balance
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		currency_id_a,
+		&maker,
+		max_amount_a.unique_saturated_into(),
+	)?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		currency_id_b,
+		&maker,
+		max_amount_b.unique_saturated_into(),
+	)?;
+
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		PoolKind::ConstantProduct,
+	);
+
+	Dex::add_liquidity(
+		RawOrigin::Signed(maker).into(),
+		currency_id_a,
+		currency_id_b,
+		max_amount_a,
+		max_amount_b,
+		Default::default(),
+		false,
+	)?;
+
+	Ok(())
+}
+
+fn place_stop_loss_order(owner: AccountId, path: Vec<CurrencyId>) -> Result<u64, &'static str> {
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		NATIVE,
+		&owner,
+		(10 * dollar(NATIVE)).unique_saturated_into(),
+	)?;
+	let order_id = DexOrders::next_order_id();
+	DexOrders::place_order(
+		RawOrigin::Signed(owner).into(),
+		OrderKind::StopLoss,
+		path,
+		dollar(NATIVE),
+		Price::saturating_from_integer(1_000_000),
+		0,
+		100,
+	)?;
+
+	Ok(order_id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_dex_orders }
+
+	place_order {
+		let owner: AccountId = whitelisted_caller();
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &owner, (10 * dollar(NATIVE)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(owner), OrderKind::Limit, vec![NATIVE, DOT, STABLECOIN], dollar(NATIVE), Price::saturating_from_integer(10), dollar(STABLECOIN), 100)
+
+	cancel_order {
+		let owner: AccountId = whitelisted_caller();
+		let order_id = place_stop_loss_order(owner.clone(), vec![NATIVE, DOT, STABLECOIN])?;
+	}: _(RawOrigin::Signed(owner), order_id)
+
+	// worst: the order is swapped through u - 2 intermediate currencies
+	execute_order {
+		let u in 2 .. TradingPathLimit::get() as u32;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let owner: AccountId = whitelisted_caller();
+		let mut path: Vec<CurrencyId> = vec![NATIVE];
+		path.extend(vec![DOT, LDOT].into_iter().take(u as usize - 2));
+		path.push(STABLECOIN);
+		for i in 1 .. path.len() {
+			inject_liquidity(maker.clone(), path[i - 1], path[i], 10_000 * dollar(path[i - 1]), 10_000 * dollar(path[i]))?;
+		}
+		let order_id = place_stop_loss_order(owner, path)?;
+	}: _(RawOrigin::None, order_id)
+
+	expire_order {
+		let owner: AccountId = whitelisted_caller();
+		let order_id = place_stop_loss_order(owner, vec![NATIVE, DOT, STABLECOIN])?;
+		System::set_block_number(101);
+	}: _(RawOrigin::None, order_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -26,7 +26,7 @@ use super::utils::{feed_price, set_balance};
 use core::convert::TryInto;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{PoolKind, TradingPairStatus};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
 use primitives::TradingPair;
@@ -115,9 +115,58 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 	}: _(RawOrigin::Signed(caller), currency_id, collateral_amount.try_into().unwrap(), debit_amount)
 
+	adjust_multi_collateral_loan {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+
+		let caller: AccountId = whitelisted_caller();
+		let currency_ids = CollateralCurrencyIds::get();
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_multi_collateral_debit_exchange_rate();
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		let mut prices: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &caller, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// set risk params
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(debit_value * 100),
+				Change::NoChange,
+			)?;
+
+			collateral_adjustments.push((currency_id, collateral_amount.try_into().unwrap()));
+			prices.push((currency_id, Price::one()));
+		}
+
+		// feed price
+		feed_price(prices)?;
+
+		// set multi-collateral risk params
+		CdpEngine::set_multi_collateral_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+	}: _(RawOrigin::Signed(caller), collateral_adjustments, debit_amount)
+
 	transfer_loan_from {
 		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
 		let sender: AccountId = account("sender", 0, SEED);
@@ -148,6 +197,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -195,9 +245,9 @@ runtime_benchmarks! {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN)?;
 		}
 		// inject liquidity
-		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, STABLECOIN)?;
-		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, NATIVE)?;
-		Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, STABLECOIN, PoolKind::ConstantProduct)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, NATIVE, PoolKind::ConstantProduct)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN, PoolKind::ConstantProduct)?;
 		Dex::add_liquidity(
 			RawOrigin::Signed(maker.clone()).into(),
 			currency_id,
@@ -248,6 +298,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
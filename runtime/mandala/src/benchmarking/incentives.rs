@@ -18,17 +18,19 @@
 
 use crate::{
 	dollar, AccountId, AccumulatePeriod, CollateralCurrencyIds, Currencies, CurrencyId, GetLiquidCurrencyId,
-	GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Incentives, Rate, Rewards, Runtime, System,
+	GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Incentives, MaxBoostLockPeriod, Rate, Rewards,
+	Runtime, System,
 };
 
 use super::utils::set_balance;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
-use module_incentives::PoolId;
+use module_incentives::{BoostConfig, EmissionCurve, PoolId};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use primitives::DexShare;
+use sp_runtime::{traits::One, FixedPointNumber};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -130,6 +132,109 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Root, updates)
 
+	update_extra_incentive_rewards {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), STABLECOIN, 100 * dollar(STABLECOIN)));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	fund_extra_rewards {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		Incentives::update_extra_incentive_rewards(
+			RawOrigin::Root.into(),
+			vec![(pool_id.clone(), STABLECOIN, 100 * dollar(STABLECOIN))]
+		)?;
+	}: _(RawOrigin::Signed(caller), pool_id, STABLECOIN, 1_000 * dollar(STABLECOIN))
+
+	create_campaign {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		let start = System::block_number() + 1;
+	}: _(RawOrigin::Signed(caller), pool_id, STABLECOIN, 1_000 * dollar(STABLECOIN), start, start + 100, EmissionCurve::LinearDecay)
+
+	close_campaign {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		let start = System::block_number() + 1;
+		Incentives::create_campaign(
+			RawOrigin::Signed(caller.clone()).into(),
+			pool_id,
+			STABLECOIN,
+			1_000 * dollar(STABLECOIN),
+			start,
+			start + 100,
+			EmissionCurve::LinearDecay,
+		)?;
+	}: _(RawOrigin::Root, 0)
+
+	update_boost_configs {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), Some(BoostConfig {
+				max_multiplier: Rate::saturating_from_rational(25, 10),
+				escrow_per_share: Rate::one(),
+			})));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	update_reward_vesting_periods {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((PoolId::LoansIncentive(currency_id), AccumulatePeriod::get()));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	lock_for_boost {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		Incentives::lock_for_boost(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(NATIVE), 100)?;
+	}: _(RawOrigin::Signed(caller), 1_000 * dollar(NATIVE), MaxBoostLockPeriod::get())
+
+	unlock_boost {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		Incentives::update_boost_configs(RawOrigin::Root.into(), vec![(pool_id.clone(), Some(BoostConfig {
+			max_multiplier: Rate::saturating_from_rational(25, 10),
+			escrow_per_share: Rate::one(),
+		}))])?;
+		Incentives::lock_for_boost(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(NATIVE), 100)?;
+		Rewards::add_share(&caller, &pool_id, 100);
+		Incentives::refresh_boost(RawOrigin::Signed(caller.clone()).into(), caller.clone(), pool_id)?;
+		System::set_block_number(System::block_number() + 100);
+	}: _(RawOrigin::Signed(caller))
+
+	refresh_boost {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::LoansIncentive(STAKING);
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		Incentives::update_boost_configs(RawOrigin::Root.into(), vec![(pool_id.clone(), Some(BoostConfig {
+			max_multiplier: Rate::saturating_from_rational(25, 10),
+			escrow_per_share: Rate::one(),
+		}))])?;
+		Incentives::lock_for_boost(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(NATIVE), 100)?;
+		Rewards::add_share(&caller, &pool_id, 100);
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), pool_id)
+
 	add_allowance {
 		let caller: AccountId = whitelisted_caller();
 		set_balance(LIQUID, &caller, 10_000 * dollar(STABLECOIN));
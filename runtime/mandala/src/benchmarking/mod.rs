@@ -29,6 +29,7 @@ pub mod cdp_treasury;
 pub mod collator_selection;
 pub mod currencies;
 pub mod dex;
+pub mod dex_orders;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
@@ -36,9 +37,9 @@ pub mod homa;
 pub mod honzon;
 pub mod incentives;
 pub mod nominees_election;
-pub mod nutsfinance_stable_asset;
 pub mod prices;
 pub mod session_manager;
+pub mod stable_asset;
 pub mod transaction_pause;
 pub mod transaction_payment;
 
@@ -16,13 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Runtime};
+use crate::{
+	CollateralCurrencyIds, CurrencyId, DataProviderId, GetStableCurrencyId, MaxPriceSources, Origin, Price,
+	PriceSnapshotInterval, Prices, Ratio, Runtime,
+};
 
 use super::utils::feed_price;
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_prices::{AggregationMethod, PriceSource, PriceSourceSet};
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use orml_traits::Change;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::prelude::*;
 
 const STAKING: CurrencyId = GetStableCurrencyId::get();
 
@@ -39,6 +45,61 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::one())])?;
 		Prices::lock_price(Origin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	set_price_safety_params {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+		Change::NewValue(Some(3_600_000))
+	)
+
+	reset_circuit_breaker {
+		module_prices::TrippedCircuitBreakers::<Runtime>::insert(STAKING, 1);
+	}: _(RawOrigin::Root, STAKING)
+
+	set_price_sources {
+		let price_sources = PriceSourceSet {
+			sources: vec![(PriceSource::Oracle(DataProviderId::Aggregated), 1); MaxPriceSources::get() as usize],
+			aggregation: AggregationMethod::WeightedMedian,
+			min_sources: MaxPriceSources::get(),
+		};
+	}: _(RawOrigin::Root, STAKING, Some(price_sources))
+
+	on_initialize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+
+		let mut feed_data: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			feed_data.push((currency_id, Price::one()));
+			Prices::set_price_safety_params(
+				Origin::root(),
+				currency_id,
+				Change::NewValue(Some(Ratio::saturating_from_rational(1, 10))),
+				Change::NoChange,
+			)?;
+			// the price jumps beyond the bound, trip the circuit breaker
+			module_prices::LastAcceptedPrice::<Runtime>::insert(currency_id, (Price::one(), 1));
+		}
+		feed_price(feed_data)?;
+	}: {
+		Prices::on_initialize(2);
+	}
+
+	record_price_snapshots {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+
+		let mut feed_data: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			feed_data.push((currency_ids[i as usize], Price::one()));
+		}
+		feed_price(feed_data)?;
+	}: {
+		Prices::on_initialize(PriceSnapshotInterval::get());
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,122 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	dollar, AccountId, CurrencyId, GetLiquidCurrencyId, GetStakingCurrencyId, Rate, Runtime, StableAsset, System,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::StableAssetPoolId;
+use sp_runtime::FixedPointNumber;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const STAKING: CurrencyId = GetStakingCurrencyId::get();
+const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
+
+fn create_pool() -> Result<StableAssetPoolId, &'static str> {
+	let pool_id = StableAsset::next_pool_id();
+	StableAsset::create_pool(
+		RawOrigin::Root.into(),
+		vec![STAKING, LIQUID],
+		vec![1, 1],
+		Rate::saturating_from_rational(1, 1000),
+		Rate::saturating_from_rational(2, 1000),
+		Rate::saturating_from_rational(5, 1000),
+		100,
+		account("fee_recipient", 0, SEED),
+	)?;
+	Ok(pool_id)
+}
+
+fn inject_liquidity(maker: &AccountId, pool_id: StableAssetPoolId) -> Result<(), &'static str> {
+	set_balance(STAKING, maker, 1_000 * dollar(STAKING));
+	set_balance(LIQUID, maker, 10_000 * dollar(LIQUID));
+	StableAsset::mint(
+		RawOrigin::Signed(maker.clone()).into(),
+		pool_id,
+		vec![100 * dollar(STAKING), 1_000 * dollar(LIQUID)],
+		0,
+	)?;
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_stable_asset }
+
+	create_pool {
+		let fee_recipient: AccountId = account("fee_recipient", 0, SEED);
+	}: _(
+		RawOrigin::Root,
+		vec![STAKING, LIQUID],
+		vec![1, 1],
+		Rate::saturating_from_rational(1, 1000),
+		Rate::saturating_from_rational(2, 1000),
+		Rate::saturating_from_rational(5, 1000),
+		100,
+		fee_recipient
+	)
+
+	ramp_a {
+		let pool_id = create_pool()?;
+		let future_a_block = System::block_number() + 100;
+	}: _(RawOrigin::Root, pool_id, 1_000, future_a_block)
+
+	// mint into a pool which already has liquidity
+	mint {
+		let maker: AccountId = account("maker", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = create_pool()?;
+		inject_liquidity(&maker, pool_id)?;
+		set_balance(STAKING, &caller, 100 * dollar(STAKING));
+		set_balance(LIQUID, &caller, 1_000 * dollar(LIQUID));
+	}: _(RawOrigin::Signed(caller), pool_id, vec![10 * dollar(STAKING), 100 * dollar(LIQUID)], 0)
+
+	swap {
+		let maker: AccountId = account("maker", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = create_pool()?;
+		inject_liquidity(&maker, pool_id)?;
+		set_balance(STAKING, &caller, 100 * dollar(STAKING));
+	}: _(RawOrigin::Signed(caller), pool_id, 0, 1, dollar(STAKING), 0)
+
+	redeem_proportion {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = create_pool()?;
+		inject_liquidity(&caller, pool_id)?;
+	}: _(RawOrigin::Signed(caller), pool_id, 100 * dollar(LIQUID), vec![0, 0])
+
+	redeem_single {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = create_pool()?;
+		inject_liquidity(&caller, pool_id)?;
+	}: _(RawOrigin::Signed(caller), pool_id, 100 * dollar(LIQUID), 1, 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -55,7 +55,8 @@ pub use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use orml_tokens::CurrencyAdapter;
 use orml_traits::{
-	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProviderExtended, MultiCurrency,
+	create_median_value_data_provider, parameter_type_with_key, DataFeeder, DataProvider, DataProviderExtended,
+	MultiCurrency,
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use sp_api::impl_runtime_apis;
@@ -105,9 +106,10 @@ pub use sp_runtime::{Perbill, Percent, Permill, Perquintill};
 pub use authority::AuthorityConfigImpl;
 pub use constants::{fee::*, time::*};
 pub use primitives::{
-	evm::EstimateResourcesRequest, AccountId, AccountIndex, AirDropCurrencyId, Amount, AuctionId, AuthoritysOriginId,
-	Balance, BlockNumber, CurrencyId, DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share,
-	Signature, TokenSymbol, TradingPair,
+	evm::{EstimateResourcesRequest, TraceConfig, TraceInfo},
+	TraceConfig, TraceInfo}, AccountId, AccountIndex, AirDropCurrencyId, Amount, AuctionId, AuthoritysOriginId, Balance,
+	BlockNumber, CurrencyId, DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share, Signature,
+	TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
 	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
@@ -122,9 +124,6 @@ pub use runtime_common::{
 	AUSD, DOT, LDOT, RENBTC,
 };
 
-/// Import the stable_asset pallet.
-pub use nutsfinance_stable_asset;
-
 mod authority;
 mod benchmarking;
 pub mod constants;
@@ -176,7 +175,7 @@ parameter_types! {
 	pub UnreleasedNativeVaultAccountId: AccountId = PalletId(*b"aca/urls").into_account();
 	// Ecosystem modules
 	pub const StarportPalletId: PalletId = PalletId(*b"aca/stpt");
-	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const StableAssetPalletId: PalletId = PalletId(*b"aca/stas");
 }
 
 pub fn get_all_module_accounts() -> Vec<AccountId> {
@@ -777,6 +776,19 @@ impl DataFeeder<CurrencyId, Price, AccountId> for AggregatedDataProvider {
 	}
 }
 
+pub struct OracleSources;
+impl DataProvider<(DataProviderId, CurrencyId), TimeStampedPrice> for OracleSources {
+	fn get(key: &(DataProviderId, CurrencyId)) -> Option<TimeStampedPrice> {
+		let (provider_id, currency_id) = key;
+		match provider_id {
+			DataProviderId::Acala => AcalaOracle::get_no_op(currency_id),
+			DataProviderId::Aggregated => {
+				<AggregatedDataProvider as DataProviderExtended<_, _>>::get_no_op(currency_id)
+			}
+		}
+	}
+}
+
 pub struct DustRemovalWhitelist;
 impl Contains<AccountId> for DustRemovalWhitelist {
 	fn contains(a: &AccountId) -> bool {
@@ -840,16 +852,26 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxPriceSources: u32 = 8;
+	pub const PriceSnapshotInterval: BlockNumber = HOURS;
+	pub const MaxPriceSnapshots: u32 = 30 * 24;
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
+	type OracleSources = OracleSources;
+	type MaxPriceSources = MaxPriceSources;
+	type PriceSnapshotCurrencyIds = CollateralCurrencyIds;
+	type PriceSnapshotInterval = PriceSnapshotInterval;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
 	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type UnixTime = Timestamp;
 	type LiquidStakingExchangeRateProvider = LiquidStakingExchangeRateProvider;
 	type DEX = Dex;
 	type Currency = Currencies;
@@ -869,6 +891,15 @@ parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 }
 
+pub struct TokensHeldCurrencyIds;
+impl module_support::HeldCurrencyIds<AccountId, CurrencyId> for TokensHeldCurrencyIds {
+	fn held_currency_ids(who: &AccountId) -> Vec<CurrencyId> {
+		orml_tokens::Accounts::<Runtime>::iter_prefix(who)
+			.map(|(currency_id, _)| currency_id)
+			.collect()
+	}
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -877,6 +908,7 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
+	type HeldCurrencyIds = TokensHeldCurrencyIds;
 }
 
 pub struct EnsureRootOrTreasury;
@@ -968,12 +1000,14 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1053,11 +1087,25 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub MaxInterestRatePerSecChange: Rate = Rate::saturating_from_rational(1, 1_000_000_000_000u128);
+	// about 1% APR at zero utilization, 5% APR at the kink and 25% APR at full utilization
+	pub InterestRateBase: Rate = Rate::saturating_from_rational(317_097_920u128, 1_000_000_000_000_000_000u128);
+	pub InterestRateMultiplier: Rate = Rate::saturating_from_rational(1_585_489_599u128, 1_000_000_000_000_000_000u128);
+	pub InterestRateJumpMultiplier: Rate = Rate::saturating_from_rational(31_709_791_984u128, 1_000_000_000_000_000_000u128);
+	pub InterestRateKink: Ratio = Ratio::saturating_from_rational(80, 100);
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
+	type InterestRateModel = module_cdp_engine::KinkedUtilizationModel<
+		InterestRateBase,
+		InterestRateMultiplier,
+		InterestRateJumpMultiplier,
+		InterestRateKink,
+	>;
+	type MaxInterestRatePerSecChange = MaxInterestRatePerSecChange;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1098,6 +1146,8 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTradingPairs: u32 = 50;
+	pub const PriceObservationLimit: u32 = 1800;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1111,11 +1161,41 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type MaxTradingPairs = MaxTradingPairs;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PriceObservationLimit = PriceObservationLimit;
+	type TreasuryAccount = TreasuryAccount;
+	type Call = Call;
+	type StableAsset = StableAsset;
+}
+
+parameter_types! {
+	pub OrderDeposit: Balance = dollar(ACA);
+}
+
+parameter_type_with_key! {
+	pub MinimumOrderSupplyAmounts: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			CurrencyId::Token(_) => 10 * cent(*currency_id),
+			_ => Balance::max_value(), // unsupported
+		}
+	};
+}
+
+impl module_dex_orders::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type DEX = Dex;
+	type TradingPathLimit = TradingPathLimit;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type OrderDeposit = OrderDeposit;
+	type MinimumSupplyAmount = MinimumOrderSupplyAmounts;
+	type UnsignedPriority = runtime_common::DexOrdersUnsignedPriority;
+	type WeightInfo = weights::module_dex_orders::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -1206,6 +1286,31 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const MaxExtraRewardCurrencies: u32 = 4;
+	pub const MaxCampaigns: u32 = 20;
+	pub const MaxCampaignDuration: BlockNumber = 365 * DAYS;
+	pub CampaignDeposit: Balance = 10 * dollar(ACA);
+	pub const MaxBoostLockPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub const IncentivesBlocksPerYear: BlockNumber = 365 * DAYS;
+}
+
+parameter_type_with_key! {
+	pub IncentivesDebitExchangeRate: |currency_id: CurrencyId| -> ExchangeRate {
+		CdpEngine::get_debit_exchange_rate(*currency_id)
+	};
+	pub MinCampaignBudget: |currency_id: CurrencyId| -> Option<Balance> {
+		// only the whitelisted tokens are accepted as campaign rewards
+		match currency_id {
+			CurrencyId::Token(symbol) => match symbol {
+				TokenSymbol::ACA |
+				TokenSymbol::AUSD |
+				TokenSymbol::DOT |
+				TokenSymbol::LDOT => Some(100 * dollar(*currency_id)),
+				_ => None,
+			},
+			_ => None,
+		}
+	};
 }
 
 impl module_incentives::Config for Runtime {
@@ -1221,7 +1326,16 @@ impl module_incentives::Config for Runtime {
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type DebitExchangeRate = IncentivesDebitExchangeRate;
+	type BlocksPerYear = IncentivesBlocksPerYear;
 	type PalletId = IncentivesPalletId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	type MaxCampaigns = MaxCampaigns;
+	type MinCampaignBudget = MinCampaignBudget;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type CampaignDeposit = CampaignDeposit;
+	type MaxBoostLockPeriod = MaxBoostLockPeriod;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1398,11 +1512,14 @@ impl InstanceFilter<Call> for ProxyType {
 				matches!(
 					c,
 					Call::Authority(..)
-						| Call::Democracy(..) | Call::PhragmenElection(..)
+						| Call::Democracy(..)
+						| Call::PhragmenElection(..)
 						| Call::GeneralCouncil(..)
 						| Call::FinancialCouncil(..)
-						| Call::HomaCouncil(..) | Call::TechnicalCommittee(..)
-						| Call::Treasury(..) | Call::Bounties(..)
+						| Call::HomaCouncil(..)
+						| Call::TechnicalCommittee(..)
+						| Call::Treasury(..)
+						| Call::Bounties(..)
 						| Call::Tips(..)
 				)
 			}
@@ -1514,7 +1631,7 @@ pub type MultiCurrencyPrecompile = runtime_common::MultiCurrencyPrecompile<
 	AccountId,
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
-	Currencies,
+	module_currencies::EvmMultiCurrency<Runtime>,
 >;
 
 pub type NFTPrecompile =
@@ -1539,7 +1656,19 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	Runtime,
 >;
 pub type DexPrecompile =
-	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex, EVM>;
+pub type HonzonPrecompile =
+	runtime_common::HonzonPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Honzon>;
+pub type IncentivesPrecompile = runtime_common::IncentivesPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	Incentives,
+	EVM,
+	weights::module_incentives::WeightInfo<Runtime>,
+>;
+pub type HomaPrecompile =
+	runtime_common::HomaPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, HomaLite, EVM>;
 
 #[cfg(feature = "with-ethereum-compatibility")]
 static ISTANBUL_CONFIG: evm::Config = evm::Config::istanbul();
@@ -1561,6 +1690,9 @@ impl module_evm::Config for Runtime {
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		HomaPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -1853,30 +1985,152 @@ impl orml_xcm::Config for Runtime {
 }
 
 parameter_types! {
-	pub const Precision: u128 = 1000000000000000000u128; // 18 decimals
-	pub const FeePrecision: u128 = 10000000000u128; // 10 decimals
+	pub const MaxStableAssetPoolAssets: u32 = 5;
 }
 
-pub struct EnsurePoolAssetId;
-impl nutsfinance_stable_asset::traits::ValidateAssetId<CurrencyId> for EnsurePoolAssetId {
-	fn validate(currency_id: CurrencyId) -> bool {
-		matches!(currency_id, CurrencyId::StableAssetPoolToken(_))
-	}
-}
-
-impl nutsfinance_stable_asset::Config for Runtime {
+impl module_stable_asset::Config for Runtime {
 	type Event = Event;
-	type AssetId = CurrencyId;
-	type Balance = Balance;
-	type Assets = Tokens;
+	type Currency = Currencies;
+	type MaxPoolAssets = MaxStableAssetPoolAssets;
 	type PalletId = StableAssetPalletId;
-
-	type AtLeast64BitUnsigned = u128;
-	type Precision = Precision;
-	type FeePrecision = FeePrecision;
-	type WeightInfo = weights::nutsfinance_stable_asset::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
-	type EnsurePoolAssetId = EnsurePoolAssetId;
+	type WeightInfo = weights::module_stable_asset::WeightInfo<Runtime>;
+}
+
+/// The pool of `nutsfinance_stable_asset` as it is encoded in its storage. The fee rates are in
+/// `NUTS_FINANCE_FEE_PRECISION`. Its recorded balances and total supply are not migrated, the
+/// reserves and the pool token issuance are the source of truth.
+#[allow(dead_code)]
+#[derive(Encode, Decode)]
+struct NutsFinanceStableAssetPoolInfo {
+	pool_asset: CurrencyId,
+	assets: Vec<CurrencyId>,
+	precisions: Vec<u128>,
+	mint_fee: u128,
+	swap_fee: u128,
+	redeem_fee: u128,
+	total_supply: Balance,
+	a: u128,
+	a_block: BlockNumber,
+	future_a: u128,
+	future_a_block: BlockNumber,
+	balances: Vec<Balance>,
+	fee_recipient: AccountId,
+	account_id: AccountId,
+	precision: u128,
+}
+
+/// The denominator of the fee rates of `nutsfinance_stable_asset`.
+const NUTS_FINANCE_FEE_PRECISION: u128 = 10_000_000_000;
+
+/// Migrate from `nutsfinance_stable_asset`, which was the `StableAsset` pallet before
+/// `module_stable_asset`. Each old pool is recreated in `Pools` under the id of its pool token, so
+/// the pool token balances of the liquidity providers stay redeemable. The reserves held by the
+/// old pool account are moved to the new pallet account and become the balances of the new pool.
+///
+/// The pool token holders are not touched, so the migration only reads and writes `O(pools *
+/// assets)` storage items. A pool whose pool token id is taken by another pool is left in its old
+/// pool account for governance to handle.
+pub struct MigrateFromNutsFinanceStableAsset;
+impl frame_support::traits::OnRuntimeUpgrade for MigrateFromNutsFinanceStableAsset {
+	fn on_runtime_upgrade() -> Weight {
+		use frame_support::{storage::migration, traits::PalletInfoAccess, Blake2_128Concat};
+
+		let pallet_name = <StableAsset as PalletInfoAccess>::name().as_bytes();
+		if migration::take_storage_value::<primitives::StableAssetPoolId>(pallet_name, b"PoolCount", &[]).is_none() {
+			return RocksDbWeight::get().reads(1);
+		}
+		// drain the old pools before inserting the new ones, they share the storage prefix.
+		let old_pools = migration::storage_key_iter::<
+			primitives::StableAssetPoolId,
+			NutsFinanceStableAssetPoolInfo,
+			Blake2_128Concat,
+		>(pallet_name, b"Pools")
+		.drain()
+		.collect::<Vec<_>>();
+		let mut reads: Weight = (old_pools.len() as Weight).saturating_add(2);
+		let mut writes: Weight = (old_pools.len() as Weight).saturating_add(2);
+
+		let module_account_id: AccountId = StableAssetPalletId::get().into_account();
+		let mut next_pool_id = StableAsset::next_pool_id();
+		for (old_pool_id, old_pool) in old_pools {
+			let pool_id = match old_pool.pool_asset {
+				CurrencyId::StableAssetPoolToken(pool_id) if !module_stable_asset::Pools::<Runtime>::contains_key(pool_id) => {
+					pool_id
+				}
+				pool_asset => {
+					log::error!(
+						target: "stable-asset",
+						"pool {:?} with pool token {:?} can't be migrated, its reserves are left in {:?}",
+						old_pool_id,
+						pool_asset,
+						old_pool.account_id
+					);
+					continue;
+				}
+			};
+
+			let mut balances: Vec<Balance> = Vec::with_capacity(old_pool.assets.len());
+			for currency_id in old_pool.assets.iter() {
+				let reserve = <Currencies as MultiCurrency<AccountId>>::free_balance(*currency_id, &old_pool.account_id);
+				let moved = match <Currencies as MultiCurrency<AccountId>>::transfer(
+					*currency_id,
+					&old_pool.account_id,
+					&module_account_id,
+					reserve,
+				) {
+					Ok(()) => reserve,
+					Err(e) => {
+						log::error!(
+							target: "stable-asset",
+							"move reserve {:?} of pool {:?} failed: {:?}",
+							currency_id,
+							old_pool_id,
+							e
+						);
+						0
+					}
+				};
+				balances.push(moved);
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(2);
+			}
+
+			let fee_rate = |fee: u128| Rate::saturating_from_rational(fee, NUTS_FINANCE_FEE_PRECISION);
+			let clamp_a = |a: u128| a.max(1).min(module_stable_asset::MAX_A);
+			module_stable_asset::Pools::<Runtime>::insert(
+				pool_id,
+				module_stable_asset::PoolInfo {
+					assets: old_pool.assets,
+					precisions: old_pool.precisions,
+					balances,
+					mint_fee: fee_rate(old_pool.mint_fee),
+					swap_fee: fee_rate(old_pool.swap_fee),
+					redeem_fee: fee_rate(old_pool.redeem_fee),
+					fee_recipient: old_pool.fee_recipient,
+					a: clamp_a(old_pool.a),
+					a_block: old_pool.a_block,
+					future_a: clamp_a(old_pool.future_a),
+					future_a_block: old_pool.future_a_block,
+				},
+			);
+			next_pool_id = next_pool_id.max(pool_id.saturating_add(1));
+		}
+		module_stable_asset::NextPoolId::<Runtime>::put(next_pool_id);
+
+		RocksDbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::{storage::migration, traits::PalletInfoAccess};
+
+		frame_support::ensure!(
+			!migration::have_storage_value(<StableAsset as PalletInfoAccess>::name().as_bytes(), b"PoolCount", &[]),
+			"nutsfinance_stable_asset storage is not cleared"
+		);
+		Ok(())
+	}
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -1909,8 +2163,18 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	(
+		module_loans::MigrateDebitPrincipals<Runtime>,
+		module_prices::PruneOutOfRangePriceSnapshots<Runtime>,
+		MigrateFromNutsFinanceStableAsset,
+	),
+>;
 
 #[allow(clippy::large_enum_variant)]
 construct_runtime! {
@@ -1976,6 +2240,7 @@ construct_runtime! {
 		// Acala Core
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 110,
 		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 111,
+		DexOrders: module_dex_orders::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 112,
 
 		// Honzon
 		AuctionManager: module_auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 120,
@@ -2033,7 +2298,7 @@ construct_runtime! {
 		SessionManager: module_session_manager::{Pallet, Call, Storage, Event<T>, Config<T>} = 195,
 
 		// Stable asset
-		StableAsset: nutsfinance_stable_asset::{Pallet, Call, Storage, Event<T>} = 200,
+		StableAsset: module_stable_asset::{Pallet, Call, Storage, Event<T>} = 200,
 
 		// Dev
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -2160,6 +2425,125 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_best_price_swap_path_with_exact_supply(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPath<Balance>> {
+			Dex::get_best_price_swap_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+				.map(|(path, amount)| module_dex_rpc_runtime_api::SwapPath { path, amount })
+		}
+
+		fn get_best_price_swap_path_with_exact_target(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPath<Balance>> {
+			Dex::get_best_price_swap_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+				.map(|(path, amount)| module_dex_rpc_runtime_api::SwapPath { path, amount })
+		}
+
+		fn get_time_weighted_average_price(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: u32,
+		) -> Option<ExchangeRate> {
+			Dex::get_time_weighted_average_price(currency_id_a, currency_id_b, window)
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_cdp_health(
+			who: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::CDPHealth<Balance>> {
+			CdpEngine::get_cdp_health(&who, currency_id)
+		}
+
+		fn get_cdp_healths(
+			who: AccountId,
+		) -> Vec<(CurrencyId, module_cdp_engine_rpc_runtime_api::CDPHealth<Balance>)> {
+			CollateralCurrencyIds::get()
+				.into_iter()
+				.filter_map(|currency_id| {
+					CdpEngine::get_cdp_health(&who, currency_id).map(|health| (currency_id, health))
+				})
+				.collect()
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<
+		Block,
+		BlockNumber,
+	> for Runtime {
+		fn get_price_snapshot_at(
+			currency_id: CurrencyId,
+			block_number: BlockNumber,
+		) -> Option<(BlockNumber, Price)> {
+			Prices::get_price_snapshot_at(currency_id, block_number)
+		}
+
+		fn get_price_snapshots(
+			currency_id: CurrencyId,
+		) -> Vec<(BlockNumber, Price)> {
+			Prices::get_price_snapshots(currency_id)
+		}
+
+		fn get_price_snapshot_aggregates(
+			currency_id: CurrencyId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<module_prices_rpc_runtime_api::PriceAggregates> {
+			Prices::get_price_snapshot_aggregates(currency_id, from, to).map(|aggregates| {
+				module_prices_rpc_runtime_api::PriceAggregates {
+					min: aggregates.min,
+					max: aggregates.max,
+					average: aggregates.average,
+					count: aggregates.count,
+				}
+			})
+		}
+	}
+
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId<AccountId>,
+		Balance,
+	> for Runtime {
+		fn get_pending_rewards(
+			pool_id: module_incentives::PoolId<AccountId>,
+			who: AccountId,
+		) -> Vec<module_incentives_rpc_runtime_api::RewardBalance<Balance>> {
+			Incentives::pending_rewards_of(&pool_id, &who)
+				.into_iter()
+				.map(|(currency_id, amount)| module_incentives_rpc_runtime_api::RewardBalance { currency_id, amount })
+				.collect()
+		}
+
+		fn get_pool_emission(
+			pool_id: module_incentives::PoolId<AccountId>,
+		) -> module_incentives_rpc_runtime_api::PoolEmission<Balance> {
+			module_incentives_rpc_runtime_api::PoolEmission {
+				rewards_per_period: Incentives::pool_emission_per_period(&pool_id)
+					.into_iter()
+					.map(|(currency_id, amount)| {
+						module_incentives_rpc_runtime_api::RewardBalance { currency_id, amount }
+					})
+					.collect(),
+				apr: Incentives::pool_apr(&pool_id),
+			}
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2262,6 +2646,65 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			module_evm::Runner::<Runtime>::trace_call(
+				from,
+				from,
+				to,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				<Runtime as module_evm::Config>::config(),
+				trace_config,
+			)
+		}
+
+		fn trace_create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			module_evm::Runner::<Runtime>::trace_create(
+				from,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				<Runtime as module_evm::Config>::config(),
+				trace_config,
+			)
+		}
+
+		fn trace_extrinsic(
+			extrinsic: Vec<u8>,
+			trace_config: TraceConfig,
+		) -> Result<TraceInfo, sp_runtime::DispatchError> {
+			let utx = UncheckedExtrinsic::decode(&mut &*extrinsic)
+				.map_err(|_| sp_runtime::DispatchError::Other("Invalid parameter extrinsic, decode failed"))?;
+
+			let (result, trace_info) = module_evm::Runner::<Runtime>::trace(trace_config, || {
+				Executive::apply_extrinsic(utx)
+			});
+			result.map_err(|_| sp_runtime::DispatchError::Other("Invalid parameter extrinsic, apply failed"))?;
+			trace_info.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, no evm execution"))
+		}
+
+		fn block_gas_limit() -> u64 {
+			runtime_common::BlockGasLimit::get()
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2298,6 +2741,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, module_homa_lite, HomaLiteBench::<Runtime>);
 
 			orml_list_benchmark!(list, extra, module_dex, benchmarking::dex);
+			orml_list_benchmark!(list, extra, module_dex_orders, benchmarking::dex_orders);
 			orml_list_benchmark!(list, extra, module_auction_manager, benchmarking::auction_manager);
 			orml_list_benchmark!(list, extra, module_cdp_engine, benchmarking::cdp_engine);
 			orml_list_benchmark!(list, extra, module_collator_selection, benchmarking::collator_selection);
@@ -2363,6 +2807,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
 			add_benchmark!(params, batches, module_homa_lite, HomaLiteBench::<Runtime>);
 			orml_add_benchmark!(params, batches, module_dex, benchmarking::dex);
+			orml_add_benchmark!(params, batches, module_dex_orders, benchmarking::dex_orders);
 			orml_add_benchmark!(params, batches, module_auction_manager, benchmarking::auction_manager);
 			orml_add_benchmark!(params, batches, module_cdp_engine, benchmarking::cdp_engine);
 			orml_add_benchmark!(params, batches, module_collator_selection, benchmarking::collator_selection);
@@ -2379,6 +2824,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, module_session_manager, benchmarking::session_manager);
+			orml_add_benchmark!(params, batches, module_stable_asset, benchmarking::stable_asset);
 
 			orml_add_benchmark!(params, batches, orml_tokens, benchmarking::tokens);
 			orml_add_benchmark!(params, batches, orml_vesting, benchmarking::vesting);
@@ -2389,7 +2835,6 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, orml_oracle, benchmarking::oracle);
 
 			orml_add_benchmark!(params, batches, ecosystem_chainsafe, benchmarking::chainsafe_transfer);
-			orml_add_benchmark!(params, batches, nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset);
 
 			if batches.is_empty() { return Err("Benchmark not found for this module.".into()) }
 			Ok(batches)
@@ -2464,4 +2909,102 @@ mod tests {
 			) > 0
 		);
 	}
+
+	#[test]
+	fn migrate_from_nuts_finance_stable_asset_keeps_pool_token_value() {
+		use frame_support::{
+			assert_ok,
+			storage::migration,
+			traits::{OnRuntimeUpgrade, PalletInfoAccess},
+			Blake2_128Concat, StorageHasher,
+		};
+
+		let storage = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			let pallet_name = <StableAsset as PalletInfoAccess>::name().as_bytes();
+			let old_pool_account: AccountId = PalletId(*b"nuts/sta").into_sub_account(0u32);
+			let alice = AccountId::from([1u8; 32]);
+			let bob = AccountId::from([2u8; 32]);
+			let pool_token = CurrencyId::StableAssetPoolToken(0);
+
+			// the old pool holds 1_000 DOT and 3_000 LDOT, alice owns 3/4 of the pool token and bob 1/4
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::deposit(
+				DOT,
+				&old_pool_account,
+				1_000 * dollar(DOT)
+			));
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::deposit(
+				LDOT,
+				&old_pool_account,
+				3_000 * dollar(LDOT)
+			));
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::deposit(
+				pool_token,
+				&alice,
+				3_000 * dollar(DOT)
+			));
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::deposit(
+				pool_token,
+				&bob,
+				1_000 * dollar(DOT)
+			));
+			migration::put_storage_value(pallet_name, b"PoolCount", &[], 1u32);
+			migration::put_storage_value(
+				pallet_name,
+				b"Pools",
+				&Blake2_128Concat::hash(&0u32.encode()),
+				NutsFinanceStableAssetPoolInfo {
+					pool_asset: pool_token,
+					assets: vec![DOT, LDOT],
+					precisions: vec![100_000_000, 100_000_000],
+					mint_fee: 0,
+					swap_fee: 4_000_000,
+					redeem_fee: 0,
+					total_supply: 4_000 * dollar(DOT),
+					a: 100,
+					a_block: 0,
+					future_a: 100,
+					future_a_block: 0,
+					balances: vec![1_000 * dollar(DOT) * 100_000_000, 3_000 * dollar(LDOT) * 100_000_000],
+					fee_recipient: bob.clone(),
+					account_id: old_pool_account.clone(),
+					precision: 1_000_000_000_000_000_000,
+				},
+			);
+
+			MigrateFromNutsFinanceStableAsset::on_runtime_upgrade();
+
+			assert!(!migration::have_storage_value(pallet_name, b"PoolCount", &[]));
+			assert_eq!(StableAsset::next_pool_id(), 1);
+			let pool = StableAsset::pools(0).unwrap();
+			assert_eq!(pool.assets, vec![DOT, LDOT]);
+			assert_eq!(pool.balances, vec![1_000 * dollar(DOT), 3_000 * dollar(LDOT)]);
+			assert_eq!(pool.swap_fee, Rate::saturating_from_rational(4, 10_000));
+			assert_eq!(pool.a, 100);
+			assert_eq!(Currencies::free_balance(DOT, &old_pool_account), 0);
+			assert_eq!(Currencies::free_balance(LDOT, &old_pool_account), 0);
+			assert_eq!(Currencies::total_issuance(pool_token), 4_000 * dollar(DOT));
+
+			// the pool token is still worth its share of the reserves
+			assert_ok!(StableAsset::redeem_proportion(
+				Origin::signed(alice.clone()),
+				0,
+				3_000 * dollar(DOT),
+				vec![0, 0]
+			));
+			assert_eq!(Currencies::free_balance(DOT, &alice), 750 * dollar(DOT));
+			assert_eq!(Currencies::free_balance(LDOT, &alice), 2_250 * dollar(LDOT));
+
+			assert_ok!(StableAsset::redeem_proportion(
+				Origin::signed(bob.clone()),
+				0,
+				1_000 * dollar(DOT),
+				vec![0, 0]
+			));
+			assert_eq!(Currencies::free_balance(DOT, &bob), 250 * dollar(DOT));
+			assert_eq!(Currencies::free_balance(LDOT, &bob), 750 * dollar(LDOT));
+		});
+	}
 }
@@ -25,6 +25,7 @@ pub mod module_cdp_treasury;
 pub mod module_collator_selection;
 pub mod module_currencies;
 pub mod module_dex;
+pub mod module_dex_orders;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
@@ -36,6 +37,7 @@ pub mod module_nft;
 pub mod module_nominees_election;
 pub mod module_prices;
 pub mod module_session_manager;
+pub mod module_stable_asset;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
 
@@ -47,4 +49,3 @@ pub mod orml_tokens;
 pub mod orml_vesting;
 
 pub mod ecosystem_chainsafe;
-pub mod nutsfinance_stable_asset;
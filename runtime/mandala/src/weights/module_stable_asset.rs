@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for nutsfinance_stable_asset
+//! Autogenerated weights for module_stable_asset
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
 //! DATE: 2021-08-25, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
@@ -44,34 +44,29 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
-/// Weight functions for nutsfinance_stable_asset.
+/// Weight functions for module_stable_asset.
 pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> nutsfinance_stable_asset::weights::WeightInfo for WeightInfo<T> {
+impl<T: frame_system::Config> module_stable_asset::WeightInfo for WeightInfo<T> {
 	fn create_pool() -> Weight {
 		(43_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
-	fn modify_a() -> Weight {
+	fn ramp_a() -> Weight {
 		(32_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	fn mint() -> Weight {
 		(209_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	fn swap() -> Weight {
 		(90_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
-	fn collect_fee() -> Weight {
-		(76_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-	}
 	fn redeem_proportion() -> Weight {
 		(139_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
@@ -82,9 +77,4 @@ impl<T: frame_system::Config> nutsfinance_stable_asset::weights::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
-	fn redeem_multi() -> Weight {
-		(141_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
-	}
 }